@@ -0,0 +1,60 @@
+// Service worker generated by `dx` for progressive web apps.
+//
+// The cache name is derived from the set of hashed assets in the build, so any change to the app
+// produces a new worker that installs alongside the old one, precaches the new assets, and then
+// removes the stale caches once it activates.
+const CACHE_PREFIX = "{cache_prefix}";
+const CACHE_NAME = "{cache_name}";
+const OFFLINE_FALLBACK = new URL("{offline_fallback}", self.location).href;
+const PRECACHE = {precache};
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    caches
+      .open(CACHE_NAME)
+      .then((cache) => cache.addAll(PRECACHE.map((url) => new Request(url, { cache: "reload" }))))
+      .then(() => self.skipWaiting())
+  );
+});
+
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) =>
+        Promise.all(
+          keys
+            .filter((key) => key.startsWith(CACHE_PREFIX) && key !== CACHE_NAME)
+            .map((key) => caches.delete(key))
+        )
+      )
+      .then(() => self.clients.claim())
+  );
+});
+
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  if (request.method !== "GET") {
+    return;
+  }
+
+  // Navigations go to the network first so server rendered pages stay fresh, falling back to the
+  // cached app shell when offline.
+  if (request.mode === "navigate") {
+    event.respondWith(
+      fetch(request).catch(() =>
+        caches.open(CACHE_NAME).then((cache) => cache.match(OFFLINE_FALLBACK))
+      )
+    );
+    return;
+  }
+
+  // Everything else is served from the precache when possible. Bundled assets are content hashed,
+  // so a cached response is never stale.
+  event.respondWith(
+    caches
+      .open(CACHE_NAME)
+      .then((cache) => cache.match(request, { ignoreSearch: true }))
+      .then((cached) => cached || fetch(request))
+  );
+});
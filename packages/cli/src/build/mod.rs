@@ -11,6 +11,7 @@
 // Platform-specific build code
 mod android;
 mod apple;
+mod pwa;
 mod web;
 mod windows;
 
@@ -0,0 +1,289 @@
+//! Progressive web app generation for web bundles.
+//!
+//! When `[web.pwa]` is set in `Dioxus.toml`, release web builds also emit:
+//! - the app icon resized to each configured size and bundled through the asset system
+//! - a `manifest.webmanifest` next to `index.html`
+//! - a `sw.js` service worker that precaches the app shell and every bundled asset
+//!
+//! The service worker's cache name is derived from the set of hashed asset paths, so the browser
+//! picks up a new worker (and drops the old cache) whenever the bundled assets change.
+
+use crate::{BuildRequest, Result, WebPwaConfig, opt::AppManifest};
+use anyhow::Context;
+use manganis::{AssetOptions, BundledAsset, ImageSize};
+use manganis_core::AssetVariant;
+use std::{
+    collections::HashSet,
+    hash::{Hash, Hasher},
+    path::PathBuf,
+};
+
+const DEFAULT_PWA_ICON_PNG: &[u8] = include_bytes!("../../assets/default_icon.png");
+const SERVICE_WORKER_TEMPLATE: &str = include_str!("../../assets/web/sw.js");
+
+impl BuildRequest {
+    /// Get the pwa config if this build should emit a progressive web app.
+    ///
+    /// Only bundled release builds hash their assets, so dev builds never get a service worker that
+    /// could serve stale files.
+    pub(crate) fn pwa_config(&self) -> Option<&WebPwaConfig> {
+        if !self.should_bundle_to_asset() {
+            return None;
+        }
+
+        self.config.web.pwa.as_ref()
+    }
+
+    /// Register the pwa icons with the asset system and write the web manifest and service worker
+    /// into the root of the web bundle.
+    ///
+    /// This must be called after the wasm and js are registered with the asset system so the service
+    /// worker precaches them.
+    pub(crate) fn write_pwa(&self, assets: &mut AppManifest) -> Result<()> {
+        let Some(pwa) = self.pwa_config() else {
+            return Ok(());
+        };
+
+        let icons = self
+            .register_pwa_icons(pwa, assets)
+            .context("Failed to generate pwa icons")?;
+
+        let manifest = self.pwa_manifest_contents(pwa, &icons);
+        std::fs::write(
+            self.root_dir().join("manifest.webmanifest"),
+            serde_json::to_string_pretty(&manifest)?,
+        )?;
+
+        if pwa.service_worker {
+            std::fs::write(
+                self.root_dir().join("sw.js"),
+                self.service_worker_contents(pwa, assets),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// The tags injected into the head of index.html to link the manifest and register the worker
+    pub(crate) fn pwa_head_elements(&self) -> String {
+        let Some(pwa) = self.pwa_config() else {
+            return String::new();
+        };
+
+        let mut head =
+            String::from(r#"<link rel="manifest" href="/{base_path}/manifest.webmanifest">"#);
+
+        if let Some(theme_color) = &pwa.theme_color {
+            head.push_str(&format!(
+                r#"<meta name="theme-color" content="{theme_color}">"#
+            ));
+        }
+
+        if pwa.service_worker {
+            head.push_str(
+                r#"<script>if ("serviceWorker" in navigator) { window.addEventListener("load", () => navigator.serviceWorker.register("/{base_path}/sw.js")); }</script>"#,
+            );
+        }
+
+        head
+    }
+
+    /// Resize the source icon to every configured size, returning each size with its bundled path
+    fn register_pwa_icons(
+        &self,
+        pwa: &WebPwaConfig,
+        assets: &mut AppManifest,
+    ) -> Result<Vec<(u32, String)>> {
+        let source = self.pwa_icon_source(pwa)?;
+
+        let mut icons = Vec::with_capacity(pwa.icon_sizes.len());
+        for &size in &pwa.icon_sizes {
+            let options = AssetOptions::image()
+                .with_png()
+                .with_size(ImageSize::Manual {
+                    width: size,
+                    height: size,
+                })
+                .into_asset_options();
+            let asset = assets.register_asset(&source, options)?;
+            icons.push((size, asset.bundled_path().to_string()));
+        }
+
+        Ok(icons)
+    }
+
+    /// Find the image the pwa icons are generated from. We prefer the explicit pwa icon, then the
+    /// first png bundle icon, and finally fall back to the dioxus logo.
+    fn pwa_icon_source(&self, pwa: &WebPwaConfig) -> Result<PathBuf> {
+        if let Some(icon) = &pwa.icon {
+            let path = self.crate_dir().join(icon);
+            if !path.exists() {
+                anyhow::bail!("PWA icon {} does not exist", path.display());
+            }
+            return Ok(path);
+        }
+
+        let bundle_png = self
+            .config
+            .bundle
+            .icon
+            .iter()
+            .flatten()
+            .map(|icon| self.crate_dir().join(icon))
+            .find(|path| path.extension().is_some_and(|ext| ext == "png") && path.exists());
+        if let Some(path) = bundle_png {
+            return Ok(path);
+        }
+
+        // The wasm dir is cleared after the assets are copied, so the default icon doesn't linger
+        let default_icon = self.wasm_bindgen_out_dir().join("pwa-icon.png");
+        std::fs::write(&default_icon, DEFAULT_PWA_ICON_PNG)?;
+        Ok(default_icon)
+    }
+
+    fn pwa_manifest_contents(
+        &self,
+        pwa: &WebPwaConfig,
+        icons: &[(u32, String)],
+    ) -> serde_json::Value {
+        let name = pwa
+            .name
+            .clone()
+            .unwrap_or_else(|| self.config.web.app.title.clone());
+        let short_name = pwa.short_name.clone().unwrap_or_else(|| name.clone());
+        let purpose = match pwa.maskable_icons {
+            true => "any maskable",
+            false => "any",
+        };
+
+        let mut manifest = serde_json::json!({
+            "name": name,
+            "short_name": short_name,
+            "start_url": pwa.start_url,
+            "scope": pwa.scope,
+            "display": pwa.display.as_str(),
+            "icons": icons
+                .iter()
+                .map(|(size, bundled_path)| {
+                    serde_json::json!({
+                        "src": format!("assets/{bundled_path}"),
+                        "sizes": format!("{size}x{size}"),
+                        "type": "image/png",
+                        "purpose": purpose,
+                    })
+                })
+                .collect::<Vec<_>>(),
+        });
+
+        let optional = [
+            ("description", &pwa.description),
+            ("theme_color", &pwa.theme_color),
+            ("background_color", &pwa.background_color),
+        ];
+        for (key, value) in optional {
+            if let Some(value) = value {
+                manifest[key] = serde_json::Value::String(value.clone());
+            }
+        }
+
+        manifest
+    }
+
+    fn service_worker_contents(&self, pwa: &WebPwaConfig, assets: &AppManifest) -> String {
+        let precache = pwa_precache_urls(pwa, assets.unique_assets());
+        let cache_prefix = format!("dioxus-{}-", self.executable_name());
+        let cache_name = format!("{cache_prefix}{}", pwa_cache_version(&precache));
+
+        SERVICE_WORKER_TEMPLATE
+            .replace("{cache_prefix}", &cache_prefix)
+            .replace("{cache_name}", &cache_name)
+            .replace("{offline_fallback}", &pwa.offline_fallback)
+            .replace(
+                "{precache}",
+                &serde_json::to_string(&precache).expect("urls are always serializable"),
+            )
+    }
+}
+
+/// Collect the urls the service worker precaches, relative to the worker's location.
+///
+/// Folder assets are skipped since a directory can't be fetched, and a single failed request
+/// would abort the entire install.
+fn pwa_precache_urls<'a>(
+    pwa: &WebPwaConfig,
+    assets: impl Iterator<Item = &'a BundledAsset>,
+) -> Vec<String> {
+    let mut urls = vec![
+        "./".to_string(),
+        format!("./{}", pwa.offline_fallback.trim_start_matches("./")),
+        "./manifest.webmanifest".to_string(),
+    ];
+
+    let mut bundled: Vec<_> = assets
+        .filter(|asset| !matches!(asset.options().variant(), AssetVariant::Folder(_)))
+        .map(|asset| format!("./assets/{}", asset.bundled_path()))
+        .collect();
+    bundled.sort();
+    urls.extend(bundled);
+
+    for extra in &pwa.precache {
+        urls.push(format!(
+            "./{}",
+            extra.trim_start_matches('/').trim_start_matches("./")
+        ));
+    }
+
+    // `cache.addAll` rejects duplicate requests
+    let mut seen = HashSet::new();
+    urls.retain(|url| seen.insert(url.clone()));
+    urls
+}
+
+/// A stable version for the precache. The bundled asset paths contain their content hash, so this
+/// changes whenever any asset does.
+fn pwa_cache_version(precache: &[String]) -> String {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    precache.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use manganis::macro_helpers::create_bundled_asset;
+
+    fn pwa_config() -> WebPwaConfig {
+        toml::from_str("").expect("empty pwa config should parse")
+    }
+
+    #[test]
+    fn precache_skips_folders_and_duplicates() {
+        let mut pwa = pwa_config();
+        pwa.precache = vec!["/index.html".to_string(), "robots.txt".to_string()];
+
+        let assets = [
+            create_bundled_asset("/app/main.css", AssetOptions::css().into_asset_options()),
+            create_bundled_asset("/app/static", AssetOptions::folder().into_asset_options()),
+        ];
+        let urls = pwa_precache_urls(&pwa, assets.iter());
+
+        assert_eq!(
+            urls,
+            [
+                "./".to_string(),
+                "./index.html".to_string(),
+                "./manifest.webmanifest".to_string(),
+                format!("./assets/{}", assets[0].bundled_path()),
+                "./robots.txt".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn cache_version_tracks_asset_set() {
+        let a = vec!["./".to_string(), "./assets/main-abc.css".to_string()];
+        let b = vec!["./".to_string(), "./assets/main-def.css".to_string()];
+        assert_eq!(pwa_cache_version(&a), pwa_cache_version(&a));
+        assert_ne!(pwa_cache_version(&a), pwa_cache_version(&b));
+    }
+}
@@ -166,12 +166,19 @@ impl BuildRequest {
                 .emit()
                 .context("Failed to emit wasm split modules")?;
 
+            // Keep track of which modules were written so we can report the chunk map once we're done
+            let mut chunk_map = Vec::new();
+
             // Write the chunks that contain shared imports
             // These will be in the format of chunk_0_modulename.wasm - this is hardcoded in wasm-split
             tracing::debug!("Writing split chunks to disk");
             for (idx, chunk) in modules.chunks.iter().enumerate() {
                 let path = bindgen_outdir.join(format!("chunk_{}_{}.wasm", idx, chunk.module_name));
                 wasm_opt::write_wasm(&chunk.bytes, &path, &wasm_opt_options).await?;
+                chunk_map.push(format!(
+                    "  chunk {idx}: {}",
+                    format_wasm_size(std::fs::metadata(&path)?.len())
+                ));
                 writeln!(
                     glue,
                     "export const __wasm_split_load_chunk_{idx} = makeLoad(\"/{base_path}/assets/{url}\", [], fusedImports);",
@@ -193,6 +200,25 @@ impl BuildRequest {
                 let path = bindgen_outdir.join(format!("module_{idx}_{comp_name}.wasm"));
                 wasm_opt::write_wasm(&module.bytes, &path, &wasm_opt_options).await?;
 
+                let mut relies_on_chunks = module.relies_on_chunks.iter().collect::<Vec<_>>();
+                relies_on_chunks.sort();
+                chunk_map.push(format!(
+                    "  {comp_name} ({module}): {size}{deps}",
+                    module = module.module_name,
+                    size = format_wasm_size(std::fs::metadata(&path)?.len()),
+                    deps = match relies_on_chunks.is_empty() {
+                        true => String::new(),
+                        false => format!(
+                            ", loads chunks {}",
+                            relies_on_chunks
+                                .iter()
+                                .map(|idx| idx.to_string())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                    }
+                ));
+
                 let hash_id = module
                     .hash_id
                     .as_ref()
@@ -218,6 +244,14 @@ impl BuildRequest {
                 )?;
             }
 
+            tracing::info!(
+                dx_src = ?TraceSrc::Bundle,
+                "Split the wasm bundle into {} lazy modules and {} shared chunks:\n{}",
+                modules.modules.len(),
+                modules.chunks.len(),
+                chunk_map.join("\n")
+            );
+
             // Write the js binding
             // It's not registered as an asset since it will get included in the main.js file
             let js_output_path = bindgen_outdir.join("__wasm_split.js");
@@ -273,6 +307,9 @@ impl BuildRequest {
             )?;
         }
 
+        // Generate the pwa manifest, icons and service worker if the user has opted into them
+        self.write_pwa(assets)?;
+
         // Write the index.html file with the pre-configured contents we got from pre-rendering
         self.write_index_html(assets)?;
 
@@ -455,6 +492,9 @@ __wbg_init({{module_or_path: "/{}/{wasm_path}"}}).then((wasm) => {{
             }
         }
 
+        // Link the pwa manifest and register the service worker
+        head_resources.push_str(&self.pwa_head_elements());
+
         // Inject any resources from manganis into the head
         for asset in assets.unique_assets() {
             let asset_path = asset.bundled_path();
@@ -607,3 +647,8 @@ __wbg_init({{module_or_path: "/{}/{wasm_path}"}}).then((wasm) => {{
         canonical_path.starts_with(&canonical_static)
     }
 }
+
+/// Format the size of a wasm module for the chunk map of a split bundle.
+fn format_wasm_size(bytes: u64) -> String {
+    format!("{:.1} kB", bytes as f64 / 1000.0)
+}
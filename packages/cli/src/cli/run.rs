@@ -170,6 +170,8 @@ impl RunArgs {
                 ServeUpdate::OpenApp => {}
                 ServeUpdate::RequestRebuild => {}
                 ServeUpdate::CycleHotreloadMode => {}
+                ServeUpdate::ToggleProfiling => {}
+                ServeUpdate::ToggleReactiveGraph => {}
                 ServeUpdate::OpenDebugger { .. } => {}
                 ServeUpdate::Redraw => {}
                 ServeUpdate::TracingLog { .. } => {}
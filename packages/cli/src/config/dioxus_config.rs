@@ -139,6 +139,7 @@ impl Default for DioxusConfig {
                 },
                 pre_compress: false,
                 wasm_opt: Default::default(),
+                pwa: None,
             },
             bundle: BundleConfig::default(),
             components: ComponentConfig::default(),
@@ -191,4 +192,27 @@ mod tests {
         let config: DioxusConfig = toml::from_str(source).expect("parse config");
         assert_eq!(config.application.public_dir.as_deref(), None);
     }
+
+    #[test]
+    fn pwa_is_disabled_by_default() {
+        let config = DioxusConfig::default();
+        assert!(config.web.pwa.is_none());
+    }
+
+    #[test]
+    fn pwa_section_uses_defaults() {
+        let source = r##"
+            [web.pwa]
+            name = "My App"
+            theme_color = "#000000"
+        "##;
+
+        let config: DioxusConfig = toml::from_str(source).expect("parse config");
+        let pwa = config.web.pwa.expect("pwa section should be parsed");
+        assert_eq!(pwa.name.as_deref(), Some("My App"));
+        assert_eq!(pwa.icon_sizes, vec![192, 512]);
+        assert_eq!(pwa.display.as_str(), "standalone");
+        assert_eq!(pwa.offline_fallback, "index.html");
+        assert!(pwa.service_worker);
+    }
 }
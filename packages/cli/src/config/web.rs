@@ -26,6 +26,11 @@ pub(crate) struct WebConfig {
     /// The wasm-opt configuration
     #[serde(default)]
     pub(crate) wasm_opt: WasmOptConfig,
+
+    /// Progressive web app configuration. When present, release web builds emit a
+    /// `manifest.webmanifest`, resized icons, and a service worker that precaches the app.
+    #[serde(default)]
+    pub(crate) pwa: Option<WebPwaConfig>,
 }
 
 impl Default for WebConfig {
@@ -38,6 +43,7 @@ impl Default for WebConfig {
             proxy: Default::default(),
             watcher: Default::default(),
             resource: Default::default(),
+            pwa: None,
         }
     }
 }
@@ -126,6 +132,121 @@ impl Default for WebAppConfig {
     }
 }
 
+/// The `[web.pwa]` section of `Dioxus.toml`
+///
+/// ```toml
+/// [web.pwa]
+/// name = "My App"
+/// short_name = "App"
+/// theme_color = "#0f172a"
+/// icon = "assets/icon.png"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct WebPwaConfig {
+    /// The name of the app shown in install prompts. Defaults to `web.app.title`.
+    #[serde(default)]
+    pub(crate) name: Option<String>,
+
+    /// A shorter name used on home screens and launchers. Defaults to `name`.
+    #[serde(default)]
+    pub(crate) short_name: Option<String>,
+
+    /// A description of the app.
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+
+    /// The url the app opens to when launched, relative to the base path [default: ./]
+    #[serde(default = "default_pwa_start_url")]
+    pub(crate) start_url: String,
+
+    /// The navigation scope of the app, relative to the base path [default: ./]
+    #[serde(default = "default_pwa_scope")]
+    pub(crate) scope: String,
+
+    /// The preferred display mode of the installed app [default: standalone]
+    #[serde(default)]
+    pub(crate) display: WebPwaDisplay,
+
+    /// The theme color used for the browser UI and the `theme-color` meta tag.
+    #[serde(default)]
+    pub(crate) theme_color: Option<String>,
+
+    /// The background color of the splash screen shown while the app loads.
+    #[serde(default)]
+    pub(crate) background_color: Option<String>,
+
+    /// The source image for the app icons, relative to the crate root. Defaults to the first png in
+    /// `bundle.icon`, or the dioxus logo if none is set.
+    #[serde(default)]
+    pub(crate) icon: Option<PathBuf>,
+
+    /// The square sizes the icon is resized to [default: [192, 512]]
+    #[serde(default = "default_pwa_icon_sizes")]
+    pub(crate) icon_sizes: Vec<u32>,
+
+    /// Mark the icons as safe to mask by the platform (adds the `maskable` purpose)
+    #[serde(default = "false_bool")]
+    pub(crate) maskable_icons: bool,
+
+    /// Whether to generate and register a service worker [default: true]
+    #[serde(default = "true_bool")]
+    pub(crate) service_worker: bool,
+
+    /// The page served for navigations while offline, relative to the base path [default: index.html]
+    #[serde(default = "default_pwa_offline_fallback")]
+    pub(crate) offline_fallback: String,
+
+    /// Extra urls to precache in addition to the app shell and bundled assets, relative to the
+    /// base path.
+    #[serde(default)]
+    pub(crate) precache: Vec<String>,
+}
+
+/// The display mode of an installed progressive web app
+#[derive(Default, Debug, Copy, Clone, Serialize, Deserialize, JsonSchema)]
+pub(crate) enum WebPwaDisplay {
+    /// Take up the entire screen without any browser UI
+    #[serde(rename = "fullscreen")]
+    Fullscreen,
+    /// Look and feel like a standalone app
+    #[serde(rename = "standalone")]
+    #[default]
+    Standalone,
+    /// Like standalone, but with a minimal set of navigation controls
+    #[serde(rename = "minimal-ui")]
+    MinimalUi,
+    /// Open in a regular browser tab
+    #[serde(rename = "browser")]
+    Browser,
+}
+
+impl WebPwaDisplay {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            WebPwaDisplay::Fullscreen => "fullscreen",
+            WebPwaDisplay::Standalone => "standalone",
+            WebPwaDisplay::MinimalUi => "minimal-ui",
+            WebPwaDisplay::Browser => "browser",
+        }
+    }
+}
+
+fn default_pwa_start_url() -> String {
+    "./".into()
+}
+
+fn default_pwa_scope() -> String {
+    "./".into()
+}
+
+fn default_pwa_icon_sizes() -> Vec<u32> {
+    vec![192, 512]
+}
+
+fn default_pwa_offline_fallback() -> String {
+    "index.html".into()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) struct WebProxyConfig {
     pub(crate) backend: String,
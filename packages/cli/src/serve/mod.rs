@@ -14,10 +14,13 @@ mod server;
 mod update;
 
 use anyhow::bail;
+use dioxus_core::{ReactiveGraph, ReactiveUpdate, ScopeId};
+use dioxus_devtools_types::ClientMsg;
 use dioxus_dx_wire_format::BuildStage;
 pub(crate) use output::*;
 pub(crate) use runner::*;
 pub(crate) use server::*;
+use std::collections::HashMap;
 pub(crate) use update::*;
 
 /// For *all* builds, the CLI spins up a dedicated webserver, file watcher, and build infrastructure to serve the project.
@@ -131,10 +134,37 @@ pub(crate) async fn serve_all(args: ServeArgs, tracer: &TraceController) -> Resu
                 builder.client_connected(id, aslr_reference, pid).await;
             }
 
-            // Received a message from the devtools server - currently we only use this for
-            // logging, so we just forward it the tui
+            // Received a message from the devtools server - we save render profiles and reactive graphs
+            // and forward everything else to the tui as logs
             ServeUpdate::WsMessage { msg, bundle } => {
-                screen.push_ws_message(bundle, &msg);
+                let client_msg = match &msg {
+                    axum::extract::ws::Message::Text(text) => {
+                        serde_json::from_str::<ClientMsg>(text.as_str()).ok()
+                    }
+                    _ => None,
+                };
+                match client_msg {
+                    Some(ClientMsg::ProfileTrace { trace }) => {
+                        match builder.save_profile_trace(&trace) {
+                            Ok(path) => tracing::info!(
+                                "Saved render profile to {LINK_STYLE}{}{LINK_STYLE:#}. Open it in chrome://tracing or https://ui.perfetto.dev",
+                                path.display()
+                            ),
+                            Err(err) => tracing::error!("Failed to save render profile: {err}"),
+                        }
+                    }
+                    Some(ClientMsg::ReactiveGraph { graph }) => {
+                        match builder.save_reactive_graph(&graph) {
+                            Ok(path) => tracing::info!(
+                                "Saved the reactive graph to {LINK_STYLE}{}{LINK_STYLE:#}",
+                                path.display()
+                            ),
+                            Err(err) => tracing::error!("Failed to save the reactive graph: {err}"),
+                        }
+                        log_most_rerun_scopes(&graph);
+                    }
+                    _ => screen.push_ws_message(bundle, &msg),
+                }
             }
 
             // Wait for logs from the build engine
@@ -296,6 +326,20 @@ pub(crate) async fn serve_all(args: ServeArgs, tracer: &TraceController) -> Resu
                 );
             }
 
+            ServeUpdate::ToggleProfiling => match devserver.toggle_profiling().await {
+                true => tracing::info!("Started the render profiler. Press f again to stop it"),
+                false => tracing::info!("Stopped the render profiler, waiting for the profile..."),
+            },
+
+            ServeUpdate::ToggleReactiveGraph => match devserver.toggle_reactive_graph().await {
+                true => {
+                    tracing::info!("Started recording the reactive graph. Press g again to stop it")
+                }
+                false => {
+                    tracing::info!("Stopped recording the reactive graph, waiting for the graph...")
+                }
+            },
+
             ServeUpdate::OpenDebugger { id } => {
                 builder.open_debugger(&devserver, id).await;
             }
@@ -312,3 +356,26 @@ pub(crate) async fn serve_all(args: ServeArgs, tracer: &TraceController) -> Resu
         }
     }
 }
+
+/// Log the scopes that reran the most while the reactive graph was recorded, with the write that last caused them to rerun
+fn log_most_rerun_scopes(graph: &ReactiveGraph) {
+    let mut reruns: HashMap<ScopeId, (usize, &ReactiveUpdate)> = HashMap::new();
+    for update in &graph.updates {
+        if let Some(scope) = update.scope {
+            let entry = reruns.entry(scope).or_insert((0, update));
+            entry.0 += 1;
+            entry.1 = update;
+        }
+    }
+
+    let mut reruns: Vec<_> = reruns.into_iter().collect();
+    reruns.sort_by_key(|(scope, (count, _))| (std::cmp::Reverse(*count), *scope));
+    for (scope, (count, last)) in reruns.into_iter().take(5) {
+        match &last.written_at {
+            Some(location) => tracing::info!(
+                "{scope:?} reran {count} times, last because of the write at {location}"
+            ),
+            None => tracing::info!("{scope:?} reran {count} times"),
+        }
+    }
+}
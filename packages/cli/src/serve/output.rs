@@ -255,6 +255,8 @@ impl Output {
             KeyCode::Char('r') => return Ok(Some(ServeUpdate::RequestRebuild)),
             KeyCode::Char('o') => return Ok(Some(ServeUpdate::OpenApp)),
             KeyCode::Char('p') => return Ok(Some(ServeUpdate::CycleHotreloadMode)),
+            KeyCode::Char('f') => return Ok(Some(ServeUpdate::ToggleProfiling)),
+            KeyCode::Char('g') => return Ok(Some(ServeUpdate::ToggleReactiveGraph)),
             KeyCode::Char('v') => {
                 self.verbose = !self.verbose;
                 tracing::info!(
@@ -843,13 +845,15 @@ impl Output {
             "r: rebuild the app",
             "o: open the app",
             "p: cycle hotreload mode",
+            "f: toggle render profiler",
+            "g: toggle reactive graph recorder",
             "v: toggle verbose logs",
             "t: toggle tracing logs",
             "c: clear the screen",
             "d: attach debugger",
             "/: toggle more commands",
         ];
-        let layout: [_; 9] = Layout::vertical(cmds.iter().map(|_| Constraint::Length(1)))
+        let layout: [_; 11] = Layout::vertical(cmds.iter().map(|_| Constraint::Length(1)))
             .horizontal_margin(1)
             .areas(col2);
         for (idx, cmd) in cmds.iter().enumerate() {
@@ -5,6 +5,7 @@ use crate::{
     platform_override::CommandWithPlatformOverrides,
 };
 use anyhow::{Context, bail};
+use dioxus_core::ReactiveGraph;
 use dioxus_core::internal::{
     HotReloadTemplateWithLocation, HotReloadedTemplate, TemplateGlobalKey,
 };
@@ -1002,6 +1003,34 @@ impl AppServer {
         (prev, self.hotreload_mode_label())
     }
 
+    /// Save a render profile sent by the app to the session cache directory. Returns the path it was written to.
+    pub(crate) fn save_profile_trace(&self, trace: &str) -> Result<PathBuf> {
+        let dir = self.client.build.session_cache_dir().join("profiles");
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join(format!(
+            "profile-{}.json",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        std::fs::write(&path, trace)?;
+        Ok(path)
+    }
+
+    /// Save a reactive graph sent by a client to the session cache and return the path it was saved to
+    pub(crate) fn save_reactive_graph(&self, graph: &ReactiveGraph) -> Result<PathBuf> {
+        let dir = self
+            .client
+            .build
+            .session_cache_dir()
+            .join("reactive-graphs");
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join(format!(
+            "reactive-graph-{}.json",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        std::fs::write(&path, serde_json::to_string_pretty(graph)?)?;
+        Ok(path)
+    }
+
     /// Fold a `.d` file's dep list into our tracking state:
     /// - `.rs` files get parsed and inserted into `file_map` (skipping entries already there so
     ///   we don't clobber a `most_recent` buffer mid-edit) so RSX hot-reload diffing can find
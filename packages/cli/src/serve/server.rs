@@ -62,6 +62,8 @@ pub(crate) struct WebServer {
     build_status: SharedStatus,
     application_name: String,
     bundle: BundleFormat,
+    profiling: bool,
+    recording_reactive_graph: bool,
 }
 
 pub(crate) struct ConnectedWsClient {
@@ -127,6 +129,8 @@ impl WebServer {
             new_build_status_sockets: build_status_sockets_rx,
             application_name: runner.app_name().to_string(),
             bundle: runner.client.build.bundle,
+            profiling: false,
+            recording_reactive_graph: false,
         })
     }
 
@@ -348,6 +352,33 @@ impl WebServer {
             .await;
     }
 
+    /// Start or stop the render profiler in all connected clients. Returns whether profiling is now enabled.
+    ///
+    /// When profiling stops, clients send the recorded profile back as a [`ClientMsg::ProfileTrace`](dioxus_devtools_types::ClientMsg::ProfileTrace).
+    pub(crate) async fn toggle_profiling(&mut self) -> bool {
+        self.profiling = !self.profiling;
+        self.send_devserver_message_to_all(DevserverMsg::SetProfiling(self.profiling))
+            .await;
+        self.profiling
+    }
+
+    /// Start or stop recording the reactive graph in all connected clients. Returns whether recording is now enabled.
+    ///
+    /// When recording stops, clients are asked for the graph they recorded and send it back as a
+    /// [`ClientMsg::ReactiveGraph`](dioxus_devtools_types::ClientMsg::ReactiveGraph).
+    pub(crate) async fn toggle_reactive_graph(&mut self) -> bool {
+        self.recording_reactive_graph = !self.recording_reactive_graph;
+        self.send_devserver_message_to_all(DevserverMsg::SetReactiveGraphRecording(
+            self.recording_reactive_graph,
+        ))
+        .await;
+        if !self.recording_reactive_graph {
+            self.send_devserver_message_to_all(DevserverMsg::QueryReactiveGraph)
+                .await;
+        }
+        self.recording_reactive_graph
+    }
+
     /// Sends a devserver message to all connected clients.
     async fn send_devserver_message_to_all(&mut self, msg: DevserverMsg) {
         for socket in self.hot_reload_sockets.iter_mut() {
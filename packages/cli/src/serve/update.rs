@@ -33,6 +33,10 @@ pub(crate) enum ServeUpdate {
 
     CycleHotreloadMode,
 
+    ToggleProfiling,
+
+    ToggleReactiveGraph,
+
     OpenDebugger {
         id: BuildId,
     },
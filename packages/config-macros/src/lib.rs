@@ -37,3 +37,79 @@ macro_rules! maybe_wasm_split {
         $right
     };
 }
+
+/// Like [`maybe_wasm_split`], but for items. The items are only emitted on wasm with the wasm-split feature.
+/// Used by the internal router-macro code. The contents here are considered to be semver exempt.
+#[doc(hidden)]
+#[cfg(all(feature = "wasm-split", target_arch = "wasm32"))]
+#[macro_export]
+macro_rules! maybe_wasm_split_items {
+    ($($item:item)*) => {
+        $($item)*
+    };
+}
+
+/// Like [`maybe_wasm_split`], but for items. The items are only emitted on wasm with the wasm-split feature.
+/// Used by the internal router-macro code. The contents here are considered to be semver exempt.
+#[doc(hidden)]
+#[cfg(any(not(feature = "wasm-split"), not(target_arch = "wasm32")))]
+#[macro_export]
+macro_rules! maybe_wasm_split_items {
+    ($($item:item)*) => {};
+}
+
+/// Like [`maybe_wasm_split`], but only splits when every route of the router should be split with the router's
+/// `wasm-split` feature. Routes marked as `lazy` use [`maybe_wasm_split`] instead.
+/// Used by the internal router-macro code. The contents here are considered to be semver exempt.
+#[doc(hidden)]
+#[cfg(all(feature = "wasm-split-routes", target_arch = "wasm32"))]
+#[macro_export]
+macro_rules! maybe_wasm_split_routes {
+    (
+        if wasm_split {
+            $left:tt
+        } else {
+            $right:tt
+        }
+    ) => {
+        $left
+    };
+}
+
+/// Like [`maybe_wasm_split`], but only splits when every route of the router should be split with the router's
+/// `wasm-split` feature. Routes marked as `lazy` use [`maybe_wasm_split`] instead.
+/// Used by the internal router-macro code. The contents here are considered to be semver exempt.
+#[doc(hidden)]
+#[cfg(any(not(feature = "wasm-split-routes"), not(target_arch = "wasm32")))]
+#[macro_export]
+macro_rules! maybe_wasm_split_routes {
+    (
+        if wasm_split {
+            $left:tt
+        } else {
+            $right:tt
+        }
+    ) => {
+        $right
+    };
+}
+
+/// Like [`maybe_wasm_split_routes`], but for items.
+/// Used by the internal router-macro code. The contents here are considered to be semver exempt.
+#[doc(hidden)]
+#[cfg(all(feature = "wasm-split-routes", target_arch = "wasm32"))]
+#[macro_export]
+macro_rules! maybe_wasm_split_routes_items {
+    ($($item:item)*) => {
+        $($item)*
+    };
+}
+
+/// Like [`maybe_wasm_split_routes`], but for items.
+/// Used by the internal router-macro code. The contents here are considered to be semver exempt.
+#[doc(hidden)]
+#[cfg(any(not(feature = "wasm-split-routes"), not(target_arch = "wasm32")))]
+#[macro_export]
+macro_rules! maybe_wasm_split_routes_items {
+    ($($item:item)*) => {};
+}
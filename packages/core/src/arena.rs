@@ -79,10 +79,16 @@ impl VirtualDom {
         let height = {
             let scope = self.scopes.remove(id.0);
             let context = scope.state();
+            // If this scope was a suspense boundary in a suspense list, let the rest of the list know it is gone
+            if let Some(suspense_context) = context.suspense_boundary() {
+                suspense_context.leave_suspense_list();
+            }
             context.height
         };
 
         self.dirty_scopes.remove(&ScopeOrder::new(height, id));
+        self.transition_scopes.remove(&ScopeOrder::new(height, id));
+        self.runtime.drop_portal_scope(id);
 
         // If this scope was a suspense boundary, remove it from the resolved scopes
         self.resolved_scopes.retain(|s| s != &id);
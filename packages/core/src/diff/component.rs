@@ -7,8 +7,8 @@ use crate::{
     Element, SuspenseContext,
     any_props::AnyProps,
     innerlude::{
-        ElementRef, MountId, ScopeOrder, SuspenseBoundaryProps, SuspenseBoundaryPropsWithOwner,
-        VComponent, WriteMutations,
+        ElementRef, MountId, ProfileSpanKind, ScopeOrder, SuspenseBoundaryProps,
+        SuspenseBoundaryPropsWithOwner, VComponent, WriteMutations,
     },
     nodes::VNode,
     scopes::{LastRenderedNode, ScopeId},
@@ -23,15 +23,35 @@ impl VirtualDom {
     ) {
         let scope = &mut self.scopes[scope_id.0];
         if SuspenseBoundaryProps::downcast_from_props(&mut *scope.props).is_some() {
-            SuspenseBoundaryProps::diff(scope_id, self, to)
+            let diff_start = self.runtime.profile_mark();
+            SuspenseBoundaryProps::diff(scope_id, self, to);
+            if let Some(mark) = diff_start {
+                self.runtime
+                    .record_profile_span(ProfileSpanKind::Diff, scope_id, mark);
+            }
+        } else if self.is_portal(scope_id) {
+            let diff_start = self.runtime.profile_mark();
+            self.run_and_diff_portal(to, scope_id);
+            if let Some(mark) = diff_start {
+                self.runtime
+                    .record_profile_span(ProfileSpanKind::Diff, scope_id, mark);
+            }
         } else {
             let new_nodes = self.run_scope(scope_id);
+            let diff_start = self.runtime.profile_mark();
             self.diff_scope(to, scope_id, new_nodes);
+            if let Some(mark) = diff_start {
+                self.runtime
+                    .record_profile_span(ProfileSpanKind::Diff, scope_id, mark);
+            }
         }
+
+        // The scope may have added or moved boundaries in a suspense list
+        self.order_suspense_lists(Some(scope_id));
     }
 
     #[tracing::instrument(skip(self, to), level = "trace", name = "VirtualDom::diff_scope")]
-    fn diff_scope<M: WriteMutations>(
+    pub(crate) fn diff_scope<M: WriteMutations>(
         &mut self,
         to: Option<&mut M>,
         scope: ScopeId,
@@ -71,7 +91,8 @@ impl VirtualDom {
         new_nodes: LastRenderedNode,
         parent: Option<ElementRef>,
     ) -> usize {
-        self.runtime.clone().with_scope_on_stack(scope, || {
+        let create_start = self.runtime.profile_mark();
+        let nodes = self.runtime.clone().with_scope_on_stack(scope, || {
             // If there are suspended scopes, we need to check if the scope is suspended before we diff it
             // If it is suspended, we need to diff it but write the mutations nothing
             // Note: It is important that we still diff the scope even if it is suspended, because the scope may render other child components which may change between renders
@@ -88,7 +109,12 @@ impl VirtualDom {
             }
 
             nodes
-        })
+        });
+        if let Some(mark) = create_start {
+            self.runtime
+                .record_profile_span(ProfileSpanKind::Diff, scope, mark);
+        }
+        nodes
     }
 
     pub(crate) fn remove_component_node<M: WriteMutations>(
@@ -98,6 +124,12 @@ impl VirtualDom {
         scope_id: ScopeId,
         replace_with: Option<usize>,
     ) {
+        // Portals remove their nodes from their target and their placeholder
+        if self.portal_placeholder(scope_id).is_some() {
+            self.remove_portal(to, destroy_component_state, scope_id, replace_with);
+            return;
+        }
+
         // If this is a suspense boundary, remove the suspended nodes as well
         SuspenseContext::remove_suspended_nodes::<M>(self, scope_id, destroy_component_state);
 
@@ -207,6 +239,11 @@ impl VNode {
 
         let scope = ScopeId(dom.get_mounted_dyn_node(mount, idx));
 
+        // Portals create their nodes inside of their target instead
+        if dom.is_portal(scope) {
+            return dom.create_portal(to, scope, parent);
+        }
+
         let new_node = dom.scopes[scope.0]
             .last_rendered_node
             .clone()
@@ -497,6 +497,11 @@ impl VNode {
                     }
                     Some((idx, DynamicNode::Component(_))) => {
                         let scope = ScopeId(mount.mounted_dynamic_nodes[idx]);
+                        // Portals are represented by their placeholder
+                        if let Some(placeholder) = dom.portal_placeholder(scope) {
+                            to.push_root(placeholder);
+                            return 1;
+                        }
                         let node = dom.get_scope(scope).unwrap().root_node();
                         node.push_all_root_nodes(dom, to)
                     }
@@ -165,10 +165,15 @@ impl VNode {
             // The node is a component, so we need to find the first element in the component
             Some((id, Component(_))) => {
                 let scope = ScopeId(dom.get_mounted_dyn_node(mount_id, id));
-                dom.get_scope(scope)
-                    .unwrap()
-                    .root_node()
-                    .find_first_element(dom)
+                // Portals are represented by their placeholder
+                if let Some(placeholder) = dom.portal_placeholder(scope) {
+                    placeholder
+                } else {
+                    dom.get_scope(scope)
+                        .unwrap()
+                        .root_node()
+                        .find_first_element(dom)
+                }
             }
         };
 
@@ -196,10 +201,15 @@ impl VNode {
             // The node is a component, so we need to find the first element in the component
             Some((id, Component(_))) => {
                 let scope = ScopeId(dom.get_mounted_dyn_node(mount_id, id));
-                dom.get_scope(scope)
-                    .unwrap()
-                    .root_node()
-                    .find_last_element(dom)
+                // Portals are represented by their placeholder
+                if let Some(placeholder) = dom.portal_placeholder(scope) {
+                    placeholder
+                } else {
+                    dom.get_scope(scope)
+                        .unwrap()
+                        .root_node()
+                        .find_last_element(dom)
+                }
             }
         };
 
@@ -1,14 +1,16 @@
 use crate::{
-    Element, IntoDynNode, Properties, ReactiveContext, Subscribers, Template, TemplateAttribute,
-    TemplateNode, VNode,
+    DynamicNode, Element, IntoDynNode, Properties, ReactiveContext, ScopeId, Subscribers, Template,
+    TemplateAttribute, TemplateNode, VNode,
     innerlude::{CapturedError, provide_context},
     try_consume_context, use_hook,
 };
 use std::{
     any::Any,
-    cell::RefCell,
+    cell::{Cell, RefCell},
     fmt::{Debug, Display},
+    hash::{DefaultHasher, Hash, Hasher},
     rc::Rc,
+    sync::Arc,
 };
 
 /// Return early with an error.
@@ -82,6 +84,8 @@ pub fn use_error_boundary_provider() -> ErrorContext {
 #[derive(Clone)]
 pub struct ErrorContext {
     error: Rc<RefCell<Option<CapturedError>>>,
+    /// Incremented every time the children of the boundary should be remounted
+    generation: Rc<Cell<usize>>,
     subscribers: Subscribers,
 }
 
@@ -104,6 +108,7 @@ impl ErrorContext {
     pub fn new(error: Option<CapturedError>) -> Self {
         Self {
             error: Rc::new(RefCell::new(error)),
+            generation: Default::default(),
             subscribers: Subscribers::new(),
         }
     }
@@ -132,6 +137,13 @@ impl ErrorContext {
         self.mark_dirty();
     }
 
+    /// Clear all errors from this Error Boundary and remount its children. Unlike [`Self::clear_errors`], any state
+    /// in the children is thrown away even if they were still mounted.
+    pub fn retry(&self) {
+        self.generation.set(self.generation.get() + 1);
+        self.clear_errors();
+    }
+
     /// Mark the error context as dirty and notify all subscribers
     fn mark_dirty(&self) {
         let mut this_subscribers_vec = Vec::new();
@@ -144,6 +156,57 @@ impl ErrorContext {
     }
 }
 
+/// An error that was thrown in a component along with where it was thrown from
+#[derive(Debug, Clone)]
+pub struct ErrorReport {
+    /// The error that was thrown
+    pub error: CapturedError,
+    /// The scope the error was thrown from
+    pub scope: ScopeId,
+    /// The names of the components from the root of the app down to the scope the error was thrown from
+    pub scope_path: Vec<&'static str>,
+}
+
+impl Display for ErrorReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.scope_path.join(" > "), self.error)
+    }
+}
+
+/// A callback that receives every error thrown in the app, whether or not an [`ErrorBoundary`] catches it.
+///
+/// Provide the reporter as a root context, either with `LaunchBuilder::with_error_reporter` or
+/// [`VirtualDom::with_root_context`](crate::VirtualDom::with_root_context), to ship errors to a logging backend.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus::core::ErrorReporter;
+/// # fn app() -> Element { VNode::empty() }
+/// let dom = VirtualDom::new(app).with_root_context(ErrorReporter::new(|report| {
+///     eprintln!("{report}");
+/// }));
+/// ```
+#[derive(Clone)]
+pub struct ErrorReporter(Arc<dyn Fn(&ErrorReport) + Send + Sync>);
+
+impl ErrorReporter {
+    /// Create a new error reporter from a callback
+    pub fn new(report: impl Fn(&ErrorReport) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(report))
+    }
+
+    /// Send a report to the callback
+    pub(crate) fn report(&self, report: &ErrorReport) {
+        (self.0)(report)
+    }
+}
+
+impl Debug for ErrorReporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ErrorReporter").finish()
+    }
+}
+
 #[allow(clippy::type_complexity)]
 #[derive(Clone)]
 pub struct ErrorHandler(Rc<dyn Fn(ErrorContext) -> Element>);
@@ -201,6 +264,7 @@ fn default_handler(errors: ErrorContext) -> Element {
 pub struct ErrorBoundaryProps {
     children: Element,
     handle_error: ErrorHandler,
+    reset_keys: Option<u64>,
 }
 
 /// Create a new error boundary component that catches any errors thrown from child components
@@ -308,9 +372,42 @@ pub struct ErrorBoundaryProps {
 ///     }
 /// }
 /// ```
+///
+/// ## Resetting automatically
+///
+/// Passing `reset_keys` clears the errors any time the keys change. The keys can be any value that implements [`Hash`]. This is useful
+/// when the error depends on some input like a route parameter: once the input changes, the boundary tries to render the children again.
+///
+/// If the children should be remounted with fresh state instead of rerendered, call [`ErrorContext::retry`] instead of [`ErrorContext::clear_errors`].
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # #[component] fn Profile(id: u32) -> Element { VNode::empty() }
+/// #[component]
+/// fn UserPage(id: u32) -> Element {
+///     rsx! {
+///         ErrorBoundary {
+///             reset_keys: id,
+///             handle_error: |errors: ErrorContext| rsx! {
+///                 button { onclick: move |_| errors.retry(), "retry" }
+///             },
+///             Profile { id }
+///         }
+///     }
+/// }
+/// ```
 #[allow(non_upper_case_globals, non_snake_case)]
 pub fn ErrorBoundary(props: ErrorBoundaryProps) -> Element {
     let error_boundary = use_error_boundary_provider();
+
+    // Clear the errors if the reset keys changed since the last render
+    let reset_keys = use_hook(|| Rc::new(Cell::new(props.reset_keys)));
+    if reset_keys.replace(props.reset_keys) != props.reset_keys
+        && error_boundary.error.borrow().is_some()
+    {
+        error_boundary.clear_errors();
+    }
+
     let errors = error_boundary.error();
     let has_errors = errors.is_some();
 
@@ -323,10 +420,17 @@ pub fn ErrorBoundary(props: ErrorBoundaryProps) -> Element {
         std::result::Result::Ok({
             static TEMPLATE: Template =
                 Template::new(&[TemplateNode::Dynamic { id: 0usize }], &[&[0u8]], &[]);
+            // The children are keyed by the generation of the boundary so that retrying replaces them
+            let children = VNode::new(
+                Some(error_boundary.generation.get().to_string()),
+                TEMPLATE,
+                Box::new([(props.children).into_dyn_node()]),
+                Default::default(),
+            );
             VNode::new(
                 None,
                 TEMPLATE,
-                Box::new([(props.children).into_dyn_node()]),
+                Box::new([DynamicNode::Fragment(vec![children])]),
                 Default::default(),
             )
         })
@@ -336,12 +440,14 @@ pub fn ErrorBoundary(props: ErrorBoundaryProps) -> Element {
 impl ErrorBoundaryProps {
     /**
     Create a builder for building `ErrorBoundaryProps`.
-    On the builder, call `.children(...)`(optional), `.handle_error(...)`(optional) to set the values of the fields.
+    On the builder, call `.children(...)`(optional), `.handle_error(...)`(optional), `.reset_keys(...)`(optional) to set the values of the fields.
     Finally, call `.build()` to create the instance of `ErrorBoundaryProps`.
                         */
     #[allow(dead_code)]
-    pub fn builder() -> ErrorBoundaryPropsBuilder<((), ())> {
-        ErrorBoundaryPropsBuilder { fields: ((), ()) }
+    pub fn builder() -> ErrorBoundaryPropsBuilder<((), (), ())> {
+        ErrorBoundaryPropsBuilder {
+            fields: ((), (), ()),
+        }
     }
 }
 
@@ -362,7 +468,7 @@ where
     }
 }
 impl Properties for ErrorBoundaryProps {
-    type Builder = ErrorBoundaryPropsBuilder<((), ())>;
+    type Builder = ErrorBoundaryPropsBuilder<((), (), ())>;
     fn builder() -> Self::Builder {
         ErrorBoundaryProps::builder()
     }
@@ -387,15 +493,15 @@ impl<T> ErrorBoundaryPropsBuilder_Optional<T> for (T,) {
     }
 }
 #[allow(dead_code, non_camel_case_types, missing_docs)]
-impl<__handle_error> ErrorBoundaryPropsBuilder<((), __handle_error)> {
+impl<__handle_error, __reset_keys> ErrorBoundaryPropsBuilder<((), __handle_error, __reset_keys)> {
     pub fn children(
         self,
         children: Element,
-    ) -> ErrorBoundaryPropsBuilder<((Element,), __handle_error)> {
+    ) -> ErrorBoundaryPropsBuilder<((Element,), __handle_error, __reset_keys)> {
         let children = (children,);
-        let (_, handle_error) = self.fields;
+        let (_, handle_error, reset_keys) = self.fields;
         ErrorBoundaryPropsBuilder {
-            fields: (children, handle_error),
+            fields: (children, handle_error, reset_keys),
         }
     }
 }
@@ -404,25 +510,27 @@ impl<__handle_error> ErrorBoundaryPropsBuilder<((), __handle_error)> {
 pub enum ErrorBoundaryPropsBuilder_Error_Repeated_field_children {}
 #[doc(hidden)]
 #[allow(dead_code, non_camel_case_types, missing_docs)]
-impl<__handle_error> ErrorBoundaryPropsBuilder<((Element,), __handle_error)> {
+impl<__handle_error, __reset_keys>
+    ErrorBoundaryPropsBuilder<((Element,), __handle_error, __reset_keys)>
+{
     #[deprecated(note = "Repeated field children")]
     pub fn children(
         self,
         _: ErrorBoundaryPropsBuilder_Error_Repeated_field_children,
-    ) -> ErrorBoundaryPropsBuilder<((Element,), __handle_error)> {
+    ) -> ErrorBoundaryPropsBuilder<((Element,), __handle_error, __reset_keys)> {
         self
     }
 }
 #[allow(dead_code, non_camel_case_types, missing_docs)]
-impl<__children> ErrorBoundaryPropsBuilder<(__children, ())> {
+impl<__children, __reset_keys> ErrorBoundaryPropsBuilder<(__children, (), __reset_keys)> {
     pub fn handle_error(
         self,
         handle_error: impl ::core::convert::Into<ErrorHandler>,
-    ) -> ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,))> {
+    ) -> ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,), __reset_keys)> {
         let handle_error = (handle_error.into(),);
-        let (children, _) = self.fields;
+        let (children, _, reset_keys) = self.fields;
         ErrorBoundaryPropsBuilder {
-            fields: (children, handle_error),
+            fields: (children, handle_error, reset_keys),
         }
     }
 }
@@ -431,12 +539,45 @@ impl<__children> ErrorBoundaryPropsBuilder<(__children, ())> {
 pub enum ErrorBoundaryPropsBuilder_Error_Repeated_field_handle_error {}
 #[doc(hidden)]
 #[allow(dead_code, non_camel_case_types, missing_docs)]
-impl<__children> ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,))> {
+impl<__children, __reset_keys>
+    ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,), __reset_keys)>
+{
     #[deprecated(note = "Repeated field handle_error")]
     pub fn handle_error(
         self,
         _: ErrorBoundaryPropsBuilder_Error_Repeated_field_handle_error,
-    ) -> ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,))> {
+    ) -> ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,), __reset_keys)> {
+        self
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__children, __handle_error> ErrorBoundaryPropsBuilder<(__children, __handle_error, ())> {
+    pub fn reset_keys(
+        self,
+        reset_keys: impl Hash,
+    ) -> ErrorBoundaryPropsBuilder<(__children, __handle_error, (Option<u64>,))> {
+        let mut hasher = DefaultHasher::new();
+        reset_keys.hash(&mut hasher);
+        let reset_keys = (Some(hasher.finish()),);
+        let (children, handle_error, _) = self.fields;
+        ErrorBoundaryPropsBuilder {
+            fields: (children, handle_error, reset_keys),
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub enum ErrorBoundaryPropsBuilder_Error_Repeated_field_reset_keys {}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__children, __handle_error>
+    ErrorBoundaryPropsBuilder<(__children, __handle_error, (Option<u64>,))>
+{
+    #[deprecated(note = "Repeated field reset_keys")]
+    pub fn reset_keys(
+        self,
+        _: ErrorBoundaryPropsBuilder_Error_Repeated_field_reset_keys,
+    ) -> ErrorBoundaryPropsBuilder<(__children, __handle_error, (Option<u64>,))> {
         self
     }
 }
@@ -444,17 +585,20 @@ impl<__children> ErrorBoundaryPropsBuilder<(__children, (ErrorHandler,))> {
 impl<
     __handle_error: ErrorBoundaryPropsBuilder_Optional<ErrorHandler>,
     __children: ErrorBoundaryPropsBuilder_Optional<Element>,
-> ErrorBoundaryPropsBuilder<(__children, __handle_error)>
+    __reset_keys: ErrorBoundaryPropsBuilder_Optional<Option<u64>>,
+> ErrorBoundaryPropsBuilder<(__children, __handle_error, __reset_keys)>
 {
     pub fn build(self) -> ErrorBoundaryProps {
-        let (children, handle_error) = self.fields;
+        let (children, handle_error, reset_keys) = self.fields;
         let children = ErrorBoundaryPropsBuilder_Optional::into_value(children, VNode::empty);
         let handle_error = ErrorBoundaryPropsBuilder_Optional::into_value(handle_error, || {
             ErrorHandler(Rc::new(default_handler))
         });
+        let reset_keys = ErrorBoundaryPropsBuilder_Optional::into_value(reset_keys, || None);
         ErrorBoundaryProps {
             children,
             handle_error,
+            reset_keys,
         }
     }
 }
@@ -15,8 +15,11 @@ mod global_context;
 mod launch;
 mod mutations;
 mod nodes;
+mod portal;
+mod profiler;
 mod properties;
 mod reactive_context;
+mod reactive_graph;
 mod render_error;
 mod root_wrapper;
 mod runtime;
@@ -65,8 +68,11 @@ pub(crate) mod innerlude {
     pub use crate::launch::*;
     pub use crate::mutations::*;
     pub use crate::nodes::*;
+    pub use crate::portal::*;
+    pub use crate::profiler::*;
     pub use crate::properties::*;
     pub use crate::reactive_context::*;
+    pub use crate::reactive_graph::*;
     pub use crate::render_error::*;
     pub use crate::runtime::{Runtime, RuntimeGuard};
     pub use crate::scheduler::*;
@@ -93,20 +99,24 @@ pub(crate) mod innerlude {
 }
 
 pub use crate::innerlude::{
-    AnyValue, AnyhowContext, Attribute, AttributeValue, Callback, CapturedError, Component,
-    ComponentFunction, DynamicNode, Element, ElementId, ErrorBoundary, ErrorContext, Event,
-    EventHandler, Fragment, HasAttributes, IntoAttributeValue, IntoDynNode, LaunchConfig,
-    ListenerCallback, MarkerWrapper, Mutation, Mutations, NoOpMutations, OptionStringFromMarker,
-    Properties, ReactiveContext, RenderError, Result, Runtime, RuntimeGuard, ScopeId, ScopeState,
-    SpawnIfAsync, SubscriberList, Subscribers, SuperFrom, SuperInto, SuspendedFuture,
-    SuspenseBoundary, SuspenseBoundaryProps, SuspenseContext, Task, Template, TemplateAttribute,
+    AnyValue, AnyhowContext, Attribute, AttributeValue, Callback, CapturedError, CodeLocation,
+    Component, ComponentFunction, DynamicNode, Element, ElementId, ErrorBoundary, ErrorContext,
+    ErrorReport, ErrorReporter, Event, EventHandler, Fragment, FrameProfile, HasAttributes,
+    IntoAttributeValue, IntoDynNode, LaunchConfig, ListenerCallback, MarkerWrapper, Mutation,
+    Mutations, NoOpMutations, OptionStringFromMarker, Portal, PortalProps, PortalTarget,
+    PortalTargetProps, Profile, ProfileSpan, ProfileSpanKind, Properties, ReactiveContext,
+    ReactiveContextInfo, ReactiveGraph, ReactiveSource, ReactiveSourceInfo, ReactiveUpdate,
+    ReactiveWriteGuard, RenderError, Result, Runtime, RuntimeGuard, ScopeId, ScopeProfile,
+    ScopeState, SpawnIfAsync, SubscriberList, Subscribers, SuperFrom, SuperInto, SuspendedFuture,
+    SuspenseBoundary, SuspenseBoundaryProps, SuspenseContext, SuspenseList, SuspenseListPosition,
+    SuspenseListProps, SuspenseRevealOrder, SuspenseTail, Task, Template, TemplateAttribute,
     TemplateNode, VComponent, VNode, VNodeInner, VPlaceholder, VText, VirtualDom, WriteMutations,
     anyhow, consume_context, consume_context_from_scope, current_owner, current_scope_id,
-    fc_to_builder, generation, has_context, needs_update, needs_update_any, parent_scope,
-    provide_context, provide_create_error_boundary, provide_root_context, queue_effect,
-    remove_future, schedule_update, schedule_update_any, spawn, spawn_forever, spawn_isomorphic,
-    suspend, throw_error, try_consume_context, use_after_render, use_before_render, use_drop,
-    use_hook, use_hook_with_cleanup, with_owner,
+    fc_to_builder, generation, has_context, is_in_transition, needs_update, needs_update_any,
+    parent_scope, provide_context, provide_create_error_boundary, provide_root_context,
+    queue_effect, remove_future, schedule_update, schedule_update_any, spawn, spawn_forever,
+    spawn_isomorphic, start_transition, suspend, throw_error, try_consume_context,
+    use_after_render, use_before_render, use_drop, use_hook, use_hook_with_cleanup, with_owner,
 };
 
 /// Equivalent to `Ok::<_, dioxus::CapturedError>(value)`.
@@ -0,0 +1,470 @@
+//! Portals render their children into a [`PortalTarget()`] somewhere else in the tree.
+//!
+//! A portal is a component like any other, except that its rendered nodes are appended to the element of its target
+//! instead of being inserted where the portal is. In the portal's place, we create a single placeholder node so the
+//! parent can still diff, move and remove the portal like any other component.
+//!
+//! Only the creation of the rendered nodes needs to know about the target. Every later mutation is relative to the ids
+//! of the existing nodes, so diffing the portal works wherever the nodes end up. The nodes are mounted with the
+//! element the portal is in as their parent, so events bubble through the component tree instead of the DOM tree.
+
+use crate::innerlude::*;
+use std::{any::TypeId, collections::HashMap};
+
+/// The portal targets and portals that are currently mounted
+#[derive(Default)]
+pub(crate) struct Portals {
+    /// The scope of the [`PortalTarget()`] with each name
+    targets: HashMap<String, ScopeId>,
+    /// The state of each portal that is mounted
+    mounted: HashMap<ScopeId, MountedPortal>,
+}
+
+struct MountedPortal {
+    /// The name of the target the portal renders into
+    name: String,
+    /// The placeholder node that stands in for the portal where it is in the tree
+    placeholder: ElementId,
+    /// The element the portal is in. The rendered nodes are mounted with this as their parent for event bubbling
+    parent: Option<ElementRef>,
+    /// The element the rendered nodes are appended to. This is `None` while the target is not mounted and the nodes
+    /// are only created in the virtual dom
+    target: Option<ElementId>,
+}
+
+impl Runtime {
+    /// Register the target a [`PortalTarget()`] scope provides and rerun any portals that render into it
+    fn register_portal_target(&self, name: String, scope: ScopeId) {
+        let stale = {
+            let mut portals = self.portals.borrow_mut();
+            let previous = portals
+                .targets
+                .iter()
+                .find(|(_, target)| **target == scope)
+                .map(|(name, _)| name.clone());
+            if previous.as_ref() == Some(&name) {
+                return;
+            }
+            if let Some(previous) = &previous {
+                portals.targets.remove(previous);
+            }
+            if portals.targets.insert(name.clone(), scope).is_some() {
+                tracing::warn!(
+                    "Multiple portal targets are named {name:?}. Portals will render into the last one that was created"
+                );
+            }
+
+            portals
+                .mounted
+                .iter()
+                .filter(|(_, portal)| {
+                    portal.name == name || Some(&portal.name) == previous.as_ref()
+                })
+                .map(|(id, _)| *id)
+                .collect::<Vec<_>>()
+        };
+
+        for portal in stale {
+            if let Some(state) = self.try_get_state(portal) {
+                state.needs_update();
+            }
+        }
+    }
+
+    /// Forget a scope that was dropped if it was a portal or a portal target. The nodes of any portals that were
+    /// rendered into a target were removed along with the target element
+    pub(crate) fn drop_portal_scope(&self, scope: ScopeId) {
+        let mut portals = self.portals.borrow_mut();
+        portals.mounted.remove(&scope);
+        let Some(name) = portals
+            .targets
+            .iter()
+            .find(|(_, target)| **target == scope)
+            .map(|(name, _)| name.clone())
+        else {
+            return;
+        };
+        portals.targets.remove(&name);
+        for portal in portals.mounted.values_mut() {
+            if portal.name == name {
+                portal.target = None;
+            }
+        }
+    }
+
+    /// Check if a scope is inside of a portal that is not rendered into its target
+    pub(crate) fn scope_in_detached_portal(&self, scope_id: ScopeId) -> bool {
+        let portals = self.portals.borrow();
+        if portals
+            .mounted
+            .values()
+            .all(|portal| portal.target.is_some())
+        {
+            return false;
+        }
+
+        let scopes = self.scope_states.borrow();
+        let mut current = scopes[scope_id.0]
+            .as_ref()
+            .and_then(|scope| scope.parent_id);
+        while let Some(id) = current {
+            if portals
+                .mounted
+                .get(&id)
+                .is_some_and(|portal| portal.target.is_none())
+            {
+                return true;
+            }
+            current = scopes[id.0].as_ref().and_then(|scope| scope.parent_id);
+        }
+        false
+    }
+}
+
+impl VirtualDom {
+    /// Get the placeholder node that stands in for a portal where it is in the tree. Returns `None` if the scope is
+    /// not a mounted portal.
+    ///
+    /// Renderers that hydrate server side rendered html bind this placeholder to the placeholder the server rendered
+    /// instead of the children of the portal.
+    pub fn portal_placeholder(&self, scope: ScopeId) -> Option<ElementId> {
+        self.runtime
+            .portals
+            .borrow()
+            .mounted
+            .get(&scope)
+            .map(|portal| portal.placeholder)
+    }
+
+    /// Check if a scope is a portal
+    pub(crate) fn is_portal(&self, scope: ScopeId) -> bool {
+        self.scopes[scope.0].props.props().type_id() == TypeId::of::<PortalProps>()
+    }
+
+    /// Get the name of the target a portal renders into from its current props
+    fn portal_target_name(&self, scope: ScopeId) -> String {
+        self.scopes[scope.0]
+            .props
+            .props()
+            .downcast_ref::<PortalProps>()
+            .map(|props| props.target.clone())
+            .unwrap_or_default()
+    }
+
+    /// Find the element of the target with a name if it is mounted and rendered
+    fn portal_target_element(&self, name: &str) -> Option<ElementId> {
+        let target = *self.runtime.portals.borrow().targets.get(name)?;
+        if !self.runtime.scope_should_render(target) {
+            return None;
+        }
+        let node = self.get_scope(target)?.try_root_node()?;
+        let mount = node.mount.get();
+        if !mount.mounted() {
+            return None;
+        }
+        // The target may still be in the middle of being created if it is a sibling of the portal
+        let element = self.get_mounted_root_node(mount, 0);
+        (element != ElementId::default()).then_some(element)
+    }
+
+    /// Create the nodes of a portal inside of its target and a placeholder for the portal in the current position
+    ///
+    /// Returns the number of nodes created on the stack
+    pub(crate) fn create_portal<M: WriteMutations>(
+        &mut self,
+        to: Option<&mut M>,
+        scope: ScopeId,
+        parent: Option<ElementRef>,
+    ) -> usize {
+        let new_nodes = self.scopes[scope.0]
+            .last_rendered_node
+            .clone()
+            .expect("Component to be mounted");
+        let to = to.filter(|_| self.runtime.scope_should_render(scope));
+        let name = self.portal_target_name(scope);
+        let target = to.as_ref().and_then(|_| self.portal_target_element(&name));
+        let placeholder = self.next_element();
+
+        // Register the portal before we create the nodes so the children know if they should be rendered
+        self.runtime.portals.borrow_mut().mounted.insert(
+            scope,
+            MountedPortal {
+                name,
+                placeholder,
+                parent,
+                target,
+            },
+        );
+
+        match (to, target) {
+            (Some(to), Some(target)) => {
+                let m = self.create_scope(Some(&mut *to), scope, new_nodes, parent);
+                to.append_children(target, m);
+                to.create_placeholder(placeholder);
+            }
+            (to, _) => {
+                self.create_scope(None::<&mut M>, scope, new_nodes, parent);
+                if let Some(to) = to {
+                    to.create_placeholder(placeholder);
+                }
+            }
+        }
+
+        1
+    }
+
+    /// Create the children of a portal inside of its target after the portal was hydrated.
+    ///
+    /// When html is hydrated, the server renders a placeholder where each portal is instead of its children. The
+    /// renderer binds that placeholder while hydrating and skips the children of the portal. Once the rest of the tree
+    /// is hydrated, this creates the children inside of the target without rerunning any components. Portals inside of
+    /// the portal are created along with it.
+    pub fn create_hydrated_portal<M: WriteMutations>(&mut self, to: &mut M, scope: ScopeId) {
+        if !self.runtime.scope_should_render(scope) {
+            return;
+        }
+        let Some((target, parent)) = self
+            .runtime
+            .portals
+            .borrow()
+            .mounted
+            .get(&scope)
+            .and_then(|portal| Some((portal.target?, portal.parent)))
+        else {
+            // If the target isn't mounted yet, the children are created once it is
+            return;
+        };
+        let Some(node) = self.scopes[scope.0].last_rendered_node.clone() else {
+            return;
+        };
+
+        // The nodes were only created in the virtual dom while hydrating. Reclaim their ids and create them again
+        // inside of the target, keeping the state of the components
+        node.remove_node_inner(self, None::<&mut M>, false, None);
+        let m = self.create_scope(Some(&mut *to), scope, node, parent);
+        to.append_children(target, m);
+    }
+
+    /// Rerun a portal. If the target element changed, the nodes are recreated inside of the new target
+    pub(crate) fn run_and_diff_portal<M: WriteMutations>(
+        &mut self,
+        to: Option<&mut M>,
+        scope: ScopeId,
+    ) {
+        let new_nodes = self.run_scope(scope);
+        let mut to = to.filter(|_| self.runtime.scope_should_render(scope));
+        let name = self.portal_target_name(scope);
+        let target = to.as_ref().and_then(|_| self.portal_target_element(&name));
+
+        let (old_target, parent) = {
+            let mut portals = self.runtime.portals.borrow_mut();
+            let portal = portals.mounted.get_mut(&scope).unwrap();
+            let old_target = std::mem::replace(&mut portal.target, target);
+            portal.name = name;
+            (old_target, portal.parent)
+        };
+
+        // If the portal is still rendered into the same target, we can diff the nodes in place
+        if old_target == target {
+            self.diff_scope(to.filter(|_| target.is_some()), scope, new_nodes);
+            return;
+        }
+
+        // Otherwise remove the nodes from the old target and create them again in the new target
+        if let Some(old) = self.scopes[scope.0].last_rendered_node.take() {
+            let old_to = to.as_deref_mut().filter(|_| old_target.is_some());
+            old.remove_node(self, old_to, None);
+        }
+        let new_to = to.as_deref_mut().filter(|_| target.is_some());
+        let m = self.create_scope(new_to, scope, LastRenderedNode::new(new_nodes), parent);
+        if let (Some(to), Some(target)) = (to, target) {
+            to.append_children(target, m);
+        }
+    }
+
+    /// Remove the nodes of a portal from its target and remove or replace its placeholder
+    pub(crate) fn remove_portal<M: WriteMutations>(
+        &mut self,
+        mut to: Option<&mut M>,
+        destroy_component_state: bool,
+        scope: ScopeId,
+        replace_with: Option<usize>,
+    ) {
+        let Some(portal) = self.runtime.portals.borrow_mut().mounted.remove(&scope) else {
+            return;
+        };
+
+        if let Some(node) = self.scopes[scope.0].last_rendered_node.clone() {
+            let node_to = to.as_deref_mut().filter(|_| portal.target.is_some());
+            node.remove_node_inner(self, node_to, destroy_component_state, None);
+        }
+
+        if let Some(to) = to {
+            match replace_with {
+                Some(m) => to.replace_node_with(portal.placeholder, m),
+                None => to.remove_node(portal.placeholder),
+            }
+        }
+        self.reclaim(portal.placeholder);
+
+        if destroy_component_state {
+            self.drop_scope(scope);
+        }
+    }
+}
+
+/// Properties for the [`Portal()`] component.
+#[derive(Clone, PartialEq)]
+pub struct PortalProps {
+    target: String,
+    children: Element,
+}
+
+#[doc(hidden)]
+pub struct PortalPropsBuilder<const TARGET: bool> {
+    target: String,
+    children: Element,
+}
+
+#[allow(missing_docs)]
+impl<const TARGET: bool> PortalPropsBuilder<TARGET> {
+    pub fn children(mut self, children: Element) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+#[allow(missing_docs)]
+impl PortalPropsBuilder<false> {
+    pub fn target(self, target: impl ToString) -> PortalPropsBuilder<true> {
+        PortalPropsBuilder {
+            target: target.to_string(),
+            children: self.children,
+        }
+    }
+}
+
+#[allow(missing_docs)]
+impl PortalPropsBuilder<true> {
+    pub fn build(self) -> PortalProps {
+        PortalProps {
+            target: self.target,
+            children: self.children,
+        }
+    }
+}
+
+impl Properties for PortalProps {
+    type Builder = PortalPropsBuilder<false>;
+    fn builder() -> Self::Builder {
+        PortalPropsBuilder {
+            target: String::new(),
+            children: VNode::empty(),
+        }
+    }
+    fn memoize(&mut self, new: &Self) -> bool {
+        let equal = self == new;
+        if !equal {
+            *self = new.clone();
+        }
+        equal
+    }
+}
+
+/// Render children into the [`PortalTarget()`] with the name `target` instead of where the portal is in the tree.
+///
+/// Portals are useful for modals, tooltips and toasts that need to escape a parent with `overflow: hidden` or a
+/// different stacking context. The children are still part of the component tree where the portal is: they can read the
+/// context of the components around the portal and events bubble up to the elements around the portal, not the
+/// elements around the target.
+///
+/// If the target is not mounted, the children are rendered in the background and moved into the target once it is
+/// mounted. During server side rendering, the children are rendered in place. If the html is hydrated, the server
+/// renders a placeholder instead and the children are created inside of the target once the page is hydrated.
+///
+/// # Example
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     rsx! {
+///         div {
+///             overflow: "hidden",
+///             Portal {
+///                 target: "modals",
+///                 div { class: "modal", "This modal is rendered at the end of the app" }
+///             }
+///         }
+///         PortalTarget { name: "modals" }
+///     }
+/// }
+/// ```
+#[allow(non_upper_case_globals, non_snake_case)]
+pub fn Portal(props: PortalProps) -> Element {
+    props.children
+}
+
+/// Properties for the [`PortalTarget()`] component.
+#[derive(Clone, PartialEq)]
+pub struct PortalTargetProps {
+    name: String,
+}
+
+#[doc(hidden)]
+pub struct PortalTargetPropsBuilder<const NAME: bool> {
+    name: String,
+}
+
+#[allow(missing_docs)]
+impl PortalTargetPropsBuilder<false> {
+    pub fn name(self, name: impl ToString) -> PortalTargetPropsBuilder<true> {
+        PortalTargetPropsBuilder {
+            name: name.to_string(),
+        }
+    }
+}
+
+#[allow(missing_docs)]
+impl PortalTargetPropsBuilder<true> {
+    pub fn build(self) -> PortalTargetProps {
+        PortalTargetProps { name: self.name }
+    }
+}
+
+impl Properties for PortalTargetProps {
+    type Builder = PortalTargetPropsBuilder<false>;
+    fn builder() -> Self::Builder {
+        PortalTargetPropsBuilder {
+            name: String::new(),
+        }
+    }
+    fn memoize(&mut self, new: &Self) -> bool {
+        let equal = self == new;
+        if !equal {
+            *self = new.clone();
+        }
+        equal
+    }
+}
+
+/// An element that [`Portal()`]s with the same `name` render their children into.
+///
+/// The target renders a single empty `div` which the children of every portal that targets it are appended to. If
+/// multiple targets have the same name, portals render into the last one that was created.
+#[allow(non_upper_case_globals, non_snake_case)]
+pub fn PortalTarget(props: PortalTargetProps) -> Element {
+    let runtime = Runtime::current();
+    runtime.register_portal_target(props.name, runtime.current_scope_id());
+
+    static TEMPLATE: Template = Template::new(
+        &[TemplateNode::Element {
+            tag: "div",
+            namespace: None,
+            attrs: &[],
+            children: &[],
+        }],
+        &[],
+        &[],
+    );
+    Ok(VNode::new(None, TEMPLATE, Box::new([]), Default::default()))
+}
@@ -0,0 +1,494 @@
+//! An opt-in profiler that records how long each component takes to render and diff.
+//!
+//! The profiler is only compiled in with the `profiler` feature, so apps that don't use it don't pay for the
+//! bookkeeping. Without the feature, [`Runtime::start_profiling`] logs a warning and nothing is recorded.
+//!
+//! Profiling is disabled by default. Once it is started with [`Runtime::start_profiling`], every frame the
+//! [`VirtualDom`] renders records:
+//! - The time each scope spent running its component
+//! - The time each scope spent diffing its output, including creating any new children
+//! - The number of mutations each diff wrote to the renderer
+//!
+//! When a frame finishes, a summary of every scope is emitted to `tracing` under the `dioxus_core::profiler` target.
+//! [`Runtime::stop_profiling`] returns the recorded [`Profile`] which can be exported to the
+//! [Chrome trace event format](https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU)
+//! and opened in `chrome://tracing` or [Perfetto](https://ui.perfetto.dev).
+
+use crate::innerlude::*;
+use std::{fmt::Write, time::Duration};
+#[cfg(feature = "profiler")]
+use {std::cell::Cell, web_time::Instant};
+
+/// A recorded profile of the frames a [`VirtualDom`] rendered while profiling was enabled
+#[derive(Debug, Clone, Default)]
+pub struct Profile {
+    /// The frames that were rendered, in order
+    pub frames: Vec<FrameProfile>,
+}
+
+/// The work done in a single frame
+#[derive(Debug, Clone)]
+pub struct FrameProfile {
+    /// When the frame started, relative to when profiling started
+    pub start: Duration,
+    /// How long the frame took
+    pub duration: Duration,
+    /// The number of mutations written in the frame
+    pub mutations: usize,
+    /// The render and diff spans recorded in the frame, in the order they finished
+    pub spans: Vec<ProfileSpan>,
+}
+
+/// What a [`ProfileSpan`] measured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileSpanKind {
+    /// Running the component
+    Render,
+    /// Diffing the output of the component and writing the mutations
+    Diff,
+}
+
+/// A single render or diff of a scope
+#[derive(Debug, Clone)]
+pub struct ProfileSpan {
+    /// What was measured
+    pub kind: ProfileSpanKind,
+    /// The scope that was rendered or diffed
+    pub scope: ScopeId,
+    /// The name of the component
+    pub name: &'static str,
+    /// When the span started, relative to when profiling started
+    pub start: Duration,
+    /// How long the span took
+    pub duration: Duration,
+    /// The number of mutations written, including the mutations written by any children that were created or diffed
+    /// in the span. This is always zero for render spans
+    pub mutations: usize,
+}
+
+/// The total work a scope did in a frame
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScopeProfile {
+    /// The scope
+    pub scope: ScopeId,
+    /// The name of the component
+    pub name: &'static str,
+    /// The number of times the component ran
+    pub renders: usize,
+    /// The total time spent running the component
+    pub render_time: Duration,
+    /// The total time spent diffing the output of the component
+    pub diff_time: Duration,
+    /// The total number of mutations the diffs of the scope wrote, including the mutations of any children it created
+    pub mutations: usize,
+}
+
+impl FrameProfile {
+    /// Sum up the work each scope did in the frame. Scopes are returned in the order they first rendered or diffed
+    pub fn scopes(&self) -> Vec<ScopeProfile> {
+        let mut scopes: Vec<ScopeProfile> = Vec::new();
+        for span in &self.spans {
+            let index = match scopes.iter().position(|scope| scope.scope == span.scope) {
+                Some(index) => index,
+                None => {
+                    scopes.push(ScopeProfile {
+                        scope: span.scope,
+                        name: span.name,
+                        renders: 0,
+                        render_time: Duration::ZERO,
+                        diff_time: Duration::ZERO,
+                        mutations: 0,
+                    });
+                    scopes.len() - 1
+                }
+            };
+            let scope = &mut scopes[index];
+            match span.kind {
+                ProfileSpanKind::Render => {
+                    scope.renders += 1;
+                    scope.render_time += span.duration;
+                }
+                ProfileSpanKind::Diff => {
+                    scope.diff_time += span.duration;
+                    scope.mutations += span.mutations;
+                }
+            }
+        }
+        scopes
+    }
+}
+
+impl Profile {
+    /// Export the profile as a JSON document in the Chrome trace event format
+    pub fn to_chrome_trace(&self) -> String {
+        let mut out = String::from("{\"traceEvents\":[");
+        let mut first = true;
+        let mut event = |out: &mut String,
+                         name: &str,
+                         category: &str,
+                         start: Duration,
+                         duration: Duration,
+                         args: &str| {
+            if !std::mem::take(&mut first) {
+                out.push(',');
+            }
+            out.push_str("{\"name\":");
+            write_json_string(out, name);
+            _ = write!(
+                out,
+                ",\"cat\":\"{category}\",\"ph\":\"X\",\"pid\":1,\"tid\":1,\"ts\":{},\"dur\":{},\"args\":{{{args}}}}}",
+                start.as_micros(),
+                duration.as_micros()
+            );
+        };
+
+        for (index, frame) in self.frames.iter().enumerate() {
+            let args = format!("\"mutations\":{}", frame.mutations);
+            event(
+                &mut out,
+                &format!("frame {index}"),
+                "frame",
+                frame.start,
+                frame.duration,
+                &args,
+            );
+            for span in &frame.spans {
+                let (category, args) = match span.kind {
+                    ProfileSpanKind::Render => ("render", format!("\"scope\":{}", span.scope.0)),
+                    ProfileSpanKind::Diff => (
+                        "diff",
+                        format!(
+                            "\"scope\":{},\"mutations\":{}",
+                            span.scope.0, span.mutations
+                        ),
+                    ),
+                };
+                event(
+                    &mut out,
+                    span.name,
+                    category,
+                    span.start,
+                    span.duration,
+                    &args,
+                );
+            }
+        }
+
+        out.push_str("]}");
+        out
+    }
+}
+
+fn write_json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => _ = write!(out, "\\u{:04x}", c as u32),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// The state of the profiler while it is recording
+#[cfg(feature = "profiler")]
+pub(crate) struct Profiler {
+    origin: Instant,
+    profile: Profile,
+    current_frame: Option<FrameProfile>,
+    /// The number of mutations that were written when the current frame started
+    frame_mutations_start: usize,
+}
+
+/// The point a span started at
+#[derive(Clone, Copy)]
+pub(crate) struct ProfileMark {
+    #[cfg(feature = "profiler")]
+    time: Duration,
+    #[cfg(feature = "profiler")]
+    mutations: usize,
+}
+
+#[cfg(feature = "profiler")]
+impl Profiler {
+    pub(crate) fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+            profile: Profile::default(),
+            current_frame: None,
+            frame_mutations_start: 0,
+        }
+    }
+
+    /// Get the time since profiling started
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+
+    /// Record a span in the current frame, starting a new frame if there isn't one
+    fn record(&mut self, span: ProfileSpan, mutations_start: usize) {
+        if self.current_frame.is_none() {
+            self.frame_mutations_start = mutations_start;
+        }
+        let start = span.start;
+        self.current_frame
+            .get_or_insert_with(|| FrameProfile {
+                start,
+                duration: Duration::ZERO,
+                mutations: 0,
+                spans: Vec::new(),
+            })
+            .spans
+            .push(span);
+    }
+
+    /// Start a new frame
+    fn begin_frame(&mut self, mutations: usize) {
+        self.end_frame(mutations);
+        self.frame_mutations_start = mutations;
+        self.current_frame = Some(FrameProfile {
+            start: self.now(),
+            duration: Duration::ZERO,
+            mutations: 0,
+            spans: Vec::new(),
+        });
+    }
+
+    /// Finish the current frame and report it to tracing. Frames without any work are dropped
+    fn end_frame(&mut self, mutations: usize) {
+        let Some(mut frame) = self.current_frame.take() else {
+            return;
+        };
+        if frame.spans.is_empty() {
+            return;
+        }
+        frame.duration = self.now().saturating_sub(frame.start);
+        frame.mutations = mutations - self.frame_mutations_start;
+
+        for scope in frame.scopes() {
+            tracing::debug!(
+                target: "dioxus_core::profiler",
+                scope = scope.scope.0,
+                component = scope.name,
+                renders = scope.renders,
+                render_us = scope.render_time.as_micros() as u64,
+                diff_us = scope.diff_time.as_micros() as u64,
+                mutations = scope.mutations,
+                "profiled scope"
+            );
+        }
+        tracing::debug!(
+            target: "dioxus_core::profiler",
+            frame = self.profile.frames.len(),
+            duration_us = frame.duration.as_micros() as u64,
+            mutations = frame.mutations,
+            "profiled frame"
+        );
+
+        self.profile.frames.push(frame);
+    }
+}
+
+impl Runtime {
+    /// Start recording how long each component takes to render and diff. Call [`Runtime::stop_profiling`] to get the recorded [`Profile`].
+    ///
+    /// If profiling was already started, the frames recorded so far are kept. This only logs a warning if dioxus-core
+    /// was built without the `profiler` feature.
+    pub fn start_profiling(&self) {
+        #[cfg(feature = "profiler")]
+        {
+            let mut profiler = self.profiler.borrow_mut();
+            if profiler.is_none() {
+                *profiler = Some(Profiler::new());
+            }
+        }
+
+        #[cfg(not(feature = "profiler"))]
+        tracing::warn!(
+            "Render profiling is not available. Enable the `profiler` feature of dioxus to record render profiles."
+        );
+    }
+
+    /// Stop profiling and return the recorded profile. Returns `None` if profiling was not started.
+    pub fn stop_profiling(&self) -> Option<Profile> {
+        #[cfg(feature = "profiler")]
+        {
+            let mut profiler = self.profiler.borrow_mut().take()?;
+            profiler.end_frame(self.mutations_written.get());
+            Some(profiler.profile)
+        }
+
+        #[cfg(not(feature = "profiler"))]
+        None
+    }
+
+    /// Check if the profiler is currently recording
+    pub fn is_profiling(&self) -> bool {
+        #[cfg(feature = "profiler")]
+        return self.profiler.borrow().is_some();
+
+        #[cfg(not(feature = "profiler"))]
+        false
+    }
+
+    /// Start a new frame in the profiler if it is recording
+    pub(crate) fn begin_profile_frame(&self) {
+        #[cfg(feature = "profiler")]
+        if let Some(profiler) = self.profiler.borrow_mut().as_mut() {
+            profiler.begin_frame(self.mutations_written.get());
+        }
+    }
+
+    /// Finish the current frame in the profiler if it is recording
+    pub(crate) fn end_profile_frame(&self) {
+        #[cfg(feature = "profiler")]
+        if let Some(profiler) = self.profiler.borrow_mut().as_mut() {
+            profiler.end_frame(self.mutations_written.get());
+        }
+    }
+
+    /// Mark the start of a span if the profiler is recording
+    pub(crate) fn profile_mark(&self) -> Option<ProfileMark> {
+        #[cfg(feature = "profiler")]
+        return self.profiler.borrow().as_ref().map(|profiler| ProfileMark {
+            time: profiler.now(),
+            mutations: self.mutations_written.get(),
+        });
+
+        #[cfg(not(feature = "profiler"))]
+        None
+    }
+
+    /// Record a span that started at `mark` and finished now
+    #[cfg_attr(not(feature = "profiler"), allow(unused_variables))]
+    pub(crate) fn record_profile_span(
+        &self,
+        kind: ProfileSpanKind,
+        scope: ScopeId,
+        mark: ProfileMark,
+    ) {
+        #[cfg(feature = "profiler")]
+        {
+            let name = self.try_get_state(scope).map(|state| state.name);
+            if let Some(profiler) = self.profiler.borrow_mut().as_mut() {
+                let duration = profiler.now().saturating_sub(mark.time);
+                let span = ProfileSpan {
+                    kind,
+                    scope,
+                    name: name.unwrap_or("unknown"),
+                    start: mark.time,
+                    duration,
+                    mutations: self.mutations_written.get() - mark.mutations,
+                };
+                profiler.record(span, mark.mutations);
+            }
+        }
+    }
+}
+
+/// A wrapper around a renderer that counts the mutations written to it while the profiler is recording
+#[cfg(feature = "profiler")]
+pub(crate) struct CountMutations<'a, M> {
+    inner: &'a mut M,
+    count: Option<&'a Cell<usize>>,
+}
+
+#[cfg(feature = "profiler")]
+impl<'a, M> CountMutations<'a, M> {
+    pub(crate) fn new(inner: &'a mut M, runtime: &'a Runtime) -> Self {
+        let count = runtime.is_profiling().then_some(&runtime.mutations_written);
+        Self { inner, count }
+    }
+
+    fn bump(&self) {
+        if let Some(count) = self.count {
+            count.set(count.get() + 1);
+        }
+    }
+}
+
+#[cfg(feature = "profiler")]
+impl<M: WriteMutations> WriteMutations for CountMutations<'_, M> {
+    fn append_children(&mut self, id: ElementId, m: usize) {
+        self.bump();
+        self.inner.append_children(id, m)
+    }
+
+    fn assign_node_id(&mut self, path: &'static [u8], id: ElementId) {
+        self.bump();
+        self.inner.assign_node_id(path, id)
+    }
+
+    fn create_placeholder(&mut self, id: ElementId) {
+        self.bump();
+        self.inner.create_placeholder(id)
+    }
+
+    fn create_text_node(&mut self, value: &str, id: ElementId) {
+        self.bump();
+        self.inner.create_text_node(value, id)
+    }
+
+    fn load_template(&mut self, template: Template, index: usize, id: ElementId) {
+        self.bump();
+        self.inner.load_template(template, index, id)
+    }
+
+    fn replace_node_with(&mut self, id: ElementId, m: usize) {
+        self.bump();
+        self.inner.replace_node_with(id, m)
+    }
+
+    fn replace_placeholder_with_nodes(&mut self, path: &'static [u8], m: usize) {
+        self.bump();
+        self.inner.replace_placeholder_with_nodes(path, m)
+    }
+
+    fn insert_nodes_after(&mut self, id: ElementId, m: usize) {
+        self.bump();
+        self.inner.insert_nodes_after(id, m)
+    }
+
+    fn insert_nodes_before(&mut self, id: ElementId, m: usize) {
+        self.bump();
+        self.inner.insert_nodes_before(id, m)
+    }
+
+    fn set_attribute(
+        &mut self,
+        name: &'static str,
+        ns: Option<&'static str>,
+        value: &AttributeValue,
+        id: ElementId,
+    ) {
+        self.bump();
+        self.inner.set_attribute(name, ns, value, id)
+    }
+
+    fn set_node_text(&mut self, value: &str, id: ElementId) {
+        self.bump();
+        self.inner.set_node_text(value, id)
+    }
+
+    fn create_event_listener(&mut self, name: &'static str, id: ElementId) {
+        self.bump();
+        self.inner.create_event_listener(name, id)
+    }
+
+    fn remove_event_listener(&mut self, name: &'static str, id: ElementId) {
+        self.bump();
+        self.inner.remove_event_listener(name, id)
+    }
+
+    fn remove_node(&mut self, id: ElementId) {
+        self.bump();
+        self.inner.remove_node(id)
+    }
+
+    fn push_root(&mut self, id: ElementId) {
+        self.bump();
+        self.inner.push_root(id)
+    }
+}
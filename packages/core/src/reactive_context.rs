@@ -1,4 +1,9 @@
-use crate::{Runtime, ScopeId, current_scope_id, scope_context::Scope, tasks::SchedulerMsg};
+use crate::{
+    Runtime, ScopeId, current_scope_id,
+    reactive_graph::{GraphNode, ReactiveGraphRecorder},
+    scope_context::Scope,
+    tasks::SchedulerMsg,
+};
 use futures_channel::mpsc::UnboundedReceiver;
 use generational_box::{BorrowMutError, GenerationalBox, SyncStorage};
 use std::{
@@ -72,17 +77,18 @@ impl ReactiveContext {
         scope: ScopeId,
         #[allow(unused)] origin: &'static std::panic::Location<'static>,
     ) -> Self {
+        let runtime = Runtime::current();
         let inner = Inner {
             self_: None,
             update: Box::new(callback),
             subscribers: Default::default(),
             #[cfg(debug_assertions)]
             origin,
-            #[cfg(debug_assertions)]
             scope: None,
+            graph: runtime.reactive_graph.clone(),
         };
 
-        let owner = Runtime::current().scope_owner(scope);
+        let owner = runtime.scope_owner(scope);
 
         let self_ = Self {
             scope,
@@ -104,7 +110,7 @@ impl ReactiveContext {
         let id = scope.id;
         let sender = runtime.sender.clone();
         let update_scope = move || {
-            _ = sender.unbounded_send(SchedulerMsg::Immediate(id));
+            _ = sender.unbounded_send(SchedulerMsg::mark_dirty(id));
         };
 
         // Otherwise, create a new context at the current scope
@@ -114,8 +120,8 @@ impl ReactiveContext {
             subscribers: Default::default(),
             #[cfg(debug_assertions)]
             origin: std::panic::Location::caller(),
-            #[cfg(debug_assertions)]
             scope: Some(id),
+            graph: runtime.reactive_graph.clone(),
         };
 
         let owner = scope.owner();
@@ -138,6 +144,7 @@ impl ReactiveContext {
         for subscriber in old_subscribers {
             subscriber.0.remove(self);
         }
+        crate::reactive_graph::record_reset(self);
     }
 
     /// Update the subscribers
@@ -225,6 +232,9 @@ impl ReactiveContext {
             }
 
             (self_write.update)();
+            let node = self.graph_node_of(&self_write);
+            drop(self_write);
+            crate::reactive_graph::record_update(node);
 
             true
         } else {
@@ -256,6 +266,28 @@ impl ReactiveContext {
     pub fn origin_scope(&self) -> ScopeId {
         self.scope
     }
+
+    /// Get the node of this context in the reactive graph of its runtime, if the runtime is recording
+    pub(crate) fn graph_node(&self) -> Option<GraphNode> {
+        let inner = self.inner.try_read().ok()?;
+        self.graph_node_of(&inner)
+    }
+
+    fn graph_node_of(&self, inner: &Inner) -> Option<GraphNode> {
+        if !inner.graph.is_recording() {
+            return None;
+        }
+        Some(GraphNode {
+            graph: inner.graph.clone(),
+            key: self.inner.id(),
+            scope: inner.scope,
+            origin_scope: self.scope,
+            #[cfg(debug_assertions)]
+            created_at: Some(inner.origin),
+            #[cfg(not(debug_assertions))]
+            created_at: None,
+        })
+    }
 }
 
 impl Hash for ReactiveContext {
@@ -299,9 +331,11 @@ struct Inner {
     #[cfg(debug_assertions)]
     origin: &'static std::panic::Location<'static>,
 
-    #[cfg(debug_assertions)]
-    // The scope that this reactive context is associated with
+    // The scope that this reactive context reruns, if it belongs to a component
     scope: Option<ScopeId>,
+
+    // The reactive graph of the runtime this context was created in
+    graph: ReactiveGraphRecorder,
 }
 
 impl Drop for Inner {
@@ -313,6 +347,8 @@ impl Drop for Inner {
         for subscriber in std::mem::take(&mut self.subscribers) {
             subscriber.0.remove(&self_);
         }
+
+        self.graph.remove_context(self_.inner.id());
     }
 }
 
@@ -0,0 +1,474 @@
+//! Optional recording of the reactive graph for debugging tools.
+//!
+//! When recording is enabled with [`Runtime::start_recording_reactive_graph`], reactive values like signals
+//! report every [`ReactiveContext`] that reads them and every write that marks a reactive context dirty.
+//! Devtools can then take a [`ReactiveGraph`] snapshot with [`Runtime::reactive_graph`] to show the dependency
+//! graph of the app and explain why a scope reran.
+//!
+//! Each runtime records its own graph. Reactive contexts keep a handle to the graph of the runtime they were
+//! created in, so writes from other threads are still attributed to the right app. Contexts are removed from
+//! the graph when they are dropped, and values are removed once no context or recent update refers to them.
+//!
+//! Recording is disabled by default and costs a single atomic load per read and write while no runtime is
+//! recording.
+
+use crate::{ReactiveContext, Runtime, ScopeId};
+use generational_box::GenerationalBoxId;
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet, VecDeque},
+    panic::Location,
+    sync::{
+        Arc, Mutex, MutexGuard,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+};
+
+/// The maximum number of updates kept in the graph. Older updates are dropped first.
+const MAX_UPDATES: usize = 1024;
+
+/// The number of runtimes that are currently recording their reactive graph
+static RECORDING_RUNTIMES: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The writes that are currently notifying their subscribers on this thread
+    static CURRENT_WRITES: RefCell<Vec<(ReactiveSource, Option<&'static Location<'static>>)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) fn any_runtime_recording() -> bool {
+    RECORDING_RUNTIMES.load(Ordering::Relaxed) > 0
+}
+
+impl Runtime {
+    /// Start recording the reactive graph of this runtime.
+    ///
+    /// While recording, reads and writes of reactive values are tracked so they can be inspected with
+    /// [`Runtime::reactive_graph`]. If recording was already started, the graph recorded so far is kept.
+    pub fn start_recording_reactive_graph(&self) {
+        self.reactive_graph.set_recording(true);
+    }
+
+    /// Stop recording the reactive graph of this runtime. The graph recorded so far is kept until
+    /// [`Runtime::clear_reactive_graph`] is called.
+    pub fn stop_recording_reactive_graph(&self) {
+        self.reactive_graph.set_recording(false);
+    }
+
+    /// Check if the reactive graph of this runtime is currently being recorded.
+    pub fn is_recording_reactive_graph(&self) -> bool {
+        self.reactive_graph.is_recording()
+    }
+
+    /// Take a snapshot of the reactive graph recorded so far.
+    pub fn reactive_graph(&self) -> ReactiveGraph {
+        self.reactive_graph.lock().snapshot()
+    }
+
+    /// Clear the recorded reactive graph.
+    pub fn clear_reactive_graph(&self) {
+        self.reactive_graph.lock().clear();
+    }
+}
+
+/// A handle to the reactive graph of a runtime
+#[derive(Clone, Default)]
+pub(crate) struct ReactiveGraphRecorder {
+    inner: Arc<RecorderInner>,
+}
+
+#[derive(Default)]
+struct RecorderInner {
+    recording: AtomicBool,
+    state: Mutex<GraphState>,
+}
+
+impl Drop for RecorderInner {
+    fn drop(&mut self) {
+        if *self.recording.get_mut() {
+            RECORDING_RUNTIMES.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
+
+impl ReactiveGraphRecorder {
+    fn set_recording(&self, enabled: bool) {
+        if self.inner.recording.swap(enabled, Ordering::Relaxed) != enabled {
+            match enabled {
+                true => RECORDING_RUNTIMES.fetch_add(1, Ordering::Relaxed),
+                false => RECORDING_RUNTIMES.fetch_sub(1, Ordering::Relaxed),
+            };
+        }
+    }
+
+    pub(crate) fn is_recording(&self) -> bool {
+        self.inner.recording.load(Ordering::Relaxed)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, GraphState> {
+        self.inner
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Remove a reactive context that was dropped from the graph
+    pub(crate) fn remove_context(&self, key: GenerationalBoxId) {
+        self.lock().remove_context(key);
+    }
+}
+
+/// A reactive context as it is recorded in the graph of the runtime it was created in
+pub(crate) struct GraphNode {
+    pub(crate) graph: ReactiveGraphRecorder,
+    pub(crate) key: GenerationalBoxId,
+    pub(crate) scope: Option<ScopeId>,
+    pub(crate) origin_scope: ScopeId,
+    pub(crate) created_at: Option<&'static Location<'static>>,
+}
+
+/// A reactive value like a signal or memo that reactive contexts can subscribe to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReactiveSource {
+    id: GenerationalBoxId,
+    created_at: Option<&'static Location<'static>>,
+}
+
+impl ReactiveSource {
+    /// Create a new reactive source from the id of the value and the location it was created at.
+    pub fn new(id: GenerationalBoxId, created_at: Option<&'static Location<'static>>) -> Self {
+        Self { id, created_at }
+    }
+
+    /// Record that a reactive context read this value. This does nothing if the runtime of the context is not
+    /// recording its graph.
+    pub fn record_read(self, context: &ReactiveContext) {
+        if !any_runtime_recording() {
+            return;
+        }
+        let Some(node) = context.graph_node() else {
+            return;
+        };
+        let mut graph = node.graph.lock();
+        let source = graph.source_id(self);
+        if graph.context(&node).reads.insert(source) {
+            graph.retain_source(source);
+        }
+    }
+
+    /// Record a write to this value. Any reactive contexts that are marked dirty while the returned
+    /// guard is alive are attributed to this write.
+    pub fn record_write(
+        self,
+        written_at: Option<&'static Location<'static>>,
+    ) -> Option<ReactiveWriteGuard> {
+        if !any_runtime_recording() {
+            return None;
+        }
+        CURRENT_WRITES.with(|writes| writes.borrow_mut().push((self, written_at)));
+        Some(ReactiveWriteGuard { _private: () })
+    }
+}
+
+/// A guard returned by [`ReactiveSource::record_write`]. Reactive contexts marked dirty while this
+/// guard is alive are attributed to the write.
+pub struct ReactiveWriteGuard {
+    _private: (),
+}
+
+impl Drop for ReactiveWriteGuard {
+    fn drop(&mut self) {
+        CURRENT_WRITES.with(|writes| writes.borrow_mut().pop());
+    }
+}
+
+/// Record that a reactive context was marked dirty by the current write.
+pub(crate) fn record_update(node: Option<GraphNode>) {
+    let Some(node) = node else {
+        return;
+    };
+    let write = CURRENT_WRITES.with(|writes| writes.borrow().last().copied());
+    let mut graph = node.graph.lock();
+    let source = write.map(|(source, _)| graph.source_id(source));
+    let context = graph.context(&node).id;
+    let update = ReactiveUpdate {
+        sequence: 0,
+        source,
+        written_at: write.and_then(|(_, written_at)| written_at).map(Into::into),
+        context,
+        scope: node.scope,
+    };
+    graph.push_update(update);
+}
+
+/// Record that a reactive context was reset and no longer reads any values.
+pub(crate) fn record_reset(context: &ReactiveContext) {
+    if !any_runtime_recording() {
+        return;
+    }
+    let Some(node) = context.graph_node() else {
+        return;
+    };
+    node.graph.lock().reset_context(node.key);
+}
+
+#[derive(Default)]
+struct GraphState {
+    next_id: u64,
+    next_sequence: u64,
+    source_ids: HashMap<GenerationalBoxId, u64>,
+    sources: HashMap<u64, SourceState>,
+    contexts: HashMap<GenerationalBoxId, ContextState>,
+    updates: VecDeque<ReactiveUpdate>,
+}
+
+struct SourceState {
+    key: GenerationalBoxId,
+    info: ReactiveSourceInfo,
+    // The number of contexts that read the source plus the number of updates caused by it
+    references: usize,
+}
+
+struct ContextState {
+    id: u64,
+    scope: Option<ScopeId>,
+    origin_scope: ScopeId,
+    created_at: Option<&'static Location<'static>>,
+    reads: HashSet<u64>,
+}
+
+impl GraphState {
+    fn next_id(&mut self) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    fn clear(&mut self) {
+        self.source_ids.clear();
+        self.sources.clear();
+        self.contexts.clear();
+        self.updates.clear();
+    }
+
+    /// Get the id of a source, adding it to the graph if it isn't there yet. The caller must retain new sources
+    /// so they are removed once nothing refers to them anymore.
+    fn source_id(&mut self, source: ReactiveSource) -> u64 {
+        if let Some(id) = self.source_ids.get(&source.id) {
+            return *id;
+        }
+        let id = self.next_id();
+        self.source_ids.insert(source.id, id);
+        self.sources.insert(
+            id,
+            SourceState {
+                key: source.id,
+                info: ReactiveSourceInfo {
+                    id,
+                    created_at: source.created_at.map(Into::into),
+                },
+                references: 0,
+            },
+        );
+        id
+    }
+
+    fn retain_source(&mut self, id: u64) {
+        if let Some(source) = self.sources.get_mut(&id) {
+            source.references += 1;
+        }
+    }
+
+    fn release_source(&mut self, id: u64) {
+        let Some(source) = self.sources.get_mut(&id) else {
+            return;
+        };
+        source.references -= 1;
+        if source.references == 0 {
+            let key = source.key;
+            self.sources.remove(&id);
+            self.source_ids.remove(&key);
+        }
+    }
+
+    fn context(&mut self, node: &GraphNode) -> &mut ContextState {
+        if !self.contexts.contains_key(&node.key) {
+            let id = self.next_id();
+            self.contexts.insert(
+                node.key,
+                ContextState {
+                    id,
+                    scope: node.scope,
+                    origin_scope: node.origin_scope,
+                    created_at: node.created_at,
+                    reads: HashSet::new(),
+                },
+            );
+        }
+        self.contexts.get_mut(&node.key).unwrap()
+    }
+
+    fn reset_context(&mut self, key: GenerationalBoxId) {
+        let Some(context) = self.contexts.get_mut(&key) else {
+            return;
+        };
+        for source in std::mem::take(&mut context.reads) {
+            self.release_source(source);
+        }
+    }
+
+    fn remove_context(&mut self, key: GenerationalBoxId) {
+        let Some(context) = self.contexts.remove(&key) else {
+            return;
+        };
+        for source in context.reads {
+            self.release_source(source);
+        }
+    }
+
+    fn push_update(&mut self, mut update: ReactiveUpdate) {
+        update.sequence = self.next_sequence;
+        self.next_sequence += 1;
+        if let Some(source) = update.source {
+            self.retain_source(source);
+        }
+        if self.updates.len() == MAX_UPDATES
+            && let Some(oldest) = self.updates.pop_front()
+            && let Some(source) = oldest.source
+        {
+            self.release_source(source);
+        }
+        self.updates.push_back(update);
+    }
+
+    fn snapshot(&self) -> ReactiveGraph {
+        let mut sources: Vec<_> = self
+            .sources
+            .values()
+            .map(|source| source.info.clone())
+            .collect();
+        sources.sort_by_key(|source| source.id);
+        let mut contexts: Vec<_> = self
+            .contexts
+            .values()
+            .map(|context| {
+                let mut reads: Vec<_> = context.reads.iter().copied().collect();
+                reads.sort_unstable();
+                ReactiveContextInfo {
+                    id: context.id,
+                    scope: context.scope,
+                    origin_scope: context.origin_scope,
+                    created_at: context.created_at.map(Into::into),
+                    reads,
+                }
+            })
+            .collect();
+        contexts.sort_by_key(|context| context.id);
+        ReactiveGraph {
+            sources,
+            contexts,
+            updates: self.updates.iter().cloned().collect(),
+        }
+    }
+}
+
+/// A location in the source code of the app.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CodeLocation {
+    /// The file the code is in
+    pub file: String,
+    /// The line of the code in the file
+    pub line: u32,
+    /// The column of the code in the line
+    pub column: u32,
+}
+
+impl From<&'static Location<'static>> for CodeLocation {
+    fn from(location: &'static Location<'static>) -> Self {
+        Self {
+            file: location.file().to_string(),
+            line: location.line(),
+            column: location.column(),
+        }
+    }
+}
+
+impl std::fmt::Display for CodeLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+/// A snapshot of the reactive graph recorded while [`Runtime::start_recording_reactive_graph`] was enabled.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReactiveGraph {
+    /// Every reactive value that was read or written
+    pub sources: Vec<ReactiveSourceInfo>,
+    /// Every reactive context that is still alive and read a value or was marked dirty
+    pub contexts: Vec<ReactiveContextInfo>,
+    /// The most recent updates, oldest first
+    pub updates: Vec<ReactiveUpdate>,
+}
+
+impl ReactiveGraph {
+    /// Get a reactive source by id.
+    pub fn source(&self, id: u64) -> Option<&ReactiveSourceInfo> {
+        self.sources.iter().find(|source| source.id == id)
+    }
+
+    /// Get a reactive context by id.
+    pub fn context(&self, id: u64) -> Option<&ReactiveContextInfo> {
+        self.contexts.iter().find(|context| context.id == id)
+    }
+
+    /// Get the updates that caused a scope to rerun, oldest first.
+    pub fn why_did_render(&self, scope: ScopeId) -> impl Iterator<Item = &ReactiveUpdate> {
+        self.updates
+            .iter()
+            .filter(move |update| update.scope == Some(scope))
+    }
+}
+
+/// A reactive value like a signal or memo.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReactiveSourceInfo {
+    /// The id of the value in this graph
+    pub id: u64,
+    /// Where the value was created. This is only available in debug builds.
+    pub created_at: Option<CodeLocation>,
+}
+
+/// A reactive context like a component, memo or effect.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReactiveContextInfo {
+    /// The id of the context in this graph
+    pub id: u64,
+    /// The scope this context reruns when it is marked dirty, if it belongs to a component
+    pub scope: Option<ScopeId>,
+    /// The scope the context was created in
+    pub origin_scope: ScopeId,
+    /// Where the context was created. This is only available in debug builds.
+    pub created_at: Option<CodeLocation>,
+    /// The ids of the sources the context read the last time it ran
+    pub reads: Vec<u64>,
+}
+
+/// A write to a reactive source that marked a reactive context dirty.
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReactiveUpdate {
+    /// The order of the update. Updates with a higher sequence happened later.
+    pub sequence: u64,
+    /// The id of the source that was written, if the write was recorded
+    pub source: Option<u64>,
+    /// Where the write happened. This is only available in debug builds.
+    pub written_at: Option<CodeLocation>,
+    /// The id of the context that was marked dirty
+    pub context: u64,
+    /// The scope that will rerun because of the update, if the context belongs to a component
+    pub scope: Option<ScopeId>,
+}
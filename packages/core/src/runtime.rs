@@ -1,11 +1,12 @@
 use crate::nodes::VNodeMount;
+use crate::reactive_graph::ReactiveGraphRecorder;
 use crate::scheduler::ScopeOrder;
 use crate::scope_context::SuspenseLocation;
 use crate::{AttributeValue, ElementId, Event};
 use crate::{CapturedError, arena::ElementRef};
 use crate::{
     SuspenseContext,
-    innerlude::{DirtyTasks, Effect},
+    innerlude::{DirtyTasks, Effect, Portals},
 };
 use crate::{
     Task,
@@ -68,6 +69,23 @@ pub struct Runtime {
     // We need to store this information on the virtual dom so that we know what nodes are mounted where when we bubble events
     // Each mount is associated with a whole rsx block. [`VirtualDom::elements`] link to a specific node in the block
     pub(crate) mounts: RefCell<Slab<VNodeMount>>,
+
+    // The render profiler, if profiling is enabled
+    #[cfg(feature = "profiler")]
+    pub(crate) profiler: RefCell<Option<crate::innerlude::Profiler>>,
+
+    // The portal targets and portals that are mounted
+    pub(crate) portals: RefCell<Portals>,
+
+    // The number of mutations written by renders. The profiler uses this to count the mutations of each span
+    #[cfg(feature = "profiler")]
+    pub(crate) mutations_written: Cell<usize>,
+
+    // The reactive graph of this runtime, recorded while devtools are inspecting it
+    pub(crate) reactive_graph: ReactiveGraphRecorder,
+
+    // The suspense lists that are alive, so their members can be reordered when the tree changes
+    pub(crate) suspense_lists: crate::innerlude::SuspenseLists,
 }
 
 impl Runtime {
@@ -89,6 +107,13 @@ impl Runtime {
             dirty_tasks: Default::default(),
             elements: RefCell::new(elements),
             mounts: Default::default(),
+            #[cfg(feature = "profiler")]
+            profiler: Default::default(),
+            #[cfg(feature = "profiler")]
+            mutations_written: Default::default(),
+            portals: Default::default(),
+            reactive_graph: Default::default(),
+            suspense_lists: Default::default(),
         })
     }
 
@@ -346,14 +371,18 @@ fn MyComponent() -> Element {{
     /// Check if we should render a scope
     pub(crate) fn scope_should_render(&self, scope_id: ScopeId) -> bool {
         // If there are no suspended futures, we know the scope is not  and we can skip context checks
-        if self.suspended_tasks.get() == 0 {
-            return true;
+        if self.suspended_tasks.get() != 0 {
+            // If this is not a suspended scope, and we are under a frozen context, then we should
+            let scopes = self.scope_states.borrow();
+            let scope = &scopes[scope_id.0].as_ref().unwrap();
+            if matches!(scope.suspense_location(), SuspenseLocation::UnderSuspense(suspense) if suspense.is_suspended())
+            {
+                return false;
+            }
         }
 
-        // If this is not a suspended scope, and we are under a frozen context, then we should
-        let scopes = self.scope_states.borrow();
-        let scope = &scopes[scope_id.0].as_ref().unwrap();
-        !matches!(scope.suspense_location(), SuspenseLocation::UnderSuspense(suspense) if suspense.is_suspended())
+        // Portals that are not rendered into their target only create their children in the background
+        !self.scope_in_detached_portal(scope_id)
     }
 
     /// Call a listener inside the VirtualDom with data from outside the VirtualDom. **The ElementId passed in must be the id of an element with a listener, not a static node or a text node.**
@@ -567,6 +596,13 @@ fn MyComponent() -> Element {{
     /// ```
     pub fn throw_error(&self, id: ScopeId, error: impl Into<CapturedError> + 'static) {
         let error = error.into();
+        if let Some(reporter) = self.consume_context::<crate::ErrorReporter>(id) {
+            reporter.report(&crate::ErrorReport {
+                error: error.clone(),
+                scope: id,
+                scope_path: self.scope_path(id),
+            });
+        }
         if let Some(cx) = self.consume_context::<crate::ErrorContext>(id) {
             cx.insert_error(error)
         } else {
@@ -577,6 +613,18 @@ fn MyComponent() -> Element {{
         }
     }
 
+    /// Get the names of the components from the root down to a scope
+    fn scope_path(&self, id: ScopeId) -> Vec<&'static str> {
+        let mut path = Vec::new();
+        let mut current = Some(id);
+        while let Some(scope) = current.and_then(|id| self.try_get_state(id)) {
+            path.push(scope.name);
+            current = scope.parent_id;
+        }
+        path.reverse();
+        path
+    }
+
     /// Get the suspense context the current scope is in
     pub fn suspense_context(&self) -> Option<SuspenseContext> {
         self.get_state(self.current_scope_id())
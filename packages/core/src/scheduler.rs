@@ -75,13 +75,20 @@
 //! 3. Effects:
 //!    Description: Effects should always run after all changes to the DOM have been applied.
 //!    Priority: These are the lowest priority tasks in the scheduler. They are run after all other dirty scopes and futures have been resolved. Other tasks may cause components to rerun, which would update the DOM. These effects should only run after the DOM has been updated.
+//!
+//! ## Transitions
+//!
+//! Updates made inside [`start_transition`] mark scopes as dirty with a low priority. Those scopes are kept in a separate
+//! queue that is only rendered once there are no urgent dirty scopes or tasks left. If an urgent update arrives for
+//! the same scope, the urgent rerun replaces the transition. While a transition renders, any suspense boundary that
+//! becomes suspended keeps showing its current children instead of switching to the fallback.
 
 use crate::ScopeId;
 use crate::Task;
 use crate::VirtualDom;
 use crate::innerlude::Effect;
 use std::borrow::Borrow;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::VecDeque;
 use std::hash::Hash;
 
@@ -121,6 +128,64 @@ impl Hash for ScopeOrder {
     }
 }
 
+thread_local! {
+    /// How many transitions are running on this thread
+    static TRANSITION_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Mark every update made inside the closure as a low priority transition.
+///
+/// Components that rerun because of a transition are rendered after all urgent work like input events, so expensive
+/// rerenders don't block the UI. If a suspense boundary suspends while a transition renders, the boundary keeps
+/// showing the previous UI instead of the fallback.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// fn app() -> Element {
+///     let mut input = use_signal(String::new);
+///     let mut filter = use_signal(String::new);
+///     rsx! {
+///         input {
+///             value: "{input}",
+///             oninput: move |evt| {
+///                 // Update the input right away
+///                 input.set(evt.value());
+///                 // Filtering the list can wait until the input is rendered
+///                 start_transition(|| filter.set(evt.value()));
+///             }
+///         }
+///         SlowList { filter }
+///     }
+/// }
+///
+/// #[component]
+/// fn SlowList(filter: ReadSignal<String>) -> Element {
+///     rsx! {
+///         for i in (0..10_000).filter(|i| i.to_string().contains(&*filter.read())) {
+///             p { "{i}" }
+///         }
+///     }
+/// }
+/// ```
+pub fn start_transition<O>(f: impl FnOnce() -> O) -> O {
+    struct TransitionGuard;
+
+    impl Drop for TransitionGuard {
+        fn drop(&mut self) {
+            TRANSITION_DEPTH.with(|depth| depth.set(depth.get() - 1));
+        }
+    }
+
+    TRANSITION_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let _guard = TransitionGuard;
+    f()
+}
+
+/// Check if the current code is running inside of [`start_transition`] or while a transition is rendered.
+pub fn is_in_transition() -> bool {
+    TRANSITION_DEPTH.with(|depth| depth.get() > 0)
+}
+
 impl VirtualDom {
     /// Queue a task to be polled
     pub(crate) fn queue_task(&mut self, task: Task, order: ScopeOrder) {
@@ -140,11 +205,24 @@ impl VirtualDom {
         self.dirty_scopes.insert(order);
     }
 
+    /// Queue a scope to be rerendered as part of a transition
+    pub(crate) fn queue_transition_scope(&mut self, order: ScopeOrder) {
+        // If the scope is already queued as urgent work, it will rerun before the transition would
+        if !self.dirty_scopes.contains(&order) {
+            self.transition_scopes.insert(order);
+        }
+    }
+
     /// Check if there are any dirty scopes
     pub(crate) fn has_dirty_scopes(&self) -> bool {
         !self.dirty_scopes.is_empty()
     }
 
+    /// Check if there are any scopes waiting to rerun as part of a transition
+    pub fn has_pending_transitions(&self) -> bool {
+        !self.transition_scopes.is_empty()
+    }
+
     /// Take the top task from the highest scope
     pub(crate) fn pop_task(&mut self) -> Option<Task> {
         let mut dirty_tasks = self.runtime.dirty_tasks.borrow_mut();
@@ -218,7 +296,11 @@ impl VirtualDom {
                 Some(Work::RerunScope(scope))
             }
             (None, Some(_)) => Some(Work::PollTask(self.pop_task().unwrap())),
-            (None, None) => None,
+            // Transitions only run once all of the urgent work is finished
+            (None, None) => self
+                .transition_scopes
+                .pop_first()
+                .map(Work::RerunTransition),
         }
     }
 }
@@ -226,6 +308,7 @@ impl VirtualDom {
 #[derive(Debug)]
 pub enum Work {
     RerunScope(ScopeOrder),
+    RerunTransition(ScopeOrder),
     PollTask(Task),
 }
 
@@ -1,7 +1,7 @@
 use crate::{
     Element, ReactiveContext,
     any_props::{AnyProps, BoxedAnyProps},
-    innerlude::{RenderError, ScopeOrder, ScopeState},
+    innerlude::{ProfileSpanKind, RenderError, ScopeOrder, ScopeState},
     scope_context::{Scope, SuspenseLocation},
     scopes::ScopeId,
     virtual_dom::VirtualDom,
@@ -48,6 +48,8 @@ impl VirtualDom {
         // Ensure we are currently inside a `Runtime`.
         crate::Runtime::current();
 
+        let profile_start = self.runtime.profile_mark();
+
         self.runtime.clone().with_scope_on_stack(scope_id, || {
             let scope = &self.scopes[scope_id.0];
             let output = {
@@ -98,8 +100,15 @@ impl VirtualDom {
             }
 
             // remove this scope from dirty scopes
-            self.dirty_scopes
-                .remove(&ScopeOrder::new(scope_state.height, scope_id));
+            let order = ScopeOrder::new(scope_state.height, scope_id);
+            self.dirty_scopes.remove(&order);
+            self.transition_scopes.remove(&order);
+
+            if let Some(mark) = profile_start {
+                self.runtime
+                    .record_profile_span(ProfileSpanKind::Render, scope_id, mark);
+            }
+
             output
         })
     }
@@ -290,6 +290,13 @@ impl SuspenseBoundaryProps {
                             .state();
                         suspense_context.mount(scope_state.id);
                         scope_id = scope_state.id;
+
+                        // Join the suspense list this boundary is directly inside of, and hide that list from any nested boundaries
+                        if let Some(NearestSuspenseList(Some(list))) = scope_state.consume_context()
+                        {
+                            suspense_context.join_suspense_list(list);
+                        }
+                        scope_state.provide_context(NearestSuspenseList(None));
                     });
             }
 
@@ -323,9 +330,9 @@ impl SuspenseBoundaryProps {
                 .unwrap()
                 .clone();
 
-            // If there are suspended futures, render the fallback
+            // If there are suspended futures or the suspense list is holding this boundary back, render the fallback
 
-            if !suspense_context.suspended_futures().is_empty() {
+            if suspense_context.should_show_fallback() {
                 let (node, nodes_created) =
                     suspense_context.in_suspense_placeholder(&dom.runtime(), || {
                         let scope_state = &mut dom.scopes[scope_id.0];
@@ -337,8 +344,10 @@ impl SuspenseBoundaryProps {
                             )
                             .unwrap();
                         suspense_context.set_suspended_nodes(children.as_vnode().clone());
-                        let suspense_placeholder =
-                            LastRenderedNode::new(props.fallback.call(suspense_context));
+                        let suspense_placeholder = LastRenderedNode::new(render_fallback(
+                            &props.fallback,
+                            suspense_context,
+                        ));
                         let nodes_created = suspense_placeholder.create(dom, parent, to);
                         (suspense_placeholder, nodes_created)
                     });
@@ -434,6 +443,7 @@ impl SuspenseBoundaryProps {
             let props = Self::downcast_from_props(&mut *scope_state.props).unwrap();
             props.children.clone_from(&children);
             scope_state.last_rendered_node = Some(children);
+            suspense_context.set_revealed(true);
 
             // Run any closures that were waiting for the suspense to resolve
             suspense_context.run_resolved_closures(&dom.runtime);
@@ -459,7 +469,7 @@ impl SuspenseBoundaryProps {
 
             let suspense_context = scope.state().suspense_boundary().unwrap().clone();
             let suspended_nodes = suspense_context.suspended_nodes();
-            let suspended = !suspense_context.suspended_futures().is_empty();
+            let suspended = suspense_context.should_show_fallback();
             match (suspended_nodes, suspended) {
                 // We already have suspended nodes that still need to be suspended
                 // Just diff the normal and suspended nodes
@@ -470,8 +480,10 @@ impl SuspenseBoundaryProps {
                     let new_placeholder =
                         suspense_context.in_suspense_placeholder(&dom.runtime(), || {
                             let old_placeholder = last_rendered_node;
-                            let new_placeholder =
-                                LastRenderedNode::new(fallback.call(suspense_context.clone()));
+                            let new_placeholder = LastRenderedNode::new(render_fallback(
+                                &fallback,
+                                suspense_context.clone(),
+                            ));
 
                             old_placeholder.diff_node(&new_placeholder, dom, to);
                             new_placeholder
@@ -493,7 +505,9 @@ impl SuspenseBoundaryProps {
                     suspense_context.set_suspended_nodes(new_suspended_nodes);
                 }
                 // We have no suspended nodes, and we are not suspended. Just diff the children like normal
-                (None, false) => {
+                // If we just became suspended inside a transition, keep showing the current children instead of the fallback.
+                // The suspended components keep their last rendered nodes until they resolve
+                (None, suspended) if !suspended || is_in_transition() => {
                     let old_children = last_rendered_node;
                     let new_children = children;
 
@@ -505,12 +519,12 @@ impl SuspenseBoundaryProps {
                     dom.scopes[scope_id.0].last_rendered_node = new_children.into();
                 }
                 // We have no suspended nodes, but we just became suspended. Move the children to the background
-                (None, true) => {
+                (None, _) => {
                     let old_children = last_rendered_node;
                     let new_children: VNode = children.as_vnode().clone();
 
                     let new_placeholder =
-                        LastRenderedNode::new(fallback.call(suspense_context.clone()));
+                        LastRenderedNode::new(render_fallback(&fallback, suspense_context.clone()));
 
                     // Move the children to the background
                     let mount = old_children.mount.get();
@@ -540,6 +554,7 @@ impl SuspenseBoundaryProps {
                     .unwrap();
                     suspense_context.set_suspended_nodes(new_children);
 
+                    suspense_context.set_revealed(false);
                     un_resolve_suspense(dom, scope_id);
                 }
                 // We have suspended nodes, but we just got out of suspense. Move the suspended nodes to the foreground
@@ -574,6 +589,18 @@ impl SuspenseBoundaryProps {
     }
 }
 
+/// Render the fallback of a suspense boundary, unless the suspense list it is in hides it
+fn render_fallback(
+    fallback: &Callback<SuspenseContext, Element>,
+    suspense_context: SuspenseContext,
+) -> Element {
+    if suspense_context.fallback_visible() {
+        fallback.call(suspense_context)
+    } else {
+        VNode::empty()
+    }
+}
+
 /// Move to a resolved suspense state
 fn mark_suspense_resolved(
     suspense_context: &SuspenseContext,
@@ -581,6 +608,7 @@ fn mark_suspense_resolved(
     scope_id: ScopeId,
 ) {
     dom.resolved_scopes.push(scope_id);
+    suspense_context.set_revealed(true);
     // Run any closures that were waiting for the suspense to resolve
     suspense_context.run_resolved_closures(&dom.runtime);
 }
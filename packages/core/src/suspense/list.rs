@@ -0,0 +1,430 @@
+use super::{SuspenseBoundaryInner, SuspenseContext};
+use crate::innerlude::*;
+use std::{
+    cell::{Cell, RefCell},
+    rc::{Rc, Weak},
+};
+
+/// The order a [`SuspenseList()`] reveals the suspense boundaries inside of it in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum SuspenseRevealOrder {
+    /// Reveal boundaries from top to bottom. A boundary stays in its fallback until every boundary before it has resolved.
+    #[default]
+    Forwards,
+    /// Reveal boundaries from bottom to top. A boundary stays in its fallback until every boundary after it has resolved.
+    Backwards,
+    /// Reveal every boundary at the same time once all of them have resolved.
+    Together,
+}
+
+/// Controls which fallbacks a [`SuspenseList()`] shows for the boundaries that are not revealed yet.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum SuspenseTail {
+    /// Show the fallback of every boundary that is not revealed yet.
+    #[default]
+    Visible,
+    /// Only show the fallback of the next boundary that will be revealed.
+    Collapsed,
+    /// Don't show any fallbacks for boundaries that are not revealed yet.
+    Hidden,
+}
+
+/// Where a suspense boundary sits in the [`SuspenseList()`] that coordinates it.
+///
+/// Renderers that reveal several boundaries at once, like out of order streaming, use this to reveal them in the order of
+/// the list.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SuspenseListPosition {
+    /// The scope of the [`SuspenseList()`] component the boundary is in
+    pub list: ScopeId,
+    /// The order the list reveals its boundaries in
+    pub reveal_order: SuspenseRevealOrder,
+    /// The index of the boundary in the list, in the order the boundaries appear in
+    pub index: usize,
+}
+
+impl SuspenseListPosition {
+    /// The position of the boundary in the order the list reveals boundaries in. Boundaries with a lower rank are
+    /// revealed first. Boundaries in a list that reveals them together all share the same rank
+    pub fn reveal_rank(&self) -> isize {
+        match self.reveal_order {
+            SuspenseRevealOrder::Forwards => self.index as isize,
+            SuspenseRevealOrder::Backwards => -(self.index as isize),
+            SuspenseRevealOrder::Together => 0,
+        }
+    }
+}
+
+/// The suspense list the next suspense boundary created in a scope will join. Suspense boundaries reset this to `None`
+/// so only the boundaries directly inside of a list are coordinated by it.
+#[derive(Clone)]
+pub(crate) struct NearestSuspenseList(pub(crate) Option<SuspenseListContext>);
+
+/// The shared state of a [`SuspenseList()`]
+#[derive(Clone)]
+pub(crate) struct SuspenseListContext {
+    inner: Rc<SuspenseListInner>,
+}
+
+pub(crate) struct SuspenseListInner {
+    /// The scope of the suspense list component
+    scope: ScopeId,
+    reveal_order: Cell<SuspenseRevealOrder>,
+    tail: Cell<SuspenseTail>,
+    /// The boundaries in the list in the order they appear in the tree
+    members: RefCell<Vec<Weak<SuspenseBoundaryInner>>>,
+    /// If the list has finished its first render. Boundaries that are created during the first render are created in
+    /// the order they appear in, but boundaries that join later may be inserted anywhere in the list
+    settled: Cell<bool>,
+    /// If boundaries joined the list since the members were last ordered
+    needs_order: Cell<bool>,
+}
+
+/// The suspense lists in a runtime, so the virtual dom can reorder their members after the tree changes
+#[derive(Default)]
+pub(crate) struct SuspenseLists(RefCell<Vec<Weak<SuspenseListInner>>>);
+
+impl SuspenseLists {
+    fn register(&self, list: &SuspenseListContext) {
+        self.0.borrow_mut().push(Rc::downgrade(&list.inner));
+    }
+
+    /// Get every suspense list that is still alive
+    fn live(&self) -> Vec<SuspenseListContext> {
+        let mut lists = self.0.borrow_mut();
+        lists.retain(|list| list.strong_count() > 0);
+        lists
+            .iter()
+            .filter_map(Weak::upgrade)
+            .map(|inner| SuspenseListContext { inner })
+            .collect()
+    }
+}
+
+impl SuspenseListContext {
+    fn new(reveal_order: SuspenseRevealOrder, tail: SuspenseTail) -> Self {
+        let list = Self {
+            inner: Rc::new(SuspenseListInner {
+                scope: current_scope_id(),
+                reveal_order: Cell::new(reveal_order),
+                tail: Cell::new(tail),
+                members: Default::default(),
+                settled: Cell::new(false),
+                needs_order: Cell::new(false),
+            }),
+        };
+        Runtime::current().suspense_lists.register(&list);
+        list
+    }
+
+    /// Update the options of the list and rerun every member if they changed
+    fn configure(&self, reveal_order: SuspenseRevealOrder, tail: SuspenseTail) {
+        let old_order = self.inner.reveal_order.replace(reveal_order);
+        let old_tail = self.inner.tail.replace(tail);
+        if old_order != reveal_order || old_tail != tail {
+            self.notify_members();
+        }
+    }
+
+    /// Add a suspense boundary to the list. Boundaries that join after the first render of the list are held back
+    /// until the virtual dom puts them in the order they appear in
+    pub(crate) fn join(&self, boundary: &SuspenseContext) {
+        self.inner
+            .members
+            .borrow_mut()
+            .push(Rc::downgrade(&boundary.inner));
+        if self.inner.settled.get() {
+            self.inner.needs_order.set(true);
+        }
+    }
+
+    /// Remove a suspense boundary from the list and let the remaining members know they may be revealed
+    pub(crate) fn leave(&self, boundary: &SuspenseContext) {
+        let ptr = Rc::as_ptr(&boundary.inner);
+        self.inner
+            .members
+            .borrow_mut()
+            .retain(|member| member.strong_count() > 0 && member.as_ptr() != ptr);
+        self.notify_members();
+    }
+
+    /// Rerun every boundary in the list. This is called whenever a member suspends or resolves because that may change
+    /// which of the other members are allowed to reveal their children.
+    pub(crate) fn notify_members(&self) {
+        for member in self.members() {
+            let id = member.inner.id.get();
+            if let Some(scope) = member.inner.rt.try_get_state(id) {
+                scope.needs_update();
+            }
+        }
+    }
+
+    /// Get the live members of the list in the order they appear in
+    fn members(&self) -> Vec<SuspenseContext> {
+        self.inner
+            .members
+            .borrow()
+            .iter()
+            .filter_map(Weak::upgrade)
+            .map(|inner| SuspenseContext { inner })
+            .collect()
+    }
+
+    /// Get the position of a member of the list
+    pub(crate) fn position(&self, boundary: &SuspenseContext) -> Option<SuspenseListPosition> {
+        let index = self
+            .members()
+            .iter()
+            .position(|member| member == boundary)?;
+        Some(SuspenseListPosition {
+            list: self.inner.scope,
+            reveal_order: self.inner.reveal_order.get(),
+            index,
+        })
+    }
+
+    /// Check if the list is holding a boundary in its fallback because of the reveal order
+    pub(crate) fn holds(&self, boundary: &SuspenseContext) -> bool {
+        let members = self.members();
+        let Some(position) = members.iter().position(|member| member == boundary) else {
+            return false;
+        };
+        // Until new members are ordered, we don't know which boundaries come before them
+        if self.inner.needs_order.get() {
+            return true;
+        }
+        let pending = |member: &SuspenseContext| member.has_suspended_tasks();
+        match self.inner.reveal_order.get() {
+            SuspenseRevealOrder::Forwards => members[..position].iter().any(pending),
+            SuspenseRevealOrder::Backwards => members[position + 1..].iter().any(pending),
+            SuspenseRevealOrder::Together => members.iter().any(pending),
+        }
+    }
+
+    /// Check if a boundary that is not revealed should render its fallback with the current tail option
+    pub(crate) fn shows_fallback(&self, boundary: &SuspenseContext) -> bool {
+        match self.inner.tail.get() {
+            SuspenseTail::Visible => true,
+            SuspenseTail::Hidden => false,
+            SuspenseTail::Collapsed => {
+                let mut members = self.members();
+                if self.inner.reveal_order.get() == SuspenseRevealOrder::Backwards {
+                    members.reverse();
+                }
+                members
+                    .iter()
+                    .find(|member| member.should_show_fallback())
+                    .is_some_and(|next| next == boundary)
+            }
+        }
+    }
+}
+
+impl VirtualDom {
+    /// Put the members of the suspense lists that `rendered` may have changed back in the order they appear in. This
+    /// is called once a scope finishes rendering and diffing so the tree of every list is complete.
+    ///
+    /// Keyed or conditional boundaries can be inserted before existing members or moved, which only shows up in the
+    /// rendered tree of the list.
+    pub(crate) fn order_suspense_lists(&mut self, rendered: Option<ScopeId>) {
+        for list in self.runtime.suspense_lists.live() {
+            let scope = list.inner.scope;
+            if self.runtime.try_get_state(scope).is_none() {
+                continue;
+            }
+            // Members of a list that just rendered for the first time were created in order
+            if !list.inner.settled.replace(true) {
+                continue;
+            }
+            let changed = rendered.is_some_and(|rendered| {
+                self.is_ancestor(rendered, scope) || self.is_ancestor(scope, rendered)
+            });
+            if changed || list.inner.needs_order.get() {
+                self.order_suspense_list(&list);
+            }
+        }
+    }
+
+    /// Check if `ancestor` is `scope` or one of its parents
+    fn is_ancestor(&self, ancestor: ScopeId, scope: ScopeId) -> bool {
+        let mut current = Some(scope);
+        while let Some(id) = current {
+            if id == ancestor {
+                return true;
+            }
+            current = self
+                .runtime
+                .try_get_state(id)
+                .and_then(|state| state.parent_id);
+        }
+        false
+    }
+
+    fn order_suspense_list(&self, list: &SuspenseListContext) {
+        let mut boundaries = Vec::new();
+        if let Some(node) = self.scopes[list.inner.scope.0]
+            .last_rendered_node
+            .as_deref()
+        {
+            self.collect_suspense_boundaries(node, &mut boundaries);
+        }
+
+        let members = list.members();
+        let mut ordered: Vec<SuspenseContext> = boundaries
+            .iter()
+            .filter_map(|&id| {
+                members
+                    .iter()
+                    .find(|member| member.inner.id.get() == id)
+                    .cloned()
+            })
+            .collect();
+        // Keep any members that are not in the tree anymore at the end until they leave the list
+        for member in &members {
+            if !ordered.contains(member) {
+                ordered.push(member.clone());
+            }
+        }
+
+        let needed_order = list.inner.needs_order.replace(false);
+        if ordered != members || needed_order {
+            *list.inner.members.borrow_mut() = ordered
+                .iter()
+                .map(|member| Rc::downgrade(&member.inner))
+                .collect();
+            list.notify_members();
+        }
+    }
+
+    /// Collect the suspense boundaries in a node in the order they appear in without looking inside of them
+    fn collect_suspense_boundaries(&self, node: &VNode, boundaries: &mut Vec<ScopeId>) {
+        for (index, dynamic_node) in node.dynamic_nodes.iter().enumerate() {
+            match dynamic_node {
+                DynamicNode::Fragment(children) => {
+                    for child in children {
+                        self.collect_suspense_boundaries(child, boundaries);
+                    }
+                }
+                DynamicNode::Component(component) => {
+                    let Some(scope) = component.mounted_scope_id(index, node, self) else {
+                        continue;
+                    };
+                    if SuspenseContext::downcast_suspense_boundary_from_scope(&self.runtime, scope)
+                        .is_some()
+                    {
+                        boundaries.push(scope);
+                    } else if let Some(child) = self
+                        .scopes
+                        .get(scope.0)
+                        .and_then(|scope| scope.last_rendered_node.as_deref())
+                    {
+                        self.collect_suspense_boundaries(child, boundaries);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Properties for the [`SuspenseList()`] component.
+#[derive(Clone, PartialEq)]
+pub struct SuspenseListProps {
+    reveal_order: SuspenseRevealOrder,
+    tail: SuspenseTail,
+    children: Element,
+}
+
+#[doc(hidden)]
+pub struct SuspenseListPropsBuilder {
+    reveal_order: SuspenseRevealOrder,
+    tail: SuspenseTail,
+    children: Element,
+}
+
+#[allow(missing_docs)]
+impl SuspenseListPropsBuilder {
+    pub fn reveal_order(mut self, reveal_order: SuspenseRevealOrder) -> Self {
+        self.reveal_order = reveal_order;
+        self
+    }
+
+    pub fn tail(mut self, tail: SuspenseTail) -> Self {
+        self.tail = tail;
+        self
+    }
+
+    pub fn children(mut self, children: Element) -> Self {
+        self.children = children;
+        self
+    }
+
+    pub fn build(self) -> SuspenseListProps {
+        SuspenseListProps {
+            reveal_order: self.reveal_order,
+            tail: self.tail,
+            children: self.children,
+        }
+    }
+}
+
+impl Properties for SuspenseListProps {
+    type Builder = SuspenseListPropsBuilder;
+    fn builder() -> Self::Builder {
+        SuspenseListPropsBuilder {
+            reveal_order: Default::default(),
+            tail: Default::default(),
+            children: VNode::empty(),
+        }
+    }
+    fn memoize(&mut self, new: &Self) -> bool {
+        let equal = self == new;
+        if !equal {
+            *self = new.clone();
+        }
+        equal
+    }
+}
+
+/// Coordinate the order the [`SuspenseBoundary()`]s inside of the list reveal their children in.
+///
+/// Without a suspense list, every suspense boundary shows its children as soon as its own futures resolve which can make
+/// content pop in out of order. Boundaries directly inside of a suspense list (not nested inside of another suspense boundary)
+/// stay in their fallback until the list allows them to reveal their children based on the `reveal_order`.
+/// Boundaries are revealed in the order they appear in, even when keyed or conditional boundaries are added or moved.
+///
+/// The `tail` option controls which fallbacks are shown while boundaries are waiting to be revealed. The same rules apply
+/// while streaming from the server, so resolved boundaries are streamed in the reveal order.
+///
+/// # Example
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # #[component] fn Post(id: usize) -> Element { rsx! { "Post {id}" } }
+/// fn App() -> Element {
+///     rsx! {
+///         SuspenseList {
+///             reveal_order: SuspenseRevealOrder::Forwards,
+///             tail: SuspenseTail::Collapsed,
+///             for id in 0..3 {
+///                 SuspenseBoundary {
+///                     key: "{id}",
+///                     fallback: |_| rsx! { "Loading..." },
+///                     Post { id }
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[allow(non_upper_case_globals, non_snake_case)]
+pub fn SuspenseList(props: SuspenseListProps) -> Element {
+    let list = use_hook(|| {
+        let list = SuspenseListContext::new(props.reveal_order, props.tail);
+        provide_context(NearestSuspenseList(Some(list.clone())));
+        list
+    });
+    list.configure(props.reveal_order, props.tail);
+
+    props.children
+}
@@ -25,6 +25,8 @@
 
 mod component;
 pub use component::*;
+mod list;
+pub use list::*;
 
 use crate::innerlude::*;
 use std::{
@@ -92,6 +94,8 @@ impl SuspenseContext {
                 suspended_nodes: Default::default(),
                 frozen: Default::default(),
                 after_suspense_resolved: Default::default(),
+                list: Default::default(),
+                revealed: Default::default(),
             }),
         }
     }
@@ -143,10 +147,72 @@ impl SuspenseContext {
         self.inner.suspended_nodes.borrow().is_some()
     }
 
+    /// Check if the suspense boundary should render its fallback instead of its children. This is true if the boundary has
+    /// suspended tasks or if the [`SuspenseList()`] it is in is still holding it back
+    pub fn should_show_fallback(&self) -> bool {
+        if self.has_suspended_tasks() {
+            return true;
+        }
+        !self.inner.revealed.get()
+            && self
+                .inner
+                .list
+                .borrow()
+                .as_ref()
+                .is_some_and(|list| list.holds(self))
+    }
+
+    /// Check if the fallback of the suspense boundary should be shown while the boundary is suspended. Suspense lists
+    /// may collapse or hide the fallbacks of boundaries that are not revealed yet
+    pub(crate) fn fallback_visible(&self) -> bool {
+        self.inner
+            .list
+            .borrow()
+            .as_ref()
+            .is_none_or(|list| list.shows_fallback(self))
+    }
+
+    /// Get the position of the suspense boundary in the [`SuspenseList()`] it is directly inside of, if any
+    pub fn suspense_list_position(&self) -> Option<SuspenseListPosition> {
+        self.inner
+            .list
+            .borrow()
+            .as_ref()
+            .and_then(|list| list.position(self))
+    }
+
+    /// Mark the children of the suspense boundary as revealed or hidden
+    pub(crate) fn set_revealed(&self, revealed: bool) {
+        self.inner.revealed.set(revealed);
+    }
+
+    /// Join the suspense list this boundary was created in
+    pub(crate) fn join_suspense_list(&self, list: SuspenseListContext) {
+        list.join(self);
+        self.inner.list.borrow_mut().replace(list);
+    }
+
+    /// Leave the suspense list this boundary is in, if any
+    pub(crate) fn leave_suspense_list(&self) {
+        let list = self.inner.list.borrow_mut().take();
+        if let Some(list) = list {
+            list.leave(self);
+        }
+    }
+
+    /// Let the other boundaries in the suspense list know this boundary suspended or resolved
+    fn notify_suspense_list(&self) {
+        let list = self.inner.list.borrow().clone();
+        if let Some(list) = list {
+            list.notify_members();
+        }
+    }
+
     /// Add a suspended task
     pub(crate) fn add_suspended_task(&self, task: SuspendedFuture) {
         self.inner.suspended_tasks.borrow_mut().push(task);
         self.inner.rt.needs_update(self.inner.id.get());
+        self.notify_suspense_list();
     }
 
     /// Remove a suspended task
@@ -156,6 +222,7 @@ impl SuspenseContext {
             .borrow_mut()
             .retain(|t| t.task != task.id);
         self.inner.rt.needs_update(self.inner.id.get());
+        self.notify_suspense_list();
     }
 
     /// Get all suspended tasks
@@ -199,6 +266,12 @@ pub struct SuspenseBoundaryInner {
 
     /// Closures queued to run after the suspense boundary is resolved
     after_suspense_resolved: RefCell<Vec<Box<dyn FnOnce()>>>,
+
+    /// The suspense list that coordinates when this boundary reveals its children
+    list: RefCell<Option<SuspenseListContext>>,
+
+    /// If the children of the boundary have been revealed. Suspense lists never hide revealed boundaries again
+    revealed: Cell<bool>,
 }
 
 impl Debug for SuspenseBoundaryInner {
@@ -208,6 +281,7 @@ impl Debug for SuspenseBoundaryInner {
             .field("id", &self.id)
             .field("suspended_nodes", &self.suspended_nodes)
             .field("frozen", &self.frozen)
+            .field("revealed", &self.revealed)
             .finish()
     }
 }
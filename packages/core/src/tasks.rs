@@ -384,6 +384,9 @@ pub(crate) enum SchedulerMsg {
     /// Immediate updates from Components that mark them as dirty
     Immediate(ScopeId),
 
+    /// Low priority updates from Components that were marked as dirty inside of a transition
+    Transition(ScopeId),
+
     /// A task has woken and needs to be progressed
     TaskNotified(slotmap::DefaultKey),
 
@@ -391,6 +394,16 @@ pub(crate) enum SchedulerMsg {
     EffectQueued,
 }
 
+impl SchedulerMsg {
+    /// Create the message that marks a scope as dirty with the priority of the current code
+    pub(crate) fn mark_dirty(id: ScopeId) -> Self {
+        match crate::scheduler::is_in_transition() {
+            true => Self::Transition(id),
+            false => Self::Immediate(id),
+        }
+    }
+}
+
 struct LocalTaskHandle {
     id: slotmap::DefaultKey,
     tx: futures_channel::mpsc::UnboundedSender<SchedulerMsg>,
@@ -12,7 +12,7 @@ use crate::{
     scopes::ScopeId,
 };
 use crate::{Task, VComponent};
-use crate::{innerlude::Work, scopes::LastRenderedNode};
+use crate::{innerlude::Work, scheduler::start_transition, scopes::LastRenderedNode};
 use futures_util::StreamExt;
 use slab::Slab;
 use std::collections::BTreeSet;
@@ -207,6 +207,9 @@ pub struct VirtualDom {
 
     pub(crate) dirty_scopes: BTreeSet<ScopeOrder>,
 
+    // Scopes that were marked dirty inside of a transition. These rerun after all other work
+    pub(crate) transition_scopes: BTreeSet<ScopeOrder>,
+
     pub(crate) runtime: Rc<Runtime>,
 
     // The scopes that have been resolved since the last render
@@ -313,6 +316,7 @@ impl VirtualDom {
             runtime: Runtime::new(tx),
             scopes: Default::default(),
             dirty_scopes: Default::default(),
+            transition_scopes: Default::default(),
             resolved_scopes: Default::default(),
         };
 
@@ -403,6 +407,22 @@ impl VirtualDom {
         self.queue_scope(order);
     }
 
+    /// Mark a scope as requiring a low priority re-render as part of a transition
+    fn mark_transition_dirty(&mut self, id: ScopeId) {
+        let Some(scope) = self.runtime.try_get_state(id) else {
+            return;
+        };
+
+        tracing::event!(
+            tracing::Level::TRACE,
+            "Marking scope {:?} as dirty in a transition",
+            id
+        );
+        let order = ScopeOrder::new(scope.height(), id);
+        drop(scope);
+        self.queue_transition_scope(order);
+    }
+
     /// Mark a task as dirty
     fn mark_task_dirty(&mut self, task: Task) {
         let Some(scope) = self.runtime.task_scope(task) else {
@@ -449,7 +469,7 @@ impl VirtualDom {
             self.process_events();
 
             // Now that we have collected all queued work, we should check if we have any dirty scopes. If there are not, then we can poll any queued futures
-            if self.has_dirty_scopes() {
+            if self.has_dirty_scopes() || self.has_pending_transitions() {
                 return;
             }
 
@@ -466,6 +486,7 @@ impl VirtualDom {
     async fn wait_for_event(&mut self) {
         match self.rx.next().await.expect("channel should never close") {
             SchedulerMsg::Immediate(id) => self.mark_dirty(id),
+            SchedulerMsg::Transition(id) => self.mark_transition_dirty(id),
             SchedulerMsg::TaskNotified(id) => {
                 // Instead of running the task immediately, we insert it into the runtime's task queue.
                 // The task may be marked dirty at the same time as the scope that owns the task is dropped.
@@ -482,6 +503,7 @@ impl VirtualDom {
         while let Ok(msg) = self.rx.try_recv() {
             match msg {
                 SchedulerMsg::Immediate(id) => self.mark_dirty(id),
+                SchedulerMsg::Transition(id) => self.mark_transition_dirty(id),
                 SchedulerMsg::TaskNotified(task) => self.mark_task_dirty(Task::from_id(task)),
                 SchedulerMsg::EffectQueued => {}
                 SchedulerMsg::AllDirty => self.mark_all_dirty(),
@@ -578,6 +600,7 @@ impl VirtualDom {
     #[instrument(skip(self, to), level = "trace", name = "VirtualDom::rebuild")]
     pub fn rebuild(&mut self, to: &mut impl WriteMutations) {
         let _runtime = RuntimeGuard::new(self.runtime.clone());
+        self.runtime.begin_profile_frame();
         let new_nodes = self
             .runtime
             .clone()
@@ -588,24 +611,79 @@ impl VirtualDom {
         self.scopes[ScopeId::ROOT.0].last_rendered_node = Some(new_nodes.clone());
 
         // Rebuilding implies we append the created elements to the root
+        #[cfg(feature = "profiler")]
+        let runtime = self.runtime.clone();
+        #[cfg(feature = "profiler")]
+        let to = &mut crate::innerlude::CountMutations::new(to, &runtime);
         let m = self.create_scope(Some(to), ScopeId::ROOT, new_nodes, None);
-
         to.append_children(ElementId(0), m);
+        self.order_suspense_lists(None);
+
+        self.runtime.end_profile_frame();
     }
 
     /// Render whatever the VirtualDom has ready as fast as possible without requiring an executor to progress
     /// suspended subtrees.
     #[instrument(skip(self, to), level = "trace", name = "VirtualDom::render_immediate")]
     pub fn render_immediate(&mut self, to: &mut impl WriteMutations) {
+        self.render_with_deadline(to, || false);
+    }
+
+    /// Render the work the VirtualDom has ready until `should_yield` returns true. This lets renderers split large
+    /// updates into slices and give control back to the platform between them.
+    ///
+    /// Work is only split between scopes. Every scope that reruns is diffed completely, so the mutations written
+    /// before the deadline always describe a consistent dom and can be applied right away. At least one unit of work
+    /// runs every call, so rendering always makes progress. Urgent updates that arrive between slices run before the
+    /// remaining [transitions](crate::start_transition).
+    ///
+    /// Returns `true` if all of the ready work was rendered, or `false` if the deadline was reached first and this
+    /// method should be called again.
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_core::NoOpMutations;
+    /// # use std::time::{Duration, Instant};
+    /// # async fn run(mut dom: VirtualDom) {
+    /// loop {
+    ///     dom.wait_for_work().await;
+    ///     loop {
+    ///         let deadline = Instant::now() + Duration::from_millis(5);
+    ///         let done = dom.render_with_deadline(&mut NoOpMutations, || Instant::now() >= deadline);
+    ///         // Apply the mutations and let the platform handle input here
+    ///         if done {
+    ///             break;
+    ///         }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    #[instrument(
+        skip(self, to, should_yield),
+        level = "trace",
+        name = "VirtualDom::render_with_deadline"
+    )]
+    pub fn render_with_deadline(
+        &mut self,
+        to: &mut impl WriteMutations,
+        mut should_yield: impl FnMut() -> bool,
+    ) -> bool {
         // Process any events that might be pending in the queue
         // Signals marked with .write() need a chance to be handled by the effect driver
         // This also processes futures which might progress into immediately rerunning a scope
         self.process_events();
 
-        // Next, diff any dirty scopes
-        // We choose not to poll the deadline since we complete pretty quickly anyways
+        // Next, diff any dirty scopes until we run out of work or time
         let _runtime = RuntimeGuard::new(self.runtime.clone());
-        while let Some(work) = self.pop_work() {
+        #[cfg(feature = "profiler")]
+        let runtime = self.runtime.clone();
+        #[cfg(feature = "profiler")]
+        let to = &mut crate::innerlude::CountMutations::new(to, &runtime);
+        self.runtime.begin_profile_frame();
+        let finished = loop {
+            let Some(work) = self.pop_work() else {
+                break true;
+            };
             match work {
                 Work::PollTask(task) => {
                     _ = self.runtime.handle_task_wakeup(task);
@@ -618,10 +696,43 @@ impl VirtualDom {
                         self.run_and_diff_scope(Some(to), scope.id);
                     });
                 }
+                Work::RerunTransition(scope) => {
+                    // Any updates while the transition renders are part of the transition
+                    start_transition(|| {
+                        self.runtime.clone().while_rendering(|| {
+                            self.run_and_diff_scope(Some(to), scope.id);
+                        });
+                    });
+                    // Pick up any urgent work that should run before the rest of the transition
+                    self.queue_events();
+                }
             }
-        }
+
+            if should_yield() {
+                break !self.has_dirty_scopes()
+                    && !self.has_pending_transitions()
+                    && self.runtime.dirty_tasks.borrow().is_empty();
+            }
+        };
 
         self.runtime.finish_render();
+        self.runtime.end_profile_frame();
+
+        finished
+    }
+
+    /// Render the work the VirtualDom has ready for at most `budget`. See [`Self::render_with_deadline`].
+    ///
+    /// This is not available on wasm because the standard library clock is not supported there. Use
+    /// [`Self::render_with_deadline`] with a browser clock instead.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn render_with_budget(
+        &mut self,
+        to: &mut impl WriteMutations,
+        budget: std::time::Duration,
+    ) -> bool {
+        let deadline = std::time::Instant::now() + budget;
+        self.render_with_deadline(to, || std::time::Instant::now() >= deadline)
     }
 
     /// [`Self::render_immediate`] to a vector of mutations for testing purposes
@@ -716,7 +827,7 @@ impl VirtualDom {
                         let _ = self.runtime.handle_task_wakeup(task);
                     }
                 }
-                Work::RerunScope(scope) => {
+                Work::RerunScope(scope) | Work::RerunTransition(scope) => {
                     let scope_id: ScopeId = scope.id;
                     let run_scope = self
                         .runtime
@@ -750,6 +861,8 @@ impl VirtualDom {
             }
         }
 
+        self.runtime.end_profile_frame();
+
         self.resolved_scopes
             .sort_by_key(|&id| self.runtime.get_state(id).height);
         std::mem::take(&mut self.resolved_scopes)
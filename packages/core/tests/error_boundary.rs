@@ -90,3 +90,121 @@ fn clear_error_boundary() {
         assert!(consume_context::<ErrorContext>().error().is_none())
     })
 }
+
+#[test]
+fn reset_keys_clear_errors() {
+    thread_local! {
+        static KEY: std::cell::RefCell<Option<Signal<u32>>> = const { std::cell::RefCell::new(None) };
+    }
+
+    fn app() -> Element {
+        let key = use_signal(|| 0);
+        KEY.with(|k| *k.borrow_mut() = Some(key));
+        rsx! {
+            ErrorBoundary {
+                reset_keys: key(),
+                handle_error: |_| rsx! { "error" },
+                throws_on_zero { value: key() }
+            }
+        }
+    }
+
+    #[component]
+    fn throws_on_zero(value: u32) -> Element {
+        if value == 0 {
+            return Err(CapturedError::from_display("zero").into());
+        }
+        rsx! { "{value}" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "error");
+
+    dom.in_scope(ScopeId::APP, || KEY.with(|k| k.borrow().unwrap().set(1)));
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "1");
+}
+
+#[test]
+fn retry_remounts_children() {
+    thread_local! {
+        static BOUNDARY: std::cell::RefCell<Option<ErrorContext>> = const { std::cell::RefCell::new(None) };
+        static MOUNTS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    fn app() -> Element {
+        rsx! {
+            ErrorBoundary {
+                counted_child {}
+            }
+        }
+    }
+
+    #[component]
+    fn counted_child() -> Element {
+        use_hook(|| {
+            MOUNTS.with(|m| m.set(m.get() + 1));
+            BOUNDARY.with(|b| *b.borrow_mut() = Some(consume_context::<ErrorContext>()));
+        });
+        rsx! { "child" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(MOUNTS.with(|m| m.get()), 1);
+
+    // Clearing errors rerenders the children in place
+    dom.in_scope(ScopeId::APP, || {
+        BOUNDARY.with(|b| b.borrow().clone().unwrap().clear_errors())
+    });
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(MOUNTS.with(|m| m.get()), 1);
+
+    // Retrying throws away the old children and mounts new ones
+    dom.in_scope(ScopeId::APP, || {
+        BOUNDARY.with(|b| b.borrow().clone().unwrap().retry())
+    });
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(MOUNTS.with(|m| m.get()), 2);
+    assert_eq!(dioxus_ssr::render(&dom), "child");
+}
+
+#[test]
+fn error_reporter_receives_scope_path() {
+    use dioxus_core::{ErrorReport, ErrorReporter};
+    use std::sync::{Arc, Mutex};
+
+    fn app() -> Element {
+        rsx! {
+            ErrorBoundary {
+                handle_error: |_| rsx! { "caught" },
+                failing_child {}
+            }
+        }
+    }
+
+    #[component]
+    fn failing_child() -> Element {
+        Err(CapturedError::from_display("failed").into())
+    }
+
+    let reports: Arc<Mutex<Vec<ErrorReport>>> = Default::default();
+    let mut dom = VirtualDom::new(app).with_root_context(ErrorReporter::new({
+        let reports = reports.clone();
+        move |report| reports.lock().unwrap().push(report.clone())
+    }));
+    dom.rebuild_in_place();
+
+    let reports = reports.lock().unwrap();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].error.to_string(), "failed");
+    let path = &reports[0].scope_path;
+    assert!(
+        path.iter().any(|name| name.contains("ErrorBoundary")),
+        "{path:?}"
+    );
+    assert!(path.last().unwrap().contains("failing_child"), "{path:?}");
+}
@@ -0,0 +1,247 @@
+use dioxus::html::SerializedHtmlEventConverter;
+use dioxus::prelude::*;
+use dioxus_core::{ElementId, Mutation::*, Mutations, NoOpMutations};
+use std::{any::Any, cell::Cell, rc::Rc};
+
+#[test]
+fn portal_renders_into_target() {
+    fn app() -> Element {
+        rsx! {
+            PortalTarget { name: "modals" }
+            div {
+                Portal { target: "modals", span { "hello" } }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let edits = dom.rebuild_to_vec();
+
+    assert_eq!(
+        edits.edits,
+        [
+            // The target
+            LoadTemplate { index: 0, id: ElementId(2) },
+            // The div the portal is in
+            LoadTemplate { index: 1, id: ElementId(3) },
+            // The children of the portal are appended to the target
+            LoadTemplate { index: 0, id: ElementId(5) },
+            AppendChildren { id: ElementId(2), m: 1 },
+            // And a placeholder stands in for the portal
+            CreatePlaceholder { id: ElementId(4) },
+            ReplacePlaceholder { path: &[0], m: 1 },
+            AppendChildren { id: ElementId(0), m: 2 },
+        ]
+    );
+}
+
+#[test]
+fn portal_waits_for_target() {
+    fn app() -> Element {
+        rsx! {
+            div {
+                Portal { target: "modals", span { "hello" } }
+            }
+            PortalTarget { name: "modals" }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let edits = dom.rebuild_to_vec();
+    assert_eq!(
+        edits.edits,
+        [
+            LoadTemplate { index: 0, id: ElementId(2) },
+            // The target isn't mounted yet, so only the placeholder is created
+            CreatePlaceholder { id: ElementId(3) },
+            ReplacePlaceholder { path: &[0], m: 1 },
+            LoadTemplate { index: 0, id: ElementId(4) },
+            AppendChildren { id: ElementId(0), m: 2 },
+        ]
+    );
+
+    // Once the target is mounted, the portal moves its children into it
+    let edits = dom.render_immediate_to_vec();
+    assert_eq!(
+        edits.edits,
+        [
+            LoadTemplate { index: 0, id: ElementId(5) },
+            AppendChildren { id: ElementId(4), m: 1 },
+        ]
+    );
+}
+
+#[test]
+fn removing_portal_removes_children_from_target() {
+    fn app() -> Element {
+        let show = use_signal(|| true);
+        use_hook(|| SHOW.with(|cell| cell.set(Some(show))));
+        rsx! {
+            PortalTarget { name: "modals" }
+            if show() {
+                Portal { target: "modals", span { "hello" } }
+            }
+        }
+    }
+
+    thread_local! {
+        static SHOW: Cell<Option<Signal<bool>>> = const { Cell::new(None) };
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    dom.in_runtime(|| SHOW.with(|cell| cell.get().unwrap().set(false)));
+    let edits = dom.render_immediate_to_vec();
+    assert_eq!(
+        edits.edits,
+        [
+            CreatePlaceholder { id: ElementId(5) },
+            // The children are removed from the target
+            Remove { id: ElementId(4) },
+            // And the placeholder is replaced in place
+            ReplaceWith { id: ElementId(3), m: 1 },
+        ]
+    );
+}
+
+#[test]
+fn portal_children_use_context_around_portal() {
+    #[component]
+    fn read_context() -> Element {
+        let value = use_context::<&'static str>();
+        rsx! { "{value}" }
+    }
+
+    fn app() -> Element {
+        rsx! {
+            PortalTarget { name: "modals" }
+            provide_value {
+                Portal { target: "modals", read_context {} }
+            }
+        }
+    }
+
+    #[component]
+    fn provide_value(children: Element) -> Element {
+        use_context_provider(|| "from the portal");
+        children
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert!(dioxus_ssr::render(&dom).contains("from the portal"));
+}
+
+#[test]
+fn events_bubble_through_component_tree() {
+    set_event_converter(Box::new(SerializedHtmlEventConverter));
+
+    thread_local! {
+        static OUTER_CLICKS: Cell<usize> = const { Cell::new(0) };
+        static TARGET_CLICKS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn app() -> Element {
+        rsx! {
+            div {
+                onclick: |_| TARGET_CLICKS.with(|clicks| clicks.set(clicks.get() + 1)),
+                PortalTarget { name: "modals" }
+            }
+            div {
+                onclick: |_| OUTER_CLICKS.with(|clicks| clicks.set(clicks.get() + 1)),
+                Portal { target: "modals", button { onclick: |_| {} } }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    let edits = dom.rebuild_to_vec();
+    // The button is appended to the target even though events bubble to the div around the portal
+    let button = ElementId(6);
+    assert!(
+        edits
+            .edits
+            .contains(&AppendChildren { id: ElementId(3), m: 1 })
+    );
+
+    let event = Event::new(
+        Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default())) as Rc<dyn Any>,
+        true,
+    );
+    dom.runtime().handle_event("click", event, button);
+
+    assert_eq!(OUTER_CLICKS.with(Cell::get), 1);
+    assert_eq!(TARGET_CLICKS.with(Cell::get), 0);
+}
+
+#[test]
+fn hydrated_portals_create_their_children_in_the_target() {
+    thread_local! {
+        static RUNS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[component]
+    fn counted_child() -> Element {
+        RUNS.with(|runs| runs.set(runs.get() + 1));
+        rsx! { span { "hello" } }
+    }
+
+    fn app() -> Element {
+        rsx! {
+            PortalTarget { name: "modals" }
+            div {
+                Portal { target: "modals", counted_child {} }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    // Hydration assigns ids to the nodes the server rendered without writing any mutations
+    dom.rebuild(&mut NoOpMutations);
+    let portal = ScopeId(ScopeId::APP.0 + 2);
+    assert_eq!(dom.portal_placeholder(portal), Some(ElementId(4)));
+
+    // The server only rendered the placeholder, so the children are created in the target after hydration
+    let mut mutations = Mutations::default();
+    dom.create_hydrated_portal(&mut mutations, portal);
+    assert_eq!(
+        mutations.edits,
+        [
+            LoadTemplate { index: 0, id: ElementId(5) },
+            AppendChildren { id: ElementId(2), m: 1 },
+        ]
+    );
+    // Without rerunning the components inside the portal
+    assert_eq!(RUNS.with(Cell::get), 1);
+
+    // The portal is diffed in its target like any other portal afterwards
+    dom.mark_dirty(portal);
+    assert!(dom.render_immediate_to_vec().edits.is_empty());
+}
+
+#[test]
+fn prerendered_portals_only_render_their_placeholder() {
+    fn app() -> Element {
+        rsx! {
+            PortalTarget { name: "modals" }
+            div {
+                Portal { target: "modals", span { "hello" } }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    // Without hydration, the children are rendered in place
+    assert!(dioxus_ssr::render(&dom).contains("<span>hello</span>"));
+
+    // When the html is hydrated, the client creates the children in the target instead
+    let mut renderer = dioxus_ssr::Renderer::new();
+    renderer.pre_render = true;
+    assert_eq!(
+        renderer.render(&dom),
+        "<div data-node-hydration=\"0\"></div><div data-node-hydration=\"1\"><!--placeholder2--></div>"
+    );
+}
@@ -0,0 +1,117 @@
+#![cfg(feature = "profiler")]
+
+use dioxus::prelude::*;
+use dioxus_core::{Mutations, NoOpMutations, ProfileSpanKind};
+use std::cell::RefCell;
+
+thread_local! {
+    static COUNT: RefCell<Option<Signal<i32>>> = const { RefCell::new(None) };
+}
+
+fn app() -> Element {
+    rsx! {
+        div {
+            counter_label {}
+            static_label {}
+        }
+    }
+}
+
+#[component]
+fn counter_label() -> Element {
+    let count = use_signal(|| 0);
+    use_hook(|| COUNT.with(|signal| *signal.borrow_mut() = Some(count)));
+    rsx! { "{count}" }
+}
+
+#[component]
+fn static_label() -> Element {
+    rsx! { "static" }
+}
+
+#[test]
+fn profiling_is_disabled_by_default() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    assert!(!dom.runtime().is_profiling());
+    assert!(dom.runtime().stop_profiling().is_none());
+}
+
+#[test]
+fn rebuild_records_every_scope() {
+    let mut dom = VirtualDom::new(app);
+    dom.runtime().start_profiling();
+    let mut mutations = Mutations::default();
+    dom.rebuild(&mut mutations);
+
+    let profile = dom.runtime().stop_profiling().unwrap();
+    assert_eq!(profile.frames.len(), 1);
+    let frame = &profile.frames[0];
+    assert_eq!(frame.mutations, mutations.edits.len());
+
+    let scopes = frame.scopes();
+    assert!(
+        scopes
+            .iter()
+            .any(|scope| scope.name.ends_with("counter_label"))
+    );
+    assert!(
+        scopes
+            .iter()
+            .any(|scope| scope.name.ends_with("static_label"))
+    );
+    assert!(scopes.iter().all(|scope| scope.renders == 1));
+}
+
+#[test]
+fn rerenders_only_record_dirty_scopes() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.runtime().start_profiling();
+
+    dom.in_runtime(|| COUNT.with(|count| count.borrow().unwrap().set(1)));
+    let mut mutations = Mutations::default();
+    dom.render_immediate(&mut mutations);
+
+    let profile = dom.runtime().stop_profiling().unwrap();
+    assert_eq!(profile.frames.len(), 1);
+    let frame = &profile.frames[0];
+    assert_eq!(frame.mutations, mutations.edits.len());
+
+    let scopes = frame.scopes();
+    assert_eq!(scopes.len(), 1);
+    assert!(scopes[0].name.ends_with("counter_label"));
+    assert_eq!(scopes[0].renders, 1);
+    assert_eq!(scopes[0].mutations, 1);
+
+    let kinds: Vec<_> = frame.spans.iter().map(|span| span.kind).collect();
+    assert_eq!(kinds, [ProfileSpanKind::Render, ProfileSpanKind::Diff]);
+}
+
+#[test]
+fn frames_without_work_are_dropped() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.runtime().start_profiling();
+
+    dom.render_immediate(&mut NoOpMutations);
+    dom.render_immediate(&mut NoOpMutations);
+
+    let profile = dom.runtime().stop_profiling().unwrap();
+    assert!(profile.frames.is_empty());
+}
+
+#[test]
+fn chrome_trace_export() {
+    let mut dom = VirtualDom::new(app);
+    dom.runtime().start_profiling();
+    dom.rebuild_in_place();
+
+    let trace = dom.runtime().stop_profiling().unwrap().to_chrome_trace();
+    assert!(trace.starts_with("{\"traceEvents\":["));
+    assert!(trace.ends_with("]}"));
+    assert!(trace.contains("\"name\":\"frame 0\",\"cat\":\"frame\""));
+    assert!(trace.contains("counter_label\",\"cat\":\"render\""));
+    assert!(trace.contains("static_label\",\"cat\":\"diff\""));
+}
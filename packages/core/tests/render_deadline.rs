@@ -0,0 +1,79 @@
+use dioxus::prelude::*;
+use dioxus_core::{Mutation, Mutations, NoOpMutations};
+use std::cell::RefCell;
+
+thread_local! {
+    static RENDERS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+    static SIGNALS: RefCell<Vec<Signal<i32>>> = const { RefCell::new(Vec::new()) };
+}
+
+fn take_renders() -> Vec<usize> {
+    RENDERS.with(|renders| std::mem::take(&mut *renders.borrow_mut()))
+}
+
+fn signal(index: usize) -> Signal<i32> {
+    SIGNALS.with(|signals| signals.borrow()[index])
+}
+
+fn app() -> Element {
+    rsx! {
+        for index in 0..3 {
+            indexed_counter { index }
+        }
+    }
+}
+
+#[component]
+fn indexed_counter(index: usize) -> Element {
+    let count = use_signal(|| 0);
+    use_hook(|| SIGNALS.with(|signals| signals.borrow_mut().push(count)));
+    RENDERS.with(|renders| renders.borrow_mut().push(index));
+    rsx! { "{count}" }
+}
+
+#[test]
+fn deadlines_split_work_between_scopes() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    take_renders();
+
+    dom.in_runtime(|| (0..3).for_each(|index| signal(index).set(1)));
+
+    // The deadline is always reached, so each call renders one scope and writes a complete set of edits for it
+    let mut slices = Vec::new();
+    loop {
+        let mut mutations = Mutations::default();
+        let done = dom.render_with_deadline(&mut mutations, || true);
+        assert!(
+            matches!(mutations.edits.as_slice(), [Mutation::SetText { value, .. }] if value == "1")
+        );
+        slices.push(take_renders());
+        if done {
+            break;
+        }
+    }
+    assert_eq!(slices, [[0], [1], [2]]);
+
+    // Without a deadline everything renders at once
+    dom.in_runtime(|| (0..3).for_each(|index| signal(index).set(2)));
+    assert!(dom.render_with_deadline(&mut NoOpMutations, || false));
+    assert_eq!(take_renders(), [0, 1, 2]);
+}
+
+#[test]
+fn urgent_updates_run_between_transition_slices() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    take_renders();
+
+    dom.in_runtime(|| start_transition(|| (0..2).for_each(|index| signal(index).set(1))));
+    assert!(!dom.render_with_deadline(&mut NoOpMutations, || true));
+    assert_eq!(take_renders(), [0]);
+
+    // An update from an event handler while the renderer yields jumps ahead of the rest of the transition
+    dom.in_runtime(|| signal(2).set(1));
+    assert!(!dom.render_with_deadline(&mut NoOpMutations, || true));
+    assert_eq!(take_renders(), [2]);
+    assert!(dom.render_with_deadline(&mut NoOpMutations, || true));
+    assert_eq!(take_renders(), [1]);
+}
@@ -0,0 +1,255 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use std::{cell::RefCell, collections::HashMap, time::Duration};
+use tokio::sync::oneshot;
+
+thread_local! {
+    static RELEASE: RefCell<HashMap<&'static str, oneshot::Sender<()>>> = RefCell::new(HashMap::new());
+}
+
+/// Let the suspended item with the given name resolve
+fn release(name: &'static str) {
+    RELEASE.with(|release| {
+        let sender = release.borrow_mut().remove(name).unwrap();
+        sender.send(()).unwrap();
+    });
+}
+
+/// Render suspense until there is no more suspense work to do
+async fn settle(dom: &mut VirtualDom) -> Vec<ScopeId> {
+    let mut resolved = Vec::new();
+    while tokio::time::timeout(Duration::from_millis(20), dom.wait_for_suspense_work())
+        .await
+        .is_ok()
+    {
+        resolved.extend(dom.render_suspense_immediate().await);
+    }
+    resolved
+}
+
+#[component]
+fn suspended_item(name: &'static str) -> Element {
+    let mut ready = use_signal(|| false);
+    let task = use_hook(|| {
+        let (sender, receiver) = oneshot::channel();
+        RELEASE.with(|release| release.borrow_mut().insert(name, sender));
+        spawn(async move {
+            _ = receiver.await;
+            ready.set(true);
+        })
+    });
+    if !ready() {
+        suspend(task)?;
+    }
+
+    rsx! { "{name}" }
+}
+
+fn list(reveal_order: SuspenseRevealOrder, tail: SuspenseTail) -> Element {
+    rsx! {
+        SuspenseList {
+            reveal_order,
+            tail,
+            for name in ["a", "b", "c"] {
+                SuspenseBoundary {
+                    key: "{name}",
+                    fallback: move |_| rsx! { "[{name}]" },
+                    suspended_item { name }
+                }
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn forwards_reveals_in_order() {
+    let mut dom = VirtualDom::new(|| list(SuspenseRevealOrder::Forwards, SuspenseTail::Visible));
+    dom.rebuild_in_place();
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "[a][b][c]");
+
+    // b resolved, but a is still loading so b stays in its fallback
+    release("b");
+    let resolved = settle(&mut dom).await;
+    assert!(resolved.is_empty());
+    assert_eq!(dioxus_ssr::render(&dom), "[a][b][c]");
+
+    // Once a resolves, a and b are revealed together
+    release("a");
+    let resolved = settle(&mut dom).await;
+    assert_eq!(resolved.len(), 2);
+    assert_eq!(dioxus_ssr::render(&dom), "ab[c]");
+
+    release("c");
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "abc");
+}
+
+#[tokio::test]
+async fn backwards_reveals_in_reverse_order() {
+    let mut dom = VirtualDom::new(|| list(SuspenseRevealOrder::Backwards, SuspenseTail::Visible));
+    dom.rebuild_in_place();
+    settle(&mut dom).await;
+
+    release("a");
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "[a][b][c]");
+
+    release("c");
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "[a][b]c");
+
+    release("b");
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "abc");
+}
+
+#[tokio::test]
+async fn together_reveals_at_once() {
+    let mut dom = VirtualDom::new(|| list(SuspenseRevealOrder::Together, SuspenseTail::Visible));
+    dom.rebuild_in_place();
+    settle(&mut dom).await;
+
+    release("a");
+    release("c");
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "[a][b][c]");
+
+    release("b");
+    let resolved = settle(&mut dom).await;
+    assert_eq!(resolved.len(), 3);
+    assert_eq!(dioxus_ssr::render(&dom), "abc");
+}
+
+#[tokio::test]
+async fn collapsed_tail_only_shows_the_next_fallback() {
+    let mut dom = VirtualDom::new(|| list(SuspenseRevealOrder::Forwards, SuspenseTail::Collapsed));
+    dom.rebuild_in_place();
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "[a]");
+
+    release("a");
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "a[b]");
+
+    release("b");
+    release("c");
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "abc");
+}
+
+#[tokio::test]
+async fn hidden_tail_shows_no_fallbacks() {
+    let mut dom = VirtualDom::new(|| list(SuspenseRevealOrder::Forwards, SuspenseTail::Hidden));
+    dom.rebuild_in_place();
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "");
+
+    release("a");
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "a");
+}
+
+#[tokio::test]
+async fn nested_boundaries_are_not_coordinated() {
+    fn app() -> Element {
+        rsx! {
+            SuspenseList {
+                SuspenseBoundary {
+                    fallback: |_| rsx! { "[outer]" },
+                    SuspenseBoundary {
+                        fallback: |_| rsx! { "[inner]" },
+                        suspended_item { name: "inner" }
+                    }
+                }
+                SuspenseBoundary {
+                    fallback: |_| rsx! { "[last]" },
+                    suspended_item { name: "last" }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    settle(&mut dom).await;
+
+    // The outer boundary never suspends itself, so the last boundary can be revealed
+    release("last");
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "[inner]last");
+}
+
+thread_local! {
+    static NAMES: std::cell::Cell<Option<Signal<Vec<&'static str>>>> = const { std::cell::Cell::new(None) };
+}
+
+/// A list of boundaries whose names can change after the first render
+fn dynamic_list(reveal_order: SuspenseRevealOrder) -> Element {
+    let names = use_signal(|| vec!["b"]);
+    use_hook(|| NAMES.with(|cell| cell.set(Some(names))));
+
+    rsx! {
+        SuspenseList { reveal_order,
+            for name in names() {
+                SuspenseBoundary {
+                    key: "{name}",
+                    fallback: move |_| rsx! { "[{name}]" },
+                    suspended_item { name }
+                }
+            }
+        }
+    }
+}
+
+/// Insert a boundary in front of the existing ones
+fn insert_first(dom: &mut VirtualDom, name: &'static str) {
+    dom.in_runtime(|| {
+        NAMES
+            .with(|cell| cell.get().unwrap())
+            .write()
+            .insert(0, name)
+    });
+}
+
+#[tokio::test]
+async fn forwards_orders_inserted_boundaries_by_position() {
+    let mut dom = VirtualDom::new(|| dynamic_list(SuspenseRevealOrder::Forwards));
+    dom.rebuild_in_place();
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "[b]");
+
+    // a is created after b, but it comes first so b doesn't hold it back
+    insert_first(&mut dom, "a");
+    dom.render_immediate(&mut NoOpMutations);
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "[a][b]");
+
+    release("a");
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "a[b]");
+
+    release("b");
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "ab");
+}
+
+#[tokio::test]
+async fn backwards_orders_inserted_boundaries_by_position() {
+    let mut dom = VirtualDom::new(|| dynamic_list(SuspenseRevealOrder::Backwards));
+    dom.rebuild_in_place();
+    settle(&mut dom).await;
+
+    insert_first(&mut dom, "a");
+    dom.render_immediate(&mut NoOpMutations);
+    settle(&mut dom).await;
+
+    // a comes before b, so it waits for b even though it resolved first
+    release("a");
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "[a][b]");
+
+    release("b");
+    settle(&mut dom).await;
+    assert_eq!(dioxus_ssr::render(&dom), "ab");
+}
@@ -0,0 +1,149 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use std::{cell::RefCell, time::Duration};
+
+thread_local! {
+    static RENDERS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+    static SIGNALS: RefCell<Vec<Signal<i32>>> = const { RefCell::new(Vec::new()) };
+}
+
+fn take_renders() -> Vec<&'static str> {
+    RENDERS.with(|renders| std::mem::take(&mut *renders.borrow_mut()))
+}
+
+fn signal(index: usize) -> Signal<i32> {
+    SIGNALS.with(|signals| signals.borrow()[index])
+}
+
+fn use_test_signal() -> Signal<i32> {
+    let signal = use_signal(|| 0);
+    use_hook(|| SIGNALS.with(|signals| signals.borrow_mut().push(signal)));
+    signal
+}
+
+#[test]
+fn transitions_render_after_urgent_updates() {
+    fn app() -> Element {
+        let slow = use_test_signal();
+        RENDERS.with(|renders| renders.borrow_mut().push("parent"));
+        rsx! { "{slow}" fast_child {} }
+    }
+
+    #[component]
+    fn fast_child() -> Element {
+        let fast = use_test_signal();
+        RENDERS.with(|renders| renders.borrow_mut().push("child"));
+        rsx! { "{fast}" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    take_renders();
+
+    // The parent is higher in the tree, but it was updated in a transition so the child renders first
+    dom.in_scope(ScopeId::APP, || {
+        start_transition(|| signal(0).set(1));
+        signal(1).set(1);
+    });
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(take_renders(), ["child", "parent"]);
+    assert!(!dom.has_pending_transitions());
+
+    // An urgent update to a scope that is waiting on a transition replaces the transition
+    dom.in_scope(ScopeId::APP, || {
+        start_transition(|| signal(0).set(2));
+        signal(0).set(3);
+    });
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(take_renders(), ["parent"]);
+    assert!(!dom.has_pending_transitions());
+}
+
+#[tokio::test]
+async fn suspense_keeps_the_previous_ui_in_transitions() {
+    thread_local! {
+        static FALLBACKS: RefCell<usize> = const { RefCell::new(0) };
+    }
+
+    fn app() -> Element {
+        rsx! {
+            SuspenseBoundary {
+                fallback: |_| {
+                    FALLBACKS.with(|count| *count.borrow_mut() += 1);
+                    rsx! { "loading" }
+                },
+                loading_child {}
+            }
+        }
+    }
+
+    #[component]
+    fn loading_child() -> Element {
+        let id = use_test_signal();
+        let mut loaded = use_signal(|| 0);
+        let target = id();
+        if loaded() != target {
+            suspend(spawn(async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                loaded.set(target);
+            }))?;
+        }
+        rsx! { "loaded {loaded}" }
+    }
+
+    let fallbacks = || FALLBACKS.with(|count| *count.borrow());
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&dom), "loaded 0");
+
+    // Suspending in a transition keeps the current children
+    dom.in_scope(ScopeId::APP, || start_transition(|| signal(0).set(1)));
+    dom.render_immediate(&mut NoOpMutations);
+    dom.render_immediate(&mut NoOpMutations);
+    assert!(!dom.has_pending_transitions());
+    assert_eq!(fallbacks(), 0);
+    assert_eq!(dioxus_ssr::render(&dom), "loaded 0");
+
+    tokio::time::timeout(Duration::from_secs(1), dom.wait_for_work())
+        .await
+        .unwrap();
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "loaded 1");
+
+    // Urgent updates still show the fallback
+    dom.in_scope(ScopeId::APP, || signal(0).set(2));
+    dom.render_immediate(&mut NoOpMutations);
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(fallbacks(), 1);
+    assert_eq!(dioxus_ssr::render(&dom), "loading");
+}
+
+#[test]
+fn deferred_values_update_in_a_transition() {
+    thread_local! {
+        static VALUES: RefCell<Vec<(i32, i32)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn app() -> Element {
+        let value = use_test_signal();
+        let deferred = use_deferred_value(value());
+        VALUES.with(|values| values.borrow_mut().push((value(), deferred)));
+        rsx! { "{deferred}" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    dom.in_scope(ScopeId::APP, || signal(0).set(1));
+    let values = || VALUES.with(|values| values.borrow().clone());
+
+    // The urgent render keeps the old value
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(values(), [(0, 0), (1, 0)]);
+    assert_eq!(dioxus_ssr::render(&dom), "0");
+
+    // Then the transition catches up in the next render
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(values(), [(0, 0), (1, 0), (1, 1)]);
+    assert_eq!(dioxus_ssr::render(&dom), "1");
+}
@@ -393,6 +393,16 @@ impl App {
             DevserverMsg::Shutdown => {
                 self.control_flow = ControlFlow::Exit;
             }
+            msg @ (DevserverMsg::SetProfiling(_)
+            | DevserverMsg::SetReactiveGraphRecording(_)
+            | DevserverMsg::QueryReactiveGraph) => {
+                for webview in self.webviews.values() {
+                    let runtime = webview.dom.runtime();
+                    if let Some(reply) = dioxus_devtools::handle_runtime_msg(&runtime, &msg) {
+                        dioxus_devtools::send(reply);
+                    }
+                }
+            }
             _ => {}
         }
     }
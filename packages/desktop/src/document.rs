@@ -2,7 +2,7 @@ use crate::{DesktopContext, WeakDesktopContext, query::Query};
 use dioxus_core::queue_effect;
 use dioxus_document::{
     Document, Eval, EvalError, Evaluator, LinkProps, MetaProps, ScriptProps, StyleProps,
-    create_element_in_head,
+    create_element_in_head, remove_elements_in_head,
 };
 
 use generational_box::{AnyStorage, GenerationalBox, UnsyncStorage};
@@ -47,6 +47,14 @@ impl Document for DesktopDocument {
         });
     }
 
+    /// Remove the meta tags that match the props from the head
+    fn remove_meta(&self, props: MetaProps) {
+        let myself = self.clone();
+        queue_effect(move || {
+            myself.eval(remove_elements_in_head("meta", &props.attributes()));
+        });
+    }
+
     /// Create a new script tag in the head
     fn create_script(&self, props: ScriptProps) {
         let myself = self.clone();
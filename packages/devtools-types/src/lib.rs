@@ -1,3 +1,4 @@
+use dioxus_core::ReactiveGraph;
 use dioxus_core::internal::HotReloadTemplateWithLocation;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -25,6 +26,17 @@ pub enum DevserverMsg {
 
     /// The program is shutting down completely - maybe toss up a splash screen or something?
     Shutdown,
+
+    /// Start or stop the render profiler. When profiling stops, the app sends the recorded profile back
+    /// with [`ClientMsg::ProfileTrace`]. Apps only record profiles if they were built with the `profiler` feature
+    SetProfiling(bool),
+
+    /// Start or stop recording the reactive graph. The graph recorded so far is kept when recording stops
+    /// so it can still be queried with [`DevserverMsg::QueryReactiveGraph`]
+    SetReactiveGraphRecording(bool),
+
+    /// Ask the app for the reactive graph it recorded so far. The app answers with [`ClientMsg::ReactiveGraph`]
+    QueryReactiveGraph,
 }
 
 /// A message the client sends from the frontend to the devserver
@@ -37,6 +49,12 @@ pub enum ClientMsg {
         level: String,
         messages: Vec<String>,
     },
+
+    /// A render profile in the Chrome trace event format
+    ProfileTrace { trace: String },
+
+    /// The reactive graph of the app, sent in response to [`DevserverMsg::QueryReactiveGraph`]
+    ReactiveGraph { graph: ReactiveGraph },
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
@@ -1,5 +1,5 @@
 use dioxus_core::internal::HotReloadedTemplate;
-use dioxus_core::{ScopeId, VirtualDom};
+use dioxus_core::{Runtime, ScopeId, VirtualDom};
 use dioxus_signals::{GlobalKey, Signal, WritableExt};
 
 pub use dioxus_devtools_types::*;
@@ -54,6 +54,32 @@ pub fn try_apply_changes(dom: &VirtualDom, msg: &HotReloadMsg) -> Result<(), Pat
     })
 }
 
+/// Handle the messages the devserver sends to inspect the runtime of an app: the render profiler and the reactive
+/// graph.
+///
+/// Returns the answer to send back to the devserver if the message asks for one.
+pub fn handle_runtime_msg(runtime: &Runtime, msg: &DevserverMsg) -> Option<ClientMsg> {
+    match msg {
+        DevserverMsg::SetProfiling(true) => runtime.start_profiling(),
+        // Once profiling stops, send the profile back
+        DevserverMsg::SetProfiling(false) => {
+            let profile = runtime.stop_profiling()?;
+            return Some(ClientMsg::ProfileTrace {
+                trace: profile.to_chrome_trace(),
+            });
+        }
+        DevserverMsg::SetReactiveGraphRecording(true) => runtime.start_recording_reactive_graph(),
+        DevserverMsg::SetReactiveGraphRecording(false) => runtime.stop_recording_reactive_graph(),
+        DevserverMsg::QueryReactiveGraph => {
+            return Some(ClientMsg::ReactiveGraph {
+                graph: runtime.reactive_graph(),
+            });
+        }
+        _ => {}
+    }
+    None
+}
+
 /// Connect to the devserver and handle its messages with a callback.
 ///
 /// This doesn't use any form of security or protocol, so it's not safe to expose to the internet.
@@ -84,6 +110,33 @@ pub fn connect_subsecond() {
     });
 }
 
+/// The connections opened by [`connect_at`] that are still alive. Messages for the devserver are sent over the
+/// first connection that is still open.
+#[cfg(not(target_family = "wasm"))]
+static CONNECTIONS: std::sync::Mutex<Vec<std::sync::mpsc::Sender<ClientMsg>>> =
+    std::sync::Mutex::new(Vec::new());
+
+/// Send a message to the devserver over a connection opened with [`connect`].
+///
+/// This does nothing if the app isn't connected to a devserver.
+#[cfg(not(target_family = "wasm"))]
+pub fn send(msg: ClientMsg) {
+    let mut connections = CONNECTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let mut msg = Some(msg);
+    connections.retain(|connection| match msg.take() {
+        Some(to_send) => match connection.send(to_send) {
+            Ok(()) => true,
+            Err(std::sync::mpsc::SendError(unsent)) => {
+                msg = Some(unsent);
+                false
+            }
+        },
+        None => true,
+    });
+}
+
 #[cfg(not(target_family = "wasm"))]
 pub fn connect_at(endpoint: String, mut callback: impl FnMut(DevserverMsg) + Send + 'static) {
     std::thread::spawn(move || {
@@ -99,11 +152,41 @@ pub fn connect_at(endpoint: String, mut callback: impl FnMut(DevserverMsg) + Sen
             Err(_) => return,
         };
 
-        while let Ok(msg) = websocket.read() {
-            if let tungstenite::Message::Text(text) = msg
-                && let Ok(msg) = serde_json::from_str(&text)
-            {
-                callback(msg);
+        // Wake up regularly to send the messages queued with `send`. The devserver is always a plain
+        // websocket, so we don't need to handle timeouts for tls streams
+        if let tungstenite::stream::MaybeTlsStream::Plain(stream) = websocket.get_ref() {
+            _ = stream.set_read_timeout(Some(std::time::Duration::from_millis(100)));
+        }
+        let (tx, rx) = std::sync::mpsc::channel();
+        CONNECTIONS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(tx);
+
+        loop {
+            for msg in rx.try_iter() {
+                let text = serde_json::to_string(&msg).unwrap();
+                if websocket
+                    .send(tungstenite::Message::Text(text.into()))
+                    .is_err()
+                {
+                    return;
+                }
+            }
+
+            match websocket.read() {
+                Ok(tungstenite::Message::Text(text)) => {
+                    if let Ok(msg) = serde_json::from_str(&text) {
+                        callback(msg);
+                    }
+                }
+                Ok(_) => {}
+                Err(tungstenite::Error::Io(err))
+                    if matches!(
+                        err.kind(),
+                        std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                    ) => {}
+                Err(_) => return,
             }
         }
     });
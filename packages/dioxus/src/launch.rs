@@ -261,6 +261,30 @@ impl LaunchBuilder {
         self
     }
 
+    /// Report every error thrown in the app to a callback, along with the path of components it was thrown from.
+    /// Errors are reported even if an error boundary catches them.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     rsx! {
+    ///         div { "Hello, world!" }
+    ///     }
+    /// }
+    ///
+    /// dioxus::LaunchBuilder::new()
+    ///     .with_error_reporter(|report| eprintln!("{report}"))
+    ///     .launch(app);
+    /// ```
+    pub fn with_error_reporter(
+        self,
+        report: impl Fn(&dioxus_core::ErrorReport) + Send + Sync + 'static,
+    ) -> Self {
+        self.with_context(dioxus_core::ErrorReporter::new(report))
+    }
+
     /// Provide a platform-specific config to the builder.
     ///
     /// # Example
@@ -225,8 +225,8 @@ pub mod prelude {
     #[cfg_attr(docsrs, doc(cfg(feature = "router")))]
     #[doc(inline)]
     pub use dioxus_router::{
-        GoBackButton, GoForwardButton, Link, NavigationTarget, Outlet, Routable, Router, hooks::*,
-        navigator, use_navigator,
+        AnimatedOutlet, GoBackButton, GoForwardButton, Link, NavigationTarget, Outlet, Prefetch,
+        Routable, Router, ScrollArea, hooks::*, navigator, use_navigator,
     };
 
     #[cfg(feature = "asset")]
@@ -241,9 +241,10 @@ pub mod prelude {
     #[doc(inline)]
     pub use dioxus_core::{
         AnyhowContext, Attribute, Callback, Component, Element, ErrorBoundary, ErrorContext, Event,
-        EventHandler, Fragment, HasAttributes, IntoDynNode, RenderError, Result, ScopeId,
-        SuspenseBoundary, SuspenseContext, VNode, VirtualDom, consume_context, provide_context,
-        spawn, suspend, try_consume_context, use_drop, use_hook,
+        EventHandler, Fragment, HasAttributes, IntoDynNode, Portal, PortalTarget, RenderError,
+        Result, ScopeId, SuspenseBoundary, SuspenseContext, SuspenseList, SuspenseRevealOrder,
+        SuspenseTail, VNode, VirtualDom, consume_context, provide_context, spawn, start_transition,
+        suspend, try_consume_context, use_drop, use_hook,
     };
 
     #[cfg(feature = "logger")]
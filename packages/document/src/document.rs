@@ -48,6 +48,18 @@ pub fn create_element_in_head(
     format!(r#"{helpers};window.createElementInHead({tag}, {attributes}, {children});"#)
 }
 
+/// Remove the elements in the head that have all of the given attributes with javascript through the
+/// [`Document::eval`] method
+///
+/// This can be used to implement the head element removal logic for most [`Document`] implementations.
+pub fn remove_elements_in_head(tag: &str, attributes: &[(&str, String)]) -> String {
+    let attributes = format_attributes(attributes);
+    let tag = format_string_for_js(tag);
+    format!(
+        r#"for (const element of document.head.querySelectorAll({tag})) {{ if ({attributes}.every(([key, value]) => element.getAttribute(key) === value)) element.remove(); }}"#
+    )
+}
+
 /// A provider for document-related functionality.
 ///
 /// Provides things like a history API, a title, a way to run JS, and some other basics/essentials used
@@ -82,12 +94,24 @@ pub trait Document: 'static {
         self.eval(create_element_in_head(name, attributes, contents));
     }
 
+    /// Remove the elements in the head with this tag name that have all of the given attributes
+    fn remove_head_element(&self, name: &str, attributes: &[(&str, String)]) {
+        self.eval(remove_elements_in_head(name, attributes));
+    }
+
     /// Create a new meta tag in the head
     fn create_meta(&self, props: MetaProps) {
         let attributes = props.attributes();
         self.create_head_element("meta", &attributes, None);
     }
 
+    /// Remove the meta tags in the head that have all of the attributes of `props`. Only set the attributes that
+    /// identify the tag, like the `name` or `property`, to remove the tag regardless of its content.
+    fn remove_meta(&self, props: MetaProps) {
+        let attributes = props.attributes();
+        self.remove_head_element("meta", &attributes);
+    }
+
     /// Create a new script tag in the head
     fn create_script(&self, props: ScriptProps) {
         let attributes = props.attributes();
@@ -146,6 +170,7 @@ impl Document for NoOpDocument {
 
     fn set_title(&self, _: String) {}
     fn create_meta(&self, _: MetaProps) {}
+    fn remove_meta(&self, _: MetaProps) {}
     fn create_script(&self, _: ScriptProps) {}
     fn create_style(&self, _: StyleProps) {}
     fn create_link(&self, _: LinkProps) {}
@@ -36,6 +36,11 @@ impl<D: Document> Document for FullstackWebDocument<D> {
         self.document.create_meta(props);
     }
 
+    /// Remove the meta tags that match the props from the head
+    fn remove_meta(&self, props: MetaProps) {
+        self.document.remove_meta(props);
+    }
+
     /// Create a new script tag in the head
     fn create_script(&self, props: ScriptProps) {
         self.document.create_script(props);
@@ -0,0 +1,24 @@
+//! How a form that posts to a server function without JavaScript gets the outcome of the call.
+//!
+//! An `ActionForm` posts to the route of the server function with [`FORM_ACTION_QUERY`] in the query. When a browser
+//! submits one of these forms natively, the server function responds with a redirect to the page the form was on
+//! instead of the raw response, and flashes the outcome of the call to that page in the [`FORM_ACTION_COOKIE`]. The
+//! page reads and clears the cookie while it renders.
+
+/// The query parameter that marks a form post as coming from an `ActionForm`. Only these posts are redirected back
+/// with the outcome of the call, other form posts are only redirected if they succeed.
+pub const FORM_ACTION_QUERY: &str = "dx-action";
+
+/// The cookie with the outcome of the last native `ActionForm` submission. The value is urlencoded with the
+/// [`FORM_ACTION_ROUTE_FIELD`] and either the [`FORM_ACTION_RESULT_FIELD`] or the [`FORM_ACTION_ERROR_FIELD`].
+pub const FORM_ACTION_COOKIE: &str = "dx-action";
+
+/// The field of the [`FORM_ACTION_COOKIE`] with the route of the server function the form was submitted to.
+pub const FORM_ACTION_ROUTE_FIELD: &str = "route";
+
+/// The field of the [`FORM_ACTION_COOKIE`] with the body of a successful response. This is left out if the body is
+/// too large to fit in a cookie.
+pub const FORM_ACTION_RESULT_FIELD: &str = "result";
+
+/// The field of the [`FORM_ACTION_COOKIE`] with the message of a failed response.
+pub const FORM_ACTION_ERROR_FIELD: &str = "error";
@@ -104,6 +104,10 @@ impl<H: History> History for FullstackHistory<H> {
         self.history.current_prefix()
     }
 
+    fn current_index(&self) -> Option<usize> {
+        self.history.current_index()
+    }
+
     fn can_go_back(&self) -> bool {
         match_hydration(|| false, || self.history.can_go_back())
     }
@@ -124,10 +128,19 @@ impl<H: History> History for FullstackHistory<H> {
         self.history.include_prevent_default()
     }
 
+    fn disable_scroll_restoration(&self) {
+        self.history.disable_scroll_restoration()
+    }
+
     fn current_route(&self) -> String {
         match_hydration(|| self.initial_route(), || self.history.current_route())
     }
 
+    fn current_state(&self) -> Option<String> {
+        // The server doesn't know the state of the entry, so it is only read after hydration
+        match_hydration(|| None, || self.history.current_state())
+    }
+
     fn go_back(&self) {
         self.history.go_back();
     }
@@ -143,4 +156,12 @@ impl<H: History> History for FullstackHistory<H> {
     fn replace(&self, path: String) {
         self.history.replace(path);
     }
+
+    fn push_with_state(&self, route: String, state: String) {
+        self.history.push_with_state(route, state);
+    }
+
+    fn replace_with_state(&self, path: String, state: String) {
+        self.history.replace_with_state(path, state);
+    }
 }
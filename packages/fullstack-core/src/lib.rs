@@ -5,14 +5,18 @@ pub mod document;
 pub mod history;
 
 mod errors;
+mod form_action;
 mod loader;
+mod prefetch;
 mod server_cached;
 mod server_future;
 mod streaming;
 mod transport;
 
 pub use crate::errors::*;
+pub use crate::form_action::*;
 pub use crate::loader::*;
+pub use crate::prefetch::{PrefetchCache, PrefetchScope};
 pub use crate::server_cached::*;
 pub use crate::server_future::*;
 pub use crate::streaming::*;
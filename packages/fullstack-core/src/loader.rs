@@ -42,9 +42,11 @@ where
     let storage_entry: crate::transport::SerializeContextEntry<Result<T, CapturedError>> =
         use_hook(|| serialize_context.create_entry());
 
-    #[cfg(feature = "server")]
     let caller = std::panic::Location::caller();
 
+    // If the route was prefetched, the data might already be loaded
+    let prefetch_entry = use_hook(|| crate::prefetch::PrefetchEntry::current(caller));
+
     // If this is the first run and we are on the web client, the data might be cached
     #[cfg(feature = "web")]
     let initial_web_result =
@@ -58,6 +60,8 @@ where
         #[cfg(feature = "server")]
         let storage_entry = storage_entry.clone();
 
+        let prefetch_entry = prefetch_entry.clone();
+
         let user_fut = future();
 
         #[cfg(feature = "web")]
@@ -95,19 +99,31 @@ where
                 None => {}
             }
 
-            // Otherwise just run the future itself
-            let out = user_fut.await;
-
-            // Remap the error to the captured error type so it's cheap to clone and pass out, just
-            // slightly more cumbersome to access the inner error.
-            let out = out.map_err(|e| {
-                let anyhow_err: CapturedError = e.into();
-                anyhow_err
-            });
-
-            // If this is the first run and we are on the server, cache the data in the slot we reserved for it
-            #[cfg(feature = "server")]
-            storage_entry.insert(&out, caller);
+            // The data was loaded when the route was prefetched, otherwise just run the future itself
+            let out = match prefetch_entry.as_ref().and_then(|entry| entry.take()) {
+                Some(out) => out,
+                None => {
+                    let out = user_fut.await;
+
+                    // Remap the error to the captured error type so it's cheap to clone and pass out, just
+                    // slightly more cumbersome to access the inner error.
+                    let out = out.map_err(|e| {
+                        let anyhow_err: CapturedError = e.into();
+                        anyhow_err
+                    });
+
+                    // If this is the first run and we are on the server, cache the data in the slot we reserved for it
+                    #[cfg(feature = "server")]
+                    storage_entry.insert(&out, caller);
+
+                    // If the route is being prefetched, save the data for when the user navigates to it
+                    if let Some(entry) = &prefetch_entry {
+                        entry.insert(&out);
+                    }
+
+                    out
+                }
+            };
 
             match out {
                 Ok(v) => {
@@ -0,0 +1,196 @@
+//! Server data that was loaded ahead of time for a route the user is likely to navigate to.
+//!
+//! The router renders a prefetched route in the background inside a recording [`PrefetchScope`]. Every
+//! [`use_server_future`](crate::use_server_future) and [`use_loader`](crate::use_loader) in that tree saves its
+//! result into the [`PrefetchCache`]. When the user navigates to the route, the router renders it inside a replaying
+//! scope and the same hooks start with the saved results instead of running their futures again.
+
+use crate::Transportable;
+use dioxus_core::try_consume_context;
+use std::{
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, HashMap},
+    rc::Rc,
+};
+
+type Location = &'static std::panic::Location<'static>;
+
+/// The results of the hooks in a route, by call site and the order the hooks were created in.
+type RouteData = HashMap<Location, BTreeMap<usize, Vec<u8>>>;
+
+/// The data loaded by prefetched routes, keyed by route.
+#[derive(Clone, Default)]
+pub struct PrefetchCache {
+    routes: Rc<RefCell<HashMap<String, RouteData>>>,
+}
+
+impl PrefetchCache {
+    /// Create a new, empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check if any data was prefetched for the route.
+    pub fn contains(&self, route: &str) -> bool {
+        self.routes
+            .borrow()
+            .get(route)
+            .is_some_and(|data| data.values().any(|entries| !entries.is_empty()))
+    }
+
+    /// Remove the data that was prefetched for the route.
+    pub fn remove(&self, route: &str) {
+        self.routes.borrow_mut().remove(route);
+    }
+
+    /// Remove all prefetched data.
+    pub fn clear(&self) {
+        self.routes.borrow_mut().clear();
+    }
+}
+
+/// Provided as context around the tree of a route to record or replay prefetched data.
+#[derive(Clone)]
+pub struct PrefetchScope {
+    cache: PrefetchCache,
+    mode: PrefetchMode,
+}
+
+#[derive(Clone)]
+enum PrefetchMode {
+    Record {
+        route: String,
+        occurrences: Rc<RefCell<HashMap<Location, usize>>>,
+    },
+    Replay {
+        current_route: Rc<dyn Fn() -> String>,
+    },
+}
+
+impl PrefetchScope {
+    /// Save the data loaded in this scope into the cache for the route. Any data that was recorded for the route
+    /// before is removed.
+    pub fn record(cache: PrefetchCache, route: impl ToString) -> Self {
+        let route = route.to_string();
+        cache.remove(&route);
+        Self {
+            cache,
+            mode: PrefetchMode::Record {
+                route,
+                occurrences: Default::default(),
+            },
+        }
+    }
+
+    /// Start the hooks in this scope with the data that was prefetched for the route they are created in.
+    ///
+    /// `current_route` is called every time a hook is created, so a single scope can be provided around the
+    /// outlet of a router and replay the data of every route the user navigates to.
+    pub fn replay(cache: PrefetchCache, current_route: impl Fn() -> String + 'static) -> Self {
+        Self {
+            cache,
+            mode: PrefetchMode::Replay {
+                current_route: Rc::new(current_route),
+            },
+        }
+    }
+
+    /// Check if this scope records data instead of replaying it.
+    pub fn is_recording(&self) -> bool {
+        matches!(self.mode, PrefetchMode::Record { .. })
+    }
+}
+
+/// The slot for a single hook in the current [`PrefetchScope`].
+///
+/// Hooks are matched up by their call site and the order they are created in, which is the same for the background
+/// and the real render of a route.
+pub(crate) struct PrefetchEntry<T> {
+    cache: PrefetchCache,
+    route: String,
+    location: Location,
+    // The order this hook was created in if it is being recorded
+    occurrence: Option<usize>,
+    // Only the first run of the hook replays prefetched data
+    replayed: Rc<Cell<bool>>,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T> Clone for PrefetchEntry<T> {
+    fn clone(&self) -> Self {
+        Self {
+            cache: self.cache.clone(),
+            route: self.route.clone(),
+            location: self.location,
+            occurrence: self.occurrence,
+            replayed: self.replayed.clone(),
+            phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T> PrefetchEntry<T> {
+    /// Create the entry for a hook if it is inside a [`PrefetchScope`]. This should be called once when the hook is
+    /// created.
+    pub(crate) fn current(location: Location) -> Option<Self> {
+        let scope = try_consume_context::<PrefetchScope>()?;
+        let (route, occurrence) = match &scope.mode {
+            PrefetchMode::Record { route, occurrences } => {
+                let mut occurrences = occurrences.borrow_mut();
+                let count = occurrences.entry(location).or_default();
+                *count += 1;
+                (route.clone(), Some(*count - 1))
+            }
+            PrefetchMode::Replay { current_route } => (current_route(), None),
+        };
+
+        Some(Self {
+            cache: scope.cache,
+            route,
+            location,
+            occurrence,
+            replayed: Default::default(),
+            phantom: std::marker::PhantomData,
+        })
+    }
+
+    /// Save the result of the hook if the scope is recording.
+    pub(crate) fn insert<M: 'static>(&self, value: &T)
+    where
+        T: Transportable<M>,
+    {
+        let Some(occurrence) = self.occurrence else {
+            return;
+        };
+
+        self.cache
+            .routes
+            .borrow_mut()
+            .entry(self.route.clone())
+            .or_default()
+            .entry(self.location)
+            .or_default()
+            .insert(occurrence, value.transport_to_bytes());
+    }
+
+    /// Take the prefetched result of the hook if the scope is replaying. Hooks with the same call site take the
+    /// results in the order they were created in.
+    pub(crate) fn take<M: 'static>(&self) -> Option<T>
+    where
+        T: Transportable<M>,
+    {
+        if self.occurrence.is_some() || self.replayed.replace(true) {
+            return None;
+        }
+
+        let (_, bytes) = self
+            .cache
+            .routes
+            .borrow_mut()
+            .get_mut(&self.route)?
+            .get_mut(self.location)?
+            .pop_first()?;
+
+        T::transport_from_bytes(&bytes).ok()
+    }
+}
@@ -74,9 +74,11 @@ where
     let storage_entry: crate::transport::SerializeContextEntry<T> =
         use_hook(|| serialize_context.create_entry());
 
-    #[cfg(feature = "server")]
     let caller = std::panic::Location::caller();
 
+    // If the route was prefetched, the data might already be loaded
+    let prefetch_entry = use_hook(|| crate::prefetch::PrefetchEntry::current(caller));
+
     // If this is the first run and we are on the web client, the data might be cached
     #[cfg(feature = "web")]
     let initial_web_result =
@@ -86,6 +88,8 @@ where
         #[cfg(feature = "server")]
         let storage_entry = storage_entry.clone();
 
+        let prefetch_entry = prefetch_entry.clone();
+
         let user_fut = future();
 
         #[cfg(feature = "web")]
@@ -111,6 +115,11 @@ where
                 None => {}
             }
 
+            // The data was loaded when the route was prefetched
+            if let Some(out) = prefetch_entry.as_ref().and_then(|entry| entry.take()) {
+                return out;
+            }
+
             // Otherwise just run the future itself
             let out = user_fut.await;
 
@@ -118,6 +127,11 @@ where
             #[cfg(feature = "server")]
             storage_entry.insert(&out, caller);
 
+            // If the route is being prefetched, save the data for when the user navigates to it
+            if let Some(entry) = &prefetch_entry {
+                entry.insert(&out);
+            }
+
             out
         }
     });
@@ -0,0 +1,101 @@
+//! Negotiate the language of a server rendered page from the `Accept-Language` header of the request.
+
+use dioxus_fullstack_core::FullstackContext;
+use http::{
+    HeaderValue,
+    header::{ACCEPT_LANGUAGE, VARY},
+};
+
+/// The languages the client of the current request accepts, from most to least preferred.
+///
+/// This reads the `Accept-Language` header of the request that is being rendered. It is empty outside of a request
+/// or if the header is missing.
+pub fn accepted_languages() -> Vec<String> {
+    let Some(context) = FullstackContext::current() else {
+        return Vec::new();
+    };
+
+    context
+        .parts_mut()
+        .headers
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .map(parse_accept_language)
+        .unwrap_or_default()
+}
+
+/// Pick the language out of `available` that the client of the current request prefers most.
+///
+/// Languages are matched on their full tag first, then on their primary language, so a request for `fr-CA` matches
+/// `fr` and a request for `en` matches `en-US`. Returns `None` if none of the languages are accepted.
+///
+/// Because the response now depends on the request's language, this also adds `Vary: Accept-Language` to the
+/// response.
+pub fn negotiate_language<'a>(available: &[&'a str]) -> Option<&'a str> {
+    if let Some(context) = FullstackContext::current() {
+        context.add_response_header(VARY, HeaderValue::from_static("accept-language"));
+    }
+
+    pick_language(&accepted_languages(), available)
+}
+
+/// Parse the value of an `Accept-Language` header into a list of language tags sorted by their quality.
+///
+/// Wildcards and languages with a quality of zero are skipped.
+pub fn parse_accept_language(header: &str) -> Vec<String> {
+    let mut languages: Vec<(String, f32)> = header
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';');
+            let tag = parts.next()?.trim();
+            if tag.is_empty() || tag == "*" {
+                return None;
+            }
+            let quality = parts
+                .filter_map(|param| param.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            (quality > 0.0).then(|| (tag.to_string(), quality))
+        })
+        .collect();
+
+    // The sort is stable, so languages with the same quality keep the order they were sent in
+    languages.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    languages.into_iter().map(|(tag, _)| tag).collect()
+}
+
+fn pick_language<'a>(accepted: &[String], available: &[&'a str]) -> Option<&'a str> {
+    fn primary(tag: &str) -> &str {
+        tag.split(['-', '_']).next().unwrap_or(tag)
+    }
+
+    accepted.iter().find_map(|accepted| {
+        available
+            .iter()
+            .find(|available| available.eq_ignore_ascii_case(accepted))
+            .or_else(|| {
+                available
+                    .iter()
+                    .find(|available| primary(available).eq_ignore_ascii_case(primary(accepted)))
+            })
+            .copied()
+    })
+}
+
+#[test]
+fn test_parse_accept_language() {
+    assert_eq!(
+        parse_accept_language("fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5"),
+        ["fr-CH", "fr", "en", "de"]
+    );
+    assert_eq!(parse_accept_language("en;q=0.5, de, it;q=0"), ["de", "en"]);
+    assert!(parse_accept_language("").is_empty());
+}
+
+#[test]
+fn test_pick_language() {
+    let accepted = parse_accept_language("fr-CA, en;q=0.8");
+    assert_eq!(pick_language(&accepted, &["en-US", "fr"]), Some("fr"));
+    assert_eq!(pick_language(&accepted, &["de", "en-US"]), Some("en-US"));
+    assert_eq!(pick_language(&accepted, &["de"]), None);
+}
@@ -46,5 +46,8 @@ pub use serverfn::*;
 pub mod isrg;
 pub use isrg::*;
 
+mod accept_language;
+pub use accept_language::*;
+
 mod index_html;
 pub(crate) use index_html::IndexHtml;
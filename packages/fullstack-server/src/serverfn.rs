@@ -5,8 +5,14 @@ use axum::{
     response::Response,
     routing::MethodRouter,
 };
-use dioxus_fullstack_core::FullstackContext;
-use http::{Method, StatusCode};
+use dioxus_fullstack_core::{
+    FORM_ACTION_COOKIE, FORM_ACTION_ERROR_FIELD, FORM_ACTION_QUERY, FORM_ACTION_RESULT_FIELD,
+    FORM_ACTION_ROUTE_FIELD, FullstackContext,
+};
+use http::{
+    HeaderValue, Method, StatusCode,
+    header::{CONTENT_LENGTH, CONTENT_TYPE, LOCATION, SET_COOKIE},
+};
 use std::{pin::Pin, prelude::rust_2024::Future};
 
 /// A function endpoint that can be called from the client.
@@ -95,6 +101,14 @@ impl ServerFunction {
                         .map(|v| v.contains("text/html"))
                         .unwrap_or(false);
 
+                    // `ActionForm`s mark the posts the browser sends for them natively, without JavaScript to
+                    // handle the response
+                    let is_action_form = request.uri().query().is_some_and(|query| {
+                        url::form_urlencoded::parse(query.as_bytes())
+                            .any(|(key, _)| key == FORM_ACTION_QUERY)
+                    });
+                    let route = request.uri().path().to_string();
+
                     server_context
                         .clone()
                         .scope(async move {
@@ -113,19 +127,23 @@ impl ServerFunction {
                                 response.headers_mut().extend(headers);
                             }
 
-                            // If the response is successful and accepts text/html (i.e., is a
-                            // plain form post) and doesn't already have a Location set, then
-                            // redirect to Referer. Only redirect on success so that error
-                            // responses (4xx, 5xx) propagate correctly to the client.
-                            if accepts_html && response.status().is_success()
-                                && let Some(referrer) = referrer {
-                                    let has_location = response.headers().get(LOCATION).is_some();
-                                    if !has_location {
-                                        *response.status_mut() = StatusCode::FOUND;
-                                        response.headers_mut().insert(LOCATION, referrer);
-                                    }
+                            let has_location = response.headers().get(LOCATION).is_some();
+                            if accepts_html && !has_location && let Some(referrer) = referrer {
+                                // Native `ActionForm` posts are sent back to the page they came from with the
+                                // outcome of the call, whether it succeeded or not
+                                if is_action_form {
+                                    return redirect_action_form(response, referrer, &route).await;
                                 }
 
+                                // If the response is successful and accepts text/html and doesn't already
+                                // have a Location set, then redirect to Referer. Only redirect on success so
+                                // that error responses (4xx, 5xx) propagate correctly to the client.
+                                if response.status().is_success() {
+                                    *response.status_mut() = StatusCode::FOUND;
+                                    response.headers_mut().insert(LOCATION, referrer);
+                                }
+                            }
+
                             response
                         })
                         .await
@@ -147,6 +165,65 @@ impl ServerFunction {
     }
 }
 
+/// The longest outcome that is flashed to the page in the [`FORM_ACTION_COOKIE`]. Browsers only keep cookies up to
+/// 4kb, including the name and attributes.
+const MAX_FORM_ACTION_COOKIE_LEN: usize = 3072;
+
+/// Redirect a form that `ActionForm` submitted without JavaScript back to the page it came from, and flash the
+/// outcome of the call to that page in the [`FORM_ACTION_COOKIE`].
+///
+/// The headers of the response are kept so cookies set by the server function still apply.
+async fn redirect_action_form(response: Response, referrer: HeaderValue, route: &str) -> Response {
+    let (mut parts, body) = response.into_parts();
+    // Bodies that are too large to fit in the cookie are left out
+    let body = axum::body::to_bytes(body, MAX_FORM_ACTION_COOKIE_LEN)
+        .await
+        .ok()
+        .and_then(|body| String::from_utf8(body.to_vec()).ok());
+
+    let outcome = |field: Option<(&str, &str)>| {
+        let mut outcome = url::form_urlencoded::Serializer::new(String::new());
+        outcome.append_pair(FORM_ACTION_ROUTE_FIELD, route);
+        if let Some((key, value)) = field {
+            outcome.append_pair(key, value);
+        }
+        outcome.finish()
+    };
+    let status = parts.status.to_string();
+    let mut value = if parts.status.is_success() {
+        outcome(body.as_deref().map(|body| (FORM_ACTION_RESULT_FIELD, body)))
+    } else {
+        // Server function errors are serialized as json with a message, but middleware can return plain text
+        let message = body
+            .as_deref()
+            .and_then(|body| serde_json::from_str::<serde_json::Value>(body).ok())
+            .and_then(|body| body.get("message")?.as_str().map(str::to_string))
+            .or(body)
+            .filter(|message| !message.is_empty())
+            .unwrap_or_else(|| status.clone());
+        outcome(Some((FORM_ACTION_ERROR_FIELD, &message)))
+    };
+    if value.len() > MAX_FORM_ACTION_COOKIE_LEN {
+        value = match parts.status.is_success() {
+            true => outcome(None),
+            false => outcome(Some((FORM_ACTION_ERROR_FIELD, &status))),
+        };
+    }
+
+    // The urlencoded outcome only contains characters that are valid in a cookie
+    let cookie =
+        format!("{FORM_ACTION_COOKIE}={value}; Path=/; Max-Age=60; HttpOnly; SameSite=Lax");
+    let Ok(cookie) = HeaderValue::from_str(&cookie) else {
+        return Response::from_parts(parts, Body::empty());
+    };
+    parts.status = StatusCode::FOUND;
+    parts.headers.remove(CONTENT_TYPE);
+    parts.headers.remove(CONTENT_LENGTH);
+    parts.headers.append(SET_COOKIE, cookie);
+    parts.headers.insert(LOCATION, referrer);
+    Response::from_parts(parts, Body::empty())
+}
+
 impl inventory::Collect for ServerFunction {
     #[inline]
     fn registry() -> &'static inventory::Registry {
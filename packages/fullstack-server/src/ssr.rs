@@ -340,7 +340,12 @@ impl SsrRendererPool {
             // After the initial render, we need to resolve suspense
             while virtual_dom.suspended_tasks_remaining() {
                 virtual_dom.wait_for_suspense_work().await;
-                let resolved_suspense_nodes = virtual_dom.render_suspense_immediate().await;
+                let mut resolved_suspense_nodes = virtual_dom.render_suspense_immediate().await;
+                Self::order_by_suspense_lists(&virtual_dom, &mut resolved_suspense_nodes);
+
+                // Every boundary that resolved in this batch is sent in one chunk so the client reveals them at the same
+                // time. This keeps boundaries in a suspense list that reveals them together from popping in one by one
+                let mut resolved_batch = String::new();
 
                 // Just rerender the resolved nodes
                 for scope in resolved_suspense_nodes {
@@ -366,7 +371,7 @@ impl SsrRendererPool {
                             throw_error!(IncrementalRendererError::RenderError(err));
                         }
 
-                        stream.render(resolved_chunk);
+                        resolved_batch.push_str(&resolved_chunk);
                         // Freeze the suspense boundary to prevent future reruns of any child nodes of the suspense boundary
                         if let Some(suspense) =
                             SuspenseContext::downcast_suspense_boundary_from_scope(
@@ -385,6 +390,9 @@ impl SsrRendererPool {
                         }
                     }
                 }
+                if !resolved_batch.is_empty() {
+                    stream.render(resolved_batch);
+                }
             }
 
             // After suspense is done, we render the html after the body
@@ -462,7 +470,9 @@ impl SsrRendererPool {
         move |renderer, to, vdom, scope| {
             let is_suspense_boundary =
                 SuspenseContext::downcast_suspense_boundary_from_scope(&vdom.runtime(), scope)
-                    .filter(|s| s.has_suspended_tasks())
+                    // Boundaries held back by a suspense list are suspended without tasks of their own. They
+                    // still need a placeholder so they can be streamed in once the list reveals them
+                    .filter(|s| s.is_suspended())
                     .is_some();
             if is_suspense_boundary {
                 let mount = stream.render_placeholder(
@@ -513,6 +523,38 @@ impl SsrRendererPool {
         }
     }
 
+    /// Order the suspense boundaries that resolved in the same batch by the reveal order of the [`SuspenseList`]s they
+    /// are in. Members of a list only trade places with each other, so every other boundary keeps its place.
+    ///
+    /// [`SuspenseList`]: dioxus_core::SuspenseList
+    fn order_by_suspense_lists(virtual_dom: &VirtualDom, resolved: &mut [ScopeId]) {
+        let runtime = virtual_dom.runtime();
+        let positions: Vec<_> = resolved
+            .iter()
+            .map(|&scope| {
+                SuspenseContext::downcast_suspense_boundary_from_scope(&runtime, scope)
+                    .and_then(|suspense| suspense.suspense_list_position())
+            })
+            .collect();
+
+        let mut lists: Vec<ScopeId> = positions.iter().flatten().map(|p| p.list).collect();
+        lists.sort();
+        lists.dedup();
+        for list in lists {
+            let slots: Vec<usize> = (0..resolved.len())
+                .filter(|&slot| positions[slot].is_some_and(|p| p.list == list))
+                .collect();
+            let mut members: Vec<_> = slots
+                .iter()
+                .filter_map(|&slot| Some((positions[slot]?.reveal_rank(), resolved[slot])))
+                .collect();
+            members.sort_by_key(|&(rank, _)| rank);
+            for (&slot, (_, scope)) in slots.iter().zip(members) {
+                resolved[slot] = scope;
+            }
+        }
+    }
+
     /// Start capturing errors at a suspense boundary. If the parent suspense boundary is frozen, we need to capture the errors in the suspense boundary
     /// and send them to the client to continue bubbling up
     fn start_capturing_errors(suspense_scope: ScopeId) {
@@ -25,6 +25,10 @@
 //!     window.dx_hydrate(2, "suspenseboundarydata");
 //! </script>
 //! ```
+//!
+//! Suspense boundaries inside of a `SuspenseList` stay in their fallback until the list reveals them, so they are only
+//! streamed once every boundary before them (or after them, or all of them for lists that reveal boundaries together)
+//! has resolved. Boundaries that are revealed at the same time are streamed in one chunk in the reveal order of their list.
 
 use dioxus_fullstack_core::SerializedHydrationData;
 use futures_channel::mpsc::Sender;
@@ -0,0 +1,227 @@
+#![cfg(feature = "server")]
+
+use axum::{Router, body::Body};
+use dioxus::fullstack::{ActionForm, Form};
+use dioxus::prelude::*;
+use dioxus_server::{DioxusRouterExt, ServeConfig};
+use http::{
+    HeaderMap, Request, StatusCode,
+    header::{ACCEPT, CONTENT_TYPE, COOKIE, LOCATION, REFERER, SET_COOKIE},
+};
+use http_body_util::BodyExt;
+use tower::ServiceExt;
+
+const PAGE: &str = "http://localhost/login?next=home";
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Login {
+    username: String,
+}
+
+#[post("/api/login")]
+async fn login(form: Form<Login>) -> Result<String> {
+    if form.username.is_empty() {
+        return Err(anyhow::anyhow!("the username is required").into());
+    }
+    Ok(format!("Welcome back, {}!", form.username))
+}
+
+#[post("/api/repeat")]
+async fn repeat(form: Form<Login>) -> Result<String> {
+    Ok(form.username.repeat(4096))
+}
+
+fn app() -> Element {
+    let action = use_action(login);
+
+    rsx! {
+        ActionForm { route: "/api/login", action,
+            input { name: "username" }
+        }
+        match action.value() {
+            Some(Ok(message)) => rsx! { p { "{message}" } },
+            Some(Err(err)) => rsx! { p { "Login failed: {err}" } },
+            None => rsx! {},
+        }
+    }
+}
+
+fn router() -> Router {
+    Router::new().serve_api_application(ServeConfig::new(), app)
+}
+
+/// Submit a form to a server function the way a browser without JavaScript does
+async fn submit(uri: &str, content_type: &str, body: impl Into<Body>) -> axum::response::Response {
+    let request = Request::post(uri)
+        .header(CONTENT_TYPE, content_type)
+        .header(ACCEPT, "text/html")
+        .header(REFERER, PAGE)
+        .body(body.into())
+        .unwrap();
+    router().oneshot(request).await.unwrap()
+}
+
+/// The `name=value` part of the flashed outcome cookie
+fn outcome_cookie(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get_all(SET_COOKIE)
+        .iter()
+        .filter_map(|cookie| cookie.to_str().ok())
+        .find(|cookie| cookie.starts_with("dx-action="))
+        .map(|cookie| cookie.split(';').next().unwrap().to_string())
+}
+
+/// The fields of the flashed outcome cookie
+fn outcome(headers: &HeaderMap) -> Vec<(String, String)> {
+    let cookie = outcome_cookie(headers).expect("the outcome should be flashed in a cookie");
+    let value = cookie.strip_prefix("dx-action=").unwrap();
+    url::form_urlencoded::parse(value.as_bytes())
+        .into_owned()
+        .collect()
+}
+
+/// Render the page the form redirected back to with the flashed cookie
+async fn render_page(cookie: &str) -> (HeaderMap, String) {
+    let request = Request::get("/login?next=home")
+        .header(ACCEPT, "text/html")
+        .header(COOKIE, cookie)
+        .body(Body::empty())
+        .unwrap();
+    let response = router().oneshot(request).await.unwrap();
+    let headers = response.headers().clone();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (headers, String::from_utf8(body.to_vec()).unwrap())
+}
+
+fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[tokio::test]
+async fn urlencoded_posts_redirect_with_the_result() {
+    let response = submit(
+        "/api/login?dx-action",
+        "application/x-www-form-urlencoded",
+        "username=alice",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FOUND);
+    assert_eq!(response.headers()[LOCATION], PAGE);
+    assert_eq!(
+        outcome(response.headers()),
+        pairs(&[
+            ("route", "/api/login"),
+            ("result", "\"Welcome back, alice!\"")
+        ])
+    );
+
+    // The page renders the result and clears the cookie so it is only shown once
+    let cookie = outcome_cookie(response.headers()).unwrap();
+    let (headers, html) = render_page(&cookie).await;
+    assert!(html.contains("Welcome back, alice!"), "{html}");
+    assert!(html.contains(r#"action="/api/login?dx-action""#), "{html}");
+    assert_eq!(headers[SET_COOKIE], "dx-action=; Path=/; Max-Age=0");
+}
+
+#[tokio::test]
+async fn multipart_posts_redirect_with_the_result() {
+    let body = "--boundary\r\n\
+        Content-Disposition: form-data; name=\"username\"\r\n\r\n\
+        bob\r\n\
+        --boundary\r\n\
+        Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"\r\n\
+        Content-Type: image/png\r\n\r\n\
+        not a png\r\n\
+        --boundary--\r\n";
+    let response = submit(
+        "/api/login?dx-action",
+        "multipart/form-data; boundary=boundary",
+        body,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FOUND);
+    assert_eq!(
+        outcome(response.headers()),
+        pairs(&[
+            ("route", "/api/login"),
+            ("result", "\"Welcome back, bob!\"")
+        ])
+    );
+}
+
+#[tokio::test]
+async fn errors_redirect_with_the_message() {
+    let response = submit(
+        "/api/login?dx-action",
+        "application/x-www-form-urlencoded",
+        "username=",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FOUND);
+    assert_eq!(response.headers()[LOCATION], PAGE);
+    let outcome = outcome(response.headers());
+    assert_eq!(outcome[0], ("route".into(), "/api/login".into()));
+    assert_eq!(outcome[1].0, "error");
+    assert!(
+        outcome[1].1.contains("the username is required"),
+        "{outcome:?}"
+    );
+
+    let cookie = outcome_cookie(response.headers()).unwrap();
+    let (_, html) = render_page(&cookie).await;
+    assert!(html.contains("Login failed: "), "{html}");
+    assert!(html.contains("the username is required"), "{html}");
+}
+
+#[tokio::test]
+async fn other_form_posts_are_only_redirected_on_success() {
+    let response = submit(
+        "/api/login",
+        "application/x-www-form-urlencoded",
+        "username=alice",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FOUND);
+    assert_eq!(response.headers()[LOCATION], PAGE);
+    assert_eq!(outcome_cookie(response.headers()), None);
+
+    let response = submit(
+        "/api/login",
+        "application/x-www-form-urlencoded",
+        "username=",
+    )
+    .await;
+    assert!(response.status().is_server_error());
+    assert_eq!(response.headers().get(LOCATION), None);
+    assert_eq!(outcome_cookie(response.headers()), None);
+}
+
+#[tokio::test]
+async fn oversized_bodies_are_left_out_of_the_cookie() {
+    // A request body over the body limit is rejected before the server function runs
+    let body = format!("username={}", "a".repeat(3 * 1024 * 1024));
+    let response = submit(
+        "/api/login?dx-action",
+        "application/x-www-form-urlencoded",
+        body,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FOUND);
+    assert_eq!(outcome(response.headers())[1].0, "error");
+
+    // A result that doesn't fit in a cookie is dropped, but the form is still redirected
+    let response = submit(
+        "/api/repeat?dx-action",
+        "application/x-www-form-urlencoded",
+        "username=abc",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FOUND);
+    assert_eq!(
+        outcome(response.headers()),
+        pairs(&[("route", "/api/repeat")])
+    );
+}
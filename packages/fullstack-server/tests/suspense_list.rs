@@ -0,0 +1,130 @@
+#![cfg(feature = "server")]
+
+use axum::{Router, body::Body};
+use dioxus::prelude::*;
+use dioxus_server::{DioxusRouterExt, ServeConfig};
+use http::{Request, header::ACCEPT};
+use http_body_util::BodyExt;
+use std::time::Duration;
+use tower::ServiceExt;
+
+#[component]
+fn Post(id: usize, delay: u64) -> Element {
+    let loaded = use_resource(move || async move {
+        tokio::time::sleep(Duration::from_millis(delay)).await;
+        id
+    })
+    .suspend()?;
+
+    rsx! { "Post {loaded}" }
+}
+
+/// A list of posts that load after the given delays
+fn posts(reveal_order: SuspenseRevealOrder, delays: [u64; 3]) -> Element {
+    // Start streaming as soon as the first render is done instead of waiting for every post
+    use_hook(dioxus_fullstack_core::commit_initial_chunk);
+
+    rsx! {
+        SuspenseList { reveal_order,
+            for (id, delay) in delays.into_iter().enumerate() {
+                SuspenseBoundary {
+                    key: "{id}",
+                    fallback: move |_| rsx! { "Loading post {id}" },
+                    Post { id, delay }
+                }
+            }
+        }
+    }
+}
+
+/// The index of the chunk with the initial frame. The head of the document is streamed before it
+const INITIAL_FRAME: usize = 1;
+
+/// Render the app with out of order streaming and collect the chunks of the response
+async fn stream_chunks(app: fn() -> Element) -> Vec<String> {
+    let router = Router::new()
+        .serve_api_application(ServeConfig::new().enable_out_of_order_streaming(), app);
+    let request = Request::get("/")
+        .header(ACCEPT, "text/html")
+        .body(Body::empty())
+        .unwrap();
+    let mut body = router.oneshot(request).await.unwrap().into_body();
+
+    let mut chunks = Vec::new();
+    while let Some(frame) = body.frame().await {
+        if let Ok(data) = frame.unwrap().into_data() {
+            chunks.push(String::from_utf8(data.to_vec()).unwrap());
+        }
+    }
+    chunks
+}
+
+/// The index of the chunk that streams in each post
+fn reveal_chunks(chunks: &[String]) -> [usize; 3] {
+    std::array::from_fn(|id| {
+        chunks
+            .iter()
+            .position(|chunk| chunk.contains(&format!("Post {id}")))
+            .unwrap_or_else(|| panic!("post {id} was never streamed: {chunks:#?}"))
+    })
+}
+
+/// The posts in the order they appear in the resolved html that is streamed after the initial frame
+fn reveal_order(chunks: &[String]) -> Vec<usize> {
+    let resolved = chunks[INITIAL_FRAME + 1..].concat();
+    let mut ids = vec![0, 1, 2];
+    ids.sort_by_key(|id| resolved.find(&format!("Post {id}")));
+    ids
+}
+
+#[tokio::test]
+async fn forwards_streams_posts_top_to_bottom() {
+    let chunks = stream_chunks(|| posts(SuspenseRevealOrder::Forwards, [60, 30, 0])).await;
+    assert!(chunks[INITIAL_FRAME].contains("Loading post 0"));
+    assert!(
+        !chunks[INITIAL_FRAME].contains("Post 2"),
+        "post 2 is held back by post 0"
+    );
+
+    // The later posts are held back until the first post resolves, then all of them stream together
+    let [first, second, third] = reveal_chunks(&chunks);
+    assert!(first > INITIAL_FRAME);
+    assert_eq!(first, second);
+    assert_eq!(second, third);
+    assert_eq!(reveal_order(&chunks), [0, 1, 2]);
+}
+
+#[tokio::test]
+async fn forwards_streams_posts_as_soon_as_the_posts_above_resolve() {
+    let chunks = stream_chunks(|| posts(SuspenseRevealOrder::Forwards, [0, 30, 90])).await;
+    let [first, second, third] = reveal_chunks(&chunks);
+    assert!(first <= second && second < third, "{chunks:#?}");
+    assert_eq!(reveal_order(&chunks), [0, 1, 2]);
+}
+
+#[tokio::test]
+async fn backwards_streams_posts_bottom_to_top() {
+    let chunks = stream_chunks(|| posts(SuspenseRevealOrder::Backwards, [0, 30, 60])).await;
+    assert!(
+        !chunks[INITIAL_FRAME].contains("Post 0"),
+        "post 0 is held back by post 2"
+    );
+
+    let [first, second, third] = reveal_chunks(&chunks);
+    assert_eq!(first, second);
+    assert_eq!(second, third);
+    assert_eq!(reveal_order(&chunks), [2, 1, 0]);
+}
+
+#[tokio::test]
+async fn together_streams_every_post_in_one_chunk() {
+    let chunks = stream_chunks(|| posts(SuspenseRevealOrder::Together, [0, 60, 30])).await;
+    for id in 0..3 {
+        assert!(chunks[INITIAL_FRAME].contains(&format!("Loading post {id}")));
+    }
+
+    let [first, second, third] = reveal_chunks(&chunks);
+    assert!(first > INITIAL_FRAME);
+    assert_eq!(first, second);
+    assert_eq!(second, third);
+}
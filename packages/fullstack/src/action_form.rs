@@ -0,0 +1,191 @@
+use crate::{
+    FORM_ACTION_COOKIE, FORM_ACTION_ERROR_FIELD, FORM_ACTION_QUERY, FORM_ACTION_RESULT_FIELD,
+    FORM_ACTION_ROUTE_FIELD, Form, FullstackContext, use_server_cached,
+};
+use dioxus_core::{Attribute, CapturedError, Element, use_hook};
+use dioxus_core_macro::{Props, rsx};
+use dioxus_hooks::Action;
+use dioxus_html::{self as dioxus_elements, FormEvent};
+use serde::{Serialize, de::DeserializeOwned};
+
+/// The properties for an [`ActionForm`].
+#[derive(Props)]
+pub struct ActionFormProps<T: 'static, O: 'static> {
+    /// The route of the server function the form posts to, like `"/api/login"`.
+    ///
+    /// The server function must accept `POST` requests and take a single [`Form<T>`] argument.
+    pub route: String,
+
+    /// The action that calls the server function once the page is hydrated.
+    pub action: Action<(Form<T>,), O>,
+
+    /// Additional attributes to pass to the `form` element.
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+
+    /// The children to render within the form.
+    pub children: Element,
+}
+
+impl<T, O> Clone for ActionFormProps<T, O> {
+    fn clone(&self) -> Self {
+        Self {
+            route: self.route.clone(),
+            action: self.action,
+            attributes: self.attributes.clone(),
+            children: self.children.clone(),
+        }
+    }
+}
+
+impl<T, O> PartialEq for ActionFormProps<T, O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.route == other.route
+            && self.action == other.action
+            && self.attributes == other.attributes
+            && self.children == other.children
+    }
+}
+
+/// A form that submits to a server function, with or without JavaScript.
+///
+/// Before the page is hydrated, the browser posts the form to `route` natively. The server runs the server function
+/// and redirects back to the page with the outcome in a short lived cookie, which is restored into `action` while the
+/// page renders. Once hydrated, submitting the form calls `action` instead so the page is not reloaded.
+///
+/// Either way, read the outcome with [`Action::value`] and [`Action::pending`].
+///
+/// # Example
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus::fullstack::{ActionForm, Form};
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Login {
+///     username: String,
+///     password: String,
+/// }
+///
+/// #[post("/api/login")]
+/// async fn login(form: Form<Login>) -> Result<String> {
+///     Ok(format!("Welcome back, {}!", form.username))
+/// }
+///
+/// fn app() -> Element {
+///     let action = use_action(login);
+///
+///     rsx! {
+///         ActionForm {
+///             route: "/api/login",
+///             action,
+///             input { name: "username" }
+///             input { name: "password", r#type: "password" }
+///             button { "Log in" }
+///         }
+///         match action.value() {
+///             Some(Ok(message)) => rsx! { "{message}" },
+///             Some(Err(err)) => rsx! { "Login failed: {err}" },
+///             None => rsx! {},
+///         }
+///     }
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn ActionForm<T, O>(props: ActionFormProps<T, O>) -> Element
+where
+    T: Serialize + DeserializeOwned + 'static,
+    O: DeserializeOwned + 'static,
+{
+    let ActionFormProps {
+        route,
+        mut action,
+        attributes,
+        children,
+    } = props;
+
+    let outcome = use_server_cached({
+        let route = route.clone();
+        move || form_action_outcome(&route)
+    });
+    use_hook(move || {
+        if let Some(result) = outcome.and_then(decode_outcome::<O>) {
+            action.set_result(result);
+        }
+    });
+
+    // Mark native submissions so the server redirects back to this page with the outcome
+    let separator = if route.contains('?') { '&' } else { '?' };
+
+    rsx! {
+        form {
+            action: "{route}{separator}{FORM_ACTION_QUERY}",
+            method: "post",
+            onsubmit: move |event: FormEvent| {
+                event.prevent_default();
+                match event.parsed_values::<T>() {
+                    Ok(values) => {
+                        action.call(Form(values));
+                    }
+                    Err(err) => action.set_result(Err(CapturedError::from_display(err))),
+                }
+            },
+            ..attributes,
+            {children}
+        }
+    }
+}
+
+/// Read the outcome of a native submission to `route` from the cookie of the current request, and clear the cookie
+/// so the outcome is only shown once.
+///
+/// Returns the raw body of a successful response (or `None` if it was too large to fit in the cookie), or the error
+/// message of a failed response.
+fn form_action_outcome(route: &str) -> Option<Result<Option<String>, String>> {
+    let context = FullstackContext::current()?;
+    let cookie = context
+        .parts_mut()
+        .headers
+        .get_all(http::header::COOKIE)
+        .iter()
+        .filter_map(|header| header.to_str().ok())
+        .flat_map(|header| header.split(';'))
+        .find_map(|cookie| {
+            let (name, value) = cookie.trim().split_once('=')?;
+            (name == FORM_ACTION_COOKIE).then(|| value.to_string())
+        })?;
+    let fields: Vec<(String, String)> = form_urlencoded::parse(cookie.as_bytes())
+        .into_owned()
+        .collect();
+    let get = |key: &str| {
+        fields
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.clone())
+    };
+
+    if get(FORM_ACTION_ROUTE_FIELD)? != route {
+        return None;
+    }
+    if let Ok(clear) =
+        http::HeaderValue::from_str(&format!("{FORM_ACTION_COOKIE}=; Path=/; Max-Age=0"))
+    {
+        context.add_response_header(http::header::SET_COOKIE, clear);
+    }
+
+    match get(FORM_ACTION_ERROR_FIELD) {
+        Some(message) => Some(Err(message)),
+        None => Some(Ok(get(FORM_ACTION_RESULT_FIELD))),
+    }
+}
+
+fn decode_outcome<O: DeserializeOwned>(
+    outcome: Result<Option<String>, String>,
+) -> Option<Result<O, CapturedError>> {
+    match outcome {
+        Ok(Some(body)) => {
+            let body = if body.is_empty() { "null" } else { &body };
+            Some(serde_json::from_str(body).map_err(CapturedError::from_display))
+        }
+        Ok(None) => None,
+        Err(message) => Some(Err(CapturedError::from_display(message))),
+    }
+}
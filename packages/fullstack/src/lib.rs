@@ -51,6 +51,9 @@ pub use http::{HeaderMap, HeaderValue, Method};
 mod client;
 pub use client::*;
 
+mod action_form;
+pub use action_form::*;
+
 pub use axum::extract::Json;
 pub use axum::response::{NoContent, Redirect};
 
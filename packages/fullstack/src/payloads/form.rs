@@ -1,6 +1,18 @@
 use super::*;
+use axum::extract::Request;
+use axum::response::Response;
+use std::ops::{Deref, DerefMut};
 
-pub use axum::extract::Form;
+/// An extractor that deserializes a form body into the given type `T`.
+///
+/// On the server, this accepts both `application/x-www-form-urlencoded` and `multipart/form-data` bodies, so a
+/// server function that takes a `Form` can be the `action` of a plain html form no matter its `enctype`. The text
+/// fields of multipart bodies are deserialized the same way as urlencoded bodies, and files are skipped. Use
+/// [`MultipartFormData`] to receive files.
+///
+/// On the client, the value is sent as an urlencoded body.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Form<T>(pub T);
 
 impl<T> IntoRequest for Form<T>
 where
@@ -10,3 +22,82 @@ where
         async move { req.send_form(&self.0).await }
     }
 }
+
+impl<T, S> FromRequest<S> for Form<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        #[cfg(feature = "server")]
+        if req
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("multipart/form-data"))
+        {
+            return from_multipart(req, state).await;
+        }
+
+        let axum::extract::Form(value) = axum::extract::Form::<T>::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        Ok(Self(value))
+    }
+}
+
+/// Collect the text fields of a multipart body and deserialize them like an urlencoded body
+#[cfg(feature = "server")]
+async fn from_multipart<T, S>(req: Request, state: &S) -> Result<Form<T>, Response>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    let mut multipart = axum::extract::Multipart::from_request(req, state)
+        .await
+        .map_err(IntoResponse::into_response)?;
+
+    let mut fields = Vec::new();
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(IntoResponse::into_response)?
+    {
+        let Some(name) = field.name().map(str::to_string) else {
+            continue;
+        };
+        if field.file_name().is_some() {
+            continue;
+        }
+        let value = field.text().await.map_err(IntoResponse::into_response)?;
+        fields.push((name, value));
+    }
+
+    let body = serde_urlencoded::to_string(&fields)
+        .map_err(|err| ServerFnError::Deserialization(err.to_string()).into_response())?;
+    serde_urlencoded::from_str(&body)
+        .map(Form)
+        .map_err(|err| ServerFnError::Deserialization(err.to_string()).into_response())
+}
+
+impl<T: Serialize> IntoResponse for Form<T> {
+    fn into_response(self) -> Response {
+        axum::extract::Form(self.0).into_response()
+    }
+}
+
+impl<T> Deref for Form<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Form<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -1185,6 +1185,329 @@ impl From<u16> for CloseCode {
     }
 }
 
+/// A message used to keep a [`Store`](dioxus_stores::Store) mirrored between a server and its clients.
+///
+/// The server sends a [`StoreSyncMessage::Snapshot`] when a client connects, and both sides send
+/// [`StoreSyncMessage::Patch`]es as the store changes. Use [`UseWebsocket::sync_store`] on the client and
+/// [`StoreSyncHub`] on the server to drive the connection.
+///
+/// ```rust, ignore
+/// #[derive(Store, Serialize, Deserialize, Default)]
+/// struct Board {
+///     cards: Vec<String>,
+/// }
+///
+/// #[cfg(feature = "server")]
+/// static BOARD: std::sync::LazyLock<StoreSyncHub<Board>> =
+///     std::sync::LazyLock::new(|| StoreSyncHub::new(Board::default()).unwrap());
+///
+/// #[get("/api/board")]
+/// async fn board(options: WebSocketOptions) -> Result<Websocket<StoreSyncMessage, StoreSyncMessage>> {
+///     Ok(options.on_upgrade(|socket| async move {
+///         _ = BOARD.serve(socket).await;
+///     }))
+/// }
+///
+/// fn app() -> Element {
+///     let board = use_store(Board::default);
+///     let mut socket = use_websocket(|| board(WebSocketOptions::new()));
+///     use_future(move || async move {
+///         _ = socket.sync_store(board).await;
+///     });
+///
+///     rsx! {
+///         for card in board.cards().iter() {
+///             p { "{card}" }
+///         }
+///     }
+/// }
+/// ```
+#[cfg(feature = "stores")]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum StoreSyncMessage {
+    /// The full serialized value of the store.
+    Snapshot(serde_json::Value),
+
+    /// Changes made to the store since the last message.
+    Patch(dioxus_stores::StorePatch),
+}
+
+#[cfg(feature = "stores")]
+impl<E: Encoding> UseWebsocket<StoreSyncMessage, StoreSyncMessage, E> {
+    /// Mirror a store with the other end of the websocket.
+    ///
+    /// Snapshots and patches received from the server are applied to the store, only marking the parts of
+    /// the store that changed as dirty. Local writes to the store are recorded as they happen and sent back
+    /// to the server as patches of the paths they changed. This future runs until the connection closes.
+    pub async fn sync_store<T, Lens>(
+        &mut self,
+        store: dioxus_stores::Store<T, Lens>,
+    ) -> Result<(), WebsocketError>
+    where
+        T: Serialize + DeserializeOwned + 'static,
+        Lens: dioxus_signals::Writable<Target = T> + 'static,
+    {
+        use futures::future::Either;
+
+        let mut recorder = dioxus_stores::PatchRecorder::new(store)
+            .map_err(|err| WebsocketError::Serialization(err.into()))?;
+
+        // Wake up whenever any part of the store is written to. Tracking the store doesn't serialize it.
+        let (rc, mut changes) = dioxus_core::ReactiveContext::new();
+        rc.run_in(|| recorder.store().selector().track());
+
+        loop {
+            let mut socket = *self;
+            let message = socket.recv();
+            futures::pin_mut!(message);
+
+            match futures::future::select(changes.next(), message).await {
+                Either::Left((None, _)) => return Ok(()),
+                Either::Left((Some(()), _)) => {
+                    rc.reset_and_run_in(|| recorder.store().selector().track());
+                    // Patches we received from the server are applied without being recorded, so they
+                    // are not sent back
+                    let patch = recorder
+                        .take_patch()
+                        .map_err(|err| WebsocketError::Serialization(err.into()))?;
+                    if !patch.is_empty() {
+                        self.send(StoreSyncMessage::Patch(patch)).await?;
+                    }
+                }
+                Either::Right((message, _)) => match message? {
+                    StoreSyncMessage::Snapshot(snapshot) => {
+                        recorder
+                            .apply_snapshot(snapshot)
+                            .map_err(|err| WebsocketError::Deserialization(err.into()))?;
+                    }
+                    StoreSyncMessage::Patch(patch) => {
+                        recorder
+                            .apply_patch(&patch)
+                            .map_err(|err| WebsocketError::Deserialization(err.into()))?;
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// The server side of a store shared with many clients over websockets.
+///
+/// The hub owns the authoritative value of the store. Every client that connects with [`StoreSyncHub::serve`]
+/// receives a snapshot of the value, and every change made with [`StoreSyncHub::update`] or received from a
+/// client is broadcast to the other clients as a [`StorePatch`](dioxus_stores::StorePatch). Changes made with
+/// [`StoreSyncHub::update`] are recorded from the writes to the store, so only the paths that were written
+/// to are serialized.
+///
+/// Patches from clients are applied in the order they arrive. If a patch no longer applies because another
+/// client changed the same part of the value first, the client that sent it is sent a fresh snapshot instead.
+#[cfg(all(feature = "server", feature = "stores"))]
+pub struct StoreSyncHub<T: 'static> {
+    inner: std::sync::Arc<std::sync::Mutex<StoreSyncHubState<T>>>,
+}
+
+#[cfg(all(feature = "server", feature = "stores"))]
+struct StoreSyncHubState<T: 'static> {
+    recorder:
+        dioxus_stores::PatchRecorder<T, dioxus_signals::CopyValue<T, dioxus_signals::SyncStorage>>,
+    next_client: usize,
+    clients: Vec<(
+        usize,
+        futures_channel::mpsc::UnboundedSender<StoreSyncMessage>,
+    )>,
+    // The owner of the store. This is declared after the recorder so the store outlives it.
+    _owner: dioxus_signals::Owner<dioxus_signals::SyncStorage>,
+}
+
+#[cfg(all(feature = "server", feature = "stores"))]
+impl<T> Clone for StoreSyncHub<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+#[cfg(all(feature = "server", feature = "stores"))]
+impl<T: Serialize + DeserializeOwned + Send + Sync + 'static> StoreSyncHub<T> {
+    /// Create a new hub with an initial value. The hub owns its store, so it can be created outside of a
+    /// component, for example in a static.
+    pub fn new(value: T) -> Result<Self, dioxus_stores::PatchError> {
+        use dioxus_signals::AnyStorage;
+
+        let owner = dioxus_signals::SyncStorage::owner();
+        let store = dioxus_core::with_owner(owner.clone(), || {
+            dioxus_stores::SyncStore::new_maybe_sync(value)
+        });
+        Ok(Self {
+            inner: std::sync::Arc::new(std::sync::Mutex::new(StoreSyncHubState {
+                recorder: dioxus_stores::PatchRecorder::new(store)?,
+                next_client: 0,
+                clients: Vec::new(),
+                _owner: owner,
+            })),
+        })
+    }
+
+    /// Read the current value of the hub.
+    pub fn read<O>(&self, f: impl FnOnce(&T) -> O) -> O {
+        let state = self.lock();
+        f(&state.recorder.store().peek())
+    }
+
+    /// Get the serialized value of the hub.
+    pub fn snapshot(&self) -> serde_json::Value {
+        self.lock().recorder.snapshot().clone()
+    }
+
+    /// Modify the store of the hub and broadcast the changes to every connected client. Only the paths
+    /// written to through the store are sent to the clients.
+    ///
+    /// ```rust, ignore
+    /// BOARD.update(|board| board.cards().push("Review".to_string()))?;
+    /// ```
+    pub fn update<O>(
+        &self,
+        f: impl FnOnce(dioxus_stores::SyncStore<T>) -> O,
+    ) -> Result<O, dioxus_stores::PatchError> {
+        let mut state = self.lock();
+        let output = f(*state.recorder.store());
+        let patch = state.recorder.take_patch()?;
+        state.broadcast(None, patch);
+        Ok(output)
+    }
+
+    /// Apply a patch to the value of the hub and broadcast it to every connected client.
+    pub fn apply_patch(
+        &self,
+        patch: dioxus_stores::StorePatch,
+    ) -> Result<(), dioxus_stores::PatchError> {
+        self.lock().apply_patch(None, patch)
+    }
+
+    /// Serve a client until the connection closes. The client is sent a snapshot of the current value,
+    /// and then patches whenever the value changes.
+    pub async fn serve<E: Encoding>(
+        &self,
+        mut socket: TypedWebsocket<StoreSyncMessage, StoreSyncMessage, E>,
+    ) -> Result<(), WebsocketError> {
+        use futures::future::Either;
+
+        let (tx, mut rx) = futures_channel::mpsc::unbounded();
+        let (id, snapshot) = {
+            let mut state = self.lock();
+            let id = state.next_client;
+            state.next_client += 1;
+            state.clients.push((id, tx));
+            (id, state.recorder.snapshot().clone())
+        };
+
+        let result = async {
+            socket.send(StoreSyncMessage::Snapshot(snapshot)).await?;
+            loop {
+                let next = match futures::future::select(socket.next(), rx.next()).await {
+                    Either::Left((incoming, _)) => Either::Left(incoming),
+                    Either::Right((outgoing, _)) => Either::Right(outgoing),
+                };
+                match next {
+                    Either::Left(None) | Either::Right(None) => return Ok(()),
+                    Either::Left(Some(incoming)) => {
+                        let patch = match incoming? {
+                            StoreSyncMessage::Patch(patch) => patch,
+                            StoreSyncMessage::Snapshot(snapshot) => {
+                                dioxus_stores::StorePatch::diff(&self.snapshot(), &snapshot)
+                            }
+                        };
+                        let rejected = self.lock().apply_patch(Some(id), patch).is_err();
+                        if rejected {
+                            socket
+                                .send(StoreSyncMessage::Snapshot(self.snapshot()))
+                                .await?;
+                        }
+                    }
+                    Either::Right(Some(outgoing)) => socket.send(outgoing).await?,
+                }
+            }
+        }
+        .await;
+
+        self.lock().clients.retain(|(client, _)| *client != id);
+        result
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, StoreSyncHubState<T>> {
+        self.inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+#[cfg(all(feature = "server", feature = "stores"))]
+impl<T: Serialize + DeserializeOwned + Send + Sync + 'static> StoreSyncHubState<T> {
+    fn apply_patch(
+        &mut self,
+        from: Option<usize>,
+        patch: dioxus_stores::StorePatch,
+    ) -> Result<(), dioxus_stores::PatchError> {
+        self.recorder.apply_patch(&patch)?;
+        self.broadcast(from, patch);
+        Ok(())
+    }
+
+    /// Send a patch to every client except the one that made it.
+    fn broadcast(&mut self, from: Option<usize>, patch: dioxus_stores::StorePatch) {
+        if patch.is_empty() {
+            return;
+        }
+        self.clients.retain(|(client, sender)| {
+            Some(*client) == from
+                || sender
+                    .unbounded_send(StoreSyncMessage::Patch(patch.clone()))
+                    .is_ok()
+        });
+    }
+}
+
+#[cfg(all(test, feature = "server", feature = "stores"))]
+mod store_sync_test {
+    use super::*;
+    use dioxus_stores::{PatchOperation, StorePatch};
+    use serde_json::json;
+
+    #[test]
+    fn hub_broadcasts_the_paths_written_to() {
+        // Hubs are usually created in statics, outside of any runtime
+        static HUB: std::sync::LazyLock<StoreSyncHub<Vec<String>>> =
+            std::sync::LazyLock::new(|| StoreSyncHub::new(vec!["Plan".to_string()]).unwrap());
+
+        let (tx, mut rx) = futures_channel::mpsc::unbounded();
+        HUB.lock().clients.push((0, tx));
+
+        HUB.update(|mut cards| cards.push("Build".to_string()))
+            .unwrap();
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            StoreSyncMessage::Patch(StorePatch::from(vec![PatchOperation::Add {
+                path: "/1".to_string(),
+                value: json!("Build"),
+            }]))
+        );
+
+        // Patches from other clients are applied and forwarded, but not recorded as new writes
+        HUB.apply_patch(StorePatch::from(vec![PatchOperation::Replace {
+            path: "/0".to_string(),
+            value: json!("Design"),
+        }]))
+        .unwrap();
+        assert!(matches!(rx.try_recv().unwrap(), StoreSyncMessage::Patch(_)));
+        HUB.update(|_| {}).unwrap();
+        assert!(rx.try_recv().is_err());
+
+        assert_eq!(HUB.read(|cards| cards.clone()), ["Design", "Build"]);
+        assert_eq!(HUB.snapshot(), json!(["Design", "Build"]));
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 mod native {
     use std::borrow::Cow;
@@ -60,6 +60,39 @@ pub trait History {
         None
     }
 
+    /// Get the position of the current entry in the history stack.
+    ///
+    /// The position grows by one when a new entry is pushed, shrinks when going back and stays the same when the
+    /// entry is replaced. The router compares positions to tell whether the user navigated forward or back. If a
+    /// [`History`] cannot know this, it should return [`None`].
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # #[component]
+    /// # fn Index() -> Element { VNode::empty() }
+    /// # #[component]
+    /// # fn Other() -> Element { VNode::empty() }
+    /// #[derive(Clone, Routable, Debug, PartialEq)]
+    /// enum Route {
+    ///     #[route("/")]
+    ///     Index {},
+    ///     #[route("/other")]
+    ///     Other {},
+    /// }
+    /// let mut history = dioxus::history::MemoryHistory::default();
+    /// assert_eq!(history.current_index(), Some(0));
+    ///
+    /// history.push(Route::Other {}.to_string());
+    /// assert_eq!(history.current_index(), Some(1));
+    ///
+    /// history.go_back();
+    /// assert_eq!(history.current_index(), Some(0));
+    /// ```
+    #[must_use]
+    fn current_index(&self) -> Option<usize> {
+        None
+    }
+
     /// Check whether there is a previous page to navigate back to.
     ///
     /// If a [`History`] cannot know this, it should return [`true`].
@@ -237,6 +270,60 @@ pub trait History {
     /// ```
     fn replace(&self, path: String);
 
+    /// Get the state attached to the current entry.
+    ///
+    /// The state is attached with [`History::push_with_state`] or [`History::replace_with_state`] and stays with the
+    /// entry when going back or forward. If the current entry has no state or a [`History`] cannot store state, it
+    /// should return [`None`].
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # #[component]
+    /// # fn Index() -> Element { VNode::empty() }
+    /// # #[component]
+    /// # fn OtherPage() -> Element { VNode::empty() }
+    /// #[derive(Clone, Routable, Debug, PartialEq)]
+    /// enum Route {
+    ///     #[route("/")]
+    ///     Index {},
+    ///     #[route("/some-other-page")]
+    ///     OtherPage {},
+    /// }
+    /// let mut history = dioxus::history::MemoryHistory::default();
+    /// assert_eq!(history.current_state(), None);
+    ///
+    /// history.push_with_state(Route::OtherPage {}.to_string(), "{\"tab\":2}".to_string());
+    /// assert_eq!(history.current_state().as_deref(), Some("{\"tab\":2}"));
+    ///
+    /// history.go_back();
+    /// assert_eq!(history.current_state(), None);
+    ///
+    /// history.go_forward();
+    /// assert_eq!(history.current_state().as_deref(), Some("{\"tab\":2}"));
+    /// ```
+    #[must_use]
+    fn current_state(&self) -> Option<String> {
+        None
+    }
+
+    /// Go to another page and attach serialized state to the new entry.
+    ///
+    /// This should work like [`History::push`], but [`History::current_state`] should return `state` while the new
+    /// entry is current. If a [`History`] cannot store state, it should push the route without it.
+    #[allow(unused_variables)]
+    fn push_with_state(&self, route: String, state: String) {
+        self.push(route);
+    }
+
+    /// Replace the current page with another one and attach serialized state to it.
+    ///
+    /// This should work like [`History::replace`], but [`History::current_state`] should return `state` afterwards.
+    /// If a [`History`] cannot store state, it should replace the route without it.
+    #[allow(unused_variables)]
+    fn replace_with_state(&self, path: String, state: String) {
+        self.replace(path);
+    }
+
     /// Navigate to an external URL.
     ///
     /// This should navigate to an external URL, which isn't controlled by the router. If a
@@ -255,6 +342,12 @@ pub trait History {
     #[allow(unused_variables)]
     fn updater(&self, callback: Arc<dyn Fn() + Send + Sync>) {}
 
+    /// Stop restoring the scroll position of the page when the user navigates.
+    ///
+    /// The router calls this when it saves and restores scroll positions itself, so only one of them scrolls the
+    /// page. [`History`]s that don't manage the scroll position can ignore it.
+    fn disable_scroll_restoration(&self) {}
+
     /// Whether the router should include the legacy prevent default attribute instead of the new
     /// prevent default method. This should only be used by liveview.
     fn include_prevent_default(&self) -> bool {
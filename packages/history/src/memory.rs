@@ -3,9 +3,16 @@ use std::cell::RefCell;
 use crate::History;
 
 struct MemoryHistoryState {
-    current: String,
-    history: Vec<String>,
-    future: Vec<String>,
+    current: MemoryEntry,
+    history: Vec<MemoryEntry>,
+    future: Vec<MemoryEntry>,
+}
+
+/// A route in the history and the state attached to it
+#[derive(PartialEq)]
+struct MemoryEntry {
+    route: String,
+    state: Option<String>,
 }
 
 /// A [`History`] provider that stores all navigation information in memory.
@@ -44,9 +51,12 @@ impl MemoryHistory {
     pub fn with_initial_path(path: impl ToString) -> Self {
         Self {
             state: MemoryHistoryState{
-                current: path.to_string().parse().unwrap_or_else(|err| {
-                    panic!("index route does not exist:\n{err}\n use MemoryHistory::with_initial_path to set a custom path")
-                }),
+                current: MemoryEntry {
+                    route: path.to_string().parse().unwrap_or_else(|err| {
+                        panic!("index route does not exist:\n{err}\n use MemoryHistory::with_initial_path to set a custom path")
+                    }),
+                    state: None,
+                },
                 history: Vec::new(),
                 future: Vec::new(),
             }.into(),
@@ -67,6 +77,17 @@ impl MemoryHistory {
         self.base_path = Some(prefix.to_string());
         self
     }
+
+    fn push_entry(&self, new: MemoryEntry) {
+        let mut write = self.state.borrow_mut();
+        // don't push the same entry twice
+        if write.current == new {
+            return;
+        }
+        let old = std::mem::replace(&mut write.current, new);
+        write.history.push(old);
+        write.future.clear();
+    }
 }
 
 impl History for MemoryHistory {
@@ -75,7 +96,15 @@ impl History for MemoryHistory {
     }
 
     fn current_route(&self) -> String {
-        self.state.borrow().current.clone()
+        self.state.borrow().current.route.clone()
+    }
+
+    fn current_state(&self) -> Option<String> {
+        self.state.borrow().current.state.clone()
+    }
+
+    fn current_index(&self) -> Option<usize> {
+        Some(self.state.borrow().history.len())
     }
 
     fn can_go_back(&self) -> bool {
@@ -103,18 +132,32 @@ impl History for MemoryHistory {
     }
 
     fn push(&self, new: String) {
-        let mut write = self.state.borrow_mut();
-        // don't push the same route twice
-        if write.current == new {
-            return;
-        }
-        let old = std::mem::replace(&mut write.current, new);
-        write.history.push(old);
-        write.future.clear();
+        self.push_entry(MemoryEntry {
+            route: new,
+            state: None,
+        });
+    }
+
+    fn push_with_state(&self, route: String, state: String) {
+        self.push_entry(MemoryEntry {
+            route,
+            state: Some(state),
+        });
     }
 
     fn replace(&self, path: String) {
         let mut write = self.state.borrow_mut();
-        write.current = path;
+        write.current = MemoryEntry {
+            route: path,
+            state: None,
+        };
+    }
+
+    fn replace_with_state(&self, path: String, state: String) {
+        let mut write = self.state.borrow_mut();
+        write.current = MemoryEntry {
+            route: path,
+            state: Some(state),
+        };
     }
 }
@@ -104,3 +104,21 @@ pub use use_action::*;
 
 mod use_waker;
 pub use use_waker::*;
+
+mod use_deferred_value;
+pub use use_deferred_value::*;
+
+mod sleep;
+pub use sleep::*;
+
+mod use_interval;
+pub use use_interval::*;
+
+mod use_timeout;
+pub use use_timeout::*;
+
+mod use_debounce;
+pub use use_debounce::*;
+
+mod use_throttle;
+pub use use_throttle::*;
@@ -0,0 +1,196 @@
+use std::time::Duration;
+
+/// Wait for a duration without blocking the thread.
+///
+/// This works on every renderer without picking a platform specific sleep:
+/// - On the web, it is backed by `setTimeout`.
+/// - Everywhere else, the timers run on a shared background thread that wakes the task awaiting them, so they work
+///   with any executor. Desktop, native and liveview apps don't need a tokio runtime for timers.
+/// - With the `tokio` feature, non-web platforms use the tokio timer instead. Only enable it if every
+///   [`VirtualDom`](dioxus_core::VirtualDom) runs inside a tokio runtime with the time driver enabled, like desktop
+///   with the default `tokio_runtime` feature, liveview or a fullstack server. Sleeping outside of one panics.
+///
+/// Like any other future, the sleep stops if the task awaiting it is cancelled, which happens automatically when
+/// the scope that spawned the task is dropped.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn app() -> Element {
+///     let mut elapsed = use_signal(|| 0);
+///     use_future(move || async move {
+///         loop {
+///             dioxus_hooks::sleep(Duration::from_secs(1)).await;
+///             elapsed += 1;
+///         }
+///     });
+///     rsx! { "Seconds elapsed: {elapsed}" }
+/// }
+/// ```
+pub async fn sleep(duration: Duration) {
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "tokio"))]
+    tokio::time::sleep(duration).await;
+
+    #[cfg(all(not(target_arch = "wasm32"), not(feature = "tokio")))]
+    timer::Sleep::new(duration).await;
+}
+
+/// An executor independent timer that wakes sleeping tasks from a background thread
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "tokio")))]
+mod timer {
+    use std::{
+        cmp::Reverse,
+        collections::BinaryHeap,
+        future::Future,
+        pin::Pin,
+        sync::{Arc, Condvar, LazyLock, Mutex, PoisonError},
+        task::{Context, Poll, Waker},
+        time::{Duration, Instant},
+    };
+
+    /// The timers waiting to fire, soonest first
+    #[derive(Default)]
+    struct Timers {
+        queue: Mutex<BinaryHeap<Reverse<Entry>>>,
+        changed: Condvar,
+    }
+
+    struct Entry {
+        deadline: Instant,
+        waker: Arc<Mutex<Waker>>,
+    }
+
+    impl PartialEq for Entry {
+        fn eq(&self, other: &Self) -> bool {
+            self.deadline == other.deadline
+        }
+    }
+
+    impl Eq for Entry {}
+
+    impl PartialOrd for Entry {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Entry {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.deadline.cmp(&other.deadline)
+        }
+    }
+
+    static TIMERS: LazyLock<Arc<Timers>> = LazyLock::new(|| {
+        let timers = Arc::new(Timers::default());
+        let thread_timers = timers.clone();
+        std::thread::Builder::new()
+            .name("dioxus-timers".to_string())
+            .spawn(move || thread_timers.run())
+            .expect("failed to spawn the timer thread");
+        timers
+    });
+
+    impl Timers {
+        /// Wake every timer once its deadline passes
+        fn run(&self) {
+            let mut queue = self.queue.lock().unwrap_or_else(PoisonError::into_inner);
+            loop {
+                let now = Instant::now();
+                while let Some(Reverse(entry)) = queue.peek() {
+                    if entry.deadline > now {
+                        break;
+                    }
+                    let Some(Reverse(entry)) = queue.pop() else {
+                        break;
+                    };
+                    entry
+                        .waker
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .wake_by_ref();
+                }
+
+                queue = match queue.peek() {
+                    Some(Reverse(next)) => {
+                        let timeout = next.deadline.saturating_duration_since(now);
+                        self.changed
+                            .wait_timeout(queue, timeout)
+                            .unwrap_or_else(PoisonError::into_inner)
+                            .0
+                    }
+                    None => self
+                        .changed
+                        .wait(queue)
+                        .unwrap_or_else(PoisonError::into_inner),
+                };
+            }
+        }
+    }
+
+    /// A future that finishes once its deadline passes
+    pub(super) struct Sleep {
+        deadline: Instant,
+        // The waker the timer thread wakes, once the sleep is registered
+        waker: Option<Arc<Mutex<Waker>>>,
+    }
+
+    impl Sleep {
+        pub(super) fn new(duration: Duration) -> Self {
+            Self {
+                deadline: Instant::now() + duration,
+                waker: None,
+            }
+        }
+    }
+
+    impl Future for Sleep {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if Instant::now() >= self.deadline {
+                return Poll::Ready(());
+            }
+
+            match &self.waker {
+                // The task may have moved to a different waker since the last poll
+                Some(waker) => waker
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .clone_from(cx.waker()),
+                None => {
+                    let waker = Arc::new(Mutex::new(cx.waker().clone()));
+                    let timers = &*TIMERS;
+                    timers
+                        .queue
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .push(Reverse(Entry {
+                            deadline: self.deadline,
+                            waker: waker.clone(),
+                        }));
+                    timers.changed.notify_one();
+                    self.waker = Some(waker);
+                }
+            }
+
+            Poll::Pending
+        }
+    }
+
+    impl Drop for Sleep {
+        fn drop(&mut self) {
+            // A sleep that is cancelled before the timer thread wakes it leaves the queue right away, so the thread never
+            // wakes the task that owned it and resetting a long timer doesn't grow the queue
+            if let Some(waker) = self.waker.take() {
+                TIMERS
+                    .queue
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .retain(|Reverse(entry)| !Arc::ptr_eq(&entry.waker, &waker));
+            }
+        }
+    }
+}
@@ -206,6 +206,28 @@ impl<I: 'static, O: 'static> Action<I, O> {
         }
         self.state.set(ActionState::Reset);
     }
+
+    /// Cancel any in-flight work and set the result as if a call just completed.
+    ///
+    /// This is useful to restore the result of a call that happened somewhere else, like a form that was
+    /// submitted before the page was hydrated.
+    pub fn set_result(&mut self, result: Result<O, CapturedError>) {
+        if let Some(t) = self.task.take() {
+            t.cancel()
+        }
+        match result {
+            Ok(res) => {
+                self.error.set(None);
+                self.value.set(Some(res));
+                self.state.set(ActionState::Ready);
+            }
+            Err(err) => {
+                self.error.set(Some(err));
+                self.value.set(None);
+                self.state.set(ActionState::Errored);
+            }
+        }
+    }
 }
 
 impl<I, T> std::fmt::Debug for Action<I, T>
@@ -0,0 +1,140 @@
+use crate::{sleep, use_callback, use_effect, use_signal};
+use dioxus_core::{Callback, Task, spawn, use_hook};
+use dioxus_signals::*;
+use std::time::Duration;
+
+/// Create a debounced action. Every call to [`UseDebounce::action`] restarts a timer, and the closure only runs with
+/// the latest value once `delay` passes without another call.
+///
+/// This is useful for expensive work that follows fast input, like searching while the user types. Any pending call
+/// is cancelled automatically when the component is dropped.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn app() -> Element {
+///     let mut query = use_signal(String::new);
+///     let mut search = use_debounce(Duration::from_millis(300), move |text: String| query.set(text));
+///     rsx! {
+///         input { oninput: move |evt| search.action(evt.value()) }
+///         "Searching for: {query}"
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_debounce<T: 'static>(
+    delay: Duration,
+    action: impl FnMut(T) + 'static,
+) -> UseDebounce<T> {
+    let mut pending = use_signal(|| false);
+    let mut task = use_hook(|| CopyValue::new(None::<Task>));
+    let mut current_delay = use_hook(|| CopyValue::new(delay));
+    current_delay.set(delay);
+
+    let action = use_callback(action);
+    let schedule = use_callback(move |value: T| {
+        if let Some(task) = task.take() {
+            task.cancel();
+        }
+        if !*pending.peek() {
+            pending.set(true);
+        }
+        let new_task = spawn(async move {
+            sleep(current_delay.cloned()).await;
+            task.set(None);
+            pending.set(false);
+            action(value);
+        });
+        task.set(Some(new_task));
+    });
+
+    UseDebounce {
+        schedule,
+        task,
+        pending,
+    }
+}
+
+/// A handle to a debounced action created by [`use_debounce`].
+pub struct UseDebounce<T: 'static> {
+    schedule: Callback<T>,
+    task: CopyValue<Option<Task>>,
+    pending: Signal<bool>,
+}
+
+impl<T: 'static> UseDebounce<T> {
+    /// Schedule the action with a value. If the action is already scheduled, the timer restarts and the new value
+    /// replaces the old one.
+    pub fn action(&mut self, value: T) {
+        self.schedule.call(value);
+    }
+
+    /// Cancel the scheduled action if there is one.
+    pub fn cancel(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.cancel();
+        }
+        if *self.pending.peek() {
+            self.pending.set(false);
+        }
+    }
+
+    /// Check if an action is waiting to run. This subscribes to changes in the state of the debounce.
+    pub fn is_pending(&self) -> bool {
+        *self.pending.read()
+    }
+}
+
+impl<T> Clone for UseDebounce<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UseDebounce<T> {}
+
+impl<T> PartialEq for UseDebounce<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.schedule == other.schedule
+    }
+}
+
+/// Debounce any readable value.
+pub trait ReadableDebounceExt: Readable<Target: Clone + PartialEq + 'static> {
+    /// Create a signal that follows this value, but only updates once the value has stopped changing for `delay`.
+    ///
+    /// This is a hook and must follow the rules of hooks.
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use std::time::Duration;
+    /// fn app() -> Element {
+    ///     let mut text = use_signal(String::new);
+    ///     let query = text.debounced(Duration::from_millis(300));
+    ///     rsx! {
+    ///         input { value: "{text}", oninput: move |evt| text.set(evt.value()) }
+    ///         "Searching for: {query}"
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    fn debounced(&self, delay: Duration) -> ReadSignal<Self::Target>
+    where
+        Self: Clone + 'static,
+    {
+        let source = self.clone();
+        let mut output = use_signal(|| self.peek().clone());
+        let mut debounce = use_debounce(delay, move |value| output.set(value));
+        use_effect(move || {
+            let value = source.cloned();
+            if *output.peek() == value {
+                debounce.cancel();
+            } else {
+                debounce.action(value);
+            }
+        });
+        ReadSignal::new(output)
+    }
+}
+
+impl<R> ReadableDebounceExt for R where R: Readable<Target: Clone + PartialEq + 'static> {}
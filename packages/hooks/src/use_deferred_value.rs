@@ -0,0 +1,51 @@
+use dioxus_core::{is_in_transition, needs_update, start_transition, use_hook};
+use dioxus_signals::*;
+
+/// Defer updating part of the UI until urgent work is done.
+///
+/// When the value changes, this hook keeps returning the previous value and reruns the component as a low priority
+/// [transition](start_transition) that returns the new value. Rendering expensive children with the deferred value
+/// keeps urgent updates like typing in an input fast, and if the new value makes a child suspend, the previous UI
+/// stays visible instead of the suspense fallback.
+///
+/// You can compare the deferred value with the latest value to show that the content is stale.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// fn app() -> Element {
+///     let mut query = use_signal(String::new);
+///     let deferred_query = use_deferred_value(query());
+///     let stale = deferred_query != query();
+///     rsx! {
+///         input { value: "{query}", oninput: move |evt| query.set(evt.value()) }
+///         div { opacity: if stale { "0.5" } else { "1" },
+///             SearchResults { query: deferred_query }
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn SearchResults(query: String) -> Element {
+///     rsx! {
+///         for i in (0..10_000).filter(|i| i.to_string().contains(&query)) {
+///             p { "{i}" }
+///         }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_deferred_value<T: Clone + PartialEq + 'static>(value: T) -> T {
+    let mut deferred = use_hook(|| CopyValue::new(value.clone()));
+
+    if *deferred.peek() != value {
+        if is_in_transition() {
+            // This is the low priority rerun. Catch up to the latest value
+            deferred.set(value);
+        } else {
+            // Keep the old value for now and rerun the component in a transition
+            start_transition(needs_update);
+        }
+    }
+
+    deferred.cloned()
+}
@@ -0,0 +1,76 @@
+use crate::{sleep, use_callback, use_signal};
+use dioxus_core::{Callback, Task, spawn, use_hook};
+use dioxus_signals::*;
+use std::time::Duration;
+
+/// Run a closure every `period` while the component is mounted.
+///
+/// The interval starts when the component first renders and is cancelled automatically when the component is
+/// dropped. The closure and period are updated every render: a new period takes effect after the current tick.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn app() -> Element {
+///     let mut count = use_signal(|| 0);
+///     let mut interval = use_interval(Duration::from_secs(1), move || count += 1);
+///     rsx! {
+///         "Count: {count}"
+///         button {
+///             onclick: move |_| if interval.is_running() { interval.cancel() } else { interval.restart() },
+///             "Start/Stop"
+///         }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_interval(period: Duration, mut action: impl FnMut() + 'static) -> UseInterval {
+    let running = use_signal(|| true);
+    let mut current_period = use_hook(|| CopyValue::new(period));
+    current_period.set(period);
+
+    let action = use_callback(move |_| action());
+    let start = use_callback(move |_| {
+        spawn(async move {
+            loop {
+                sleep(current_period.cloned()).await;
+                action(());
+            }
+        })
+    });
+    let task = use_hook(|| CopyValue::new(start(())));
+
+    UseInterval {
+        task,
+        running,
+        start,
+    }
+}
+
+/// A handle to an interval created by [`use_interval`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseInterval {
+    task: CopyValue<Task>,
+    running: Signal<bool>,
+    start: Callback<(), Task>,
+}
+
+impl UseInterval {
+    /// Stop the interval. It can be started again with [`UseInterval::restart`].
+    pub fn cancel(&mut self) {
+        self.task.peek().cancel();
+        self.running.set(false);
+    }
+
+    /// Restart the interval. The next tick happens one full period after this call.
+    pub fn restart(&mut self) {
+        self.task.peek().cancel();
+        self.task.set(self.start.call(()));
+        self.running.set(true);
+    }
+
+    /// Check if the interval is running. This subscribes to changes in the state of the interval.
+    pub fn is_running(&self) -> bool {
+        *self.running.read()
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{sleep, use_callback};
+use dioxus_core::{Callback, Task, spawn, use_hook};
+use dioxus_signals::*;
+use std::time::Duration;
+
+/// Create a throttled action that runs at most once every `interval`.
+///
+/// The first call runs immediately. Calls during the following `interval` are collapsed into one trailing call with
+/// the latest value that runs when the interval ends. Any trailing call is cancelled automatically when the component
+/// is dropped.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn app() -> Element {
+///     let mut position = use_signal(|| (0.0, 0.0));
+///     let mut track = use_throttle(Duration::from_millis(100), move |point: (f64, f64)| position.set(point));
+///     rsx! {
+///         div {
+///             onmousemove: move |evt| {
+///                 let point = evt.client_coordinates();
+///                 track.action((point.x, point.y));
+///             },
+///             "Mouse at {position:?}"
+///         }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_throttle<T: 'static>(
+    interval: Duration,
+    action: impl FnMut(T) + 'static,
+) -> UseThrottle<T> {
+    let mut window = use_hook(|| CopyValue::new(None::<Task>));
+    let mut trailing = use_hook(|| CopyValue::new(None::<T>));
+    let mut current_interval = use_hook(|| CopyValue::new(interval));
+    current_interval.set(interval);
+
+    let action = use_callback(action);
+    let schedule = use_callback(move |value: T| {
+        // Inside the window, only keep the latest value for the trailing call
+        if window.peek().is_some() {
+            trailing.set(Some(value));
+            return;
+        }
+
+        action(value);
+        let new_task = spawn(async move {
+            loop {
+                sleep(current_interval.cloned()).await;
+                // Each trailing call opens a new window
+                match trailing.take() {
+                    Some(value) => action(value),
+                    None => break,
+                }
+            }
+            window.set(None);
+        });
+        window.set(Some(new_task));
+    });
+
+    UseThrottle {
+        schedule,
+        window,
+        trailing,
+    }
+}
+
+/// A handle to a throttled action created by [`use_throttle`].
+pub struct UseThrottle<T: 'static> {
+    schedule: Callback<T>,
+    window: CopyValue<Option<Task>>,
+    trailing: CopyValue<Option<T>>,
+}
+
+impl<T: 'static> UseThrottle<T> {
+    /// Run the action with a value, or save the value for the end of the current interval if the action ran recently.
+    pub fn action(&mut self, value: T) {
+        self.schedule.call(value);
+    }
+
+    /// Drop any trailing call and reset the interval, so the next call runs immediately.
+    pub fn cancel(&mut self) {
+        if let Some(task) = self.window.take() {
+            task.cancel();
+        }
+        self.trailing.set(None);
+    }
+}
+
+impl<T> Clone for UseThrottle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UseThrottle<T> {}
+
+impl<T> PartialEq for UseThrottle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.schedule == other.schedule
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{sleep, use_callback, use_signal};
+use dioxus_core::{Callback, Task, spawn, use_hook};
+use dioxus_signals::*;
+use std::time::Duration;
+
+/// Run a closure once after `delay` has passed since the component first rendered.
+///
+/// The timeout is cancelled automatically if the component is dropped before it fires. Use
+/// [`UseTimeout::restart`] to schedule it again.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn app() -> Element {
+///     let mut visible = use_signal(|| true);
+///     let mut timeout = use_timeout(Duration::from_secs(3), move || visible.set(false));
+///     rsx! {
+///         if visible() {
+///             div { class: "toast", "Saved!" }
+///         }
+///         button { onclick: move |_| { visible.set(true); timeout.restart(); }, "Save again" }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_timeout(delay: Duration, mut action: impl FnMut() + 'static) -> UseTimeout {
+    let mut pending = use_signal(|| true);
+    let mut current_delay = use_hook(|| CopyValue::new(delay));
+    current_delay.set(delay);
+
+    let action = use_callback(move |_| action());
+    let start = use_callback(move |_| {
+        spawn(async move {
+            sleep(current_delay.cloned()).await;
+            pending.set(false);
+            action(());
+        })
+    });
+    let task = use_hook(|| CopyValue::new(start(())));
+
+    UseTimeout {
+        task,
+        pending,
+        start,
+    }
+}
+
+/// A handle to a timeout created by [`use_timeout`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseTimeout {
+    task: CopyValue<Task>,
+    pending: Signal<bool>,
+    start: Callback<(), Task>,
+}
+
+impl UseTimeout {
+    /// Cancel the timeout if it hasn't fired yet.
+    pub fn cancel(&mut self) {
+        self.task.peek().cancel();
+        if *self.pending.peek() {
+            self.pending.set(false);
+        }
+    }
+
+    /// Cancel the timeout if it is pending and schedule it again, `delay` from now.
+    pub fn restart(&mut self) {
+        self.task.peek().cancel();
+        self.task.set(self.start.call(()));
+        self.pending.set(true);
+    }
+
+    /// Check if the timeout is still waiting to fire. This subscribes to changes in the state of the timeout.
+    pub fn is_pending(&self) -> bool {
+        *self.pending.read()
+    }
+}
@@ -0,0 +1,270 @@
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+
+type Log = Rc<RefCell<Vec<i32>>>;
+
+/// Run the dom and rerender whenever there is work until the duration has passed
+async fn run_for(dom: &mut VirtualDom, duration: Duration) {
+    let _ = tokio::time::timeout(duration, async {
+        loop {
+            dom.wait_for_work().await;
+            dom.render_immediate(&mut NoOpMutations);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn interval_runs_until_cancelled() {
+    let log = Log::default();
+    let mut dom = VirtualDom::new_with_props(
+        |log: Log| {
+            let mut ticks = use_signal(|| 0);
+            let mut interval = use_interval(Duration::from_millis(10), move || ticks += 1);
+            log.borrow_mut().push(ticks());
+            if ticks() == 3 && interval.is_running() {
+                interval.cancel();
+            }
+            rsx! {}
+        },
+        log.clone(),
+    );
+    dom.rebuild_in_place();
+    run_for(&mut dom, Duration::from_millis(150)).await;
+
+    assert_eq!(log.borrow().last(), Some(&3));
+    assert!(!log.borrow().contains(&4));
+}
+
+#[tokio::test]
+async fn interval_is_cancelled_when_the_scope_drops() {
+    let ticks = Rc::new(RefCell::new(0));
+
+    #[component]
+    fn ticking_child(ticks: Rc<RefCell<i32>>) -> Element {
+        use_interval(Duration::from_millis(10), move || *ticks.borrow_mut() += 1);
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new_with_props(
+        |ticks: Rc<RefCell<i32>>| {
+            let mut show = use_signal(|| true);
+            use_timeout(Duration::from_millis(35), move || show.set(false));
+            rsx! {
+                if show() {
+                    ticking_child { ticks: ticks.clone() }
+                }
+            }
+        },
+        ticks.clone(),
+    );
+    dom.rebuild_in_place();
+    run_for(&mut dom, Duration::from_millis(60)).await;
+    let after_drop = *ticks.borrow();
+    assert!(after_drop > 0);
+    run_for(&mut dom, Duration::from_millis(60)).await;
+    assert_eq!(*ticks.borrow(), after_drop);
+}
+
+#[tokio::test]
+async fn timeout_fires_once_and_can_restart() {
+    let log = Log::default();
+    let mut dom = VirtualDom::new_with_props(
+        |log: Log| {
+            let mut fired = use_signal(|| 0);
+            let mut timeout = use_timeout(Duration::from_millis(10), move || fired += 1);
+            log.borrow_mut().push(fired());
+            if fired() == 1 && !timeout.is_pending() {
+                timeout.restart();
+            }
+            rsx! {}
+        },
+        log.clone(),
+    );
+    dom.rebuild_in_place();
+    run_for(&mut dom, Duration::from_millis(100)).await;
+
+    assert_eq!(log.borrow().last(), Some(&2));
+    assert!(!log.borrow().contains(&3));
+}
+
+#[tokio::test]
+async fn debounce_runs_with_the_latest_value() {
+    let log = Log::default();
+    let mut dom = VirtualDom::new_with_props(
+        |log: Log| {
+            let mut debounce = use_debounce(Duration::from_millis(30), move |value| {
+                log.borrow_mut().push(value)
+            });
+            use_hook(|| {
+                spawn(async move {
+                    for value in 1..=3 {
+                        debounce.action(value);
+                        sleep(Duration::from_millis(5)).await;
+                    }
+                    sleep(Duration::from_millis(60)).await;
+                    debounce.action(4);
+                    debounce.action(5);
+                    debounce.cancel();
+                    debounce.action(6);
+                })
+            });
+            rsx! {}
+        },
+        log.clone(),
+    );
+    dom.rebuild_in_place();
+    run_for(&mut dom, Duration::from_millis(150)).await;
+
+    assert_eq!(*log.borrow(), vec![3, 6]);
+}
+
+#[tokio::test]
+async fn throttle_runs_leading_and_trailing_calls() {
+    let log = Log::default();
+    let mut dom = VirtualDom::new_with_props(
+        |log: Log| {
+            let mut throttle = use_throttle(Duration::from_millis(50), move |value| {
+                log.borrow_mut().push(value)
+            });
+            use_hook(|| {
+                spawn(async move {
+                    for value in 1..=3 {
+                        throttle.action(value);
+                        sleep(Duration::from_millis(5)).await;
+                    }
+                    sleep(Duration::from_millis(150)).await;
+                    throttle.action(4);
+                })
+            });
+            rsx! {}
+        },
+        log.clone(),
+    );
+    dom.rebuild_in_place();
+    run_for(&mut dom, Duration::from_millis(250)).await;
+
+    assert_eq!(*log.borrow(), vec![1, 3, 4]);
+}
+
+#[tokio::test]
+async fn debounced_signals_settle_on_the_last_value() {
+    let log = Log::default();
+    let mut dom = VirtualDom::new_with_props(
+        |log: Log| {
+            let mut value = use_signal(|| 0);
+            let debounced = value.debounced(Duration::from_millis(30));
+            log.borrow_mut().push(debounced());
+            use_hook(|| {
+                spawn(async move {
+                    for _ in 0..3 {
+                        value += 1;
+                        sleep(Duration::from_millis(5)).await;
+                    }
+                })
+            });
+            rsx! {}
+        },
+        log.clone(),
+    );
+    dom.rebuild_in_place();
+    run_for(&mut dom, Duration::from_millis(100)).await;
+
+    let log = log.borrow();
+    assert_eq!(log.first(), Some(&0));
+    assert_eq!(log.last(), Some(&3));
+    assert!(!log.contains(&1) && !log.contains(&2));
+}
+
+/// Drive a future on the current thread without a tokio runtime, giving up after a second
+#[cfg(not(feature = "tokio"))]
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    use std::task::{Context, Poll, Wake, Waker};
+
+    struct Unpark(std::thread::Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: std::sync::Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(std::sync::Arc::new(Unpark(std::thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    let deadline = std::time::Instant::now() + Duration::from_secs(1);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => {
+                let now = std::time::Instant::now();
+                assert!(now < deadline, "the future was never woken");
+                std::thread::park_timeout(deadline - now);
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "tokio"))]
+#[test]
+fn timers_work_without_a_tokio_runtime() {
+    let fired = Rc::new(RefCell::new(Vec::new()));
+    let mut dom = VirtualDom::new_with_props(
+        |fired: Rc<RefCell<Vec<std::time::Instant>>>| {
+            use_timeout(Duration::from_millis(20), move || {
+                fired.borrow_mut().push(std::time::Instant::now())
+            });
+            rsx! {}
+        },
+        fired.clone(),
+    );
+
+    let start = std::time::Instant::now();
+    dom.rebuild_in_place();
+    // The timeout runs while the dom waits for work, so stop waiting as soon as it fires
+    block_on(std::future::poll_fn(|cx| {
+        if !fired.borrow().is_empty() {
+            return std::task::Poll::Ready(());
+        }
+        let _ = std::pin::pin!(dom.wait_for_work()).poll(cx);
+        std::task::Poll::Pending
+    }));
+
+    assert_eq!(fired.borrow().len(), 1);
+    assert!(fired.borrow()[0] - start >= Duration::from_millis(20));
+}
+
+#[cfg(not(feature = "tokio"))]
+#[test]
+fn cancelled_sleeps_never_wake_their_task() {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        task::{Context, Wake, Waker},
+    };
+
+    struct CountWakes(AtomicUsize);
+
+    impl Wake for CountWakes {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let wakes = Arc::new(CountWakes(AtomicUsize::new(0)));
+    let waker = Waker::from(wakes.clone());
+    let mut context = Context::from_waker(&waker);
+
+    // Start a sleep, then cancel it the way a debounce that is reset does
+    let mut cancelled = Box::pin(dioxus_hooks::sleep(Duration::from_millis(10)));
+    assert!(cancelled.as_mut().poll(&mut context).is_pending());
+    drop(cancelled);
+
+    // A sleep that finishes later proves the timer thread has passed the deadline of the cancelled one
+    block_on(dioxus_hooks::sleep(Duration::from_millis(30)));
+    assert_eq!(wakes.0.load(Ordering::SeqCst), 0);
+}
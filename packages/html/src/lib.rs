@@ -40,6 +40,11 @@ mod transit;
 #[cfg(feature = "serialize")]
 pub use transit::*;
 
+#[cfg(feature = "serialize")]
+mod use_form;
+#[cfg(feature = "serialize")]
+pub use use_form::*;
+
 pub use attribute_groups::*;
 pub use elements::*;
 pub use events::*;
@@ -0,0 +1,628 @@
+//! Typed form state built on top of [`FormData`](crate::FormData).
+
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    future::Future,
+    marker::PhantomData,
+    rc::Rc,
+};
+
+use dioxus_core::{Attribute, Runtime, ScopeId, current_scope_id, spawn, use_hook};
+use dioxus_signals::{CopyValue, ReadSignal, ReadableExt, Signal, WritableExt};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+use crate::FormEvent;
+
+/// Validation for the values of a form created with [`use_form`].
+///
+/// Errors are added to the path of the field they belong to, for example `"email"` or `"address.city"`.
+///
+/// ```rust, ignore
+/// #[derive(Default, Clone, Serialize, Deserialize)]
+/// struct Signup {
+///     username: String,
+///     age: u32,
+/// }
+///
+/// impl Validate for Signup {
+///     fn validate(&self, errors: &mut FormErrors) {
+///         if self.age < 13 {
+///             errors.add("age", "You must be at least 13 years old");
+///         }
+///     }
+///
+///     // Async validators can call server functions to run checks on the server
+///     async fn validate_async(&self, errors: &mut FormErrors) {
+///         if !username_available(self.username.clone()).await.unwrap_or(true) {
+///             errors.add("username", "That username is taken");
+///         }
+///     }
+/// }
+/// ```
+pub trait Validate {
+    /// Check the values synchronously. This runs every time a field changes.
+    fn validate(&self, errors: &mut FormErrors) {
+        _ = errors;
+    }
+
+    /// Check the values with async work like a request to the server. This runs when a field loses focus and
+    /// before the form is submitted.
+    fn validate_async(&self, errors: &mut FormErrors) -> impl Future<Output = ()> {
+        _ = errors;
+        async {}
+    }
+}
+
+/// The errors of a form, grouped by the path of the field they belong to.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FormErrors {
+    errors: BTreeMap<String, Vec<String>>,
+}
+
+impl FormErrors {
+    /// Add an error to the field at `path`.
+    pub fn add(&mut self, path: impl Into<String>, message: impl Into<String>) {
+        self.errors
+            .entry(path.into())
+            .or_default()
+            .push(message.into());
+    }
+
+    /// Get the errors of the field at `path`.
+    pub fn get(&self, path: &str) -> &[String] {
+        self.errors.get(path).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Check if there are no errors.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Iterate over the paths and errors of every field with errors.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[String])> {
+        self.errors
+            .iter()
+            .map(|(path, errors)| (path.as_str(), errors.as_slice()))
+    }
+
+    fn extend(&mut self, other: &FormErrors) {
+        for (path, errors) in other.iter() {
+            for error in errors {
+                self.add(path, error.clone());
+            }
+        }
+    }
+}
+
+/// Create a form for the values in `T`, starting with the default value of `T`.
+///
+/// Inputs are bound to the form by the path of a field with [`Form::bind`], and the form runs the [`Validate`] impl
+/// of `T` as the values change. The form only relies on the values of form events, so it works the same way on
+/// every renderer.
+///
+/// ```rust, ignore
+/// fn app() -> Element {
+///     let form = use_form::<Signup>();
+///     let username = form.field("username");
+///
+///     rsx! {
+///         form {
+///             onsubmit: form.on_submit(async move |values: Signup| {
+///                 _ = create_account(values).await;
+///             }),
+///             input { ..form.bind("username") }
+///             for error in username.errors().iter() {
+///                 p { "{error}" }
+///             }
+///             input { r#type: "number", ..form.bind("age") }
+///             button { disabled: form.is_submitting(), "Sign up" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_form<T>() -> Form<T>
+where
+    T: Default + Serialize + DeserializeOwned + Validate + 'static,
+{
+    use_form_with(T::default)
+}
+
+/// Create a form for the values in `T`, starting with the values returned by `init`.
+///
+/// See [`use_form`] for more details.
+pub fn use_form_with<T>(init: impl FnOnce() -> T) -> Form<T>
+where
+    T: Serialize + DeserializeOwned + Validate + 'static,
+{
+    use_hook(|| Form::new(init()))
+}
+
+/// The state of a form created with [`use_form`].
+pub struct Form<T: 'static> {
+    values: Signal<Value>,
+    errors: Signal<FormErrors>,
+    validating: Signal<bool>,
+    submitting: Signal<bool>,
+    submit_count: Signal<usize>,
+    state: CopyValue<FormState>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Form<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Form<T> {}
+
+impl<T> PartialEq for Form<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+}
+
+struct FormState {
+    owner: ScopeId,
+    initial: Value,
+    fields: HashMap<String, FieldSignals>,
+    /// Errors from values that couldn't be parsed into the type of their field
+    parse_errors: HashMap<String, String>,
+    /// Errors from the last run of the async validators
+    async_errors: FormErrors,
+    /// Incremented whenever the values change so async validation that finishes late can be ignored
+    generation: usize,
+}
+
+#[derive(Clone, Copy)]
+struct FieldSignals {
+    touched: Signal<bool>,
+    errors: Signal<Vec<String>>,
+    errors_read: ReadSignal<Vec<String>>,
+}
+
+impl<T> Form<T>
+where
+    T: Serialize + DeserializeOwned + Validate + 'static,
+{
+    fn new(initial: T) -> Self {
+        let initial = serde_json::to_value(&initial).expect("form values must serialize to json");
+        let form = Self {
+            values: Signal::new(initial.clone()),
+            errors: Signal::new(FormErrors::default()),
+            validating: Signal::new(false),
+            submitting: Signal::new(false),
+            submit_count: Signal::new(0),
+            state: CopyValue::new(FormState {
+                owner: current_scope_id(),
+                initial,
+                fields: HashMap::new(),
+                parse_errors: HashMap::new(),
+                async_errors: FormErrors::default(),
+                generation: 0,
+            }),
+            _marker: PhantomData,
+        };
+        form.revalidate();
+        form
+    }
+
+    /// Get a handle to the field at `path`.
+    ///
+    /// Paths are the names of fields separated by `.`, with numbers for the items of lists, for example
+    /// `"address.city"` or `"tags.0"`.
+    pub fn field(&self, path: &str) -> FormField<T> {
+        FormField {
+            form: *self,
+            path: path.into(),
+        }
+    }
+
+    /// Get the attributes that bind an `input`, `select` or `textarea` to the field at `path`.
+    ///
+    /// This sets the `name` and `value` of the input, or `checked` if the field is a `bool`, and updates the field
+    /// when the input changes and when it loses focus. Spread the attributes into the input:
+    ///
+    /// ```rust, ignore
+    /// input { r#type: "email", ..form.bind("email") }
+    /// ```
+    pub fn bind(&self, path: &str) -> Vec<Attribute> {
+        let field = self.field(path);
+        let is_bool = matches!(field.value(), Some(Value::Bool(_)));
+
+        let mut attributes = vec![Attribute::new("name", path.to_string(), None, false)];
+        if is_bool {
+            let checked = field.get::<bool>().unwrap_or_default();
+            attributes.push(Attribute::new("checked", checked, None, true));
+        } else {
+            attributes.push(Attribute::new("value", field.text(), None, true));
+        }
+
+        attributes.push(crate::events::oninput({
+            let field = field.clone();
+            move |event: FormEvent| match is_bool {
+                true => field.set(event.checked()),
+                false => field.set_text(&event.value()),
+            }
+        }));
+        attributes.push(crate::events::onfocusout(move |_| field.blur()));
+
+        attributes
+    }
+
+    /// Parse the current values of the form into `T`.
+    pub fn values(&self) -> Result<T, serde_json::Error> {
+        T::deserialize(&*self.values.read())
+    }
+
+    /// Get all of the errors in the form.
+    pub fn errors(&self) -> FormErrors {
+        self.errors.cloned()
+    }
+
+    /// Check if the form has no errors.
+    pub fn is_valid(&self) -> bool {
+        self.errors.read().is_empty()
+    }
+
+    /// Check if any field is different from the initial values.
+    pub fn is_dirty(&self) -> bool {
+        *self.values.read() != self.state.read().initial
+    }
+
+    /// Check if the async validators are running.
+    pub fn is_validating(&self) -> bool {
+        (self.validating)()
+    }
+
+    /// Check if the submit handler is running.
+    pub fn is_submitting(&self) -> bool {
+        (self.submitting)()
+    }
+
+    /// Get the number of times the form was submitted, including submissions that failed validation.
+    pub fn submit_count(&self) -> usize {
+        (self.submit_count)()
+    }
+
+    /// Set the value of the field at `path`.
+    pub fn set(&self, path: &str, value: impl Serialize) {
+        self.field(path).set(value)
+    }
+
+    /// Reset the form to its initial values and clear the state of every field.
+    pub fn reset(&self) {
+        let mut state = self.state.write_unchecked();
+        state.generation += 1;
+        state.parse_errors.clear();
+        state.async_errors = FormErrors::default();
+        for field in state.fields.values_mut() {
+            field.touched.set(false);
+        }
+        let initial = state.initial.clone();
+        drop(state);
+
+        self.values.clone().set(initial);
+        self.submit_count.clone().set(0);
+        self.revalidate();
+    }
+
+    /// Run every validator, including the async validators, and return whether the form is valid.
+    pub async fn validate(&self) -> bool {
+        // Errors from the last async validation may depend on fields that changed since, so only parse and sync errors
+        // skip the async validators
+        if !self.sync_errors().is_empty() {
+            return false;
+        }
+        self.state.write_unchecked().async_errors = FormErrors::default();
+        self.run_async_validation().await;
+        self.is_valid()
+    }
+
+    /// Create a handler for the `onsubmit` event of a `form`.
+    ///
+    /// When the form is submitted, every field is marked as touched and the validators run. If the form is valid,
+    /// `handler` is called with the parsed values.
+    pub fn on_submit<F>(
+        &self,
+        handler: impl FnMut(T) -> F + 'static,
+    ) -> impl FnMut(FormEvent) + 'static
+    where
+        F: Future<Output = ()> + 'static,
+    {
+        let form = *self;
+        let handler = Rc::new(RefCell::new(handler));
+        move |event: FormEvent| {
+            event.prevent_default();
+            if *form.submitting.peek() {
+                return;
+            }
+
+            let handler = handler.clone();
+            form.in_owner(|| {
+                spawn(async move {
+                    form.submit_count.clone().with_mut(|count| *count += 1);
+                    for field in form.state.read().fields.values() {
+                        field.touched.clone().set(true);
+                    }
+
+                    if !form.validate().await {
+                        return;
+                    }
+                    let Ok(values) = form.values() else {
+                        return;
+                    };
+
+                    form.submitting.clone().set(true);
+                    let submission = handler.borrow_mut()(values);
+                    submission.await;
+                    form.submitting.clone().set(false);
+                })
+            });
+        }
+    }
+
+    fn field_signals(&self, path: &str) -> FieldSignals {
+        if let Some(signals) = self.state.read().fields.get(path) {
+            return *signals;
+        }
+
+        // Fields can be created from any component, but they live as long as the form
+        let signals = self.in_owner(|| {
+            let errors = Signal::new(self.errors.peek().get(path).to_vec());
+            FieldSignals {
+                touched: Signal::new(false),
+                errors,
+                errors_read: errors.into(),
+            }
+        });
+        self.state
+            .write_unchecked()
+            .fields
+            .insert(path.to_string(), signals);
+        signals
+    }
+
+    fn in_owner<O>(&self, f: impl FnOnce() -> O) -> O {
+        let owner = self.state.read().owner;
+        Runtime::current().in_scope(owner, f)
+    }
+
+    fn set_value(&self, path: &str, value: Value) {
+        let mut values = self.values;
+        values.with_mut(|values| *value_at_mut(values, path) = value);
+
+        let mut state = self.state.write_unchecked();
+        state.generation += 1;
+        // Async errors were checked against the old value
+        state.async_errors.errors.remove(path);
+
+        state.parse_errors.remove(path);
+        if T::deserialize(&*values.peek()).is_ok() {
+            state.parse_errors.clear();
+        } else {
+            // Put the initial values back into the other fields that failed to parse. If the values still don't parse,
+            // this field is the culprit
+            let mut checked = values.peek().clone();
+            for other in state.parse_errors.keys() {
+                if let Some(initial) = value_at(&state.initial, other) {
+                    *value_at_mut(&mut checked, other) = initial.clone();
+                }
+            }
+            if let Err(err) = T::deserialize(&checked) {
+                state.parse_errors.insert(path.to_string(), err.to_string());
+            }
+        }
+        drop(state);
+
+        self.revalidate();
+    }
+
+    /// Collect the parse errors and the errors from the sync validators.
+    fn sync_errors(&self) -> FormErrors {
+        let mut errors = FormErrors::default();
+        for (path, error) in &self.state.read().parse_errors {
+            errors.add(path.clone(), error.clone());
+        }
+        if let Ok(values) = T::deserialize(&*self.values.peek()) {
+            values.validate(&mut errors);
+        }
+        errors
+    }
+
+    /// Rebuild the errors from the parse errors, the sync validators and the last async validation.
+    fn revalidate(&self) {
+        let mut errors = self.sync_errors();
+        let state = self.state.read();
+        errors.extend(&state.async_errors);
+
+        for (path, field) in &state.fields {
+            let field_errors = errors.get(path);
+            if *field.errors.peek() != field_errors {
+                field.errors.clone().set(field_errors.to_vec());
+            }
+        }
+        drop(state);
+
+        if *self.errors.peek() != errors {
+            self.errors.clone().set(errors);
+        }
+    }
+
+    async fn run_async_validation(&self) {
+        let Ok(values) = self.values() else {
+            return;
+        };
+        let generation = self.state.read().generation;
+
+        self.validating.clone().set(true);
+        let mut errors = FormErrors::default();
+        values.validate_async(&mut errors).await;
+        self.validating.clone().set(false);
+
+        // The values changed while we were validating, so the results are out of date
+        if self.state.read().generation != generation {
+            return;
+        }
+        self.state.write_unchecked().async_errors = errors;
+        self.revalidate();
+    }
+}
+
+/// A handle to one field of a [`Form`].
+pub struct FormField<T: 'static> {
+    form: Form<T>,
+    path: Rc<str>,
+}
+
+impl<T> Clone for FormField<T> {
+    fn clone(&self) -> Self {
+        Self {
+            form: self.form,
+            path: self.path.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for FormField<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.form == other.form && self.path == other.path
+    }
+}
+
+impl<T> FormField<T>
+where
+    T: Serialize + DeserializeOwned + Validate + 'static,
+{
+    /// Get the path of the field.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Get the raw value of the field.
+    pub fn value(&self) -> Option<Value> {
+        value_at(&self.form.values.read(), &self.path).cloned()
+    }
+
+    /// Parse the value of the field.
+    pub fn get<V: DeserializeOwned>(&self) -> Option<V> {
+        V::deserialize(self.value()?).ok()
+    }
+
+    /// Get the value of the field as the text an input displays.
+    pub fn text(&self) -> String {
+        match self.value() {
+            Some(Value::String(text)) => text,
+            None | Some(Value::Null) => String::new(),
+            Some(value) => value.to_string(),
+        }
+    }
+
+    /// Set the value of the field.
+    pub fn set(&self, value: impl Serialize) {
+        let value = serde_json::to_value(value).expect("form values must serialize to json");
+        self.form.set_value(&self.path, value);
+    }
+
+    /// Set the value of the field from the text of an input.
+    ///
+    /// The text is parsed into the same kind of value the field already has, so numbers stay numbers. If the text
+    /// can't be parsed, the error is added to the field.
+    pub fn set_text(&self, text: &str) {
+        // Parse the text into the kind of value the field started with, since the current value might be text that
+        // failed to parse
+        let state = self.form.state.read();
+        let values = self.form.values.peek();
+        let current =
+            value_at(&state.initial, &self.path).or_else(|| value_at(&values, &self.path));
+        let value = parse_text(current, text);
+        drop((state, values));
+        self.form.set_value(&self.path, value);
+    }
+
+    /// Mark the field as touched and run the async validators.
+    pub fn blur(&self) {
+        self.form.field_signals(&self.path).touched.set(true);
+        let form = self.form;
+        form.in_owner(|| spawn(async move { form.run_async_validation().await }));
+    }
+
+    /// Check if the field has lost focus since the form was created or reset.
+    pub fn touched(&self) -> bool {
+        (self.form.field_signals(&self.path).touched)()
+    }
+
+    /// Check if the field is different from its initial value.
+    pub fn dirty(&self) -> bool {
+        let initial = value_at(&self.form.state.read().initial, &self.path).cloned();
+        self.value() != initial
+    }
+
+    /// Get the errors of the field.
+    pub fn errors(&self) -> ReadSignal<Vec<String>> {
+        self.form.field_signals(&self.path).errors_read
+    }
+
+    /// Get the first error of the field if it was touched.
+    pub fn visible_error(&self) -> Option<String> {
+        match self.touched() {
+            true => self.errors().read().first().cloned(),
+            false => None,
+        }
+    }
+}
+
+fn value_at<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(value, |value, segment| match value {
+            Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+            Value::Object(fields) => fields.get(segment),
+            _ => None,
+        })
+}
+
+fn value_at_mut<'a>(mut value: &'a mut Value, path: &str) -> &'a mut Value {
+    for segment in path.split('.') {
+        let index = segment.parse::<usize>().ok().filter(|_| value.is_array());
+        value = match index {
+            Some(index) => {
+                let items = value.as_array_mut().unwrap();
+                if items.len() <= index {
+                    items.resize(index + 1, Value::Null);
+                }
+                &mut items[index]
+            }
+            None => {
+                if !value.is_object() {
+                    *value = Value::Object(Default::default());
+                }
+                value
+                    .as_object_mut()
+                    .unwrap()
+                    .entry(segment)
+                    .or_insert(Value::Null)
+            }
+        };
+    }
+    value
+}
+
+/// Parse the text of an input into the same kind of value as the current value of the field
+fn parse_text(current: Option<&Value>, text: &str) -> Value {
+    match current {
+        Some(Value::String(_)) => Value::String(text.to_string()),
+        Some(Value::Bool(_)) => Value::Bool(matches!(text, "true" | "on")),
+        Some(Value::Number(_)) | Some(Value::Null) | None => {
+            if text.is_empty() && !matches!(current, Some(Value::Number(_))) {
+                return Value::Null;
+            }
+            match text.trim().parse::<serde_json::Number>() {
+                Ok(number) => Value::Number(number),
+                Err(_) => Value::String(text.to_string()),
+            }
+        }
+        Some(_) => serde_json::from_str(text).unwrap_or_else(|_| Value::String(text.to_string())),
+    }
+}
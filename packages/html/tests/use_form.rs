@@ -0,0 +1,220 @@
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_html::{Form, FormErrors, Validate, use_form};
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Clone, Serialize, Deserialize)]
+struct Signup {
+    username: String,
+    age: u32,
+    newsletter: bool,
+    address: Address,
+}
+
+#[derive(Default, Clone, Serialize, Deserialize)]
+struct Address {
+    city: String,
+}
+
+impl Validate for Signup {
+    fn validate(&self, errors: &mut FormErrors) {
+        if self.age < 13 {
+            errors.add("age", "too young");
+        }
+    }
+
+    async fn validate_async(&self, errors: &mut FormErrors) {
+        tokio::task::yield_now().await;
+        if self.username == "taken" {
+            errors.add("username", "username is taken");
+        }
+        if self.username == "restricted" {
+            errors.add("address.city", "not available for this account");
+        }
+    }
+}
+
+thread_local! {
+    static FORM: Cell<Option<Form<Signup>>> = const { Cell::new(None) };
+}
+
+fn app() -> Element {
+    let form = use_form::<Signup>();
+    use_hook(|| FORM.with(|cell| cell.set(Some(form))));
+    rsx! {
+        form {
+            input { ..form.bind("username") }
+            input { ..form.bind("age") }
+        }
+    }
+}
+
+fn form() -> Form<Signup> {
+    FORM.with(|cell| cell.get().unwrap())
+}
+
+#[test]
+fn sync_validation_runs_on_change() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    dom.in_runtime(|| {
+        let form = form();
+        let age = form.field("age");
+        assert_eq!(age.errors().cloned(), ["too young"]);
+        assert!(!form.is_valid());
+
+        age.set_text("21");
+        assert_eq!(age.get::<u32>(), Some(21));
+        assert!(age.errors().read().is_empty());
+        assert!(age.dirty());
+        assert!(form.is_valid());
+        assert_eq!(form.values().unwrap().age, 21);
+    });
+}
+
+#[test]
+fn invalid_text_is_an_error_on_the_field() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    dom.in_runtime(|| {
+        let form = form();
+        let age = form.field("age");
+        age.set_text("abc");
+        assert_eq!(age.text(), "abc");
+        assert_eq!(age.errors().read().len(), 1);
+        assert!(form.values().is_err());
+
+        age.set_text("30");
+        assert!(age.errors().read().is_empty());
+        assert!(form.values().is_ok());
+    });
+}
+
+#[test]
+fn parse_errors_stay_on_the_field_that_failed() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    dom.in_runtime(|| {
+        let form = form();
+        let age = form.field("age");
+        age.set_text("abc");
+        form.set("newsletter", "maybe");
+        assert!(!form.errors().get("age").is_empty());
+        assert!(!form.errors().get("newsletter").is_empty());
+
+        // Fixing the first field leaves the error of the second field where it was
+        age.set_text("21");
+        assert!(age.errors().read().is_empty());
+        assert!(form.errors().get("age").is_empty());
+        assert!(!form.errors().get("newsletter").is_empty());
+
+        form.set("newsletter", true);
+        assert!(form.is_valid());
+    });
+}
+
+#[test]
+fn nested_paths_and_bools() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    dom.in_runtime(|| {
+        let form = form();
+        form.set("address.city", "Lisbon");
+        form.set("newsletter", true);
+        form.set("age", 40);
+
+        let values = form.values().unwrap();
+        assert_eq!(values.address.city, "Lisbon");
+        assert!(values.newsletter);
+        assert!(form.is_dirty());
+
+        form.reset();
+        assert!(!form.is_dirty());
+        assert_eq!(form.field("address.city").text(), "");
+    });
+}
+
+/// Run `form.validate()` and the dom until it goes idle
+async fn validate(dom: &mut VirtualDom) -> Option<bool> {
+    let valid = Rc::new(Cell::new(None));
+    dom.in_runtime(|| {
+        let form = form();
+        let valid = valid.clone();
+        dom.runtime().in_scope(ScopeId::APP, || {
+            spawn(async move { valid.set(Some(form.validate().await)) })
+        });
+    });
+
+    // The validation doesn't dirty any scopes, so run the dom until it goes idle
+    let _ = tokio::time::timeout(Duration::from_millis(100), async {
+        loop {
+            dom.wait_for_work().await;
+            dom.render_immediate(&mut NoOpMutations);
+        }
+    })
+    .await;
+
+    valid.get()
+}
+
+#[tokio::test]
+async fn async_errors_on_other_fields_are_rechecked_on_validate() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    dom.in_runtime(|| {
+        let form = form();
+        form.set("age", 20);
+        form.set("username", "restricted");
+    });
+    assert_eq!(validate(&mut dom).await, Some(false));
+
+    dom.in_runtime(|| {
+        let form = form();
+        assert_eq!(
+            form.field("address.city").errors().cloned(),
+            ["not available for this account"]
+        );
+
+        // The error is on another field, so it stays until the async validators run again
+        form.set("username", "someone");
+        assert!(!form.is_valid());
+    });
+
+    assert_eq!(validate(&mut dom).await, Some(true));
+    dom.in_runtime(|| {
+        let form = form();
+        assert!(form.is_valid());
+        assert!(form.field("address.city").errors().is_empty());
+    });
+}
+
+#[tokio::test]
+async fn async_validation_adds_errors() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    dom.in_runtime(|| {
+        let form = form();
+        form.set("age", 20);
+        form.set("username", "taken");
+    });
+    assert_eq!(validate(&mut dom).await, Some(false));
+    dom.in_runtime(|| {
+        let form = form();
+        assert_eq!(
+            form.field("username").errors().cloned(),
+            ["username is taken"]
+        );
+
+        // Changing the field clears the errors from the last async validation
+        form.set("username", "available");
+        assert!(form.is_valid());
+    });
+}
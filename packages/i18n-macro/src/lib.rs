@@ -0,0 +1,171 @@
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use fluent_syntax::ast::Entry;
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    Expr, Ident, LitStr, Token,
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+};
+
+/// Translate a message into the current language.
+///
+/// The first argument is the id of the message, optionally followed by an attribute like `"sign-in.title"`. The
+/// rest are the variables of the message as `name: value` pairs.
+///
+/// The id is checked at compile time against the `.ftl` files in the `locales` folder next to your `Cargo.toml`.
+/// The message only needs to exist in one language. Missing translations fall back to the fallback language at
+/// runtime.
+///
+/// ```rust, ignore
+/// let greeting = t!("greeting", name: "Ferris");
+/// let title = t!("sign-in.title");
+/// ```
+#[proc_macro]
+pub fn t(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Translate);
+    match input.expand() {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+struct Translate {
+    key: LitStr,
+    args: Punctuated<Argument, Token![,]>,
+}
+
+struct Argument {
+    name: Ident,
+    value: Expr,
+}
+
+impl Parse for Translate {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key = input.parse()?;
+        let args = match input.parse::<Option<Token![,]>>()? {
+            Some(_) => Punctuated::parse_terminated(input)?,
+            None => Punctuated::new(),
+        };
+        Ok(Self { key, args })
+    }
+}
+
+impl Parse for Argument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.call(Ident::parse_any)?;
+        input.parse::<Token![:]>()?;
+        let value = input.parse()?;
+        Ok(Self { name, value })
+    }
+}
+
+impl Translate {
+    fn expand(&self) -> syn::Result<TokenStream2> {
+        let key = self.key.value();
+        let files = self.check_key(&key)?;
+
+        // Include the files so the crate is rebuilt when a translation changes
+        let files = files.iter().map(|file| file.to_string_lossy().into_owned());
+        let args = self.args.iter().map(|Argument { name, value }| {
+            let name = name.unraw().to_string();
+            quote! { __args.set(#name, #value); }
+        });
+
+        Ok(quote! {
+            {
+                #(const _: &str = include_str!(#files);)*
+                #[allow(unused_mut)]
+                let mut __args = ::dioxus_i18n::fluent::FluentArgs::new();
+                #(#args)*
+                ::dioxus_i18n::i18n().translate_with_args(#key, Some(&__args))
+            }
+        })
+    }
+
+    /// Make sure the key is defined in at least one of the locales, and return the files that were checked
+    fn check_key(&self, key: &str) -> syn::Result<Vec<PathBuf>> {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").map_err(|_| {
+            syn::Error::new(self.key.span(), "CARGO_MANIFEST_DIR is not set")
+        })?;
+        let locales = Path::new(&manifest_dir).join("locales");
+
+        let mut files = Vec::new();
+        collect_ftl_files(&locales, &mut files).map_err(|err| {
+            syn::Error::new(
+                self.key.span(),
+                format!(
+                    "Failed to read the translations in {}: {err}. t! expects a `locales` folder next to your Cargo.toml",
+                    locales.display()
+                ),
+            )
+        })?;
+
+        let mut keys = BTreeSet::new();
+        for file in &files {
+            let source = std::fs::read_to_string(file).map_err(|err| {
+                syn::Error::new(
+                    self.key.span(),
+                    format!("Failed to read {}: {err}", file.display()),
+                )
+            })?;
+            collect_keys(&source, &mut keys);
+        }
+
+        if !keys.contains(key) {
+            return Err(syn::Error::new(
+                self.key.span(),
+                format!(
+                    "`{key}` is not defined in any of the .ftl files in {}",
+                    locales.display()
+                ),
+            ));
+        }
+
+        Ok(files)
+    }
+}
+
+fn collect_ftl_files(dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    let mut entries = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            collect_ftl_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "ftl") {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Collect the ids of the messages and their attributes in a fluent resource. Entries that fail to parse are skipped.
+fn collect_keys(source: &str, keys: &mut BTreeSet<String>) {
+    let resource = match fluent_syntax::parser::parse(source) {
+        Ok(resource) => resource,
+        Err((resource, _)) => resource,
+    };
+
+    for entry in resource.body {
+        if let Entry::Message(message) = entry {
+            for attribute in &message.attributes {
+                keys.insert(format!("{}.{}", message.id.name, attribute.id.name));
+            }
+            keys.insert(message.id.name.to_string());
+        }
+    }
+}
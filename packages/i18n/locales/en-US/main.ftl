@@ -0,0 +1,9 @@
+greeting = Hello, { $name }!
+inbox =
+    { $count ->
+        [one] You have one new message
+       *[other] You have { $count } new messages
+    }
+sign-in = Sign in
+    .title = Sign in to your account
+only-in-english = This page is only available in English
@@ -0,0 +1,8 @@
+greeting = Bonjour, { $name } !
+inbox =
+    { $count ->
+        [one] Vous avez un nouveau message
+       *[other] Vous avez { $count } nouveaux messages
+    }
+sign-in = Se connecter
+    .title = Connectez-vous à votre compte
@@ -0,0 +1,124 @@
+use manganis::Asset;
+use unic_langid::LanguageIdentifier;
+
+/// The translations for one language, written in [Fluent](https://projectfluent.org/).
+#[derive(Clone, Debug)]
+pub struct Locale {
+    pub(crate) language: LanguageIdentifier,
+    pub(crate) source: LocaleSource,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) enum LocaleSource {
+    Static(&'static str),
+    Asset(Asset),
+}
+
+impl Locale {
+    /// Create a locale from a `.ftl` file that is bundled as an asset.
+    ///
+    /// On native platforms and the server, the file is read before the first render. On the web, it is fetched in
+    /// the background and messages fall back to the fallback language until it loads.
+    ///
+    /// ```rust, ignore
+    /// Locale::new_asset(langid!("fr"), asset!("/locales/fr/main.ftl"))
+    /// ```
+    pub fn new_asset(language: LanguageIdentifier, asset: Asset) -> Self {
+        Self {
+            language,
+            source: LocaleSource::Asset(asset),
+        }
+    }
+
+    /// Create a locale from the text of a `.ftl` file that is compiled into the binary.
+    ///
+    /// ```rust, ignore
+    /// Locale::new_static(langid!("fr"), include_str!("../locales/fr/main.ftl"))
+    /// ```
+    pub fn new_static(language: LanguageIdentifier, source: &'static str) -> Self {
+        Self {
+            language,
+            source: LocaleSource::Static(source),
+        }
+    }
+
+    /// The language of this locale.
+    pub fn language(&self) -> &LanguageIdentifier {
+        &self.language
+    }
+}
+
+/// The configuration passed to [`use_init_i18n`](crate::use_init_i18n).
+#[derive(Clone, Debug)]
+pub struct I18nConfig {
+    pub(crate) fallback: LanguageIdentifier,
+    pub(crate) language: Option<LanguageIdentifier>,
+    pub(crate) locales: Vec<Locale>,
+}
+
+impl I18nConfig {
+    /// Create a new configuration. Messages that are missing in the current language are looked up in the
+    /// `fallback` language.
+    pub fn new(fallback: LanguageIdentifier) -> Self {
+        Self {
+            fallback,
+            language: None,
+            locales: Vec::new(),
+        }
+    }
+
+    /// Add the translations for a language. A language can have multiple locales, for example one per page.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locales.push(locale);
+        self
+    }
+
+    /// Start in the given language instead of negotiating one.
+    ///
+    /// By default, the server picks the language from the `Accept-Language` header of the request when the
+    /// `server` feature is enabled, and the fallback language is used everywhere else.
+    pub fn with_language(mut self, language: LanguageIdentifier) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// The languages there are translations for, in the order they were added.
+    pub fn languages(&self) -> Vec<LanguageIdentifier> {
+        let mut languages: Vec<LanguageIdentifier> = Vec::new();
+        for locale in &self.locales {
+            if !languages.contains(&locale.language) {
+                languages.push(locale.language.clone());
+            }
+        }
+        languages
+    }
+
+    /// Pick the language out of the available languages that best matches the requested languages, falling back to
+    /// the fallback language.
+    pub fn negotiate(&self, requested: &[LanguageIdentifier]) -> LanguageIdentifier {
+        let available = self.languages();
+        fluent_langneg::negotiate_languages(
+            requested,
+            &available,
+            Some(&self.fallback),
+            fluent_langneg::NegotiationStrategy::Lookup,
+        )
+        .first()
+        .map(|language| (*language).clone())
+        .unwrap_or_else(|| self.fallback.clone())
+    }
+
+    /// The language of the request that is being rendered, negotiated from its `Accept-Language` header.
+    #[cfg(feature = "fullstack")]
+    pub(crate) fn request_language(&self) -> Option<String> {
+        #[cfg(feature = "server")]
+        {
+            let available: Vec<String> = self.languages().iter().map(|l| l.to_string()).collect();
+            let available: Vec<&str> = available.iter().map(String::as_str).collect();
+            dioxus_server::negotiate_language(&available).map(str::to_string)
+        }
+
+        #[cfg(not(feature = "server"))]
+        None
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+use dioxus_core::{consume_context, provide_context, use_hook};
+use dioxus_signals::{CopyValue, ReadableExt, Signal, WritableExt};
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+use unic_langid::LanguageIdentifier;
+
+use crate::{I18nConfig, Locale, config::LocaleSource};
+
+/// Set up translations for this component and its children.
+///
+/// This should be called once near the root of your app. Read the translations in children with [`t!`](crate::t)
+/// or [`use_i18n`].
+///
+/// ```rust, ignore
+/// fn app() -> Element {
+///     use_init_i18n(|| {
+///         I18nConfig::new(langid!("en-US"))
+///             .with_locale(Locale::new_asset(langid!("en-US"), asset!("/locales/en-US/main.ftl")))
+///             .with_locale(Locale::new_asset(langid!("fr"), asset!("/locales/fr/main.ftl")))
+///     });
+///
+///     rsx! { h1 { {t!("greeting", name: "Ferris")} } }
+/// }
+/// ```
+pub fn use_init_i18n(init: impl FnOnce() -> I18nConfig) -> I18n {
+    let config = use_hook(|| std::rc::Rc::new(init()));
+
+    // The server negotiates the language of the request and sends it to the client so hydration starts in the same
+    // language
+    #[cfg(feature = "fullstack")]
+    let request_language = dioxus_fullstack_core::use_server_cached({
+        let config = config.clone();
+        move || config.request_language()
+    });
+    #[cfg(not(feature = "fullstack"))]
+    let request_language: Option<String> = None;
+
+    use_hook(|| {
+        let language = config
+            .language
+            .clone()
+            .or_else(|| request_language.and_then(|language| language.parse().ok()))
+            .unwrap_or_else(|| config.fallback.clone());
+        provide_context(I18n::new(&config, language))
+    })
+}
+
+/// Get the [`I18n`] handle that was set up with [`use_init_i18n`] in a parent component.
+pub fn use_i18n() -> I18n {
+    use_hook(i18n)
+}
+
+/// Get the [`I18n`] handle that was set up with [`use_init_i18n`] in a parent component.
+///
+/// Unlike [`use_i18n`], this is not a hook and can be called anywhere the runtime is active, including event handlers.
+///
+/// # Panics
+///
+/// Panics if [`use_init_i18n`] was not called in a parent component.
+pub fn i18n() -> I18n {
+    consume_context()
+}
+
+/// A handle to the translations of the app.
+///
+/// The current language is stored in a signal. Reading a translation subscribes the current component to the
+/// language, so only components that render translated text re-render when the language changes.
+#[derive(Clone, Copy, PartialEq)]
+pub struct I18n {
+    language: Signal<LanguageIdentifier>,
+    // Written when a locale finishes loading in the background
+    loaded: Signal<usize>,
+    state: CopyValue<I18nState>,
+}
+
+struct I18nState {
+    fallback: LanguageIdentifier,
+    languages: Vec<LanguageIdentifier>,
+    bundles: HashMap<LanguageIdentifier, FluentBundle<FluentResource>>,
+}
+
+impl I18n {
+    fn new(config: &I18nConfig, language: LanguageIdentifier) -> Self {
+        let myself = Self {
+            language: Signal::new(language),
+            loaded: Signal::new(0),
+            state: CopyValue::new(I18nState {
+                fallback: config.fallback.clone(),
+                languages: config.languages(),
+                bundles: HashMap::new(),
+            }),
+        };
+
+        for locale in &config.locales {
+            myself.load(locale);
+        }
+
+        myself
+    }
+
+    /// The current language. Reading it subscribes the current component to changes.
+    pub fn language(&self) -> LanguageIdentifier {
+        self.language.cloned()
+    }
+
+    /// Switch to a different language.
+    pub fn set_language(&mut self, language: LanguageIdentifier) {
+        self.language.set(language);
+    }
+
+    /// The language messages fall back to if they are missing in the current language.
+    pub fn fallback_language(&self) -> LanguageIdentifier {
+        self.state.read().fallback.clone()
+    }
+
+    /// The languages there are translations for.
+    pub fn languages(&self) -> Vec<LanguageIdentifier> {
+        self.state.read().languages.clone()
+    }
+
+    /// Translate a message into the current language. Prefer the [`t!`](crate::t) macro which checks the key at
+    /// compile time.
+    ///
+    /// The key is the id of the message, optionally followed by an attribute like `"sign-in.title"`. If the message
+    /// is missing in both the current and the fallback language, the key itself is returned.
+    pub fn translate(&self, key: &str) -> String {
+        self.translate_with_args(key, None)
+    }
+
+    /// Translate a message with variables into the current language.
+    pub fn translate_with_args(&self, key: &str, args: Option<&FluentArgs>) -> String {
+        self.try_translate_with_args(key, args).unwrap_or_else(|| {
+            tracing::warn!("Missing translation for `{key}`");
+            key.to_string()
+        })
+    }
+
+    /// Translate a message with variables into the current language, or return `None` if it is missing in both the
+    /// current and the fallback language.
+    pub fn try_translate_with_args(&self, key: &str, args: Option<&FluentArgs>) -> Option<String> {
+        let language = self.language.read();
+        _ = self.loaded.read();
+        let state = self.state.read();
+
+        [&*language, &state.fallback]
+            .into_iter()
+            .filter_map(|language| state.bundles.get(language))
+            .find_map(|bundle| format_message(bundle, key, args))
+    }
+
+    fn load(&self, locale: &Locale) {
+        match &locale.source {
+            LocaleSource::Static(source) => self.add_resource(&locale.language, source.to_string()),
+            LocaleSource::Asset(asset) => {
+                // Read the file right away if it is on disk so the first render is already translated
+                if let Ok(path) = dioxus_asset_resolver::asset_path(asset)
+                    && let Ok(source) = std::fs::read_to_string(path)
+                {
+                    self.add_resource(&locale.language, source);
+                    return;
+                }
+
+                let myself = *self;
+                let language = locale.language.clone();
+                let asset = *asset;
+                dioxus_core::spawn(async move {
+                    match dioxus_asset_resolver::read_asset_bytes(asset).await {
+                        Ok(bytes) => {
+                            myself.add_resource(&language, String::from_utf8_lossy(&bytes).into_owned());
+                            *myself.loaded.write_unchecked() += 1;
+                        }
+                        Err(err) => tracing::error!("Failed to load the {language} translations: {err}"),
+                    }
+                });
+            }
+        }
+    }
+
+    fn add_resource(&self, language: &LanguageIdentifier, source: String) {
+        let resource = FluentResource::try_new(source).unwrap_or_else(|(resource, errors)| {
+            for error in errors {
+                tracing::error!("Failed to parse the {language} translations: {error}");
+            }
+            resource
+        });
+
+        let mut state = self.state.write_unchecked();
+        let bundle = state.bundles.entry(language.clone()).or_insert_with(|| {
+            let mut bundle = FluentBundle::new(vec![language.clone()]);
+            // Unicode isolation marks around variables show up as stray characters in many fonts
+            bundle.set_use_isolating(false);
+            bundle
+        });
+        bundle.add_resource_overriding(resource);
+    }
+}
+
+fn format_message(
+    bundle: &FluentBundle<FluentResource>,
+    key: &str,
+    args: Option<&FluentArgs>,
+) -> Option<String> {
+    let (id, attribute) = match key.split_once('.') {
+        Some((id, attribute)) => (id, Some(attribute)),
+        None => (key, None),
+    };
+
+    let message = bundle.get_message(id)?;
+    let pattern = match attribute {
+        Some(attribute) => message.get_attribute(attribute)?.value(),
+        None => message.value()?,
+    };
+
+    let mut errors = Vec::new();
+    let value = bundle.format_pattern(pattern, args, &mut errors);
+    for error in errors {
+        tracing::warn!("Failed to format `{key}`: {error}");
+    }
+    Some(value.into_owned())
+}
@@ -0,0 +1,16 @@
+#![doc = include_str!("../README.md")]
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![warn(missing_docs)]
+
+mod config;
+mod i18n;
+
+pub use config::*;
+pub use i18n::*;
+
+pub use dioxus_i18n_macro::t;
+pub use unic_langid::{LanguageIdentifier, langid};
+
+/// Re-export of the fluent crate used to format messages.
+pub use fluent_bundle as fluent;
@@ -0,0 +1,87 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_i18n::{I18nConfig, Locale, i18n, langid, t, use_init_i18n};
+
+fn config() -> I18nConfig {
+    I18nConfig::new(langid!("en-US"))
+        .with_locale(Locale::new_static(
+            langid!("en-US"),
+            include_str!("../locales/en-US/main.ftl"),
+        ))
+        .with_locale(Locale::new_static(
+            langid!("fr"),
+            include_str!("../locales/fr/main.ftl"),
+        ))
+}
+
+fn app() -> Element {
+    use_init_i18n(config);
+
+    rsx! {
+        h1 { {t!("greeting", name: "Ferris")} }
+        p { {t!("inbox", count: 1)} }
+        p { {t!("inbox", count: 3)} }
+        button { title: t!("sign-in.title"), {t!("sign-in")} }
+        footer { {t!("only-in-english")} }
+    }
+}
+
+#[test]
+fn translates_into_the_fallback_language() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<h1>Hello, Ferris!</h1>\
+         <p>You have one new message</p>\
+         <p>You have 3 new messages</p>\
+         <button title=\"Sign in to your account\">Sign in</button>\
+         <footer>This page is only available in English</footer>"
+    );
+}
+
+#[test]
+fn switching_languages_rerenders_translations() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    dom.in_scope(ScopeId::APP, || i18n().set_language(langid!("fr")));
+    dom.render_immediate(&mut NoOpMutations);
+
+    // Messages that are missing in French fall back to English
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<h1>Bonjour, Ferris !</h1>\
+         <p>Vous avez un nouveau message</p>\
+         <p>Vous avez 3 nouveaux messages</p>\
+         <button title=\"Connectez-vous à votre compte\">Se connecter</button>\
+         <footer>This page is only available in English</footer>"
+    );
+}
+
+#[test]
+fn starts_in_the_configured_language() {
+    fn french_app() -> Element {
+        use_init_i18n(|| config().with_language(langid!("fr")));
+        rsx! { {t!("sign-in")} }
+    }
+
+    let mut dom = VirtualDom::new(french_app);
+    dom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&dom), "Se connecter");
+
+    dom.in_scope(ScopeId::APP, || {
+        let i18n = i18n();
+        assert_eq!(i18n.languages(), [langid!("en-US"), langid!("fr")]);
+        assert_eq!(i18n.translate("missing-key"), "missing-key");
+    });
+}
+
+#[test]
+fn negotiates_languages() {
+    let config = config();
+    assert_eq!(config.negotiate(&[langid!("fr-CA")]), langid!("fr"));
+    assert_eq!(config.negotiate(&[langid!("de"), langid!("en")]), langid!("en-US"));
+    assert_eq!(config.negotiate(&[langid!("de")]), langid!("en-US"));
+}
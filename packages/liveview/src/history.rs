@@ -16,11 +16,15 @@ pub(crate) struct LiveviewHistory {
 struct Timeline {
     current_index: usize,
     routes: BTreeMap<usize, String>,
+    /// The serialized state the app attached to the entries
+    states: BTreeMap<usize, String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 struct State {
     index: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -33,8 +37,8 @@ struct Session {
 enum Action {
     GoBack,
     GoForward,
-    Push(String),
-    Replace(String),
+    Push(String, Option<String>),
+    Replace(String, Option<String>),
     External(String),
 }
 
@@ -43,6 +47,7 @@ impl Timeline {
         Self {
             current_index: 0,
             routes: BTreeMap::from([(0, initial_path)]),
+            states: BTreeMap::new(),
         }
     }
 
@@ -69,10 +74,11 @@ impl Timeline {
             None => {
                 let index = depth - 1;
                 self.current_index = index;
-                State { index }
+                State { index, state: None }
             }
         };
         self.routes.insert(state.index, route);
+        self.set_state(state.index, state.state.clone());
         state
     }
 
@@ -80,34 +86,51 @@ impl Timeline {
         if let Some(state) = state {
             self.current_index = state.index;
             self.routes.insert(self.current_index, route);
+            self.set_state(self.current_index, state.state.clone());
             state
         } else {
-            self.push(route)
+            self.push(route, None)
         }
     }
 
-    fn push(&mut self, route: String) -> State {
+    fn push(&mut self, route: String, state: Option<String>) -> State {
         // top of stack
         let index = self.current_index + 1;
         self.current_index = index;
         self.routes.insert(index, route);
         self.routes.retain(|&rhs, _| index >= rhs);
+        self.states.retain(|&rhs, _| index >= rhs);
+        self.set_state(index, state.clone());
         State {
             index: self.current_index,
+            state,
         }
     }
 
-    fn replace(&mut self, route: String) -> State {
+    fn replace(&mut self, route: String, state: Option<String>) -> State {
         self.routes.insert(self.current_index, route);
+        self.set_state(self.current_index, state.clone());
         State {
             index: self.current_index,
+            state,
         }
     }
 
+    fn set_state(&mut self, index: usize, state: Option<String>) {
+        match state {
+            Some(state) => self.states.insert(index, state),
+            None => self.states.remove(&index),
+        };
+    }
+
     fn current_route(&self) -> &str {
         &self.routes[&self.current_index]
     }
 
+    fn current_state(&self) -> Option<String> {
+        self.states.get(&self.current_index).cloned()
+    }
+
     fn session(&self) -> Session {
         Session {
             routes: self.routes.clone(),
@@ -166,9 +189,9 @@ impl LiveviewHistory {
                                 history.forward();
                             "#,
                         ),
-                        Action::Push(route) => {
+                        Action::Push(route, state) => {
                             let mut timeline = timeline.lock().expect("unpoisoned mutex");
-                            let state = timeline.push(route.clone());
+                            let state = timeline.push(route.clone(), state);
                             let state = serde_json::to_string(&state).expect("serializable state");
                             let session = serde_json::to_string(&timeline.session())
                                 .expect("serializable session");
@@ -180,9 +203,9 @@ impl LiveviewHistory {
                             "#
                             ))
                         }
-                        Action::Replace(route) => {
+                        Action::Replace(route, state) => {
                             let mut timeline = timeline.lock().expect("unpoisoned mutex");
-                            let state = timeline.replace(route.clone());
+                            let state = timeline.replace(route.clone(), state);
                             let state = serde_json::to_string(&state).expect("serializable state");
                             let session = serde_json::to_string(&timeline.session())
                                 .expect("serializable session");
@@ -296,11 +319,19 @@ impl History for LiveviewHistory {
     }
 
     fn push(&self, route: String) {
-        let _ = self.action_tx.send(Action::Push(route));
+        let _ = self.action_tx.send(Action::Push(route, None));
+    }
+
+    fn push_with_state(&self, route: String, state: String) {
+        let _ = self.action_tx.send(Action::Push(route, Some(state)));
     }
 
     fn replace(&self, route: String) {
-        let _ = self.action_tx.send(Action::Replace(route));
+        let _ = self.action_tx.send(Action::Replace(route, None));
+    }
+
+    fn replace_with_state(&self, route: String, state: String) {
+        let _ = self.action_tx.send(Action::Replace(route, Some(state)));
     }
 
     fn external(&self, url: String) -> bool {
@@ -313,6 +344,16 @@ impl History for LiveviewHistory {
         timeline.current_route().to_string()
     }
 
+    fn current_index(&self) -> Option<usize> {
+        let timeline = self.timeline.lock().expect("unpoisoned mutex");
+        Some(timeline.current_index)
+    }
+
+    fn current_state(&self) -> Option<String> {
+        let timeline = self.timeline.lock().expect("unpoisoned mutex");
+        timeline.current_state()
+    }
+
     fn can_go_back(&self) -> bool {
         let timeline = self.timeline.lock().expect("unpoisoned mutex");
         // Check if the one before is contiguous (i.e., not an external page)
@@ -231,6 +231,13 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
                         // usually only web gets this message - what are we supposed to do?
                         // Maybe we could just binary patch ourselves in place without losing window state?
                     },
+                    msg @ (dioxus_devtools::DevserverMsg::SetProfiling(_)
+                    | dioxus_devtools::DevserverMsg::SetReactiveGraphRecording(_)
+                    | dioxus_devtools::DevserverMsg::QueryReactiveGraph) => {
+                        if let Some(reply) = dioxus_devtools::handle_runtime_msg(&vdom.runtime(), &msg) {
+                            dioxus_devtools::send(reply);
+                        }
+                    },
                     _ => {}
                 }
                 #[cfg(not(all(feature = "devtools", debug_assertions)))]
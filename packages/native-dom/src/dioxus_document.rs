@@ -180,6 +180,39 @@ impl DioxusDocument {
         }
     }
 
+    /// Used to respond to a `RemoveHeadElement` event generated by Dioxus. Removes the
+    /// elements in the `<head>` with this tag name that have all of the given attributes.
+    #[doc(hidden)]
+    pub fn remove_head_element(&mut self, name: &str, attributes: &[(String, String)]) {
+        let mut inner = self.inner.borrow_mut();
+        let Some(head) = inner.get_node(self.head_element_id) else {
+            return;
+        };
+        let matching: Vec<usize> = head
+            .children
+            .iter()
+            .copied()
+            .filter(|&child| {
+                let Some(element) = inner.get_node(child).and_then(|node| node.element_data())
+                else {
+                    return false;
+                };
+                &*element.name.local == name
+                    && attributes.iter().all(|(key, value)| {
+                        element
+                            .attrs()
+                            .iter()
+                            .any(|attr| &*attr.name.local == key && &attr.value == value)
+                    })
+            })
+            .collect();
+
+        let mut mutr = inner.mutate();
+        for node_id in matching {
+            mutr.remove_node(node_id);
+        }
+    }
+
     pub(crate) fn flush_queued_mounted_events(&mut self) {
         let mut queued_mounted_events = mem::take(&mut self.vdom_state.queued_mounted_events);
         for element_id in queued_mounted_events.drain(..) {
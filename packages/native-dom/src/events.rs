@@ -230,10 +230,22 @@ impl RenderedElementBacking for NodeHandle {
 
     fn scroll(
         &self,
-        _coordinates: PixelsVector2D,
+        coordinates: PixelsVector2D,
         _behavior: ScrollBehavior,
     ) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
-        Box::pin(async { Err(MountedError::NotSupported) })
+        let mut doc = self.doc_mut();
+        let Some(node) = doc.get_node(self.node_id) else {
+            return self.node_not_exist_err();
+        };
+        // Scrolling is relative to the current offset. Blitz doesn't animate scrolling, so every scroll is instant
+        let offset = node.scroll_offset;
+        doc.scroll_node_by(
+            self.node_id,
+            offset.x - coordinates.x,
+            offset.y - coordinates.y,
+            |_| {},
+        );
+        Box::pin(async { Ok(()) })
     }
 
     fn set_focus(&self, focus: bool) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
@@ -40,6 +40,20 @@ impl Document for DioxusNativeDocument {
         ));
     }
 
+    fn remove_head_element(&self, name: &str, attributes: &[(&str, String)]) {
+        let window = self.window;
+        self.proxy.send_event(BlitzShellEvent::embedder_event(
+            DioxusNativeEvent::RemoveHeadElement {
+                name: name.to_string(),
+                attributes: attributes
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.clone()))
+                    .collect(),
+                window,
+            },
+        ));
+    }
+
     fn set_title(&self, title: String) {
         self.create_head_element("title", &[], Some(title));
     }
@@ -29,6 +29,13 @@ pub enum DioxusNativeEvent {
         attributes: Vec<(String, String)>,
         contents: Option<String>,
     },
+
+    /// Remove the head elements with this tag name that have all of the attributes
+    RemoveHeadElement {
+        window: WindowId,
+        name: String,
+        attributes: Vec<(String, String)>,
+    },
 }
 
 pub struct DioxusNativeApplication {
@@ -81,6 +88,17 @@ impl DioxusNativeApplication {
                 dioxus_devtools::DevserverMsg::FullReloadStart => {}
                 dioxus_devtools::DevserverMsg::FullReloadFailed => {}
                 dioxus_devtools::DevserverMsg::FullReloadCommand => {}
+                msg @ (dioxus_devtools::DevserverMsg::SetProfiling(_)
+                | dioxus_devtools::DevserverMsg::SetReactiveGraphRecording(_)
+                | dioxus_devtools::DevserverMsg::QueryReactiveGraph) => {
+                    for window in self.inner.windows.values_mut() {
+                        let doc = window.downcast_doc_mut::<DioxusDocument>();
+                        let runtime = doc.vdom.runtime();
+                        if let Some(reply) = dioxus_devtools::handle_runtime_msg(&runtime, msg) {
+                            dioxus_devtools::send(reply);
+                        }
+                    }
+                }
                 _ => {}
             },
 
@@ -97,6 +115,18 @@ impl DioxusNativeApplication {
                 }
             }
 
+            DioxusNativeEvent::RemoveHeadElement {
+                name,
+                attributes,
+                window,
+            } => {
+                if let Some(window) = self.inner.windows.get_mut(window) {
+                    let doc = window.downcast_doc_mut::<DioxusDocument>();
+                    doc.remove_head_element(name, attributes);
+                    window.poll();
+                }
+            }
+
             // Suppress unused variable warning
             #[cfg(not(all(feature = "hot-reload", debug_assertions)))]
             #[allow(unreachable_patterns)]
@@ -78,3 +78,16 @@ test("onmounted", async ({ page }) => {
   const mountedDiv = page.locator("div.onmounted-div");
   await expect(mountedDiv).toHaveText("onmounted was called 1 times");
 });
+
+test("portals", async ({ page }) => {
+  await page.goto("http://127.0.0.1:3030");
+  // The children of the portal are rendered inside of the target instead of where the portal is
+  const button = page.locator("div#portal-source + div > button#portal-button");
+  await expect(button).toHaveText("Portal clicked 0 times");
+  await expect(page.locator("#portal-button")).toHaveCount(1);
+  await expect(page.locator("div#portal-source button")).toHaveCount(0);
+
+  // Events still reach the children after they were moved into the target
+  await button.click();
+  await expect(button).toHaveText("Portal clicked 1 times");
+});
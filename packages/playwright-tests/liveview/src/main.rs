@@ -21,6 +21,7 @@ fn app() -> Element {
         input { value: "hello input" }
         div { class: "style-div", color: "red", "colored text" }
         OnMounted {}
+        Portals {}
     }
 }
 
@@ -38,6 +39,22 @@ fn OnMounted() -> Element {
     }
 }
 
+#[component]
+fn Portals() -> Element {
+    let mut count = use_signal(|| 0);
+    rsx! {
+        div {
+            id: "portal-source",
+            overflow: "hidden",
+            Portal {
+                target: "portal-target",
+                button { id: "portal-button", onclick: move |_| count += 1, "Portal clicked {count} times" }
+            }
+        }
+        PortalTarget { name: "portal-target" }
+    }
+}
+
 #[tokio::main]
 async fn main() {
     _ = dioxus::logger::init(Level::DEBUG);
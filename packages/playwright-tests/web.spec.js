@@ -199,3 +199,16 @@ test("select multiple", async ({ page }) => {
   await dynamicSelect.selectOption(["1", "2"]);
   await expect(dynamicSelect).toHaveValues(["1", "2"]);
 });
+
+test("portals", async ({ page }) => {
+  await page.goto("http://localhost:9990");
+  // The children of the portal are rendered inside of the target instead of where the portal is
+  const button = page.locator("div#portal-source + div > button#portal-button");
+  await expect(button).toHaveText("Portal clicked 0 times");
+  await expect(page.locator("#portal-button")).toHaveCount(1);
+  await expect(page.locator("div#portal-source button")).toHaveCount(0);
+
+  // Events still reach the children after they were moved into the target
+  await button.click();
+  await expect(button).toHaveText("Portal clicked 1 times");
+});
@@ -65,6 +65,23 @@ fn app() -> Element {
         DocumentElements {}
         MergeStyles {}
         SelectMultiple {}
+        Portals {}
+    }
+}
+
+#[component]
+fn Portals() -> Element {
+    let mut count = use_signal(|| 0);
+    rsx! {
+        div {
+            id: "portal-source",
+            overflow: "hidden",
+            Portal {
+                target: "portal-target",
+                button { id: "portal-button", onclick: move |_| count += 1, "Portal clicked {count} times" }
+            }
+        }
+        PortalTarget { name: "portal-target" }
     }
 }
 
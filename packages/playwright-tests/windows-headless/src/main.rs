@@ -19,5 +19,22 @@ fn app() -> Element {
             id: "increment-button",
             onclick: move |_| count += 1, "Up high!"
         }
+        Portals {}
+    }
+}
+
+#[component]
+fn Portals() -> Element {
+    let mut count = use_signal(|| 0);
+    rsx! {
+        div {
+            id: "portal-source",
+            overflow: "hidden",
+            Portal {
+                target: "portal-target",
+                button { id: "portal-button", onclick: move |_| count += 1, "Portal clicked {count} times" }
+            }
+        }
+        PortalTarget { name: "portal-target" }
     }
 }
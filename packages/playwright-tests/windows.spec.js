@@ -35,3 +35,14 @@ test("button click", async ({ page }) => {
   await expect(main).toContainText("High-five counter: 1");
 });
 
+
+test("portals", async ({ page }) => {
+  // The children of the portal are rendered inside of the target instead of where the portal is
+  const button = page.locator("div#portal-source + div > button#portal-button");
+  await expect(button).toHaveText("Portal clicked 0 times");
+  await expect(page.locator("div#portal-source button")).toHaveCount(0);
+
+  // Events still reach the children after they were moved into the target
+  await button.click();
+  await expect(button).toHaveText("Portal clicked 1 times");
+});
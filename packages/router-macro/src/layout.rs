@@ -2,7 +2,9 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Path;
 
+use crate::lazy::{Lazy, SplitComponent};
 use crate::nest::{Nest, NestId};
+use crate::segment::RouteSegment;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct LayoutId(pub usize);
@@ -11,15 +13,47 @@ pub struct LayoutId(pub usize);
 pub struct Layout {
     pub comp: Path,
     pub active_nests: Vec<NestId>,
+    pub lazy: Option<Lazy>,
 }
 
 impl Layout {
     pub fn routable_match(&self, nests: &[Nest]) -> TokenStream {
-        let comp_name = &self.comp;
-        let dynamic_segments = self
+        if let Some(split) = self.split_component(nests) {
+            let dynamic_segments = self.dynamic_segments(nests);
+            return split.render(quote! { (#(#dynamic_segments,)*) });
+        }
+
+        self.render(nests)
+    }
+
+    /// The layout split into its own wasm module if it is lazy. The dynamic segments of the nests it is in are passed
+    /// through the module boundary as a tuple.
+    pub fn split_component(&self, nests: &[Nest]) -> Option<SplitComponent<'_>> {
+        let lazy = self.lazy.as_ref()?;
+        let comp_name = &self.comp.segments.last()?.ident;
+        let dynamic_segments = self.dynamic_segments(nests);
+        let types = self
             .active_nests
             .iter()
-            .flat_map(|id| nests[id.0].dynamic_segments());
+            .flat_map(|id| nests[id.0].segments.iter())
+            .filter_map(|seg| match seg {
+                RouteSegment::Dynamic(_, ty) | RouteSegment::CatchAll(_, ty) => Some(ty),
+                RouteSegment::Static(_) => None,
+            });
+
+        Some(SplitComponent {
+            name: format!("Layout{comp_name}"),
+            span: comp_name.span(),
+            args_ty: quote! { (#(#types,)*) },
+            args_pat: quote! { (#(#dynamic_segments,)*) },
+            render: self.render(nests),
+            lazy: Some(lazy),
+        })
+    }
+
+    fn render(&self, nests: &[Nest]) -> TokenStream {
+        let comp_name = &self.comp;
+        let dynamic_segments = self.dynamic_segments(nests);
 
         quote! {
             rsx! {
@@ -27,6 +61,12 @@ impl Layout {
             }
         }
     }
+
+    fn dynamic_segments<'a>(&'a self, nests: &'a [Nest]) -> impl Iterator<Item = TokenStream> + 'a {
+        self.active_nests
+            .iter()
+            .flat_map(|id| nests[id.0].dynamic_segments())
+    }
 }
 
 impl Layout {
@@ -35,6 +75,19 @@ impl Layout {
         let _ = input.parse::<syn::Token![,]>();
         let comp: Path = input.parse()?;
 
-        Ok(Self { comp, active_nests })
+        // And finally the options
+        let mut lazy = None;
+        if input.parse::<syn::Token![,]>().is_ok() {
+            lazy = Lazy::parse_option(input)?;
+            if lazy.is_none() {
+                return Err(input.error("Expected `lazy`"));
+            }
+        }
+
+        Ok(Self {
+            comp,
+            active_nests,
+            lazy,
+        })
     }
 }
@@ -0,0 +1,225 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::ParseStream;
+use syn::{Ident, Path, Token};
+
+/// The `lazy` option of a route or layout. Lazy components are split into their own wasm module when the `wasm-split`
+/// feature is enabled.
+#[derive(Debug, Clone, Default)]
+pub struct Lazy {
+    /// The component to render while the module is downloading. If this is not set, the component suspends.
+    pub fallback: Option<Path>,
+}
+
+impl Lazy {
+    /// Parse `lazy` or `lazy(fallback = Component)` if it is the next option.
+    pub fn parse_option(input: ParseStream) -> syn::Result<Option<Self>> {
+        let fork = input.fork();
+        match fork.parse::<Ident>() {
+            Ok(ident)
+                if ident == "lazy"
+                    && (fork.is_empty()
+                        || fork.peek(Token![,])
+                        || fork.peek(syn::token::Paren)) => {}
+            _ => return Ok(None),
+        }
+        input.parse::<Ident>()?;
+
+        let mut lazy = Self::default();
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            let key: Ident = content.parse()?;
+            if key != "fallback" {
+                return Err(syn::Error::new(
+                    key.span(),
+                    "Expected `fallback = Component`",
+                ));
+            }
+            content.parse::<Token![=]>()?;
+            lazy.fallback = Some(content.parse()?);
+        }
+
+        Ok(Some(lazy))
+    }
+}
+
+/// A component that may be split into its own wasm module.
+pub struct SplitComponent<'a> {
+    /// A unique name for the component in the router enum
+    pub name: String,
+    /// The span used to make the names of the generated items unique
+    pub span: proc_macro2::Span,
+    /// The type of the arguments that are passed through the wasm module boundary
+    pub args_ty: TokenStream,
+    /// A pattern that destructures the arguments into the bindings `render` uses
+    pub args_pat: TokenStream,
+    /// Renders the component
+    pub render: TokenStream,
+    /// `None` if the component is only split when every route of the router is split
+    pub lazy: Option<&'a Lazy>,
+}
+
+struct SplitNames {
+    module_name: String,
+    comp_name: Ident,
+    loader: Ident,
+    loader_component: Ident,
+}
+
+impl SplitComponent<'_> {
+    fn names(&self) -> SplitNames {
+        use sha2::Digest;
+        let name = &self.name;
+        let unique_identifier = base16::encode_lower(
+            &sha2::Sha256::digest(format!("{name} {span:?}", span = self.span))[..16],
+        );
+        SplitNames {
+            module_name: format_ident!("module{}{unique_identifier}", name).to_string(),
+            comp_name: format_ident!("route{}{unique_identifier}", name),
+            loader: format_ident!("LOADER_{}{unique_identifier}", name),
+            loader_component: format_ident!("Loader{}{unique_identifier}", name),
+        }
+    }
+
+    fn expr_macro(&self) -> TokenStream {
+        match self.lazy {
+            Some(_) => quote!(dioxus::config_macros::maybe_wasm_split!),
+            None => quote!(dioxus::config_macros::maybe_wasm_split_routes!),
+        }
+    }
+
+    /// The items that split the component into its own wasm module. These are emitted next to the routable impl so
+    /// both `render` and `preload` can refer to the loader.
+    pub fn items(&self) -> TokenStream {
+        /*
+        The implementation of this is pretty gnarly/gross.
+
+        We achieve the bundle splitting by wrapping the incoming function in a new component
+        that suspends based on an internal lazy loader. This lets us use suspense features
+        without breaking the rules of hooks. The router derive is quite complex so this shoves
+        the complexity towards the "leaf" of the codegen rather to its core. In the future though,
+        we should think about restructuring the router macro completely since its codegen
+        makes up nearly 30-40% of the binary size in the dioxus docsite.
+        */
+        let Self {
+            args_ty,
+            args_pat,
+            render,
+            ..
+        } = self;
+        let SplitNames {
+            module_name,
+            comp_name,
+            loader,
+            loader_component,
+        } = self.names();
+        let items_macro = match self.lazy {
+            Some(_) => quote!(dioxus::config_macros::maybe_wasm_split_items!),
+            None => quote!(dioxus::config_macros::maybe_wasm_split_routes_items!),
+        };
+
+        quote! {
+            #items_macro {
+                #[allow(non_snake_case)]
+                fn #comp_name(args: #args_ty) -> Element {
+                    #[allow(irrefutable_let_patterns)]
+                    let #args_pat = args else {
+                        unreachable!()
+                    };
+                    #render
+                }
+
+                #[allow(non_upper_case_globals)]
+                static #loader: wasm_split::LazyLoader<#args_ty, Element> =
+                    wasm_split::lazy_loader!(extern #module_name fn #comp_name(props: #args_ty) -> Element);
+
+                #[component]
+                fn #loader_component(args: NoPartialEq<#args_ty>) -> Element {
+                    use_resource(|| async move { #loader.load().await }).suspend()?;
+                    #loader.call(args.0).unwrap()
+                }
+            }
+        }
+    }
+
+    /// Render the component, loading its module first if it is split. `args` creates the arguments of the component
+    /// from the bindings of the surrounding match arm, which `render` uses directly if the component is not split.
+    pub fn render(&self, args: TokenStream) -> TokenStream {
+        let SplitNames {
+            loader_component, ..
+        } = self.names();
+        let expr_macro = self.expr_macro();
+        let render = &self.render;
+
+        let loader = match self.lazy.and_then(|lazy| lazy.fallback.as_ref()) {
+            Some(fallback) => quote! {
+                rsx! {
+                    dioxus_core::SuspenseBoundary {
+                        fallback: |_: dioxus_core::SuspenseContext| rsx! { #fallback {} },
+                        #loader_component { args: NoPartialEq(#args) }
+                    }
+                }
+            },
+            None => quote! {
+                rsx! {
+                    #loader_component { args: NoPartialEq(#args) }
+                }
+            },
+        };
+
+        quote! {
+            #expr_macro {
+                if wasm_split {
+                    {
+                        #loader
+                    }
+                } else {
+                    {
+                        #render
+                    }
+                }
+            }
+        }
+    }
+
+    /// A statement that downloads the module of the component if it is split.
+    pub fn preload(&self) -> TokenStream {
+        let SplitNames { loader, .. } = self.names();
+        let expr_macro = self.expr_macro();
+
+        quote! {
+            #expr_macro {
+                if wasm_split {
+                    {
+                        #loader.load().await;
+                    }
+                } else {
+                    {}
+                }
+            }
+        }
+    }
+}
+
+/// The helper every split component wraps its arguments in so the loader component always re-renders with the
+/// latest arguments.
+pub fn no_partial_eq() -> TokenStream {
+    quote! {
+        dioxus::config_macros::maybe_wasm_split_items! {
+            struct NoPartialEq<T>(T);
+
+            impl<T: Clone> Clone for NoPartialEq<T> {
+                fn clone(&self) -> Self {
+                    Self(self.0.clone())
+                }
+            }
+
+            impl<T> PartialEq for NoPartialEq<T> {
+                fn eq(&self, _other: &Self) -> bool {
+                    false
+                }
+            }
+        }
+    }
+}
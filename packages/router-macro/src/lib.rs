@@ -11,7 +11,9 @@ use quote::{ToTokens, format_ident, quote};
 use redirect::Redirect;
 use route::{Route, RouteType};
 use segment::RouteSegment;
-use syn::{Ident, Token, Type, parse::ParseStream, parse_macro_input};
+use syn::{
+    Ident, LitStr, Token, Type, parse::ParseStream, parse_macro_input, punctuated::Punctuated,
+};
 
 use proc_macro2::TokenStream as TokenStream2;
 
@@ -19,7 +21,10 @@ use crate::{layout::LayoutId, route_tree::ParseRouteTree};
 
 mod hash;
 mod layout;
+mod lazy;
+mod metadata;
 mod nest;
+mod outlet;
 mod query;
 mod redirect;
 mod route;
@@ -94,9 +99,13 @@ mod segment;
 ///
 /// # `#[route("path", component)]`
 ///
-/// The `#[route]` attribute is used to define a route. It takes up to 2 parameters:
+/// The `#[route]` attribute is used to define a route. It takes up to 6 parameters:
 /// - `path`: The path to the enum variant (relative to the parent nest)
 /// - (optional) `component`: The component to render when the route is matched. If not specified, the name of the variant is used
+/// - (optional) `lazy`: Split the component into its own wasm module. See [`lazy`](#lazy)
+/// - (optional) `intercept`: Render the route over the current page when navigating to it from inside the app. See [`intercept`](#intercept)
+/// - (optional) `title`: The title of the page. See [`title` and `meta`](#title-and-meta)
+/// - (optional) `meta`: The meta tags of the page. See [`title` and `meta`](#title-and-meta)
 ///
 /// Routes are the most basic attribute. They allow you to define a route and the component to render when the route is matched. The component must take all dynamic parameters of the route and all parent nests.
 /// The next variant will be tied to the component. If you link to that variant, the component will be rendered.
@@ -190,8 +199,9 @@ mod segment;
 ///
 /// # `#[layout(component)]`
 ///
-/// The `#[layout]` attribute is used to define a layout. It takes 1 parameter:
+/// The `#[layout]` attribute is used to define a layout. It takes up to 2 parameters:
 /// - `component`: The component to render when the route is matched. If not specified, the name of the variant is used
+/// - (optional) `lazy`: Split the layout and every route in it into their own wasm modules. See [`lazy`](#lazy)
 ///
 /// The layout component allows you to wrap all children of the layout in a component. The child routes are rendered in the Outlet of the layout component. The layout component must take all dynamic parameters of the nests it is nested in.
 ///
@@ -239,10 +249,180 @@ mod segment;
 /// # #[component]
 /// # fn Home() -> Element { VNode::empty() }
 /// ```
+///
+/// # `#[outlet("name", component)]`
+///
+/// The `#[outlet]` attribute fills a named outlet with a component while the route is active. It takes 2 parameters:
+/// - `name`: The name of the outlet, like `Outlet::<Route> { name: "sidebar" }`
+/// - `component`: The component to render in the outlet. It takes the same parameters as the component of the route
+///
+/// A route can fill any number of named outlets next to the main outlet its component is rendered in. Named outlets that the current route doesn't fill render nothing.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, Routable)]
+/// enum Route {
+///     #[layout(Mail)]
+///         // The sidebar outlet of the Mail layout shows the Folders component on this route
+///         #[route("/:folder")]
+///         #[outlet("sidebar", Folders)]
+///         Inbox { folder: String },
+/// }
+/// # #[component]
+/// # fn Mail() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn Inbox(folder: String) -> Element { VNode::empty() }
+/// # #[component]
+/// # fn Folders(folder: String) -> Element { VNode::empty() }
+/// ```
+///
+/// # `#[lang_prefix("en", "fr")]`
+///
+/// The `#[lang_prefix]` attribute goes on the enum itself. It takes the languages the app supports.
+///
+/// Every route can then be matched with an optional segment for one of those languages in front of it, like `/fr/blog`.
+/// Routes that match without the prefix take precedence, and paths that start with any other segment are parsed as
+/// usual. When a route is displayed, the language of the current url is added back in front
+/// of it so links stay in the same language. See the `dioxus_router::lang_prefix` module for helpers to read and switch
+/// the language.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, Routable)]
+/// #[lang_prefix("en", "fr")]
+/// enum Route {
+///     // This is at /, /en and /fr
+///     #[route("/")]
+///     Home {},
+///     // This is at /blog, /en/blog and /fr/blog
+///     #[route("/blog")]
+///     Blog {},
+/// }
+/// # #[component]
+/// # fn Home() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn Blog() -> Element { VNode::empty() }
+/// ```
+///
+/// # `lazy`
+///
+/// Routes and layouts can be marked as `lazy` to split their component into its own wasm module that is only
+/// downloaded when the route is rendered. Every route in a lazy layout is lazy too. Pass a `fallback` component to
+/// render while the module is downloading, otherwise the route suspends until it is ready.
+///
+/// Splitting only happens in web builds with the `wasm-split` feature of dioxus enabled, like `dx build --wasm-split`.
+/// To split every route without marking them, enable the `wasm-split` feature of the router.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, Routable)]
+/// enum Route {
+///     #[route("/")]
+///     Home {},
+///     // The settings page is downloaded the first time the user navigates to it
+///     #[route("/settings", lazy(fallback = Loading))]
+///     Settings {},
+///     // The admin frame and every route in it are split
+///     #[layout(AdminFrame, lazy)]
+///         #[route("/admin")]
+///         Admin {},
+/// }
+/// # #[component]
+/// # fn Home() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn Settings() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn Loading() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn AdminFrame() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn Admin() -> Element { VNode::empty() }
+/// ```
+///
+/// # `intercept`
+///
+/// Routes marked as `intercept` are rendered over the current page when the user navigates to them from inside the
+/// app, like a photo that opens in a modal over the feed. The page the user navigated from stays rendered in the main
+/// outlets and the route is rendered in the named outlet `"modal"`, or the outlet passed with
+/// `intercept(outlet = "name")`. When the page is loaded directly or the user navigates back to it, the route is
+/// rendered as a full page. Use `RouterContext::is_intercepted` to check which way the route is rendered.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, Routable)]
+/// enum Route {
+///     #[layout(Frame)]
+///         #[route("/")]
+///         Feed {},
+///         // Opening a photo from the feed renders it in the modal outlet over the feed
+///         #[route("/photo/:id", intercept)]
+///         Photo { id: u32 },
+/// }
+///
+/// #[component]
+/// fn Frame() -> Element {
+///     rsx! {
+///         Outlet::<Route> {}
+///         Outlet::<Route> { name: "modal" }
+///     }
+/// }
+/// # #[component]
+/// # fn Feed() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn Photo(id: u32) -> Element { VNode::empty() }
+/// ```
+///
+/// # `title` and `meta`
+///
+/// The `title = "..."` and `meta(name = "content", ...)` options of the `#[route]` attribute set the metadata of the
+/// route. Both are format strings that can use the fields of the route. The router sets the title of the page and
+/// the meta tags in the head to the metadata of the current route while rendering on the server and every time the
+/// route changes. Names of meta tags that are not valid identifiers like `og:title` can be written as strings.
+///
+/// The metadata is available with `Routable::metadata`. The titles of the current route and the index routes of the
+/// nests it is in are available with `use_breadcrumbs`. Routes without a title use the title of the closest of those
+/// index routes with a title.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, Routable)]
+/// enum Route {
+///     #[route("/", title = "Home")]
+///     Home {},
+///     #[route("/users/:id", title = "User {id}", meta(description = "The profile of user {id}", "og:type" = "profile"))]
+///     User { id: u32 },
+/// }
+///
+/// let metadata = Route::User { id: 1 }.metadata();
+/// assert_eq!(metadata.title.as_deref(), Some("User 1"));
+/// assert_eq!(
+///     metadata.meta,
+///     [("description", "The profile of user 1".to_string()), ("og:type", "profile".to_string())]
+/// );
+/// # #[component]
+/// # fn Home() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn User(id: u32) -> Element { VNode::empty() }
+/// ```
 #[doc(alias = "route")]
 #[proc_macro_derive(
     Routable,
-    attributes(route, nest, end_nest, layout, end_layout, redirect, child)
+    attributes(
+        route,
+        nest,
+        end_nest,
+        layout,
+        end_layout,
+        redirect,
+        child,
+        outlet,
+        lang_prefix
+    )
 )]
 pub fn routable(input: TokenStream) -> TokenStream {
     let routes_enum = parse_macro_input!(input as syn::ItemEnum);
@@ -277,12 +457,43 @@ struct RouteEnum {
     nests: Vec<Nest>,
     layouts: Vec<Layout>,
     site_map: Vec<SiteMapSegment>,
+    lang_prefix: Option<Vec<LitStr>>,
 }
 
 impl RouteEnum {
     fn parse(data: syn::ItemEnum) -> syn::Result<Self> {
         let name = &data.ident;
 
+        let mut lang_prefix = None;
+        for attr in &data.attrs {
+            if attr.path().is_ident("lang_prefix") {
+                let languages = attr
+                    .parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)
+                    .map_err(|err| {
+                        syn::Error::new(
+                            err.span(),
+                            "Expected the supported languages, like `#[lang_prefix(\"en\", \"fr\")]`",
+                        )
+                    })?;
+                for language in &languages {
+                    let value = language.value();
+                    if value.is_empty() || value.contains(['/', '?', '#']) {
+                        return Err(syn::Error::new(
+                            language.span(),
+                            "Languages must be a single path segment, like \"en\" or \"fr-CA\"",
+                        ));
+                    }
+                }
+                if languages.is_empty() {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "Expected at least one supported language",
+                    ));
+                }
+                lang_prefix = Some(languages.into_iter().collect());
+            }
+        }
+
         let mut site_map = Vec::new();
         let mut site_map_stack: Vec<Vec<SiteMapSegment>> = Vec::new();
 
@@ -409,7 +620,12 @@ impl RouteEnum {
             let mut active_layouts = layout_stack.clone();
             active_layouts.retain(|&id| !excluded.contains(&id));
 
-            let route = Route::parse(active_nests, active_layouts, variant.clone())?;
+            let mut route = Route::parse(active_nests, active_layouts, variant.clone())?;
+
+            // Every route in a lazy layout is lazy too
+            if route.lazy.is_none() && route.layouts.iter().any(|id| layouts[id.0].lazy.is_some()) {
+                route.lazy = Some(Default::default());
+            }
 
             // add the route to the site map
             let mut segment = SiteMapSegment::new(&route.segments);
@@ -474,6 +690,7 @@ impl RouteEnum {
             nests,
             layouts,
             site_map,
+            lang_prefix,
         };
 
         Ok(myself)
@@ -490,9 +707,30 @@ impl RouteEnum {
 
         let name = &self.name;
 
+        // Render the route without the prefix, then add the language of the current url in front of it
+        let write_lang_prefix = self.lang_prefix.is_some().then(|| {
+            quote! {
+                if let Some(language) = <Self as dioxus_router::routable::Routable>::current_language() {
+                    let route = dioxus_router::lang_prefix::with_route_language(None, || self.to_string());
+                    return match route.as_str() {
+                        "/" => write!(f, "/{}", language),
+                        route => write!(f, "/{}{}", language, route),
+                    };
+                }
+            }
+        });
+
+        // The prefix is rendered with the language overridden to `None`, so the display impl only recurses once
+        let allow_recursion = self
+            .lang_prefix
+            .is_some()
+            .then(|| quote! { #[allow(clippy::recursive_format_impl)] });
+
         quote! {
             impl std::fmt::Display for #name {
+                #allow_recursion
                 fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    #write_lang_prefix
                     #[allow(unused)]
                     match self {
                         #(#display_match)*
@@ -512,6 +750,12 @@ impl RouteEnum {
             let route = tree.get(id).unwrap();
             route.to_tokens(&self.nests, &tree, self.name.clone(), error_name.clone())
         });
+        let parse = match &self.lang_prefix {
+            Some(languages) => quote! {
+                dioxus_router::lang_prefix::parse_with_lang_prefix(s, &[#(#languages),*], parse_route)
+            },
+            None => quote! { parse_route(s) },
+        };
 
         quote! {
             impl<'a> ::core::convert::TryFrom<&'a str> for #name {
@@ -526,40 +770,47 @@ impl RouteEnum {
                 type Err = dioxus_router::routable::RouteParseError<#error_name>;
 
                 fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
-                    let route = s;
-                    let (route, raw_hash) = route.split_once('#').unwrap_or((route, ""));
-                    let (route, raw_query) = route.split_once('?').unwrap_or((route, ""));
-                    // Remove any trailing slashes. We parse /route/ and /route in the same way
-                    // Note: we don't use trim because it includes more code
-                    let route = route.strip_suffix('/').unwrap_or(route);
-                    let query = dioxus_router::exports::percent_encoding::percent_decode_str(raw_query)
-                        .decode_utf8()
-                        .unwrap_or(raw_query.into());
-                    let hash = dioxus_router::exports::percent_encoding::percent_decode_str(raw_hash)
+                    #parse
+                }
+            }
+
+            fn parse_route(s: &str) -> ::std::result::Result<#name, dioxus_router::routable::RouteParseError<#error_name>> {
+                #[allow(unused_imports)]
+                use ::std::str::FromStr;
+
+                let route = s;
+                let (route, raw_hash) = route.split_once('#').unwrap_or((route, ""));
+                let (route, raw_query) = route.split_once('?').unwrap_or((route, ""));
+                // Remove any trailing slashes. We parse /route/ and /route in the same way
+                // Note: we don't use trim because it includes more code
+                let route = route.strip_suffix('/').unwrap_or(route);
+                let query = dioxus_router::exports::percent_encoding::percent_decode_str(raw_query)
+                    .decode_utf8()
+                    .unwrap_or(raw_query.into());
+                let hash = dioxus_router::exports::percent_encoding::percent_decode_str(raw_hash)
+                    .decode_utf8()
+                    .unwrap_or(raw_hash.into());
+                let mut segments = route.split('/').map(|s| {
+                    dioxus_router::exports::percent_encoding::percent_decode_str(s)
                         .decode_utf8()
-                        .unwrap_or(raw_hash.into());
-                    let mut segments = route.split('/').map(|s| {
-                        dioxus_router::exports::percent_encoding::percent_decode_str(s)
-                            .decode_utf8()
-                            .unwrap_or(s.into())
+                        .unwrap_or(s.into())
+                });
+                // skip the first empty segment
+                if s.starts_with('/') {
+                    let _ = segments.next();
+                } else {
+                    // if this route does not start with a slash, it is not a valid route
+                    return Err(dioxus_router::routable::RouteParseError {
+                        attempted_routes: Vec::new(),
                     });
-                    // skip the first empty segment
-                    if s.starts_with('/') {
-                        let _ = segments.next();
-                    } else {
-                        // if this route does not start with a slash, it is not a valid route
-                        return Err(dioxus_router::routable::RouteParseError {
-                            attempted_routes: Vec::new(),
-                        });
-                    }
-                    let mut errors = Vec::new();
+                }
+                let mut errors = Vec::new();
 
-                    #(#tokens)*
+                #(#tokens)*
 
-                    Err(dioxus_router::routable::RouteParseError {
-                        attempted_routes: errors,
-                    })
-                }
+                Err(dioxus_router::routable::RouteParseError {
+                    attempted_routes: errors,
+                })
             }
         }
     }
@@ -667,20 +918,122 @@ impl RouteEnum {
         let site_map = &self.site_map;
 
         let mut matches = Vec::new();
+        let mut outlet_matches = Vec::new();
+        let mut intercept_matches = Vec::new();
+        let mut metadata_matches = Vec::new();
+        let mut ancestors_matches = Vec::new();
+        let mut preload_matches = Vec::new();
+        let mut split_items = Vec::new();
+
+        let routes: Vec<&Route> = self
+            .endpoints
+            .iter()
+            .filter_map(|endpoint| match endpoint {
+                RouteEndpoint::Route(route) => Some(route),
+                RouteEndpoint::Redirect(_) => None,
+            })
+            .collect();
 
         // Collect all routes matches
         for route in &self.endpoints {
             if let RouteEndpoint::Route(route) = route {
                 matches.push(route.routable_match(&self.layouts, &self.nests, name));
+                outlet_matches.push(route.outlet_match(name));
+                intercept_matches.extend(route.intercept_match());
+                metadata_matches.extend(route.metadata_match());
+                ancestors_matches.extend(route.ancestors_match(&routes, &self.nests));
+                preload_matches.push(route.preload_match(&self.layouts, &self.nests, name));
+                split_items.extend(route.split_items(name));
             }
         }
+        split_items.extend(
+            self.layouts
+                .iter()
+                .filter_map(|layout| layout.split_component(&self.nests))
+                .map(|split| split.items()),
+        );
+        let no_partial_eq = lazy::no_partial_eq();
+
+        let current_language = self.lang_prefix.as_ref().map(|languages| {
+            quote! {
+                fn current_language() -> Option<String> {
+                    dioxus_router::lang_prefix::current_lang_prefix(&[#(#languages),*], |route| parse_route(route).is_ok())
+                }
+            }
+        });
+
+        // Only routers with named outlets or intercepting routes override the default implementations
+        let render_outlet = outlet_matches
+            .iter()
+            .any(|tokens| !tokens.is_empty())
+            .then(|| {
+                quote! {
+                    fn render_outlet(&self, name: &str) -> dioxus_core::Element {
+                        let myself = self.clone();
+                        match (name, myself) {
+                            #(#outlet_matches)*
+                            _ => VNode::empty()
+                        }
+                    }
+                }
+            });
+        let intercept_outlet = (!intercept_matches.is_empty()).then(|| {
+            quote! {
+                fn intercept_outlet(&self) -> Option<&'static str> {
+                    #[allow(unreachable_patterns)]
+                    match self {
+                        #(#intercept_matches)*
+                        _ => None
+                    }
+                }
+            }
+        });
+
+        let metadata = (!metadata_matches.is_empty()).then(|| {
+            quote! {
+                #[allow(clippy::useless_format)]
+                fn metadata(&self) -> dioxus_router::routable::RouteMetadata {
+                    #[allow(unreachable_patterns)]
+                    match self {
+                        #(#metadata_matches)*
+                        _ => Default::default()
+                    }
+                }
+            }
+        });
+
+        let ancestors = (!ancestors_matches.is_empty()).then(|| {
+            quote! {
+                fn ancestors(&self) -> Vec<Self> {
+                    #[allow(unreachable_patterns)]
+                    match self {
+                        #(#ancestors_matches)*
+                        _ => Vec::new()
+                    }
+                }
+            }
+        });
 
         quote! {
+            #(#split_items)*
+
+            #no_partial_eq
+
             impl dioxus_router::routable::Routable for #name where Self: Clone {
                 const SITE_MAP: &'static [dioxus_router::routable::SiteMapSegment] = &[
                     #(#site_map,)*
                 ];
 
+                #current_language
+
+                #render_outlet
+
+                #intercept_outlet
+
+                #metadata
+
+                #ancestors
+
                 fn render(&self, level: usize) -> dioxus_core::Element {
                     let myself = self.clone();
                     match (level, myself) {
@@ -688,6 +1041,12 @@ impl RouteEnum {
                         _ => VNode::empty()
                     }
                 }
+
+                fn preload(&self) -> ::std::pin::Pin<Box<dyn ::std::future::Future<Output = ()>>> {
+                    match self {
+                        #(#preload_matches)*
+                    }
+                }
             }
         }
     }
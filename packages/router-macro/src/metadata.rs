@@ -0,0 +1,85 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::ParseStream;
+use syn::{Ident, LitStr, Token};
+
+/// The `title = "..."` and `meta(name = "...")` options of a route. The values are format strings that can use the
+/// fields of the route.
+#[derive(Debug, Default)]
+pub struct RouteMetadata {
+    pub title: Option<LitStr>,
+    pub meta: Vec<(LitStr, LitStr)>,
+}
+
+impl RouteMetadata {
+    /// Parse `title = "..."` or `meta(name = "...", ...)` if it is the next option. Returns `false` if the next option
+    /// is something else.
+    pub fn parse_option(&mut self, input: ParseStream) -> syn::Result<bool> {
+        let fork = input.fork();
+        match fork.parse::<Ident>() {
+            Ok(ident) if ident == "title" && fork.peek(Token![=]) => {
+                input.parse::<Ident>()?;
+                input.parse::<Token![=]>()?;
+                let title: LitStr = input.parse()?;
+                if self.title.is_some() {
+                    return Err(syn::Error::new(title.span(), "The title is already set"));
+                }
+                self.title = Some(title);
+                Ok(true)
+            }
+            Ok(ident) if ident == "meta" && fork.peek(syn::token::Paren) => {
+                input.parse::<Ident>()?;
+                let content;
+                syn::parenthesized!(content in input);
+                while !content.is_empty() {
+                    // Names like `og:title` are not valid identifiers, so they can be written as strings
+                    let name = if content.peek(LitStr) {
+                        content.parse::<LitStr>()?
+                    } else {
+                        let ident = content.parse::<Ident>()?;
+                        LitStr::new(&ident.to_string(), ident.span())
+                    };
+                    content.parse::<Token![=]>()?;
+                    let value: LitStr = content.parse()?;
+                    if self
+                        .meta
+                        .iter()
+                        .any(|(other, _)| other.value() == name.value())
+                    {
+                        return Err(syn::Error::new(name.span(), "This meta tag is already set"));
+                    }
+                    self.meta.push((name, value));
+
+                    if !content.is_empty() {
+                        content.parse::<Token![,]>()?;
+                    }
+                }
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.meta.is_empty()
+    }
+
+    /// An expression that formats the metadata. The fields of the route must be in scope.
+    pub fn construct(&self) -> TokenStream {
+        let title = match &self.title {
+            Some(title) => quote! { Some(format!(#title)) },
+            None => quote! { None },
+        };
+        let meta = self
+            .meta
+            .iter()
+            .map(|(name, value)| quote! { (#name, format!(#value)) });
+
+        quote! {
+            dioxus_router::routable::RouteMetadata {
+                title: #title,
+                meta: vec![#(#meta,)*],
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, LitStr, Path, Token};
+
+/// A `#[outlet("name", Component)]` attribute that fills a named outlet of the layouts of a route.
+#[derive(Debug)]
+pub struct NamedOutlet {
+    pub name: LitStr,
+    pub comp: Path,
+}
+
+impl Parse for NamedOutlet {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let comp = input.parse()?;
+
+        Ok(Self { name, comp })
+    }
+}
+
+/// The `intercept` option of a route. Intercepting routes are rendered in a named outlet over the current page when
+/// the user navigates to them from inside the app.
+#[derive(Debug)]
+pub struct Intercept {
+    /// The named outlet the route is rendered in. Defaults to `"modal"`
+    pub outlet: LitStr,
+}
+
+impl Intercept {
+    /// Parse `intercept` or `intercept(outlet = "name")` if it is the next option.
+    pub fn parse_option(input: ParseStream) -> syn::Result<Option<Self>> {
+        let fork = input.fork();
+        match fork.parse::<Ident>() {
+            Ok(ident)
+                if ident == "intercept"
+                    && (fork.is_empty()
+                        || fork.peek(Token![,])
+                        || fork.peek(syn::token::Paren)) => {}
+            _ => return Ok(None),
+        }
+        let ident = input.parse::<Ident>()?;
+
+        let mut outlet = LitStr::new("modal", ident.span());
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            let key: Ident = content.parse()?;
+            if key != "outlet" {
+                return Err(syn::Error::new(key.span(), "Expected `outlet = \"name\"`"));
+            }
+            content.parse::<Token![=]>()?;
+            outlet = content.parse()?;
+        }
+
+        Ok(Some(Self { outlet }))
+    }
+}
@@ -12,8 +12,11 @@ use proc_macro2::TokenStream as TokenStream2;
 use crate::hash::HashFragment;
 use crate::layout::Layout;
 use crate::layout::LayoutId;
+use crate::lazy::{Lazy, SplitComponent};
+use crate::metadata::RouteMetadata;
 use crate::nest::Nest;
 use crate::nest::NestId;
+use crate::outlet::{Intercept, NamedOutlet};
 use crate::query::QuerySegment;
 use crate::segment::RouteSegment;
 use crate::segment::create_error_type;
@@ -22,18 +25,43 @@ use crate::segment::parse_route_segments;
 struct RouteArgs {
     route: LitStr,
     comp_name: Option<Path>,
+    lazy: Option<Lazy>,
+    intercept: Option<Intercept>,
+    metadata: RouteMetadata,
 }
 
 impl Parse for RouteArgs {
     fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
         let route = input.parse::<LitStr>()?;
+        let mut comp_name = None;
+        let mut lazy = None;
+        let mut intercept = None;
+        let mut metadata = RouteMetadata::default();
+
+        while input.parse::<syn::Token![,]>().is_ok() {
+            if let Some(parsed) = Lazy::parse_option(input)? {
+                lazy = Some(parsed);
+            } else if let Some(parsed) = Intercept::parse_option(input)? {
+                intercept = Some(parsed);
+            } else if metadata.parse_option(input)? {
+                continue;
+            } else if comp_name.is_none()
+                && lazy.is_none()
+                && intercept.is_none()
+                && metadata.is_empty()
+            {
+                comp_name = input.parse().ok();
+            } else {
+                return Err(input.error("Expected `lazy`, `intercept`, `title` or `meta`"));
+            }
+        }
 
         Ok(RouteArgs {
             route,
-            comp_name: {
-                let _ = input.parse::<syn::Token![,]>();
-                input.parse().ok()
-            },
+            comp_name,
+            lazy,
+            intercept,
+            metadata,
         })
     }
 }
@@ -60,6 +88,10 @@ pub(crate) struct Route {
     pub hash: Option<HashFragment>,
     pub nests: Vec<NestId>,
     pub layouts: Vec<LayoutId>,
+    pub lazy: Option<Lazy>,
+    pub outlets: Vec<NamedOutlet>,
+    pub intercept: Option<Intercept>,
+    pub metadata: RouteMetadata,
     fields: Vec<(Ident, Type)>,
 }
 
@@ -75,6 +107,9 @@ impl Route {
             .find(|attr| attr.path().is_ident("route"));
         let route;
         let ty;
+        let mut lazy = None;
+        let mut intercept = None;
+        let mut metadata = RouteMetadata::default();
         let route_name = variant.ident.clone();
         match route_attr {
             Some(attr) => {
@@ -84,6 +119,9 @@ impl Route {
                     component: comp_name,
                 };
                 route = args.route.value();
+                lazy = args.lazy;
+                intercept = args.intercept;
+                metadata = args.metadata;
             }
             None => {
                 if let Some(route_attr) = variant
@@ -128,6 +166,31 @@ impl Route {
             }
         };
 
+        let mut outlets: Vec<NamedOutlet> = Vec::new();
+        for attr in &variant.attrs {
+            if attr.path().is_ident("outlet") {
+                let outlet: NamedOutlet = attr.parse_args()?;
+                if matches!(ty, RouteType::Child(_)) {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "Named outlets can only be filled by routes with a #[route(..)] attribute",
+                    ));
+                }
+                let duplicate = outlets
+                    .iter()
+                    .map(|outlet| &outlet.name)
+                    .chain(intercept.as_ref().map(|intercept| &intercept.outlet))
+                    .any(|name| name.value() == outlet.name.value());
+                if duplicate {
+                    return Err(syn::Error::new_spanned(
+                        &outlet.name,
+                        "This route already fills the outlet with this name",
+                    ));
+                }
+                outlets.push(outlet);
+            }
+        }
+
         let fields = match &variant.fields {
             syn::Fields::Named(fields) => fields
                 .named
@@ -161,6 +224,10 @@ impl Route {
             hash,
             nests,
             layouts,
+            lazy,
+            outlets,
+            intercept,
+            metadata,
             fields,
         })
     }
@@ -265,107 +332,233 @@ impl Route {
                     }
                 }
             }
-            RouteType::Leaf { component } => {
+            RouteType::Leaf { .. } => {
                 let dynamic_segments = self.dynamic_segments();
-                let dynamic_segments_from_route = self.dynamic_segments();
-
-
-                let component = quote_spanned! { name.span() =>
-                    #component
-                };
-
-                /*
-                The implementation of this is pretty gnarly/gross.
-
-                We achieve the bundle splitting by wrapping the incoming function in a new component
-                that suspends based on an internal lazy loader. This lets us use suspense features
-                without breaking the rules of hooks. The router derive is quite complex so this shoves
-                the complexity towards the "leaf" of the codegen rather to its core. In the future though,
-                we should think about restructuring the router macro completely since its codegen
-                makes up nearly 30-40% of the binary size in the dioxus docsite.
-                */
-                use sha2::Digest;
                 let dynamic_segments_receiver = self.dynamic_segments();
-                let dynamic_segments_from_route_ = self.dynamic_segments();
-                let dynamic_segments_from_route__ = self.dynamic_segments();
-                    let unique_identifier = base16::encode_lower(
-                    &sha2::Sha256::digest(format!("{name} {span:?}", span = name.span()))[..16],
-                );
-                let module_name = format_ident!("module{}{unique_identifier}", name).to_string();
-                let comp_name = format_ident!("route{}{unique_identifier}", name);
+                let render = self
+                    .split_component(router_name)
+                    .unwrap()
+                    .render(quote! { #router_name::#name { #(#dynamic_segments_receiver,)* } });
 
                 quote! {
                     #[allow(unused)]
                     (#last_index, Self::#name { #(#dynamic_segments,)* }) => {
-                        dioxus::config_macros::maybe_wasm_split! {
-                            if wasm_split {
-                                {
-                                    fn #comp_name(args: #router_name) -> Element {
-                                        match args {
-                                            #router_name::#name { #(#dynamic_segments_from_route_,)* } => {
-                                                rsx! {
-                                                    #component {
-                                                        #(#dynamic_segments_from_route__: #dynamic_segments_from_route__,)*
-                                                    }
-                                                }
-                                            }
-                                            _ => unreachable!()
-                                        }
-                                    }
+                        #render
+                    }
+                }
+            }
+        });
 
+        tokens
+    }
 
+    /// The match arms that render the components this route fills named outlets with. An intercepting route fills
+    /// the outlet it intercepts into with its own component.
+    pub(crate) fn outlet_match(&self, router_name: &Ident) -> TokenStream2 {
+        let name = &self.route_name;
+        let mut tokens = TokenStream2::new();
 
-                                    #[component]
-                                    fn LoaderInner(args: NoPartialEq<#router_name>) -> Element {
-                                        static MODULE: wasm_split::LazyLoader<#router_name, Element> =
-                                            wasm_split::lazy_loader!(extern #module_name fn #comp_name(props: #router_name) -> Element);
+        for outlet in &self.outlets {
+            let outlet_name = &outlet.name;
+            let component = &outlet.comp;
+            let dynamic_segments = self.dynamic_segments();
+            let dynamic_segments_from_route = self.dynamic_segments();
+            let dynamic_segments_from_route_ = self.dynamic_segments();
+            tokens.extend(quote! {
+                #[allow(unused)]
+                (#outlet_name, Self::#name { #(#dynamic_segments,)* }) => {
+                    rsx! {
+                        #component {
+                            #(#dynamic_segments_from_route: #dynamic_segments_from_route_,)*
+                        }
+                    }
+                }
+            });
+        }
 
-                                        use_resource(|| async move { MODULE.load().await }).suspend()?;
-                                        MODULE.call(args.0).unwrap()
-                                    }
+        if let Some(intercept) = &self.intercept
+            && let Some(split) = self.split_component(router_name)
+        {
+            let outlet_name = &intercept.outlet;
+            let dynamic_segments = self.dynamic_segments();
+            let dynamic_segments_receiver = self.dynamic_segments();
+            let render =
+                split.render(quote! { #router_name::#name { #(#dynamic_segments_receiver,)* } });
+            tokens.extend(quote! {
+                #[allow(unused)]
+                (#outlet_name, Self::#name { #(#dynamic_segments,)* }) => {
+                    #render
+                }
+            });
+        }
 
-                                    struct NoPartialEq<T>(T);
+        tokens
+    }
 
-                                    impl<T: Clone> Clone for NoPartialEq<T> {
-                                        fn clone(&self) -> Self {
-                                            Self(self.0.clone())
-                                        }
-                                    }
+    /// The match arm that returns the outlet this route intercepts into if it intercepts navigation.
+    pub(crate) fn intercept_match(&self) -> Option<TokenStream2> {
+        let name = &self.route_name;
+        let outlet_name = &self.intercept.as_ref()?.outlet;
+        Some(quote! {
+            Self::#name { .. } => Some(#outlet_name),
+        })
+    }
 
-                                    impl<T: ::std::fmt::Display> ::std::fmt::Display for NoPartialEq<T> {
-                                        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                                            self.0.fmt(f)
-                                        }
-                                    }
+    /// The match arm that formats the metadata of this route. Child routes use the metadata of the child router.
+    pub(crate) fn metadata_match(&self) -> Option<TokenStream2> {
+        let name = &self.route_name;
+        match &self.ty {
+            RouteType::Child(field) => {
+                let field_name = field.ident.as_ref().unwrap();
+                Some(quote! {
+                    Self::#name { #field_name, .. } => dioxus_router::routable::Routable::metadata(#field_name),
+                })
+            }
+            RouteType::Leaf { .. } if !self.metadata.is_empty() => {
+                let dynamic_segments = self.dynamic_segments();
+                let metadata = self.metadata.construct();
+                Some(quote! {
+                    #[allow(unused)]
+                    Self::#name { #(#dynamic_segments,)* } => #metadata,
+                })
+            }
+            RouteType::Leaf { .. } => None,
+        }
+    }
 
-                                    impl<T> PartialEq for NoPartialEq<T> {
-                                        fn eq(&self, _other: &Self) -> bool {
-                                            false
-                                        }
-                                    }
+    /// A match arm that returns the index routes of the nests this route is in. The index route of a nest is the leaf
+    /// route with the path `/` directly inside of it. Index routes are only included if they can be created from the
+    /// parameters of the nests, which every route in the nest has too.
+    pub(crate) fn ancestors_match(
+        &self,
+        routes: &[&Route],
+        nests: &[Nest],
+    ) -> Option<TokenStream2> {
+        let name = &self.route_name;
+        let mut used_fields = Vec::new();
+        let mut ancestors = Vec::new();
 
-                                    rsx! {
-                                        LoaderInner {
-                                            args: NoPartialEq(#router_name::#name { #(#dynamic_segments_receiver,)* } )
-                                        }
-                                    }
-                                }
-                            } else {
-                                {
-                                    rsx! {
-                                        #component {
-                                            #(#dynamic_segments_from_route: #dynamic_segments_from_route,)*
-                                        }
-                                    }
-                                }
-                            }
-                        }
+        for depth in 0..=self.nests.len() {
+            let parent_nests = &self.nests[..depth];
+            let nest_params: Vec<Ident> = parent_nests
+                .iter()
+                .flat_map(|id| nests[id.0].dynamic_segments_names())
+                .collect();
+            let index = routes.iter().find(|route| {
+                route.route_name != *name
+                    && matches!(route.ty, RouteType::Leaf { .. })
+                    && route.is_index()
+                    && route
+                        .nests
+                        .iter()
+                        .map(|id| id.0)
+                        .eq(parent_nests.iter().map(|id| id.0))
+                    && route
+                        .fields
+                        .iter()
+                        .all(|(field, _)| nest_params.contains(field))
+            });
+            let Some(index) = index else {
+                continue;
+            };
+
+            let index_name = &index.route_name;
+            let fields: Vec<&Ident> = index.fields.iter().map(|(field, _)| field).collect();
+            for field in &fields {
+                if !used_fields.contains(field) {
+                    used_fields.push(*field);
+                }
+            }
+            ancestors.push(quote! {
+                Self::#index_name { #(#fields: #fields.clone(),)* }
+            });
+        }
+
+        if ancestors.is_empty() {
+            return None;
+        }
+        Some(quote! {
+            Self::#name { #(#used_fields,)* .. } => vec![#(#ancestors,)*],
+        })
+    }
+
+    /// Check if the path of this route is `/`
+    fn is_index(&self) -> bool {
+        self.segments
+            .iter()
+            .all(|segment| matches!(segment, RouteSegment::Static(segment) if segment.is_empty()))
+    }
+
+    /// The component of a leaf route, which is split into its own wasm module if it is lazy or the router splits
+    /// every route.
+    fn split_component(&self, router_name: &Ident) -> Option<SplitComponent<'_>> {
+        let RouteType::Leaf { component } = &self.ty else {
+            return None;
+        };
+
+        let name = &self.route_name;
+        let component = quote_spanned! { name.span() =>
+            #component
+        };
+        let dynamic_segments = self.dynamic_segments();
+        let dynamic_segments_from_route = self.dynamic_segments();
+        let dynamic_segments_from_route_ = self.dynamic_segments();
+
+        Some(SplitComponent {
+            name: name.to_string(),
+            span: name.span(),
+            args_ty: quote! { #router_name },
+            args_pat: quote! { #router_name::#name { #(#dynamic_segments,)* } },
+            render: quote! {
+                rsx! {
+                    #component {
+                        #(#dynamic_segments_from_route: #dynamic_segments_from_route_,)*
                     }
                 }
+            },
+            lazy: self.lazy.as_ref(),
+        })
+    }
+
+    /// The items that split a leaf route into its own wasm module. These are emitted next to the routable impl so
+    /// both `render` and `preload` can refer to the loader.
+    pub(crate) fn split_items(&self, router_name: &Ident) -> Option<TokenStream2> {
+        self.split_component(router_name).map(|split| split.items())
+    }
+
+    /// Fetch the wasm modules of this route and its lazy layouts ahead of time.
+    pub(crate) fn preload_match(
+        &self,
+        layouts: &[Layout],
+        nests: &[Nest],
+        router_name: &Ident,
+    ) -> TokenStream2 {
+        let name = &self.route_name;
+        match &self.ty {
+            RouteType::Child(field) => {
+                let field_name = field.ident.as_ref().unwrap();
+                quote! {
+                    Self::#name { #field_name, .. } => #field_name.preload(),
+                }
             }
-        });
+            RouteType::Leaf { .. } => {
+                let preload_layouts = self
+                    .layouts
+                    .iter()
+                    .filter_map(|id| layouts[id.0].split_component(nests))
+                    .map(|split| split.preload());
+                let preload_route = self
+                    .split_component(router_name)
+                    .map(|split| split.preload());
 
-        tokens
+                quote! {
+                    Self::#name { .. } => Box::pin(async {
+                        #(#preload_layouts)*
+                        #preload_route
+                    }),
+                }
+            }
+        }
     }
 
     fn dynamic_segments(&self) -> impl Iterator<Item = TokenStream2> + '_ {
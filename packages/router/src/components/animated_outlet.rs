@@ -0,0 +1,355 @@
+use std::time::Duration;
+
+use dioxus_core::{
+    Element, provide_context, schedule_update, spawn, try_consume_context, use_after_render,
+    use_hook,
+};
+use dioxus_core_macro::{Props, rsx};
+use dioxus_document::Eval;
+use dioxus_history::history;
+use dioxus_html as dioxus_elements;
+use dioxus_signals::{CopyValue, ReadableExt, Signal, WritableExt};
+
+use crate::{
+    Outlet, RouteTransition, TransitionDirection, TransitionPhase, outlet::RouteOverride,
+    routable::Routable, utils::use_router_internal::use_router_internal,
+};
+
+/// The props for [`AnimatedOutlet`].
+#[derive(Props, Clone, PartialEq)]
+pub struct AnimatedOutletProps {
+    /// How long routes stay in the entering and exiting phases. This should match the duration of the css animations
+    /// of the routes.
+    #[props(default = Duration::from_millis(300))]
+    pub duration: Duration,
+
+    /// Whether to animate with the [View Transitions API](https://developer.mozilla.org/en-US/docs/Web/API/View_Transition_API)
+    /// on platforms that support it. Defaults to `true`.
+    #[props(default = true)]
+    pub view_transition: bool,
+}
+
+/// An [`Outlet`] that animates between routes.
+///
+/// When the route changes, the old route stays mounted while it animates out and the new route animates in. Every
+/// route is wrapped in a `div` with the `dx-route` class, a class for the phase of its animation
+/// (`dx-route-entering`, `dx-route-entered` or `dx-route-exiting`) and a class for the direction of the navigation
+/// (`dx-route-forward`, `dx-route-back` or `dx-route-replace`) so the animations can be written in css. Routes are
+/// removed once they have been exiting for [`AnimatedOutletProps::duration`]. Components inside of the routes can
+/// read the phase and direction with [`use_route_transition`](crate::use_route_transition).
+///
+/// On platforms that support the [View Transitions API](https://developer.mozilla.org/en-US/docs/Web/API/View_Transition_API),
+/// the router swaps the routes inside of `document.startViewTransition` instead and the browser animates between
+/// them. The direction of the navigation is set as the `data-route-transition` attribute of the root element while
+/// the transition runs, so the animation can be picked with a selector like
+/// `:root[data-route-transition="back"]::view-transition-old(root)`.
+///
+/// Every navigation that changes the path or query of the current route animates the outlet, so place the
+/// [`AnimatedOutlet`] in the innermost layout whose content should animate.
+///
+/// # Example
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_router::components::AnimatedOutlet;
+/// #[derive(Clone, Routable)]
+/// #[rustfmt::skip]
+/// enum Route {
+///     #[layout(Frame)]
+///         #[route("/")]
+///         Home {},
+///         #[route("/about")]
+///         About {},
+/// }
+///
+/// #[component]
+/// fn Frame() -> Element {
+///     rsx! {
+///         nav { "Navbar" }
+///         AnimatedOutlet::<Route> {}
+///     }
+/// }
+///
+/// #[component]
+/// fn Home() -> Element {
+///     rsx! { "Home" }
+/// }
+///
+/// #[component]
+/// fn About() -> Element {
+///     rsx! { "About" }
+/// }
+///
+/// # let mut vdom = VirtualDom::new(|| rsx! { Router::<Route> {} });
+/// # vdom.rebuild_in_place();
+/// # assert_eq!(
+/// #     dioxus_ssr::render(&vdom),
+/// #     r#"<nav>Navbar</nav><div class="dx-route dx-route-entered dx-route-forward">Home</div>"#
+/// # );
+/// ```
+pub fn AnimatedOutlet<R: Routable + Clone>(props: AnimatedOutletProps) -> Element {
+    let router = use_router_internal().expect("AnimatedOutlet must be inside of a router");
+    let parent_override = use_hook(try_consume_context::<RouteOverride<R>>);
+    let route = match parent_override.and_then(|o| o.route()) {
+        Some(route) => route.to_string(),
+        None => router.full_route_string(),
+    };
+
+    let history = use_hook(history);
+    let update = use_hook(schedule_update);
+    let state =
+        use_hook(|| CopyValue::new(TransitionState::new(route.clone(), history.current_index())));
+
+    // The view transition waits for the new route to be rendered before it animates
+    let view_transition = use_hook(|| CopyValue::new(None::<Eval>));
+    use_after_render(move || {
+        if let Some(eval) = view_transition.write_unchecked().take() {
+            _ = eval.send(true);
+        }
+    });
+
+    if !same_page(&route, &state.peek().route) {
+        let index = history.current_index();
+        let mut write = state.write_unchecked();
+        let direction = write.navigate(route, index);
+        // Keep rendering the old page until the new one is ready to animate in
+        write.freeze();
+        drop(write);
+
+        let duration = props.duration;
+        let view_transitions = props.view_transition;
+        spawn(async move {
+            if view_transitions {
+                let mut eval = dioxus_document::eval(VIEW_TRANSITION_JS);
+                _ = eval.send(direction_class(direction));
+                // The browser took a snapshot of the old page, so it can be swapped out
+                if let Ok(true) = eval.recv::<bool>().await {
+                    state.write_unchecked().enter(direction, false);
+                    *view_transition.write_unchecked() = Some(eval);
+                    update();
+                    return;
+                }
+            }
+
+            // Otherwise animate with css classes
+            let id = state.write_unchecked().enter(direction, true);
+            update();
+            dioxus_hooks::sleep(duration).await;
+            state.write_unchecked().finish(id);
+            update();
+        });
+    } else {
+        // The hash changed, which doesn't animate the page
+        state.write_unchecked().route = route;
+    }
+
+    let has_override = parent_override.is_some();
+    let read = state.read();
+    rsx! {
+        for entry in read.entries.iter() {
+            AnimatedRoute::<R> {
+                key: "{entry.id}",
+                id: entry.id,
+                state,
+                route: (entry.frozen || has_override).then(|| entry.route.clone().unwrap_or_else(|| read.route.clone())),
+            }
+        }
+    }
+}
+
+/// Starts a view transition if the platform supports it. The router sends the direction of the navigation, then this
+/// script answers whether the transition started and waits until the router rendered the new route.
+const VIEW_TRANSITION_JS: &str = r#"
+const direction = await dioxus.recv();
+if (!document.startViewTransition) {
+    dioxus.send(false);
+    return;
+}
+const root = document.documentElement;
+root.dataset.routeTransition = direction;
+const transition = document.startViewTransition(async () => {
+    dioxus.send(true);
+    await dioxus.recv();
+});
+transition.finished.finally(() => delete root.dataset.routeTransition);
+"#;
+
+/// Two routes are on the same page if they only differ in their hash
+fn same_page(a: &str, b: &str) -> bool {
+    fn without_hash(route: &str) -> &str {
+        route.split_once('#').map_or(route, |(route, _)| route)
+    }
+    without_hash(a) == without_hash(b)
+}
+
+struct TransitionEntry {
+    id: usize,
+    /// The route the entry renders once it is frozen. The entry of the current route is not frozen and renders the
+    /// current route.
+    route: Option<String>,
+    frozen: bool,
+    transition: RouteTransition,
+    /// The signal the components inside of the entry read the transition from, once the entry is mounted
+    signal: Option<Signal<RouteTransition>>,
+}
+
+impl TransitionEntry {
+    fn new(id: usize, transition: RouteTransition) -> Self {
+        Self {
+            id,
+            route: None,
+            frozen: false,
+            transition,
+            signal: None,
+        }
+    }
+
+    fn set_transition(&mut self, transition: RouteTransition) {
+        self.transition = transition;
+        if let Some(mut signal) = self.signal {
+            signal.set(transition);
+        }
+    }
+}
+
+struct TransitionState {
+    entries: Vec<TransitionEntry>,
+    next_id: usize,
+    /// The route that was rendered last
+    route: String,
+    /// The position in the history of the route that was rendered last
+    index: Option<usize>,
+}
+
+impl TransitionState {
+    fn new(route: String, index: Option<usize>) -> Self {
+        Self {
+            entries: vec![TransitionEntry::new(0, RouteTransition::default())],
+            next_id: 1,
+            route,
+            index,
+        }
+    }
+
+    /// Record a navigation to `route` and return its direction. Going back in the history moves to a lower
+    /// position, and replacing the route keeps the same position.
+    fn navigate(&mut self, route: String, index: Option<usize>) -> TransitionDirection {
+        let direction = match (self.index, index) {
+            (Some(old), Some(new)) if new < old => TransitionDirection::Back,
+            (Some(old), Some(new)) if new == old => TransitionDirection::Replace,
+            _ => TransitionDirection::Forward,
+        };
+
+        // Remember the route every entry on the page renders so they keep rendering it while they animate out
+        let previous = std::mem::replace(&mut self.route, route);
+        for entry in &mut self.entries {
+            entry.route.get_or_insert_with(|| previous.clone());
+        }
+        self.index = index;
+
+        direction
+    }
+
+    /// Keep rendering the entries on the page even though the route changed
+    fn freeze(&mut self) {
+        for entry in &mut self.entries {
+            entry.frozen = true;
+        }
+    }
+
+    /// Add the entry of the current route. If `animate` is true, the entries on the page animate out, otherwise they
+    /// are removed immediately.
+    fn enter(&mut self, direction: TransitionDirection, animate: bool) -> usize {
+        if animate {
+            for entry in &mut self.entries {
+                entry.set_transition(RouteTransition {
+                    phase: TransitionPhase::Exiting,
+                    direction,
+                });
+            }
+        } else {
+            self.entries.clear();
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        let phase = match animate {
+            true => TransitionPhase::Entering,
+            false => TransitionPhase::Entered,
+        };
+        self.entries.push(TransitionEntry::new(
+            id,
+            RouteTransition { phase, direction },
+        ));
+
+        id
+    }
+
+    /// Remove the entries that were animating out when the entry `id` was added and finish animating it in
+    fn finish(&mut self, id: usize) {
+        self.entries
+            .retain(|entry| entry.id >= id || entry.transition.phase != TransitionPhase::Exiting);
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == id) {
+            entry.set_transition(RouteTransition {
+                phase: TransitionPhase::Entered,
+                ..entry.transition
+            });
+        }
+    }
+}
+
+fn phase_class(phase: TransitionPhase) -> &'static str {
+    match phase {
+        TransitionPhase::Entering => "entering",
+        TransitionPhase::Entered => "entered",
+        TransitionPhase::Exiting => "exiting",
+    }
+}
+
+fn direction_class(direction: TransitionDirection) -> &'static str {
+    match direction {
+        TransitionDirection::Forward => "forward",
+        TransitionDirection::Back => "back",
+        TransitionDirection::Replace => "replace",
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct AnimatedRouteProps {
+    id: usize,
+    state: CopyValue<TransitionState>,
+    route: Option<String>,
+}
+
+fn AnimatedRoute<R: Routable + Clone>(props: AnimatedRouteProps) -> Element {
+    let route_override = use_hook(|| provide_context(RouteOverride::<R>::new(None)));
+    let transition = use_hook(|| {
+        let mut state = props.state.write_unchecked();
+        let entry = state.entries.iter_mut().find(|entry| entry.id == props.id);
+        let signal = Signal::new(
+            entry
+                .as_ref()
+                .map(|entry| entry.transition)
+                .unwrap_or_default(),
+        );
+        if let Some(entry) = entry {
+            entry.signal = Some(signal);
+        }
+        provide_context(signal)
+    });
+
+    // The outlets inside of the entry render the route it is frozen to, if any
+    route_override.set(
+        props
+            .route
+            .as_deref()
+            .and_then(|route| R::from_str(route).ok()),
+    );
+
+    let RouteTransition { phase, direction } = transition();
+    rsx! {
+        div {
+            class: "dx-route dx-route-{phase_class(phase)} dx-route-{direction_class(direction)}",
+            Outlet::<R> {}
+        }
+    }
+}
@@ -6,6 +6,7 @@ use dioxus_core::{Attribute, Element, EventHandler, VNode};
 use dioxus_core_macro::{Props, rsx};
 use dioxus_html::{
     self as dioxus_elements, ModifiersInteraction, MountedEvent, MouseEvent, PointerInteraction,
+    VisibleEvent,
 };
 
 use tracing::error;
@@ -47,6 +48,12 @@ pub struct LinkProps {
     /// 3. If `onclick_only` is [`true`], only the provided `onclick` handler will be executed.
     pub onclick_only: bool,
 
+    /// When to load the `to` route ahead of time. See [`Prefetch`] for more details.
+    ///
+    /// This has no effect for external targets.
+    #[props(default)]
+    pub prefetch: Prefetch,
+
     /// The rel attribute for the generated HTML anchor tag.
     ///
     /// For external `a`s, this defaults to `noopener noreferrer`.
@@ -69,11 +76,31 @@ impl Debug for LinkProps {
             .field("new_tab", &self.new_tab)
             .field("onclick", &self.onclick.as_ref().map(|_| "onclick is set"))
             .field("onclick_only", &self.onclick_only)
+            .field("prefetch", &self.prefetch)
             .field("rel", &self.rel)
             .finish()
     }
 }
 
+/// When a [`Link`] should load its target route ahead of time.
+///
+/// Prefetching downloads the wasm module of the route if it was split with the `wasm-split` feature. With the
+/// `fullstack` feature, the route is also rendered in the background without being mounted so the data it loads with
+/// `use_loader` and `use_server_future` is ready when the user navigates to it. See
+/// [`crate::RouterContext::prefetch`] for more details.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Prefetch {
+    /// Only load the route when the link is clicked.
+    #[default]
+    None,
+    /// Load the route when the pointer moves over the link or the link is focused.
+    Hover,
+    /// Load the route when the link scrolls into view.
+    Visible,
+    /// Load the route as soon as the link is mounted.
+    Eager,
+}
+
 /// A link to navigate to another route.
 ///
 /// Only works as descendant of a [`super::Router`] component, otherwise it will be inactive.
@@ -142,6 +169,7 @@ pub fn Link(props: LinkProps) -> Element {
         new_tab,
         onclick,
         onclick_only,
+        prefetch,
         rel,
         to,
         class,
@@ -201,6 +229,8 @@ pub fn Link(props: LinkProps) -> Element {
 
     let do_default = onclick.is_none() || !onclick_only;
 
+    let prefetch_target = (!is_external && prefetch != Prefetch::None).then(|| to.clone());
+
     let action = move |event: MouseEvent| {
         // Only handle events without modifiers
         if !event.modifiers().is_empty() {
@@ -232,12 +262,47 @@ pub fn Link(props: LinkProps) -> Element {
         }
     };
 
-    let onmounted = move |event| {
-        if let Some(handler) = props.onmounted {
-            handler.call(event);
+    let onmounted = {
+        let prefetch_target = prefetch_target.clone();
+        move |event| {
+            if prefetch == Prefetch::Eager
+                && let Some(target) = prefetch_target.clone()
+            {
+                router.prefetch(target);
+            }
+            if let Some(handler) = props.onmounted {
+                handler.call(event);
+            }
         }
     };
 
+    // Only listen to the events that trigger the prefetch
+    let mut attributes = attributes;
+    if let Some(target) = prefetch_target {
+        let prefetch_now = move || router.prefetch(target.clone());
+        match prefetch {
+            Prefetch::Hover => {
+                let prefetch_on_focus = prefetch_now.clone();
+                attributes.push(dioxus_elements::events::onmouseenter(move |_| {
+                    prefetch_now()
+                }));
+                attributes.push(dioxus_elements::events::onfocus(move |_| {
+                    prefetch_on_focus()
+                }));
+            }
+            Prefetch::Visible => {
+                attributes.push(dioxus_elements::events::onvisible(
+                    move |event: VisibleEvent| {
+                        if event.is_intersecting().unwrap_or_default() {
+                            prefetch_now();
+                        }
+                    },
+                ));
+            }
+            Prefetch::None | Prefetch::Eager => {}
+        }
+    }
+
     // In liveview, we need to prevent the default action if the user clicks on the link with modifiers
     // in javascript. The prevent_default method is not available in the liveview renderer because
     // event handlers are handled over a websocket.
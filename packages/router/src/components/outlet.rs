@@ -1,5 +1,16 @@
 use crate::{outlet::OutletContext, *};
 use dioxus_core::Element;
+use dioxus_core_macro::Props;
+
+/// The props for [`Outlet`].
+#[derive(Props, Clone, PartialEq, Default)]
+pub struct OutletProps {
+    /// The name of the outlet. Named outlets render the component the current route fills them with using the
+    /// `#[outlet("name", Component)]` attribute, or the route that intercepted the navigation to it. If this is not
+    /// set, the outlet renders the next level of the current route.
+    #[props(default, into)]
+    pub name: Option<String>,
+}
 
 /// An outlet for the current content.
 ///
@@ -68,6 +79,63 @@ use dioxus_core::Element;
 /// # vdom.rebuild_in_place();
 /// # assert_eq!(dioxus_ssr::render(&vdom), "<h1>App</h1><p>Child</p>");
 /// ```
-pub fn Outlet<R: Routable + Clone>() -> Element {
-    OutletContext::<R>::render()
+///
+/// # Named outlets
+///
+/// A layout can have any number of named outlets next to its main outlet so one url can fill several regions of the
+/// page. Each route picks the components its named outlets render with the `#[outlet("name", Component)]` attribute.
+/// Named outlets the current route doesn't fill render nothing.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// #[derive(Clone, Routable)]
+/// #[rustfmt::skip]
+/// enum Route {
+///     #[layout(Dashboard)]
+///         #[route("/inbox")]
+///         #[outlet("sidebar", Folders)]
+///         Inbox {},
+///         #[route("/settings")]
+///         Settings {},
+/// }
+///
+/// #[component]
+/// fn Dashboard() -> Element {
+///     rsx! {
+///         aside { Outlet::<Route> { name: "sidebar" } }
+///         main { Outlet::<Route> {} }
+///     }
+/// }
+///
+/// #[component]
+/// fn Folders() -> Element {
+///     rsx! { "Folders" }
+/// }
+///
+/// #[component]
+/// fn Inbox() -> Element {
+///     rsx! { "Inbox" }
+/// }
+///
+/// #[component]
+/// fn Settings() -> Element {
+///     rsx! { "Settings" }
+/// }
+///
+/// # #[component]
+/// # fn App() -> Element {
+/// #     rsx! {
+/// #         dioxus_router::components::HistoryProvider {
+/// #             history:  move |_| std::rc::Rc::new(dioxus_history::MemoryHistory::with_initial_path(Route::Inbox {}.to_string())) as std::rc::Rc<dyn dioxus_history::History>,
+/// #             Router::<Route> {}
+/// #         }
+/// #     }
+/// # }
+/// #
+/// # let mut vdom = VirtualDom::new(App);
+/// # vdom.rebuild_in_place();
+/// # assert_eq!(dioxus_ssr::render(&vdom), "<aside>Folders</aside><main>Inbox</main>");
+/// ```
+pub fn Outlet<R: Routable + Clone>(props: OutletProps) -> Element {
+    OutletContext::<R>::render(props.name.as_deref())
 }
@@ -0,0 +1,76 @@
+use std::rc::Rc;
+
+use dioxus_core::{
+    Element, ErrorBoundary, SuspenseBoundary, SuspenseContext, VNode, provide_context, spawn,
+    suspend, use_hook,
+};
+use dioxus_core_macro::{Props, rsx};
+use dioxus_document::{Document, NoOpDocument};
+use dioxus_fullstack_core::PrefetchScope;
+use dioxus_hooks::use_effect;
+
+use crate::{
+    Outlet, outlet::RouteOverride, routable::Routable,
+    utils::use_router_internal::use_router_internal,
+};
+
+/// Renders the routes that were prefetched with [`crate::RouterContext::prefetch`] in the background so the data
+/// they load is ready when the user navigates to them.
+///
+/// A prefetched route is never mounted. It renders under a suspense boundary that stays suspended, so none of its
+/// nodes are created in the renderer, `onmounted` never fires and its effects never run. Head elements like
+/// `document::Title` are rendered with a no-op document so they don't replace the head of the current page.
+pub(crate) fn PrefetchedRoutes<R: Routable + Clone>() -> Element {
+    let router = use_router_internal().expect("PrefetchedRoutes must be inside of a router");
+
+    // Once the user navigates, the new route already took its prefetched data and everything else is stale
+    let mut last_route = None;
+    use_effect(move || {
+        let route = router.full_route_string();
+        if last_route
+            .replace(route.clone())
+            .is_some_and(|last| last != route)
+        {
+            router.clear_prefetched();
+        }
+    });
+
+    rsx! {
+        for route in router.prefetched_routes() {
+            PrefetchedRoute::<R> { key: "{route}", route }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct PrefetchedRouteProps {
+    route: String,
+}
+
+fn PrefetchedRoute<R: Routable + Clone>(props: PrefetchedRouteProps) -> Element {
+    let router = use_router_internal().expect("PrefetchedRoute must be inside of a router");
+
+    let parsed = use_hook(|| {
+        let route = R::from_str(&props.route).ok()?;
+        provide_context(PrefetchScope::record(router.prefetch_cache(), &props.route));
+        provide_context(Rc::new(NoOpDocument) as Rc<dyn Document>);
+        provide_context(RouteOverride::new(Some(route)));
+        Some(())
+    });
+    if parsed.is_none() {
+        return VNode::empty();
+    }
+
+    rsx! {
+        SuspenseBoundary { fallback: |_: SuspenseContext| VNode::empty(),
+            KeepSuspended {}
+            ErrorBoundary { handle_error: |_| VNode::empty(), Outlet::<R> {} }
+        }
+    }
+}
+
+/// Suspends the boundary it is in until it is dropped, so its siblings only ever render in the background.
+fn KeepSuspended() -> Element {
+    let task = use_hook(|| spawn(std::future::pending::<()>()));
+    suspend(task)
+}
@@ -0,0 +1,85 @@
+use std::{cell::RefCell, rc::Rc};
+
+use dioxus_core::{Element, VNode, use_hook};
+use dioxus_document::{MetaProps, document};
+
+use crate::{
+    routable::{Routable, RouteMetadata},
+    utils::use_router_internal::use_router_internal,
+};
+
+/// Applies the metadata of the current route to the head of the document. The title and meta tags are created while
+/// rendering so they are included in server side rendered html, then updated every time the route changes.
+///
+/// Routes without a title use the title of the closest nest above them, or the default title of the router.
+pub(crate) fn RouteHead<R: Routable>() -> Element {
+    let router = use_router_internal().expect("RouteHead must be inside of a router");
+    let metadata = R::from_str(&router.full_route_string())
+        .map(|route| {
+            let mut metadata = route.metadata();
+            if metadata.title.is_none() {
+                metadata.title = route
+                    .ancestors()
+                    .into_iter()
+                    .rev()
+                    .find_map(|ancestor| ancestor.metadata().title);
+            }
+            metadata
+        })
+        .unwrap_or_default();
+    let metadata = RouteMetadata {
+        title: metadata.title.or_else(|| router.default_title()),
+        ..metadata
+    };
+
+    let document = use_hook(document);
+    let applied = use_hook(|| {
+        if let Some(title) = &metadata.title {
+            document.set_title(title.clone());
+        }
+        if document.create_head_component() {
+            for (name, content) in &metadata.meta {
+                document.create_meta(meta_props(name, content));
+            }
+        }
+        Rc::new(RefCell::new(metadata.clone()))
+    });
+
+    let mut applied = applied.borrow_mut();
+    if *applied != metadata {
+        if let Some(title) = &metadata.title
+            && applied.title.as_ref() != Some(title)
+        {
+            document.set_title(title.clone());
+        }
+        // Remove the meta tags the new route doesn't have or has with a different content before adding the new ones
+        for (name, content) in &applied.meta {
+            if !metadata.meta.contains(&(*name, content.clone())) {
+                document.remove_meta(meta_key(name));
+            }
+        }
+        for (name, content) in &metadata.meta {
+            if !applied.meta.contains(&(*name, content.clone())) {
+                document.create_meta(meta_props(name, content));
+            }
+        }
+        *applied = metadata;
+    }
+
+    VNode::empty()
+}
+
+fn meta_props(name: &str, content: &str) -> MetaProps {
+    let mut props = meta_key(name);
+    props.content = Some(content.to_string());
+    props
+}
+
+/// The props that identify the meta tag with this name regardless of its content
+fn meta_key(name: &str) -> MetaProps {
+    let builder = MetaProps::builder();
+    match name.starts_with("og:") {
+        true => builder.property(name.to_string()).build(),
+        false => builder.name(name.to_string()).build(),
+    }
+}
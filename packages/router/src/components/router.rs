@@ -49,5 +49,48 @@ pub fn Router<R: Routable + Clone>(props: RouterProps<R>) -> Element {
         provide_context(OutletContext::<R>::new());
     });
 
+    // Restore the scroll position of the page and scroll areas when the route changes
+    #[cfg(feature = "html")]
+    let scroll_restoration = use_hook(|| {
+        let enabled = crate::router().scroll_restoration();
+        if enabled {
+            // The router restores the scroll position, so the history must not scroll the page too
+            dioxus_history::history().disable_scroll_restoration();
+            provide_context(crate::components::ScrollManager::new());
+        }
+        enabled
+    });
+
+    // Start the route the user navigates to with the data that was prefetched for it
+    #[cfg(feature = "fullstack")]
+    {
+        use_hook(|| {
+            let router = crate::router();
+            provide_context(dioxus_fullstack_core::PrefetchScope::replay(
+                router.prefetch_cache(),
+                || dioxus_history::history().current_route(),
+            ));
+        });
+
+        rsx! {
+            Outlet::<R> {}
+            crate::components::RouteHead::<R> {}
+            if scroll_restoration {
+                crate::components::ScrollRestoration {}
+            }
+            crate::components::PrefetchedRoutes::<R> {}
+        }
+    }
+
+    #[cfg(all(feature = "html", not(feature = "fullstack")))]
+    rsx! {
+        Outlet::<R> {}
+        crate::components::RouteHead::<R> {}
+        if scroll_restoration {
+            crate::components::ScrollRestoration {}
+        }
+    }
+
+    #[cfg(not(feature = "html"))]
     rsx! { Outlet::<R> {} }
 }
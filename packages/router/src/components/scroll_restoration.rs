@@ -0,0 +1,310 @@
+use std::{collections::HashMap, rc::Rc};
+
+use dioxus_core::{
+    Attribute, Element, VNode, consume_context, spawn, try_consume_context, use_hook,
+};
+use dioxus_core_macro::{Props, rsx};
+use dioxus_history::history;
+use dioxus_hooks::use_effect;
+use dioxus_html::{self as dioxus_elements, MountedData, ScrollBehavior, geometry::PixelsVector2D};
+use dioxus_signals::{CopyValue, ReadableExt, Signal, WritableExt};
+use percent_encoding::percent_decode_str;
+
+use crate::utils::use_router_internal::use_router_internal;
+
+/// The props for [`ScrollArea`].
+#[derive(Props, Clone, PartialEq)]
+pub struct ScrollAreaProps {
+    /// The name the scroll position of the area is saved under. Scroll areas on the same page must have different
+    /// names.
+    #[props(into)]
+    pub name: String,
+
+    /// Additional attributes for the `div` element.
+    #[props(extends = GlobalAttributes, extends = div)]
+    pub attributes: Vec<Attribute>,
+
+    /// The children of the scroll area.
+    pub children: Element,
+}
+
+/// A scrollable `div` whose scroll position is saved and restored by the router like the scroll position of the
+/// page.
+///
+/// The router saves the scroll position of the page and every scroll area for each entry in the history. When the
+/// user goes back or forward, the scroll positions of that entry are restored. Pushing or replacing a route scrolls
+/// the page and the scroll areas to the top. If the route has a hash like `/docs#install`, the page scrolls the
+/// element with that id into view instead.
+///
+/// Use scroll areas for containers that scroll instead of the page, like a sidebar or the content next to it. The
+/// scroll position of the page is managed on platforms that can evaluate javascript, and scroll areas are managed on
+/// every platform. Scroll restoration can be turned off with [`RouterConfig::scroll_restoration`](crate::RouterConfig::scroll_restoration).
+///
+/// # Example
+/// ```rust
+/// # use dioxus::prelude::*;
+/// #[derive(Clone, Routable)]
+/// #[rustfmt::skip]
+/// enum Route {
+///     #[layout(Frame)]
+///         #[route("/")]
+///         Home {},
+/// }
+///
+/// #[component]
+/// fn Frame() -> Element {
+///     rsx! {
+///         nav { "Navbar" }
+///         ScrollArea {
+///             name: "content",
+///             style: "overflow-y: auto; height: 100vh;",
+///             Outlet::<Route> {}
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn Home() -> Element {
+///     rsx! { "Home" }
+/// }
+///
+/// # let mut vdom = VirtualDom::new(|| rsx! { Router::<Route> {} });
+/// # vdom.rebuild_in_place();
+/// # assert_eq!(
+/// #     dioxus_ssr::render(&vdom),
+/// #     r#"<nav>Navbar</nav><div style="overflow-y: auto; height: 100vh;">Home</div>"#
+/// # );
+/// ```
+pub fn ScrollArea(props: ScrollAreaProps) -> Element {
+    let manager = use_hook(try_consume_context::<ScrollManager>);
+    let mut mounted = use_hook(|| Signal::new(None::<Rc<MountedData>>));
+
+    let name = props.name.clone();
+    use_effect(move || {
+        let (Some(manager), Some(mounted)) = (manager, mounted()) else {
+            return;
+        };
+        let target = manager.target(Some(&name), &manager.navigation.read());
+        if let Some([x, y]) = target.position {
+            spawn(async move {
+                _ = mounted
+                    .scroll(PixelsVector2D::new(x, y), ScrollBehavior::Instant)
+                    .await;
+            });
+        }
+    });
+
+    let name = props.name;
+    rsx! {
+        div {
+            onmounted: move |event| mounted.set(Some(event.data())),
+            onscroll: move |event| {
+                if let Some(manager) = manager {
+                    manager.save(Some(&name), [event.scroll_left(), event.scroll_top()]);
+                }
+            },
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
+
+/// Saves and restores the scroll position of the page and the [`ScrollArea`]s. The router provides it if scroll
+/// restoration is enabled.
+#[derive(Clone, Copy)]
+pub(crate) struct ScrollManager {
+    /// The scroll positions of the page (`None`) and the scroll areas of every entry in the history
+    positions: CopyValue<HashMap<ScrollKey, [f64; 2]>>,
+    navigation: Signal<Navigation>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct ScrollKey {
+    entry: HistoryEntry,
+    area: Option<String>,
+}
+
+/// An entry in the history. Entries are identified by their position and route, because the history doesn't give
+/// them an id and the position is reused after going back and pushing another route.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+struct HistoryEntry {
+    index: Option<usize>,
+    route: String,
+}
+
+impl HistoryEntry {
+    fn current() -> (Self, Option<String>) {
+        let history = history();
+        let route = history.current_route();
+        let (route, hash) = match route.split_once('#') {
+            Some((route, hash)) => (route.to_string(), Some(hash.to_string())),
+            None => (route, None),
+        };
+        let hash = hash
+            .filter(|hash| !hash.is_empty())
+            .map(|hash| percent_decode_str(&hash).decode_utf8_lossy().into_owned());
+        let entry = Self {
+            index: history.current_index(),
+            route,
+        };
+        (entry, hash)
+    }
+}
+
+/// The last navigation the scroll positions are updated for
+#[derive(Clone, Default, PartialEq)]
+struct Navigation {
+    entry: HistoryEntry,
+    hash: Option<String>,
+    kind: NavigationKind,
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+enum NavigationKind {
+    /// The page was loaded. The scroll positions are left alone unless the route has a hash.
+    #[default]
+    Load,
+    /// A route was pushed or replaced
+    Push,
+    /// The user went back or forward in the history
+    Traverse,
+}
+
+/// Where the page or a scroll area scrolls to after a navigation
+#[derive(Clone, Debug, Default, PartialEq)]
+struct ScrollTarget {
+    /// The id of the element to scroll into view
+    anchor: Option<String>,
+    /// The position to scroll to if there is no anchor or the element doesn't exist
+    position: Option<[f64; 2]>,
+}
+
+impl ScrollManager {
+    pub(crate) fn new() -> Self {
+        let (entry, hash) = HistoryEntry::current();
+        Self {
+            positions: CopyValue::new(HashMap::new()),
+            navigation: Signal::new(Navigation {
+                entry,
+                hash,
+                kind: NavigationKind::Load,
+            }),
+        }
+    }
+
+    /// Save the scroll position of the page or a scroll area for the current entry in the history
+    fn save(&self, area: Option<&str>, position: [f64; 2]) {
+        let (entry, _) = HistoryEntry::current();
+        let key = ScrollKey {
+            entry,
+            area: area.map(ToString::to_string),
+        };
+        self.positions.write_unchecked().insert(key, position);
+    }
+
+    /// Update the navigation if the route changed
+    fn navigate(&self, pushed: bool) {
+        let (entry, hash) = HistoryEntry::current();
+        let last = self.navigation.peek();
+        if last.entry == entry && last.hash == hash {
+            return;
+        }
+        drop(last);
+
+        let kind = match pushed {
+            true => NavigationKind::Push,
+            false => NavigationKind::Traverse,
+        };
+        // The entries after a pushed entry were discarded, so their positions can't be restored anymore
+        if kind == NavigationKind::Push
+            && let Some(index) = entry.index
+        {
+            self.positions
+                .write_unchecked()
+                .retain(|key, _| key.entry.index.is_some_and(|other| other < index));
+        }
+
+        let mut navigation = self.navigation;
+        navigation.set(Navigation { entry, hash, kind });
+    }
+
+    /// Where the page (`None`) or a scroll area scrolls to after the navigation
+    fn target(&self, area: Option<&str>, navigation: &Navigation) -> ScrollTarget {
+        if navigation.kind == NavigationKind::Traverse {
+            let key = ScrollKey {
+                entry: navigation.entry.clone(),
+                area: area.map(ToString::to_string),
+            };
+            if let Some(position) = self.positions.read().get(&key) {
+                return ScrollTarget {
+                    anchor: None,
+                    position: Some(*position),
+                };
+            }
+        }
+
+        let anchor = navigation.hash.clone().filter(|_| area.is_none());
+        let position = match navigation.kind {
+            NavigationKind::Load => None,
+            // Scrolling the element into view already scrolls the scroll areas it is in
+            _ if navigation.hash.is_some() && area.is_some() => None,
+            _ => Some([0.0, 0.0]),
+        };
+        ScrollTarget { anchor, position }
+    }
+}
+
+/// Scrolls the page after every navigation and saves its scroll position while the user scrolls.
+pub(crate) fn ScrollRestoration() -> Element {
+    let router = use_router_internal().expect("ScrollRestoration must be inside of a router");
+    let manager = use_hook(consume_context::<ScrollManager>);
+
+    // Rerun when the route changes
+    router.full_route_string();
+    let pushed = router.take_pushed();
+    manager.navigate(pushed);
+
+    let eval = use_hook(|| {
+        let mut eval = dioxus_document::eval(SCROLL_RESTORATION_JS);
+        spawn(async move {
+            while let Ok(position) = eval.recv::<[f64; 2]>().await {
+                manager.save(None, position);
+            }
+        });
+        eval
+    });
+
+    // Effects run once the new route is rendered
+    use_effect(move || {
+        let target = manager.target(None, &manager.navigation.read());
+        if target != ScrollTarget::default() {
+            _ = eval.send((target.anchor, target.position));
+        }
+    });
+
+    VNode::empty()
+}
+
+/// Sends the scroll position of the page once per frame while the user scrolls, and scrolls to the targets the router
+/// sends after every navigation.
+const SCROLL_RESTORATION_JS: &str = r#"
+// The router restores the scroll position instead of the browser
+if ("scrollRestoration" in history) history.scrollRestoration = "manual";
+let frame = null;
+window.addEventListener("scroll", () => {
+    if (frame !== null) return;
+    frame = requestAnimationFrame(() => {
+        frame = null;
+        dioxus.send([window.scrollX, window.scrollY]);
+    });
+});
+while (true) {
+    const [anchor, position] = await dioxus.recv();
+    const element = anchor && document.getElementById(anchor);
+    if (element) {
+        element.scrollIntoView();
+    } else if (position) {
+        window.scrollTo(position[0], position[1]);
+    }
+}
+"#;
@@ -1,3 +1,5 @@
+use serde::Serialize;
+
 use crate::{ExternalNavigationFailure, NavigationTarget, RouterContext};
 
 /// Acquire the navigator without subscribing to updates.
@@ -52,6 +54,19 @@ impl Navigator {
         self.0.push(target)
     }
 
+    /// Push a new location and attach state to it, like `history.pushState` in the browser.
+    ///
+    /// The state is serialized into the new history entry and can be read with
+    /// [`use_history_state`](crate::hooks::use_history_state) while the entry is current, including after going back
+    /// or forward to it. The previous location will be available to go back to.
+    pub fn push_with_state(
+        &self,
+        target: impl Into<NavigationTarget>,
+        state: impl Serialize,
+    ) -> Option<ExternalNavigationFailure> {
+        self.0.push_with_state(target, state)
+    }
+
     /// Replace the current location.
     ///
     /// The previous location will **not** be available to go back to.
@@ -61,4 +76,16 @@ impl Navigator {
     ) -> Option<ExternalNavigationFailure> {
         self.0.replace(target)
     }
+
+    /// Replace the current location and attach state to it, like `history.replaceState` in the browser.
+    ///
+    /// Replacing the current route with itself updates the state of the current entry, which is useful for state
+    /// like a selected tab or a form draft. The previous location will **not** be available to go back to.
+    pub fn replace_with_state(
+        &self,
+        target: impl Into<NavigationTarget>,
+        state: impl Serialize,
+    ) -> Option<ExternalNavigationFailure> {
+        self.0.replace_with_state(target, state)
+    }
 }
@@ -1,4 +1,5 @@
 use dioxus_core::{Element, VNode, provide_context, try_consume_context, use_hook};
+use dioxus_signals::{CopyValue, ReadableExt, WritableExt};
 
 use crate::{routable::Routable, utils::use_router_internal::use_router_internal};
 
@@ -38,7 +39,7 @@ impl<R> OutletContext<R> {
         self.current_level
     }
 
-    pub(crate) fn render() -> Element
+    pub(crate) fn render(name: Option<&str>) -> Element
     where
         R: Routable + Clone,
     {
@@ -47,15 +48,63 @@ impl<R> OutletContext<R> {
         let current_level = outlet.level();
         provide_context(outlet.next());
 
+        let render = |route: R| match name {
+            Some(name) => route.render_outlet(name),
+            None => route.render(current_level),
+        };
+
+        // Prefetched routes and routes that are animating out render a fixed route instead of the current one
+        if let Some(route) = try_consume_context::<RouteOverride<R>>().and_then(|o| o.route()) {
+            return render(route);
+        }
+
         if let Some(error) = router.render_error() {
-            return if current_level == 0 {
+            return if current_level == 0 && name.is_none() {
                 error
             } else {
                 VNode::empty()
             };
         }
 
-        router.current::<R>().render(current_level)
+        let current = router.current::<R>();
+        let background = router.background::<R>();
+
+        // If the current route intercepted the navigation, the page the user navigated from stays rendered and the
+        // current route only fills the named outlet it intercepts into
+        match name {
+            Some(name) if current.intercept_outlet() == Some(name) => match background {
+                Some(_) => render(current),
+                None => VNode::empty(),
+            },
+            _ => render(background.unwrap_or(current)),
+        }
+    }
+}
+
+/// Provided around a tree of outlets that should render a different route than the current one, like the background
+/// tree of a prefetched route or a route that is animating out.
+pub(crate) struct RouteOverride<R: 'static>(CopyValue<Option<R>>);
+
+impl<R> Clone for RouteOverride<R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R> Copy for RouteOverride<R> {}
+
+impl<R: Clone + 'static> RouteOverride<R> {
+    pub(crate) fn new(route: Option<R>) -> Self {
+        Self(CopyValue::new(route))
+    }
+
+    /// Render `route` in the tree instead of the current route, or go back to the current route if it is `None`.
+    pub(crate) fn set(&self, route: Option<R>) {
+        *self.0.write_unchecked() = route;
+    }
+
+    pub(crate) fn route(&self) -> Option<R> {
+        self.0.cloned()
     }
 }
 
@@ -8,6 +8,7 @@ use std::{
 use dioxus_core::{Element, ReactiveContext, ScopeId, provide_context};
 use dioxus_history::history;
 use dioxus_signals::{CopyValue, ReadableExt, Signal, WritableExt};
+use serde::{Serialize, de::DeserializeOwned};
 
 use crate::{
     SiteMapSegment, components::child_router::consume_child_route_mapping,
@@ -77,10 +78,46 @@ struct RouterContextInner {
 
     internal_route: fn(&str) -> bool,
 
+    prefetch: fn(&str, Signal<Vec<String>>),
+
+    intercept_outlet: fn(&str) -> Option<&'static str>,
+
+    // The page the user navigated from if the current route intercepted the navigation
+    background: Option<Background>,
+
+    // The routes that were prefetched since the last navigation
+    prefetched: Signal<Vec<String>>,
+
+    #[cfg(feature = "html")]
+    scroll_restoration: bool,
+
+    #[cfg(feature = "html")]
+    default_title: Option<String>,
+
+    // Whether the last navigation pushed or replaced a route instead of moving through the history
+    #[cfg(feature = "html")]
+    pushed: bool,
+
+    #[cfg(feature = "fullstack")]
+    prefetch_cache: dioxus_fullstack_core::PrefetchCache,
+
     site_map: &'static [SiteMapSegment],
 }
 
+/// The page an intercepting route is rendered over
+struct Background {
+    page: String,
+    // The intercepting route. If the history moved to another route without the router, like when the user presses
+    // the back button of the browser, the background no longer applies.
+    over: String,
+}
+
 impl RouterContextInner {
+    fn current_background(&self) -> Option<&str> {
+        let background = self.background.as_ref()?;
+        (background.over == history().current_route()).then_some(background.page.as_str())
+    }
+
     fn update_subscribers(&self) {
         for &id in self.subscribers.lock().unwrap().iter() {
             id.mark_dirty();
@@ -144,6 +181,36 @@ impl RouterContext {
 
             internal_route: |route| R::from_str(route).is_ok(),
 
+            prefetch: |route, mut prefetched| {
+                let Ok(route) = R::from_str(route) else {
+                    return;
+                };
+                let key = route.to_string();
+                if prefetched.peek().contains(&key) {
+                    return;
+                }
+                dioxus_core::spawn_forever(route.preload());
+                prefetched.write().push(key);
+            },
+
+            prefetched: Signal::new_in_scope(Vec::new(), ScopeId::ROOT),
+
+            intercept_outlet: |route| R::from_str(route).ok()?.intercept_outlet(),
+
+            background: None,
+
+            #[cfg(feature = "html")]
+            scroll_restoration: cfg.scroll_restoration,
+
+            #[cfg(feature = "html")]
+            default_title: cfg.default_title,
+
+            #[cfg(feature = "html")]
+            pushed: false,
+
+            #[cfg(feature = "fullstack")]
+            prefetch_cache: Default::default(),
+
             site_map: R::SITE_MAP,
         };
 
@@ -192,7 +259,10 @@ impl RouterContext {
     ///
     /// Will fail silently if there is no previous location to go to.
     pub fn go_back(&self) {
+        let from = history().current_route();
         history().go_back();
+        self.update_background(from, false);
+        self.set_pushed(false);
         self.change_route();
     }
 
@@ -200,11 +270,15 @@ impl RouterContext {
     ///
     /// Will fail silently if there is no next location to go to.
     pub fn go_forward(&self) {
+        let from = history().current_route();
         history().go_forward();
+        self.update_background(from, false);
+        self.set_pushed(false);
         self.change_route();
     }
 
     pub(crate) fn push_any(&self, target: NavigationTarget) -> Option<ExternalNavigationFailure> {
+        let from = history().current_route();
         {
             let mut write = self.inner.write_unchecked();
             match target {
@@ -213,6 +287,8 @@ impl RouterContext {
             }
         }
 
+        self.update_background(from, true);
+        self.set_pushed(true);
         self.change_route()
     }
 
@@ -220,18 +296,44 @@ impl RouterContext {
     ///
     /// The previous location will be available to go back to.
     pub fn push(&self, target: impl Into<NavigationTarget>) -> Option<ExternalNavigationFailure> {
-        let target = target.into();
+        self.push_entry(target.into(), None)
+    }
+
+    /// Push a new location and attach state to it. The state is serialized into the history entry and can be read
+    /// with [`use_history_state`](crate::hooks::use_history_state) while the entry is current, including after going
+    /// back or forward to it. The state is dropped for external locations.
+    ///
+    /// The previous location will be available to go back to.
+    pub fn push_with_state(
+        &self,
+        target: impl Into<NavigationTarget>,
+        state: impl Serialize,
+    ) -> Option<ExternalNavigationFailure> {
+        self.push_entry(target.into(), serialize_state(state))
+    }
+
+    fn push_entry(
+        &self,
+        target: NavigationTarget,
+        state: Option<String>,
+    ) -> Option<ExternalNavigationFailure> {
+        let from = history().current_route();
         {
             let mut write = self.inner.write_unchecked();
             match target {
                 NavigationTarget::Internal(p) => {
                     let history = history();
-                    history.push(p)
+                    match state {
+                        Some(state) => history.push_with_state(p, state),
+                        None => history.push(p),
+                    }
                 }
                 NavigationTarget::External(e) => return write.external(e),
             }
         }
 
+        self.update_background(from, true);
+        self.set_pushed(true);
         self.change_route()
     }
 
@@ -242,42 +344,150 @@ impl RouterContext {
         &self,
         target: impl Into<NavigationTarget>,
     ) -> Option<ExternalNavigationFailure> {
-        let target = target.into();
+        self.replace_entry(target.into(), None)
+    }
+
+    /// Replace the current location and attach state to it. See [`RouterContext::push_with_state`] for how the state
+    /// is stored.
+    ///
+    /// The previous location will **not** be available to go back to.
+    pub fn replace_with_state(
+        &self,
+        target: impl Into<NavigationTarget>,
+        state: impl Serialize,
+    ) -> Option<ExternalNavigationFailure> {
+        self.replace_entry(target.into(), serialize_state(state))
+    }
+
+    fn replace_entry(
+        &self,
+        target: NavigationTarget,
+        state: Option<String>,
+    ) -> Option<ExternalNavigationFailure> {
+        let from = history().current_route();
         {
-            let mut state = self.inner.write_unchecked();
+            let mut write = self.inner.write_unchecked();
             match target {
                 NavigationTarget::Internal(p) => {
                     let history = history();
-                    history.replace(p)
+                    match state {
+                        Some(state) => history.replace_with_state(p, state),
+                        None => history.replace(p),
+                    }
                 }
-                NavigationTarget::External(e) => return state.external(e),
+                NavigationTarget::External(e) => return write.external(e),
             }
         }
 
+        self.update_background(from, false);
+        self.set_pushed(true);
         self.change_route()
     }
 
+    /// The state attached to the current history entry, if it was pushed or replaced with state of type `T`.
+    pub fn current_state<T: DeserializeOwned>(&self) -> Option<T> {
+        let inner = self.inner.read();
+        inner.subscribe_to_current_context();
+        let state = history().current_state()?;
+        match serde_json::from_str(&state) {
+            Ok(state) => Some(state),
+            Err(err) => {
+                tracing::trace!("The state of the history entry is not the requested type: {err}");
+                None
+            }
+        }
+    }
+
     /// The route that is currently active.
     pub fn current<R: Routable>(&self) -> R {
         let absolute_route = self.full_route_string();
+
+        match Self::parse_route(&absolute_route) {
+            Ok(route) => route,
+            Err(err) => {
+                dioxus_core::throw_error(ParseRouteError { message: err });
+                "/".parse().unwrap_or_else(|err| panic!("{err}"))
+            }
+        }
+    }
+
+    fn parse_route<R: Routable>(absolute_route: &str) -> Result<R, String> {
         // If this is a child route, map the absolute route to the child route before parsing
         let mapping = consume_child_route_mapping::<R>();
-        let route = match mapping.as_ref() {
+        match mapping.as_ref() {
             Some(mapping) => mapping
-                .parse_route_from_root_route(&absolute_route)
+                .parse_route_from_root_route(absolute_route)
                 .ok_or_else(|| "Failed to parse route".to_string()),
             None => {
-                R::from_str(&absolute_route).map_err(|err| format!("Failed to parse route {err}"))
+                R::from_str(absolute_route).map_err(|err| format!("Failed to parse route {err}"))
             }
-        };
+        }
+    }
 
-        match route {
-            Ok(route) => route,
-            Err(err) => {
-                dioxus_core::throw_error(ParseRouteError { message: err });
-                "/".parse().unwrap_or_else(|err| panic!("{err}"))
-            }
+    /// Check if the current route intercepted the navigation to it. Intercepting routes are rendered in a named
+    /// outlet over the page the user navigated from instead of replacing it. They are only intercepted when the user
+    /// navigates to them from inside the app, not when the page is loaded directly.
+    pub fn is_intercepted(&self) -> bool {
+        let inner = self.inner.read();
+        inner.subscribe_to_current_context();
+        inner.current_background().is_some()
+    }
+
+    /// The page the current route is rendered over if it intercepted the navigation to it.
+    pub(crate) fn background<R: Routable>(&self) -> Option<R> {
+        let inner = self.inner.read();
+        inner.subscribe_to_current_context();
+        Self::parse_route(inner.current_background()?).ok()
+    }
+
+    /// Keep the page the user navigated from in the background if the new route intercepts navigation. The
+    /// background stays the same while the user navigates between intercepting routes.
+    fn update_background(&self, from: String, pushed: bool) {
+        let mut inner = self.inner.write_unchecked();
+        let intercepts = inner.intercept_outlet;
+        let current = history().current_route();
+        let previous = inner
+            .background
+            .take()
+            .filter(|background| background.over == from);
+        inner.background = match intercepts(&current) {
+            Some(_) => match pushed && intercepts(&from).is_none() {
+                true => Some(from),
+                false => previous.map(|background| background.page),
+            },
+            None => None,
         }
+        .map(|page| Background {
+            page,
+            over: current,
+        });
+    }
+
+    #[cfg(feature = "html")]
+    fn set_pushed(&self, pushed: bool) {
+        self.inner.write_unchecked().pushed = pushed;
+    }
+
+    #[cfg(not(feature = "html"))]
+    fn set_pushed(&self, _: bool) {}
+
+    /// Check if the last navigation pushed or replaced a route and reset it. Navigations the router didn't start, like
+    /// the back button of the browser, move through the history.
+    #[cfg(feature = "html")]
+    pub(crate) fn take_pushed(&self) -> bool {
+        std::mem::take(&mut self.inner.write_unchecked().pushed)
+    }
+
+    /// Check if the router manages the scroll position of the page.
+    #[cfg(feature = "html")]
+    pub(crate) fn scroll_restoration(&self) -> bool {
+        self.inner.read().scroll_restoration
+    }
+
+    /// The title of the page for routes without a title.
+    #[cfg(feature = "html")]
+    pub(crate) fn default_title(&self) -> Option<String> {
+        self.inner.read().default_title.clone()
     }
 
     /// The full route that is currently active. If this is called from inside a child router, this will always return the parent's view of the route.
@@ -342,6 +552,44 @@ impl RouterContext {
     pub(crate) fn internal_route(&self, route: &str) -> bool {
         (self.inner.read().internal_route)(route)
     }
+
+    /// Load a route ahead of time so navigating to it is instant.
+    ///
+    /// If the route was split into its own wasm module with the `wasm-split` feature, the module is downloaded. With
+    /// the `fullstack` feature, the route is also rendered in the background and the data loaded by
+    /// [`use_loader`](https://docs.rs/dioxus-fullstack/latest/dioxus_fullstack/fn.use_loader.html) and
+    /// [`use_server_future`](https://docs.rs/dioxus-fullstack/latest/dioxus_fullstack/fn.use_server_future.html) is
+    /// reused when the user navigates to it. Each route is only prefetched once between navigations.
+    pub fn prefetch(&self, target: impl Into<NavigationTarget>) {
+        let NavigationTarget::Internal(route) = target.into() else {
+            return;
+        };
+        let inner = self.inner.read();
+        (inner.prefetch)(&route, inner.prefetched);
+    }
+
+    /// The routes that were prefetched since the last navigation.
+    #[cfg(feature = "fullstack")]
+    pub(crate) fn prefetched_routes(&self) -> Vec<String> {
+        self.inner.read().prefetched.cloned()
+    }
+
+    #[cfg(feature = "fullstack")]
+    pub(crate) fn prefetch_cache(&self) -> dioxus_fullstack_core::PrefetchCache {
+        self.inner.read().prefetch_cache.clone()
+    }
+
+    /// Forget everything that was prefetched. The data of the route the user navigated to was already taken by its
+    /// hooks when they were created.
+    #[cfg(feature = "fullstack")]
+    pub(crate) fn clear_prefetched(&self) {
+        let inner = self.inner.read();
+        // Don't rerender the prefetched routes on every navigation if nothing was prefetched
+        if !inner.prefetched.peek_unchecked().is_empty() {
+            inner.prefetched.write_unchecked().clear();
+        }
+        inner.prefetch_cache.clear();
+    }
 }
 
 /// This context is set to the RouterConfig on_update method
@@ -418,3 +666,14 @@ where
         self.inner.clear_error()
     }
 }
+
+/// Serialize the state of a history entry. The entry is created without state if serialization fails.
+fn serialize_state(state: impl Serialize) -> Option<String> {
+    match serde_json::to_string(&state) {
+        Ok(state) => Some(state),
+        Err(err) => {
+            tracing::error!("Failed to serialize the state of the history entry: {err}");
+            None
+        }
+    }
+}
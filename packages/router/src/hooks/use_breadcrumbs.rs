@@ -0,0 +1,93 @@
+use crate::Routable;
+use crate::utils::use_router_internal::use_router_internal;
+
+/// A route in the breadcrumbs of the current route. See [`use_breadcrumbs`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Breadcrumb<R> {
+    /// The route the breadcrumb links to
+    pub route: R,
+    /// The title of the route from the `title` option of its `#[route]` attribute
+    pub title: String,
+}
+
+/// A hook that returns the titles of the routes above the current route, followed by the title of the current route.
+///
+/// The routes above the current route are its [`Routable::ancestors`]: the index routes of the root of the router and
+/// of the nests the current route is in. Routes without a `title` are skipped.
+///
+/// # Panic
+/// - When the calling component is not nested within a [`crate::Router`] component.
+///
+/// # Example
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_router::{components::HistoryProvider, use_breadcrumbs};
+/// # use dioxus_history::{History, MemoryHistory};
+/// # use std::rc::Rc;
+/// #[derive(Clone, Routable, PartialEq)]
+/// #[rustfmt::skip]
+/// enum Route {
+///     #[route("/", title = "Home")]
+///     Home {},
+///     #[nest("/users")]
+///         #[route("/", title = "Users")]
+///         Users {},
+///         #[route("/:id", title = "User {id}")]
+///         User { id: u32 },
+/// }
+///
+/// #[component]
+/// fn User(id: u32) -> Element {
+///     let breadcrumbs = use_breadcrumbs::<Route>();
+///     rsx! {
+///         nav {
+///             for breadcrumb in breadcrumbs {
+///                 Link { to: breadcrumb.route, "{breadcrumb.title}" }
+///             }
+///         }
+///     }
+/// }
+/// # #[component]
+/// # fn Home() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn Users() -> Element { VNode::empty() }
+/// #
+/// # let mut vdom = VirtualDom::new(|| {
+/// #     rsx! {
+/// #         HistoryProvider {
+/// #             history: |_| Rc::new(MemoryHistory::with_initial_path(Route::User { id: 1 })) as Rc<dyn History>,
+/// #             Router::<Route> {}
+/// #         }
+/// #     }
+/// # });
+/// # vdom.rebuild_in_place();
+/// # assert_eq!(
+/// #     dioxus_ssr::render(&vdom),
+/// #     r#"<nav><a href="/">Home</a><a href="/users/">Users</a><a href="/users/1" aria-current="page">User 1</a></nav>"#
+/// # );
+/// ```
+#[must_use]
+pub fn use_breadcrumbs<R: Routable>() -> Vec<Breadcrumb<R>> {
+    match use_router_internal() {
+        Some(router) => breadcrumbs(&router.full_route_string()),
+        None => {
+            panic!("`use_breadcrumbs` must be called in a descendant of a Router component")
+        }
+    }
+}
+
+fn breadcrumbs<R: Routable>(route: &str) -> Vec<Breadcrumb<R>> {
+    let Ok(route) = R::from_str(route) else {
+        return Vec::new();
+    };
+
+    route
+        .ancestors()
+        .into_iter()
+        .chain([route])
+        .filter_map(|route| {
+            let title = route.metadata().title?;
+            Some(Breadcrumb { route, title })
+        })
+        .collect()
+}
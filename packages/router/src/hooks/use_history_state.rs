@@ -0,0 +1,57 @@
+use serde::de::DeserializeOwned;
+
+use crate::utils::use_router_internal::use_router_internal;
+
+/// A hook that provides access to the state attached to the current history entry.
+///
+/// State is attached to an entry with [`Navigator::push_with_state`](crate::Navigator::push_with_state) or
+/// [`Navigator::replace_with_state`](crate::Navigator::replace_with_state) and stays with the entry when the user goes
+/// back or forward to it, so it is a good place for state like a selected tab or a form draft that should not be part
+/// of the url. Returns [`None`] if the entry has no state or the state is not a `T`.
+///
+/// The state is stored by the history of the platform. Histories that cannot store state drop it.
+///
+/// # Panic
+/// - When the calling component is not nested within a [`crate::Router`] component.
+///
+/// # Example
+/// ```rust
+/// # use dioxus::prelude::*;
+/// #[derive(Clone, Routable)]
+/// enum Route {
+///     #[route("/")]
+///     Settings {},
+/// }
+///
+/// #[component]
+/// fn Settings() -> Element {
+///     let tab = use_history_state::<usize>().unwrap_or_default();
+///     let navigator = use_navigator();
+///
+///     rsx! {
+///         for index in 0..3 {
+///             button {
+///                 onclick: move |_| { navigator.replace_with_state(Route::Settings {}, index); },
+///                 "Tab {index}"
+///             }
+///         }
+///         p { "Showing tab {tab}" }
+///     }
+/// }
+///
+/// # let mut vdom = VirtualDom::new(|| rsx! { Router::<Route> {} });
+/// # vdom.rebuild_in_place();
+/// # assert_eq!(
+/// #     dioxus_ssr::render(&vdom),
+/// #     "<button>Tab 0</button><button>Tab 1</button><button>Tab 2</button><p>Showing tab 0</p>"
+/// # );
+/// ```
+#[must_use]
+pub fn use_history_state<T: DeserializeOwned>() -> Option<T> {
+    match use_router_internal() {
+        Some(router) => router.current_state(),
+        None => {
+            panic!("`use_history_state` must be called in a descendant of a Router component")
+        }
+    }
+}
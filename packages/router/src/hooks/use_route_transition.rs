@@ -0,0 +1,64 @@
+use dioxus_core::{try_consume_context, use_hook};
+use dioxus_signals::Signal;
+
+/// The phase of the animation of a route rendered in an [`AnimatedOutlet`](crate::components::AnimatedOutlet).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TransitionPhase {
+    /// The route was just navigated to and is animating in
+    Entering,
+    /// The route is done animating in
+    #[default]
+    Entered,
+    /// The user navigated away from the route and it is animating out. It is removed once the animation is done.
+    Exiting,
+}
+
+/// The direction of the navigation that started the animation of a route.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TransitionDirection {
+    /// A new route was pushed or the user went forward in the history
+    #[default]
+    Forward,
+    /// The user went back in the history
+    Back,
+    /// The current route was replaced
+    Replace,
+}
+
+/// The state of the animation of a route rendered in an [`AnimatedOutlet`](crate::components::AnimatedOutlet).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RouteTransition {
+    /// The phase of the animation
+    pub phase: TransitionPhase,
+    /// The direction of the navigation that started the animation
+    pub direction: TransitionDirection,
+}
+
+/// A hook that returns the state of the animation of the route the component is rendered in.
+///
+/// Components outside of an [`AnimatedOutlet`](crate::components::AnimatedOutlet) are always
+/// [`TransitionPhase::Entered`].
+///
+/// # Example
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_router::{TransitionPhase, use_route_transition};
+/// #[component]
+/// fn Page() -> Element {
+///     let transition = use_route_transition();
+///     rsx! {
+///         h1 {
+///             // Only focus the heading once the page is done animating in
+///             autofocus: transition.phase == TransitionPhase::Entered,
+///             "Page"
+///         }
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn use_route_transition() -> RouteTransition {
+    let transition = use_hook(try_consume_context::<Signal<RouteTransition>>);
+    transition
+        .map(|transition| transition())
+        .unwrap_or_default()
+}
@@ -0,0 +1,122 @@
+//! Routes with an optional language prefix like `/fr/blog/1`.
+//!
+//! Deriving [`Routable`] with `#[lang_prefix("en", "fr")]` lets every route be matched with or without a leading
+//! segment for one of the supported languages. The language is not part of the route enum. Instead, it is read from
+//! the current url so links keep the language the user is browsing in.
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//!
+//! #[derive(Clone, Debug, PartialEq, Routable)]
+//! #[lang_prefix("en", "fr")]
+//! enum Route {
+//!     #[route("/")]
+//!     Home {},
+//!     #[route("/blog/:id")]
+//!     Blog { id: usize },
+//! }
+//! # #[component]
+//! # fn Home() -> Element { VNode::empty() }
+//! # #[component]
+//! # fn Blog(id: usize) -> Element { VNode::empty() }
+//!
+//! assert_eq!("/fr/blog/1".parse::<Route>().unwrap(), Route::Blog { id: 1 });
+//! assert_eq!("/blog/1".parse::<Route>().unwrap(), Route::Blog { id: 1 });
+//! // Languages the router doesn't support are not stripped
+//! assert!("/es/blog/1".parse::<Route>().is_err());
+//! assert_eq!(
+//!     dioxus::router::with_route_language(Some("fr"), || Route::Blog { id: 1 }.to_string()),
+//!     "/fr/blog/1"
+//! );
+//! ```
+
+use std::{cell::RefCell, rc::Rc};
+
+use dioxus_core::Runtime;
+use dioxus_history::History;
+
+use crate::{navigation::NavigationTarget, routable::Routable, router};
+
+thread_local! {
+    static LANGUAGE_OVERRIDE: RefCell<Option<Option<String>>> = const { RefCell::new(None) };
+}
+
+/// Split a leading language segment off of a path.
+///
+/// Returns the language and the rest of the path, or `None` if the first segment is not one of the `languages`.
+/// Languages are matched case-insensitively like language tags, but returned as they appear in the path.
+pub fn split_lang_prefix<'a>(path: &'a str, languages: &[&str]) -> Option<(&'a str, String)> {
+    let rest = path.strip_prefix('/')?;
+    let end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let (language, rest) = rest.split_at(end);
+    if !languages
+        .iter()
+        .any(|supported| supported.eq_ignore_ascii_case(language))
+    {
+        return None;
+    }
+
+    let rest = match rest.starts_with('/') {
+        true => rest.to_string(),
+        false => format!("/{rest}"),
+    };
+    Some((language, rest))
+}
+
+/// Render routes with `language` as their language prefix while `f` runs, instead of the language of the current url.
+///
+/// Pass `None` to render routes without a language prefix.
+pub fn with_route_language<T>(language: Option<&str>, f: impl FnOnce() -> T) -> T {
+    let language = language.map(str::to_string);
+    let previous = LANGUAGE_OVERRIDE.with(|cell| cell.replace(Some(language)));
+    let result = f();
+    LANGUAGE_OVERRIDE.with(|cell| *cell.borrow_mut() = previous);
+    result
+}
+
+/// The language prefix of the current url, if `R` was derived with `#[lang_prefix(..)]` and the url has one.
+pub fn route_language<R: Routable>() -> Option<String> {
+    R::current_language()
+}
+
+/// Replace the current route with the same route under a different language prefix.
+///
+/// Pass `None` to remove the language prefix.
+pub fn set_route_language<R: Routable>(language: Option<&str>) {
+    let router = router();
+    let route = with_route_language(language, || router.current::<R>().to_string());
+    router.replace(NavigationTarget::Internal(route));
+}
+
+/// Parse a route that may have a language prefix. Paths that match without stripping the prefix take precedence.
+#[doc(hidden)]
+pub fn parse_with_lang_prefix<R, E>(
+    route: &str,
+    languages: &[&str],
+    parse: impl Fn(&str) -> Result<R, E>,
+) -> Result<R, E> {
+    parse(route).or_else(|err| match split_lang_prefix(route, languages) {
+        Some((_, rest)) => parse(&rest).map_err(|_| err),
+        None => Err(err),
+    })
+}
+
+/// Find the language prefix of the current url. `is_route` checks if a path matches a route without stripping the
+/// prefix.
+#[doc(hidden)]
+pub fn current_lang_prefix(languages: &[&str], is_route: impl Fn(&str) -> bool) -> Option<String> {
+    if let Some(language) = LANGUAGE_OVERRIDE.with(|cell| cell.borrow().clone()) {
+        return language;
+    }
+
+    let runtime = Runtime::try_current()?;
+    let scope = runtime.try_current_scope_id()?;
+    let history = runtime.consume_context::<Rc<dyn History>>(scope)?;
+    let route = history.current_route();
+    if is_route(&route) {
+        return None;
+    }
+
+    let (language, rest) = split_lang_prefix(&route, languages)?;
+    is_route(&rest).then(|| language.to_string())
+}
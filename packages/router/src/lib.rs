@@ -5,6 +5,7 @@
 #![deny(missing_docs)]
 #![allow(non_snake_case)]
 
+pub mod lang_prefix;
 pub mod navigation;
 pub mod routable;
 
@@ -28,9 +29,31 @@ pub mod components {
     mod outlet;
     pub use outlet::*;
 
+    #[cfg(feature = "html")]
+    mod animated_outlet;
+    #[cfg(feature = "html")]
+    pub use animated_outlet::*;
+
     mod router;
     pub use router::*;
 
+    #[cfg(feature = "html")]
+    mod route_head;
+    #[cfg(feature = "html")]
+    pub(crate) use route_head::*;
+
+    #[cfg(feature = "html")]
+    mod scroll_restoration;
+    #[cfg(feature = "html")]
+    pub use scroll_restoration::{ScrollArea, ScrollAreaProps};
+    #[cfg(feature = "html")]
+    pub(crate) use scroll_restoration::{ScrollManager, ScrollRestoration};
+
+    #[cfg(feature = "fullstack")]
+    mod prefetched_routes;
+    #[cfg(feature = "fullstack")]
+    pub(crate) use prefetched_routes::*;
+
     mod history_provider;
     pub use history_provider::*;
 
@@ -61,15 +84,28 @@ pub mod hooks {
 
     mod use_navigator;
     pub use use_navigator::*;
+
+    mod use_route_transition;
+    pub use use_route_transition::*;
+
+    mod use_breadcrumbs;
+    pub use use_breadcrumbs::*;
+
+    mod use_history_state;
+    pub use use_history_state::*;
 }
 
 pub use hooks::router;
 
 #[cfg(feature = "html")]
-pub use crate::components::{GoBackButton, GoForwardButton, HistoryButtonProps, Link, LinkProps};
-pub use crate::components::{Outlet, Router, RouterProps};
+pub use crate::components::{
+    AnimatedOutlet, AnimatedOutletProps, GoBackButton, GoForwardButton, HistoryButtonProps, Link,
+    LinkProps, Prefetch, ScrollArea, ScrollAreaProps,
+};
+pub use crate::components::{Outlet, OutletProps, Router, RouterProps};
 pub use crate::contexts::*;
 pub use crate::hooks::*;
+pub use crate::lang_prefix::{route_language, set_route_language, with_route_language};
 pub use crate::navigation::*;
 pub use crate::routable::*;
 pub use crate::router_cfg::RouterConfig;
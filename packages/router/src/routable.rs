@@ -1,8 +1,9 @@
 #![allow(non_snake_case)]
 //! # Routable
 
-use dioxus_core::Element;
+use dioxus_core::{Element, VNode};
 use std::iter::FlatMap;
+use std::pin::Pin;
 use std::slice::Iter;
 use std::{fmt::Display, str::FromStr};
 
@@ -23,6 +24,17 @@ impl<E: Display> Display for RouteParseError<E> {
     }
 }
 
+/// The metadata of a route from the `title` and `meta` options of the `#[route]` attribute. The router applies the
+/// metadata of the current route to the head of the document.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RouteMetadata {
+    /// The title of the page
+    pub title: Option<String>,
+    /// The name and content of the meta tags of the page. Names that start with `og:` are Open Graph properties and
+    /// are rendered with the `property` attribute instead of `name`.
+    pub meta: Vec<(&'static str, String)>,
+}
+
 /// Something that can be created from an entire query string. This trait must be implemented for any type that is spread into the query segment like `#[route("/?:..query")]`.
 ///
 ///
@@ -734,6 +746,45 @@ pub trait Routable: FromStr<Err: Display> + Display + Clone + 'static {
     /// Render the route at the given level
     fn render(&self, level: usize) -> Element;
 
+    /// Render the component this route fills a named outlet with. Routes fill named outlets with the
+    /// `#[outlet("name", Component)]` attribute.
+    fn render_outlet(&self, _name: &str) -> Element {
+        VNode::empty()
+    }
+
+    /// The named outlet this route is rendered in over the current page if it intercepts navigation. Routes intercept
+    /// navigation with the `intercept` option of the `#[route]` attribute.
+    fn intercept_outlet(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// The metadata of this route. Routes set their metadata with the `title` and `meta` options of the `#[route]`
+    /// attribute.
+    fn metadata(&self) -> RouteMetadata {
+        RouteMetadata::default()
+    }
+
+    /// The routes above this route in the nests of the router, from the root route to the innermost nest.
+    ///
+    /// These are the index routes (`#[route("/")]`) of the root of the router and of every `#[nest]` this route is
+    /// in, created with the parameters of this route. Nests without an index route are skipped.
+    fn ancestors(&self) -> Vec<Self> {
+        Vec::new()
+    }
+
+    /// The language prefix of the current url if this enum was derived with `#[lang_prefix(..)]`.
+    ///
+    /// See the [`lang_prefix`](crate::lang_prefix) module for more details.
+    fn current_language() -> Option<String> {
+        None
+    }
+
+    /// Download the code of the route ahead of time if it was split into its own wasm module with the `wasm-split`
+    /// feature. Otherwise, this resolves immediately.
+    fn preload(&self) -> Pin<Box<dyn Future<Output = ()>>> {
+        Box::pin(async {})
+    }
+
     /// Checks if this route is a child of the given route.
     ///
     /// # Example
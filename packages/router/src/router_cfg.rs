@@ -29,6 +29,10 @@ use std::sync::Arc;
 pub struct RouterConfig<R> {
     pub(crate) failure_external_navigation: fn() -> Element,
     pub(crate) on_update: Option<RoutingCallback<R>>,
+    #[cfg(feature = "html")]
+    pub(crate) scroll_restoration: bool,
+    #[cfg(feature = "html")]
+    pub(crate) default_title: Option<String>,
 }
 
 #[cfg(not(feature = "html"))]
@@ -47,6 +51,8 @@ impl<R> Default for RouterConfig<R> {
         Self {
             failure_external_navigation: crate::components::FailureExternalNavigation,
             on_update: None,
+            scroll_restoration: true,
+            default_title: dioxus_cli_config::app_title(),
         }
     }
 }
@@ -90,4 +96,33 @@ where
             ..self
         }
     }
+
+    /// Whether the router manages the scroll position of the page and of
+    /// [`ScrollArea`](crate::components::ScrollArea)s.
+    ///
+    /// The router saves the scroll positions of every entry in the history and restores them when the user goes back
+    /// or forward. Pushing or replacing a route scrolls to the top, or to the element with the id in the hash of the
+    /// route like `/docs#install`.
+    ///
+    /// Defaults to `true`.
+    #[cfg(feature = "html")]
+    pub fn scroll_restoration(self, enabled: bool) -> Self {
+        Self {
+            scroll_restoration: enabled,
+            ..self
+        }
+    }
+
+    /// The title of the page for routes that don't have a `#[title]` themselves or in one of their parent nests.
+    ///
+    /// If there is no default title, navigating to a route without a title keeps the title of the previous page.
+    ///
+    /// Defaults to the title of the app in the `Dioxus.toml`.
+    #[cfg(feature = "html")]
+    pub fn default_title(self, title: impl Into<String>) -> Self {
+        Self {
+            default_title: Some(title.into()),
+            ..self
+        }
+    }
 }
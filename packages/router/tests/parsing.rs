@@ -221,3 +221,58 @@ fn child_route_preserves_query_and_hash() {
     assert_eq!(reserved.to_string(), "/search?query=a%23b&word_count=1");
     assert_eq!(Route::from_str(&reserved.to_string()).unwrap(), reserved);
 }
+
+#[test]
+fn lang_prefix_parse() {
+    #[derive(Routable, Clone, Copy, PartialEq, Debug)]
+    #[lang_prefix("en", "fr", "de-CH", "pt-BR")]
+    enum Route {
+        #[route("/")]
+        Root {},
+        #[route("/faq")]
+        Test {},
+        #[route("/:id/test")]
+        Dynamic { id: usize },
+    }
+
+    assert_eq!(Route::from_str("/").unwrap(), Route::Root {});
+    assert_eq!(Route::from_str("/fr").unwrap(), Route::Root {});
+    assert_eq!(Route::from_str("/de-CH/").unwrap(), Route::Root {});
+    assert_eq!(Route::from_str("/faq").unwrap(), Route::Test {});
+    assert_eq!(Route::from_str("/en/faq").unwrap(), Route::Test {});
+    assert_eq!(
+        Route::from_str("/pt-BR/123/test").unwrap(),
+        Route::Dynamic { id: 123 }
+    );
+    assert!(Route::from_str("/english/faq").is_err());
+    assert_eq!(Route::from_str("/DE-ch").unwrap(), Route::Root {});
+
+    // Outside of a router, routes are rendered without a prefix unless one is given
+    assert_eq!(Route::Test {}.to_string(), "/faq");
+    assert_eq!(
+        dioxus_router::with_route_language(Some("fr"), || Route::Test {}.to_string()),
+        "/fr/faq"
+    );
+    assert_eq!(
+        dioxus_router::with_route_language(Some("fr"), || Route::Root {}.to_string()),
+        "/fr"
+    );
+}
+
+#[test]
+fn lang_prefix_only_strips_supported_languages() {
+    #[derive(Routable, Clone, Copy, PartialEq, Debug)]
+    #[lang_prefix("en", "fr")]
+    enum Route {
+        #[route("/")]
+        Root {},
+        #[route("/faq")]
+        Test {},
+    }
+
+    // Unknown paths that look like a language are not found instead of parsing as a page in that language
+    assert!(Route::from_str("/xyz").is_err());
+    assert!(Route::from_str("/api/faq").is_err());
+    assert!(Route::from_str("/de/faq").is_err());
+    assert_eq!(Route::from_str("/fr/faq").unwrap(), Route::Test {});
+}
@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_router::{RouterContext, components::AnimatedOutlet, root_router};
+
+#[derive(Routable, Clone, PartialEq)]
+#[rustfmt::skip]
+enum Route {
+    #[layout(Frame)]
+        #[route("/")]
+        Home {},
+        #[route("/about")]
+        About {},
+}
+
+#[component]
+fn Frame() -> Element {
+    rsx! {
+        AnimatedOutlet::<Route> { duration: Duration::from_millis(10) }
+    }
+}
+
+#[component]
+fn Home() -> Element {
+    let transition = use_route_transition();
+    rsx! { "Home {transition.phase:?}" }
+}
+
+#[component]
+fn About() -> Element {
+    rsx! { "About" }
+}
+
+fn navigate(vdom: &mut VirtualDom, navigate: impl FnOnce(RouterContext)) -> String {
+    vdom.in_scope(ScopeId::APP, || navigate(root_router().unwrap()));
+    vdom.render_immediate(&mut NoOpMutations);
+    dioxus_ssr::render(vdom)
+}
+
+async fn wait(vdom: &mut VirtualDom) -> String {
+    _ = tokio::time::timeout(Duration::from_millis(100), vdom.wait_for_work()).await;
+    vdom.render_immediate(&mut NoOpMutations);
+    dioxus_ssr::render(vdom)
+}
+
+#[tokio::test]
+async fn old_route_stays_mounted_until_it_animated_out() {
+    let mut vdom = VirtualDom::new(|| rsx! { Router::<Route> {} });
+    vdom.rebuild_in_place();
+    assert_eq!(
+        dioxus_ssr::render(&vdom),
+        r#"<div class="dx-route dx-route-entered dx-route-forward">Home Entered</div>"#
+    );
+
+    // The old page keeps rendering while the router checks for view transitions, which ssr doesn't support
+    let pushed = navigate(&mut vdom, |router| {
+        router.push(Route::About {});
+    });
+    assert_eq!(
+        pushed,
+        r#"<div class="dx-route dx-route-entered dx-route-forward">Home Entered</div>"#
+    );
+
+    // Then it falls back to animating with css classes
+    assert_eq!(
+        wait(&mut vdom).await,
+        r#"<div class="dx-route dx-route-exiting dx-route-forward">Home Exiting</div><div class="dx-route dx-route-entering dx-route-forward">About</div>"#
+    );
+    assert_eq!(
+        wait(&mut vdom).await,
+        r#"<div class="dx-route dx-route-entered dx-route-forward">About</div>"#
+    );
+
+    // Going back in the history animates in the other direction
+    navigate(&mut vdom, |router| router.go_back());
+    assert_eq!(
+        wait(&mut vdom).await,
+        r#"<div class="dx-route dx-route-exiting dx-route-back">About</div><div class="dx-route dx-route-entering dx-route-back">Home Entering</div>"#
+    );
+    assert_eq!(
+        wait(&mut vdom).await,
+        r#"<div class="dx-route dx-route-entered dx-route-back">Home Entered</div>"#
+    );
+}
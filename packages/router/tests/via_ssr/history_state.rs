@@ -0,0 +1,76 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_router::{RouterContext, root_router};
+use serde::{Deserialize, Serialize};
+
+#[derive(Routable, Clone, PartialEq)]
+enum Route {
+    #[route("/")]
+    Home {},
+    #[route("/compose")]
+    Compose {},
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Draft {
+    text: String,
+}
+
+#[component]
+fn Home() -> Element {
+    let tab = use_history_state::<usize>();
+    rsx! { "Home {tab:?}" }
+}
+
+#[component]
+fn Compose() -> Element {
+    let draft = use_history_state::<Draft>();
+    let text = draft.map(|draft| draft.text).unwrap_or_default();
+    rsx! { "Compose {text}" }
+}
+
+#[test]
+fn state_is_kept_per_history_entry() {
+    let mut vdom = VirtualDom::new(|| rsx! { Router::<Route> {} });
+    vdom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&vdom), "Home None");
+
+    let draft = Draft {
+        text: "Hello".to_string(),
+    };
+    navigate(&mut vdom, |router| {
+        router.push_with_state(Route::Compose {}, &draft);
+    });
+    assert_eq!(dioxus_ssr::render(&vdom), "Compose Hello");
+
+    navigate(&mut vdom, |router| router.go_back());
+    assert_eq!(dioxus_ssr::render(&vdom), "Home None");
+
+    // Replacing the route with itself only updates the state of the entry
+    navigate(&mut vdom, |router| {
+        router.replace_with_state(Route::Home {}, 2);
+    });
+    assert_eq!(dioxus_ssr::render(&vdom), "Home Some(2)");
+
+    navigate(&mut vdom, |router| router.go_forward());
+    assert_eq!(dioxus_ssr::render(&vdom), "Compose Hello");
+
+    navigate(&mut vdom, |router| router.go_back());
+    assert_eq!(dioxus_ssr::render(&vdom), "Home Some(2)");
+}
+
+#[test]
+fn state_of_another_type_is_ignored() {
+    let mut vdom = VirtualDom::new(|| rsx! { Router::<Route> {} });
+    vdom.rebuild_in_place();
+
+    navigate(&mut vdom, |router| {
+        router.push_with_state(Route::Compose {}, "not a draft");
+    });
+    assert_eq!(dioxus_ssr::render(&vdom), "Compose ");
+}
+
+fn navigate(vdom: &mut VirtualDom, navigate: impl FnOnce(RouterContext)) {
+    vdom.in_scope(ScopeId::APP, || navigate(root_router().unwrap()));
+    vdom.render_immediate(&mut NoOpMutations);
+}
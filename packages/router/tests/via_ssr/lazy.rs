@@ -0,0 +1,78 @@
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use dioxus_history::{History, MemoryHistory};
+use dioxus_router::components::HistoryProvider;
+
+#[derive(Routable, Clone, PartialEq)]
+#[rustfmt::skip]
+enum Route {
+    #[route("/")]
+    Home {},
+    #[route("/settings", lazy(fallback = Loading))]
+    Settings {},
+    #[nest("/user/:id")]
+        #[layout(UserFrame, lazy)]
+            #[route("/")]
+            User { id: u8 },
+            #[route("/posts/:post", UserPost)]
+            Post { id: u8, post: String },
+}
+
+#[component]
+fn Loading() -> Element {
+    rsx! { "Loading" }
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! { "Home" }
+}
+
+#[component]
+fn Settings() -> Element {
+    rsx! { "Settings" }
+}
+
+#[component]
+fn UserFrame(id: u8) -> Element {
+    rsx! {
+        h1 { "User {id}" }
+        Outlet::<Route> {}
+    }
+}
+
+#[component]
+fn User(id: u8) -> Element {
+    rsx! { "Profile" }
+}
+
+#[component]
+fn UserPost(id: u8, post: String) -> Element {
+    rsx! { "Post {post}" }
+}
+
+fn render(path: &str) -> String {
+    let mut vdom = VirtualDom::new_with_props(
+        |path: String| {
+            rsx! {
+                HistoryProvider {
+                    history: move |_| Rc::new(MemoryHistory::with_initial_path(path.clone())) as Rc<dyn History>,
+                    Router::<Route> {}
+                }
+            }
+        },
+        path.to_string(),
+    );
+    vdom.rebuild_in_place();
+    dioxus_ssr::render(&vdom)
+}
+
+// Without the wasm-split feature, lazy routes render like any other route
+#[test]
+fn lazy_routes_render_without_wasm_split() {
+    assert_eq!(render("/"), "Home");
+    assert_eq!(render("/settings"), "Settings");
+    assert_eq!(render("/user/1"), "<h1>User 1</h1>Profile");
+    assert_eq!(render("/user/1/posts/hello"), "<h1>User 1</h1>Post hello");
+}
@@ -97,6 +97,40 @@ fn href_internal() {
     assert_eq!(prepare_at_with_base_path::<Route>("/", base_path), expected);
 }
 
+#[test]
+fn href_lang_prefix() {
+    #[derive(Routable, Clone)]
+    #[lang_prefix("en", "fr")]
+    enum Route {
+        #[route("/")]
+        Root {},
+        #[route("/test")]
+        Test {},
+    }
+
+    #[component]
+    fn Test() -> Element {
+        unimplemented!()
+    }
+
+    #[component]
+    fn Root() -> Element {
+        rsx! {
+            Link {
+                to: Route::Test {},
+                "Link"
+            }
+        }
+    }
+
+    let expected = format!("<h1>App</h1><a {href}>Link</a>", href = r#"href="/test""#,);
+    assert_eq!(prepare::<Route>(), expected);
+
+    // Links keep the language of the current url
+    let expected = format!("<h1>App</h1><a {href}>Link</a>", href = r#"href="/fr/test""#,);
+    assert_eq!(prepare_at::<Route>("/fr"), expected);
+}
+
 #[test]
 fn href_external() {
     #[derive(Routable, Clone)]
@@ -1,6 +1,14 @@
+mod animated_outlet;
 mod child_outlet;
+mod history_state;
+mod lazy;
 mod link;
+mod metadata;
+mod named_outlet;
 mod navigation;
 mod outlet;
+#[cfg(feature = "fullstack")]
+mod prefetch;
 mod redirect;
+mod scroll_restoration;
 mod without_index;
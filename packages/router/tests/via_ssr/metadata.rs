@@ -0,0 +1,249 @@
+use std::{cell::RefCell, rc::Rc};
+
+use dioxus::document::{Document, Eval, MetaProps, NoOpDocument};
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_history::{History, MemoryHistory};
+use dioxus_router::{
+    RouteMetadata, RouterConfig, RouterContext, components::HistoryProvider, root_router,
+    use_breadcrumbs,
+};
+
+#[derive(Routable, Clone, PartialEq, Debug)]
+#[rustfmt::skip]
+enum Route {
+    #[route("/", title = "Home", meta(description = "The home page"))]
+    Home {},
+    #[nest("/users")]
+        #[route("/", title = "Users")]
+        Users {},
+        #[route("/:id", title = "User {id}", meta(description = "The profile of user {id}", "og:type" = "profile"))]
+        User { id: u32 },
+        #[route("/:id/settings")]
+        Settings { id: u32 },
+    #[end_nest]
+    #[route("/about")]
+    About {},
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! { "Home" }
+}
+
+#[component]
+fn Users() -> Element {
+    rsx! { "Users" }
+}
+
+#[component]
+fn User(id: u32) -> Element {
+    let breadcrumbs = use_breadcrumbs::<Route>();
+    rsx! {
+        for breadcrumb in breadcrumbs {
+            "{breadcrumb.title};"
+        }
+    }
+}
+
+#[component]
+fn Settings(id: u32) -> Element {
+    let breadcrumbs = use_breadcrumbs::<Route>();
+    rsx! {
+        for breadcrumb in breadcrumbs {
+            "{breadcrumb.title};"
+        }
+    }
+}
+
+#[component]
+fn About() -> Element {
+    rsx! { "About" }
+}
+
+#[derive(Routable, Clone, PartialEq, Debug)]
+enum Untitled {
+    #[route("/")]
+    Index {},
+    #[route("/titled", title = "Titled")]
+    Titled {},
+}
+
+#[component]
+fn Index() -> Element {
+    rsx! { "Index" }
+}
+
+#[component]
+fn Titled() -> Element {
+    rsx! { "Titled" }
+}
+
+/// A document that records the changes the router makes to the head
+#[derive(Default)]
+struct RecordingDocument {
+    changes: RefCell<Vec<String>>,
+}
+
+impl Document for RecordingDocument {
+    fn eval(&self, js: String) -> Eval {
+        self.changes.borrow_mut().push("eval".to_string());
+        NoOpDocument.eval(js)
+    }
+
+    fn set_title(&self, title: String) {
+        self.changes.borrow_mut().push(format!("title {title}"));
+    }
+
+    fn create_meta(&self, props: MetaProps) {
+        let attributes = props
+            .attributes()
+            .into_iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>();
+        self.changes
+            .borrow_mut()
+            .push(format!("meta {}", attributes.join(" ")));
+    }
+
+    fn remove_meta(&self, props: MetaProps) {
+        let attributes = props
+            .attributes()
+            .into_iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>();
+        self.changes
+            .borrow_mut()
+            .push(format!("remove meta {}", attributes.join(" ")));
+    }
+}
+
+#[test]
+fn metadata_is_formatted_with_the_route_fields() {
+    assert_eq!(Route::Home {}.metadata().title.as_deref(), Some("Home"));
+    assert_eq!(
+        Route::User { id: 7 }.metadata(),
+        RouteMetadata {
+            title: Some("User 7".to_string()),
+            meta: vec![
+                ("description", "The profile of user 7".to_string()),
+                ("og:type", "profile".to_string())
+            ],
+        }
+    );
+    assert_eq!(
+        Route::Settings { id: 7 }.metadata(),
+        RouteMetadata::default()
+    );
+}
+
+#[test]
+fn breadcrumbs_follow_the_nests_of_the_route() {
+    let mut vdom = VirtualDom::new(|| {
+        rsx! {
+            HistoryProvider {
+                history: |_| Rc::new(MemoryHistory::with_initial_path("/users/3")) as Rc<dyn History>,
+                Router::<Route> {}
+            }
+        }
+    });
+    vdom.rebuild_in_place();
+
+    assert_eq!(dioxus_ssr::render(&vdom), "Home;Users;User 3;");
+}
+
+#[test]
+fn breadcrumbs_skip_path_segments_that_are_not_nests() {
+    let mut vdom = VirtualDom::new(|| {
+        rsx! {
+            HistoryProvider {
+                history: |_| Rc::new(MemoryHistory::with_initial_path("/users/3/settings")) as Rc<dyn History>,
+                Router::<Route> {}
+            }
+        }
+    });
+    vdom.rebuild_in_place();
+
+    // `/users/3` is a prefix of the path, but not a nest the settings route is in
+    assert_eq!(dioxus_ssr::render(&vdom), "Home;Users;");
+}
+
+#[test]
+fn metadata_is_applied_to_the_head() {
+    let document = Rc::new(RecordingDocument::default());
+    // Scroll restoration evaluates javascript too, so it is turned off to only record the changes to the head
+    let mut vdom = VirtualDom::new(|| {
+        rsx! {
+            Router::<Route> { config: || RouterConfig::default().scroll_restoration(false) }
+        }
+    })
+    .with_root_context(document.clone() as Rc<dyn Document>);
+    vdom.rebuild_in_place();
+
+    // The metadata of the first route is created while rendering so it is part of the server side rendered html
+    assert_eq!(
+        document.changes.take(),
+        ["title Home", "meta name=description content=The home page"]
+    );
+
+    navigate(&mut vdom, |router| {
+        router.push(Route::User { id: 1 });
+    });
+    assert_eq!(
+        document.changes.take(),
+        [
+            "title User 1",
+            "remove meta name=description",
+            "meta name=description content=The profile of user 1",
+            "meta property=og:type content=profile"
+        ]
+    );
+
+    // Routes without a title use the title of their nest, and the meta tags of the last route are removed
+    navigate(&mut vdom, |router| {
+        router.push(Route::Settings { id: 1 });
+    });
+    assert_eq!(
+        document.changes.take(),
+        [
+            "title Users",
+            "remove meta name=description",
+            "remove meta property=og:type"
+        ]
+    );
+
+    navigate(&mut vdom, |router| {
+        router.push(Route::About {});
+    });
+    assert_eq!(document.changes.take(), ["title Home"]);
+}
+
+#[test]
+fn routes_without_a_title_use_the_default_title() {
+    let document = Rc::new(RecordingDocument::default());
+    let mut vdom = VirtualDom::new(|| {
+        rsx! {
+            Router::<Untitled> {
+                config: || RouterConfig::default().scroll_restoration(false).default_title("My App")
+            }
+        }
+    })
+    .with_root_context(document.clone() as Rc<dyn Document>);
+    vdom.rebuild_in_place();
+    assert_eq!(document.changes.take(), ["title My App"]);
+
+    navigate(&mut vdom, |router| {
+        router.push(Untitled::Titled {});
+    });
+    assert_eq!(document.changes.take(), ["title Titled"]);
+
+    navigate(&mut vdom, |router| {
+        router.push(Untitled::Index {});
+    });
+    assert_eq!(document.changes.take(), ["title My App"]);
+}
+
+fn navigate(vdom: &mut VirtualDom, navigate: impl FnOnce(RouterContext)) {
+    vdom.in_scope(ScopeId::APP, || navigate(root_router().unwrap()));
+    vdom.render_immediate(&mut NoOpMutations);
+}
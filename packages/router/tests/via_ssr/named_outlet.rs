@@ -0,0 +1,204 @@
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use std::sync::{Arc, Mutex};
+
+use dioxus_history::{History, MemoryHistory};
+use dioxus_router::{RouterContext, components::HistoryProvider, root_router};
+
+#[derive(Routable, Clone, PartialEq)]
+#[rustfmt::skip]
+enum Route {
+    #[layout(Frame)]
+        #[route("/")]
+        #[outlet("sidebar", Folders)]
+        Feed {},
+        #[route("/photo/:id", intercept)]
+        Photo { id: u32 },
+        #[route("/settings")]
+        Settings {},
+}
+
+#[component]
+fn Frame() -> Element {
+    rsx! {
+        aside { Outlet::<Route> { name: "sidebar" } }
+        main { Outlet::<Route> {} }
+        dialog { Outlet::<Route> { name: "modal" } }
+    }
+}
+
+#[component]
+fn Folders() -> Element {
+    rsx! { "Folders" }
+}
+
+#[component]
+fn Feed() -> Element {
+    rsx! { "Feed" }
+}
+
+#[component]
+fn Photo(id: u32) -> Element {
+    rsx! { "Photo {id}" }
+}
+
+#[component]
+fn Settings() -> Element {
+    rsx! { "Settings" }
+}
+
+fn render(path: &str) -> String {
+    let mut vdom = VirtualDom::new_with_props(
+        |path: String| {
+            rsx! {
+                HistoryProvider {
+                    history: move |_| Rc::new(MemoryHistory::with_initial_path(path.clone())) as Rc<dyn History>,
+                    Router::<Route> {}
+                }
+            }
+        },
+        path.to_string(),
+    );
+    vdom.rebuild_in_place();
+    dioxus_ssr::render(&vdom)
+}
+
+fn navigate(vdom: &mut VirtualDom, navigate: impl FnOnce(RouterContext)) -> String {
+    vdom.in_scope(ScopeId::APP, || navigate(root_router().unwrap()));
+    vdom.render_immediate(&mut NoOpMutations);
+    dioxus_ssr::render(vdom)
+}
+
+#[test]
+fn named_outlets_render_the_component_the_route_fills_them_with() {
+    assert_eq!(
+        render("/"),
+        "<aside>Folders</aside><main>Feed</main><dialog></dialog>"
+    );
+    assert_eq!(
+        render("/settings"),
+        "<aside></aside><main>Settings</main><dialog></dialog>"
+    );
+}
+
+#[test]
+fn intercepting_routes_render_as_a_full_page_when_loaded_directly() {
+    assert_eq!(
+        render("/photo/1"),
+        "<aside></aside><main>Photo 1</main><dialog></dialog>"
+    );
+}
+
+#[test]
+fn intercepting_routes_render_over_the_current_page() {
+    // Navigate with the default history of the router, which starts at `/`
+    let mut vdom = VirtualDom::new(|| rsx! { Router::<Route> {} });
+    vdom.rebuild_in_place();
+
+    let over_feed = navigate(&mut vdom, |router| {
+        router.push(Route::Photo { id: 1 });
+        assert!(router.is_intercepted());
+    });
+    assert_eq!(
+        over_feed,
+        "<aside>Folders</aside><main>Feed</main><dialog>Photo 1</dialog>"
+    );
+
+    // Navigating between intercepting routes keeps the same page in the background
+    let next_photo = navigate(&mut vdom, |router| {
+        router.push(Route::Photo { id: 2 });
+    });
+    assert_eq!(
+        next_photo,
+        "<aside>Folders</aside><main>Feed</main><dialog>Photo 2</dialog>"
+    );
+
+    let back = navigate(&mut vdom, |router| router.go_back());
+    assert_eq!(
+        back,
+        "<aside>Folders</aside><main>Feed</main><dialog>Photo 1</dialog>"
+    );
+
+    let closed = navigate(&mut vdom, |router| {
+        router.go_back();
+        assert!(!router.is_intercepted());
+    });
+    assert_eq!(
+        closed,
+        "<aside>Folders</aside><main>Feed</main><dialog></dialog>"
+    );
+}
+
+/// A history the user can move through without the router, like with the back button of a browser
+#[derive(Default)]
+struct BrowserHistory {
+    history: MemoryHistory,
+    updater: Mutex<Option<Arc<dyn Fn() + Send + Sync>>>,
+}
+
+impl BrowserHistory {
+    fn press_back(&self) {
+        self.history.go_back();
+        if let Some(updater) = self.updater.lock().unwrap().as_ref() {
+            updater();
+        }
+    }
+}
+
+impl History for BrowserHistory {
+    fn current_route(&self) -> String {
+        self.history.current_route()
+    }
+
+    fn go_back(&self) {
+        self.history.go_back()
+    }
+
+    fn go_forward(&self) {
+        self.history.go_forward()
+    }
+
+    fn push(&self, route: String) {
+        self.history.push(route)
+    }
+
+    fn replace(&self, path: String) {
+        self.history.replace(path)
+    }
+
+    fn updater(&self, callback: Arc<dyn Fn() + Send + Sync>) {
+        *self.updater.lock().unwrap() = Some(callback);
+    }
+}
+
+#[test]
+fn the_background_is_cleared_when_the_browser_leaves_the_intercepting_route() {
+    let history = Rc::new(BrowserHistory::default());
+    history.push("/settings".to_string());
+    let mut vdom = VirtualDom::new(|| rsx! { Router::<Route> {} })
+        .with_root_context(history.clone() as Rc<dyn History>);
+    vdom.rebuild_in_place();
+
+    navigate(&mut vdom, |router| {
+        router.push(Route::Feed {});
+    });
+    let over_feed = navigate(&mut vdom, |router| {
+        router.push(Route::Photo { id: 1 });
+    });
+    assert_eq!(
+        over_feed,
+        "<aside>Folders</aside><main>Feed</main><dialog>Photo 1</dialog>"
+    );
+
+    history.press_back();
+    history.press_back();
+    let settings = navigate(&mut vdom, |router| {
+        assert!(!router.is_intercepted());
+    });
+    assert_eq!(
+        settings,
+        "<aside></aside><main>Settings</main><dialog></dialog>"
+    );
+}
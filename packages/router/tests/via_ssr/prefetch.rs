@@ -0,0 +1,114 @@
+use dioxus::document::{Document, Eval, NoOpDocument};
+use dioxus::prelude::*;
+use dioxus_core::{CapturedError, NoOpMutations};
+use dioxus_router::root_router;
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+static LOADS: AtomicUsize = AtomicUsize::new(0);
+static EFFECTS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static TITLES: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A document that records every title that is set
+struct TitleDocument;
+
+impl Document for TitleDocument {
+    fn eval(&self, js: String) -> Eval {
+        NoOpDocument.eval(js)
+    }
+
+    fn set_title(&self, title: String) {
+        TITLES.with_borrow_mut(|titles| titles.push(title));
+    }
+}
+
+fn app() -> Element {
+    use_hook(|| provide_context(Rc::new(TitleDocument) as Rc<dyn Document>));
+    rsx! { Router::<Route> {} }
+}
+
+#[derive(Routable, Clone, Debug, PartialEq)]
+enum Route {
+    #[route("/")]
+    Home {},
+    #[route("/user/:id")]
+    User { id: u32 },
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! {
+        Link { to: Route::User { id: 1 }, prefetch: Prefetch::Hover, "User" }
+    }
+}
+
+#[component]
+fn User(id: u32) -> Element {
+    let name = dioxus_fullstack_core::use_loader(move || async move {
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        LOADS.fetch_add(1, Ordering::SeqCst);
+        Ok::<_, CapturedError>(format!("user {id}"))
+    })?;
+    use_effect(|| {
+        EFFECTS.fetch_add(1, Ordering::SeqCst);
+    });
+
+    rsx! {
+        document::Title { "User {id}" }
+        "{name}"
+    }
+}
+
+#[tokio::test]
+async fn prefetched_data_is_reused_after_navigation() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&dom), r#"<a href="/user/1">User</a>"#);
+
+    // Prefetching renders the route in the background until its data is loaded
+    dom.in_scope(ScopeId::APP, || {
+        root_router().unwrap().prefetch(Route::User { id: 1 })
+    });
+    dom.render_immediate(&mut NoOpMutations);
+    tokio::time::timeout(Duration::from_secs(1), async {
+        while LOADS.load(Ordering::SeqCst) == 0 {
+            dom.wait_for_work().await;
+            dom.render_immediate(&mut NoOpMutations);
+        }
+    })
+    .await
+    .unwrap();
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), r#"<a href="/user/1">User</a>"#);
+    assert_eq!(LOADS.load(Ordering::SeqCst), 1);
+
+    // The prefetched route is never mounted, so its effects and head elements don't run
+    assert_eq!(EFFECTS.load(Ordering::SeqCst), 0);
+    assert!(TITLES.with_borrow(|titles| titles.is_empty()));
+
+    // The route starts with the prefetched data instead of suspending
+    dom.in_scope(ScopeId::APP, || {
+        root_router().unwrap().push(Route::User { id: 1 });
+    });
+    dom.render_immediate(&mut NoOpMutations);
+    assert!(dioxus_ssr::render(&dom).starts_with("user 1"));
+    assert_eq!(LOADS.load(Ordering::SeqCst), 1);
+    assert_eq!(TITLES.with_borrow(|titles| titles.clone()), ["User 1"]);
+
+    // The hidden tree is removed once the navigation is done
+    _ = tokio::time::timeout(Duration::from_millis(100), dom.wait_for_work()).await;
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "user 1");
+    assert_eq!(LOADS.load(Ordering::SeqCst), 1);
+
+    // Only the effects of the mounted route run
+    dom.process_events();
+    assert_eq!(EFFECTS.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,245 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    rc::Rc,
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+
+use dioxus::document::{Document, Eval, EvalError, Evaluator};
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_history::{History, MemoryHistory};
+use dioxus_router::{RouterConfig, RouterContext, components::HistoryProvider, root_router};
+use generational_box::{Owner, UnsyncStorage};
+use serde_json::{Value, json};
+
+#[derive(Routable, Clone, PartialEq)]
+enum Route {
+    #[route("/")]
+    Home {},
+    #[route("/about")]
+    About {},
+    #[route("/docs#:section")]
+    Docs { section: String },
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! { "Home" }
+}
+
+#[component]
+fn About() -> Element {
+    rsx! { "About" }
+}
+
+#[component]
+fn Docs(section: String) -> Element {
+    rsx! { "Docs" }
+}
+
+/// The messages between the router and the javascript that scrolls the page
+#[derive(Default)]
+struct Messages {
+    /// The targets the router scrolled the page to
+    sent: Vec<Value>,
+    /// The scroll positions the page reports to the router
+    received: VecDeque<Value>,
+    waker: Option<Waker>,
+}
+
+struct ScrollDocument {
+    owner: Owner<UnsyncStorage>,
+    messages: Rc<RefCell<Messages>>,
+}
+
+impl ScrollDocument {
+    fn new() -> Self {
+        Self {
+            owner: Owner::default(),
+            messages: Default::default(),
+        }
+    }
+
+    /// Scroll the page like the user would
+    fn scroll(&self, x: f64, y: f64) {
+        let mut messages = self.messages.borrow_mut();
+        messages.received.push_back(json!([x, y]));
+        if let Some(waker) = messages.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Document for ScrollDocument {
+    fn eval(&self, _: String) -> Eval {
+        Eval::new(self.owner.insert(Box::new(ScrollEvaluator {
+            messages: self.messages.clone(),
+        })))
+    }
+}
+
+struct ScrollEvaluator {
+    messages: Rc<RefCell<Messages>>,
+}
+
+impl Evaluator for ScrollEvaluator {
+    fn send(&self, data: Value) -> Result<(), EvalError> {
+        self.messages.borrow_mut().sent.push(data);
+        Ok(())
+    }
+
+    fn poll_recv(&mut self, context: &mut Context<'_>) -> Poll<Result<Value, EvalError>> {
+        let mut messages = self.messages.borrow_mut();
+        match messages.received.pop_front() {
+            Some(message) => Poll::Ready(Ok(message)),
+            None => {
+                messages.waker = Some(context.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    fn poll_join(&mut self, _: &mut Context<'_>) -> Poll<Result<Value, EvalError>> {
+        Poll::Pending
+    }
+}
+
+async fn wait(vdom: &mut VirtualDom) {
+    _ = tokio::time::timeout(Duration::from_millis(10), vdom.wait_for_work()).await;
+    vdom.render_immediate(&mut NoOpMutations);
+}
+
+async fn navigate(vdom: &mut VirtualDom, navigate: impl FnOnce(RouterContext)) {
+    vdom.in_scope(ScopeId::APP, || navigate(root_router().unwrap()));
+    vdom.render_immediate(&mut NoOpMutations);
+    wait(vdom).await;
+}
+
+#[tokio::test]
+async fn scroll_positions_are_restored_per_history_entry() {
+    let document = Rc::new(ScrollDocument::new());
+    let mut vdom = VirtualDom::new(|| rsx! { Router::<Route> {} })
+        .with_root_context(document.clone() as Rc<dyn Document>);
+    vdom.rebuild_in_place();
+    wait(&mut vdom).await;
+
+    // Loading the page leaves the scroll position alone
+    assert!(document.messages.borrow().sent.is_empty());
+
+    document.scroll(0.0, 500.0);
+    wait(&mut vdom).await;
+
+    // Pushing a route scrolls to the top
+    navigate(&mut vdom, |router| {
+        router.push(Route::About {});
+    })
+    .await;
+    assert_eq!(document.messages.borrow().sent, [json!([null, [0.0, 0.0]])]);
+
+    // Going back restores the scroll position of the entry
+    navigate(&mut vdom, |router| router.go_back()).await;
+    assert_eq!(
+        document.messages.borrow().sent[1..],
+        [json!([null, [0.0, 500.0]])]
+    );
+
+    // Going forward to an entry that was never scrolled scrolls to the top
+    navigate(&mut vdom, |router| router.go_forward()).await;
+    assert_eq!(
+        document.messages.borrow().sent[2..],
+        [json!([null, [0.0, 0.0]])]
+    );
+
+    // The hash of the route is scrolled into view
+    navigate(&mut vdom, |router| {
+        router.push(Route::Docs {
+            section: "team".to_string(),
+        });
+    })
+    .await;
+    assert_eq!(
+        document.messages.borrow().sent[3..],
+        [json!(["team", [0.0, 0.0]])]
+    );
+}
+
+#[tokio::test]
+async fn scroll_restoration_can_be_turned_off() {
+    let document = Rc::new(ScrollDocument::new());
+    let mut vdom = VirtualDom::new(|| {
+        rsx! {
+            Router::<Route> { config: || RouterConfig::default().scroll_restoration(false) }
+        }
+    })
+    .with_root_context(document.clone() as Rc<dyn Document>);
+    vdom.rebuild_in_place();
+
+    navigate(&mut vdom, |router| {
+        router.push(Route::About {});
+    })
+    .await;
+    assert!(document.messages.borrow().sent.is_empty());
+}
+
+/// A history that records if the router took over scroll restoration
+#[derive(Default)]
+struct ScrollingHistory {
+    history: MemoryHistory,
+    restores_scroll: Cell<bool>,
+}
+
+impl History for ScrollingHistory {
+    fn current_route(&self) -> String {
+        self.history.current_route()
+    }
+
+    fn go_back(&self) {
+        self.history.go_back()
+    }
+
+    fn go_forward(&self) {
+        self.history.go_forward()
+    }
+
+    fn push(&self, route: String) {
+        self.history.push(route)
+    }
+
+    fn replace(&self, path: String) {
+        self.history.replace(path)
+    }
+
+    fn disable_scroll_restoration(&self) {
+        self.restores_scroll.set(false);
+    }
+}
+
+#[test]
+fn the_history_stops_scrolling_when_the_router_restores_scroll() {
+    fn render(scroll_restoration: bool) -> bool {
+        let history = Rc::new(ScrollingHistory {
+            restores_scroll: Cell::new(true),
+            ..Default::default()
+        });
+        let mut vdom = VirtualDom::new_with_props(
+            |(history, scroll_restoration): (Rc<ScrollingHistory>, bool)| {
+                rsx! {
+                    HistoryProvider {
+                        history: move |_| history.clone() as Rc<dyn History>,
+                        Router::<Route> {
+                            config: move || RouterConfig::default().scroll_restoration(scroll_restoration),
+                        }
+                    }
+                }
+            },
+            (history.clone(), scroll_restoration),
+        );
+        vdom.rebuild_in_place();
+        history.restores_scroll.get()
+    }
+
+    assert!(!render(true));
+    assert!(render(false));
+}
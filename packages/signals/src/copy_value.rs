@@ -96,10 +96,14 @@ impl<T, S: Storage<T>> CopyValue<T, S> {
         caller: &'static std::panic::Location<'static>,
     ) -> Self {
         let owner = current_owner();
+        // Values owned by an owner set with `with_owner` may be created outside of a runtime
+        let origin_scope = Runtime::try_current()
+            .and_then(|runtime| runtime.try_current_scope_id())
+            .unwrap_or(ScopeId::ROOT);
 
         Self {
             value: owner.insert_rc_with_caller(value, caller),
-            origin_scope: current_scope_id(),
+            origin_scope,
         }
     }
 
@@ -190,6 +190,7 @@ where
             if let Some(reactive_context) = ReactiveContext::current() {
                 tracing::trace!("Subscribing to the reactive context {}", reactive_context);
                 reactive_context.subscribe(read.subscribers.clone());
+                self.inner.reactive_source().record_read(&reactive_context);
             }
         }
         result.map(|read| <UnsyncStorage as AnyStorage>::map(read, |v| &v.value))
@@ -2,7 +2,9 @@ use crate::{
     CopyValue, Global, GlobalMemo, GlobalSignal, Memo, ReadableRef, WritableRef, default_impl,
     fmt_impls, read::*, write::*, write_impls,
 };
-use dioxus_core::{IntoAttributeValue, IntoDynNode, ReactiveContext, ScopeId, Subscribers};
+use dioxus_core::{
+    IntoAttributeValue, IntoDynNode, ReactiveContext, ReactiveSource, ScopeId, Subscribers,
+};
 use generational_box::{BorrowResult, Storage, SyncStorage, UnsyncStorage};
 use std::{collections::HashSet, ops::Deref, sync::Arc, sync::Mutex};
 
@@ -252,11 +254,17 @@ impl<T, S: Storage<SignalData<T>>> Signal<T, S> {
         self.inner.origin_scope()
     }
 
-    fn update_subscribers(&self)
+    /// Get the reactive source used to record reads and writes of this signal in the reactive graph.
+    pub(crate) fn reactive_source(&self) -> ReactiveSource {
+        ReactiveSource::new(self.id(), self.inner.value.created_at())
+    }
+
+    fn update_subscribers(&self, written_at: Option<&'static std::panic::Location<'static>>)
     where
         T: 'static,
     {
         {
+            let _write = self.reactive_source().record_write(written_at);
             let inner = self.inner.read();
 
             // We cannot hold the subscribers lock while calling mark_dirty, because mark_dirty can run user code which may cause a new subscriber to be added. If we hold the lock, we will deadlock.
@@ -411,6 +419,7 @@ impl<T, S: Storage<SignalData<T>>> Readable for Signal<T, S> {
         if let Some(reactive_context) = ReactiveContext::current() {
             tracing::trace!("Subscribing to the reactive context {}", reactive_context);
             reactive_context.subscribe(inner.subscribers.clone());
+            self.reactive_source().record_read(&reactive_context);
         }
 
         Ok(S::map(inner, |v| &v.value))
@@ -533,7 +542,11 @@ impl<T: 'static, S: Storage<SignalData<T>> + 'static> Drop for SignalSubscriberD
                 self.origin
             );
         }
-        self.signal.update_subscribers();
+        #[cfg(debug_assertions)]
+        let written_at = Some(self.origin);
+        #[cfg(not(debug_assertions))]
+        let written_at = None;
+        self.signal.update_subscribers(written_at);
     }
 }
 
@@ -0,0 +1,145 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+
+#[test]
+fn records_which_write_reran_a_scope() {
+    let mut dom = VirtualDom::new(|| {
+        let signal = use_signal(|| 0);
+        use_context_provider(|| signal);
+        rsx! { signal_reader {} }
+    });
+    dom.rebuild_in_place();
+
+    #[component]
+    fn signal_reader() -> Element {
+        let signal = use_context::<Signal<i32>>();
+        rsx! { "{signal}" }
+    }
+
+    let child_scope = ScopeId(ScopeId::APP.0 + 1);
+    let runtime = dom.runtime();
+
+    // Nothing is recorded until recording is enabled
+    dom.in_scope(ScopeId::APP, || {
+        *consume_context::<Signal<i32>>().write() += 1
+    });
+    assert!(runtime.reactive_graph().updates.is_empty());
+
+    runtime.start_recording_reactive_graph();
+    dom.render_immediate(&mut NoOpMutations);
+    dom.in_scope(ScopeId::APP, || {
+        *consume_context::<Signal<i32>>().write() += 1
+    });
+    runtime.stop_recording_reactive_graph();
+
+    let graph = runtime.reactive_graph();
+    let updates: Vec<_> = graph.why_did_render(child_scope).collect();
+    assert_eq!(updates.len(), 1);
+    let update = updates[0];
+    let source = graph.source(update.source.unwrap()).unwrap();
+    let context = graph.context(update.context).unwrap();
+    assert_eq!(context.scope, Some(child_scope));
+    assert!(context.reads.contains(&source.id));
+    if cfg!(debug_assertions) {
+        assert!(
+            update
+                .written_at
+                .as_ref()
+                .unwrap()
+                .file
+                .ends_with("reactive_graph.rs")
+        );
+        assert!(source.created_at.is_some());
+    }
+
+    runtime.clear_reactive_graph();
+    assert_eq!(runtime.reactive_graph(), Default::default());
+}
+
+#[test]
+fn dropped_contexts_are_removed_from_the_graph() {
+    let mut dom = VirtualDom::new(|| {
+        let show = use_signal(|| true);
+        use_context_provider(|| show);
+        rsx! {
+            if show() {
+                local_reader {}
+            }
+        }
+    });
+    dom.rebuild_in_place();
+
+    #[component]
+    fn local_reader() -> Element {
+        let local = use_signal(|| 0);
+        rsx! { "{local}" }
+    }
+
+    let child_scope = ScopeId(ScopeId::APP.0 + 1);
+    let runtime = dom.runtime();
+    runtime.start_recording_reactive_graph();
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut NoOpMutations);
+    dom.mark_dirty(child_scope);
+    dom.render_immediate(&mut NoOpMutations);
+    let graph = runtime.reactive_graph();
+    let reader = graph
+        .contexts
+        .iter()
+        .find(|context| context.scope == Some(child_scope))
+        .unwrap();
+    let local = *reader
+        .reads
+        .iter()
+        .find(|source| {
+            // The local signal is the only value the reader reads that nothing else reads
+            graph
+                .contexts
+                .iter()
+                .filter(|context| context.reads.contains(source))
+                .count()
+                == 1
+        })
+        .unwrap();
+
+    // Unmounting the reader drops its reactive context and the signal only it read
+    dom.in_scope(ScopeId::APP, || consume_context::<Signal<bool>>().set(false));
+    dom.render_immediate(&mut NoOpMutations);
+    let graph = runtime.reactive_graph();
+    assert!(
+        graph
+            .contexts
+            .iter()
+            .all(|context| context.scope != Some(child_scope))
+    );
+    assert!(graph.source(local).is_none());
+    // The write that reran the app is still referenced by its update
+    let update = graph.why_did_render(ScopeId::APP).next().unwrap();
+    assert!(graph.source(update.source.unwrap()).is_some());
+}
+
+#[test]
+fn each_runtime_records_its_own_graph() {
+    fn app() -> Element {
+        let signal = use_signal(|| 0);
+        use_context_provider(|| signal);
+        rsx! { "{signal}" }
+    }
+
+    let mut recorded = VirtualDom::new(app);
+    recorded.rebuild_in_place();
+    let mut ignored = VirtualDom::new(app);
+    ignored.rebuild_in_place();
+
+    recorded.runtime().start_recording_reactive_graph();
+    for dom in [&mut recorded, &mut ignored] {
+        dom.mark_dirty(ScopeId::APP);
+        dom.render_immediate(&mut NoOpMutations);
+        dom.in_scope(ScopeId::APP, || {
+            *consume_context::<Signal<i32>>().write() += 1
+        });
+    }
+
+    assert_eq!(recorded.runtime().reactive_graph().updates.len(), 1);
+    assert_eq!(ignored.runtime().reactive_graph(), Default::default());
+}
@@ -172,10 +172,12 @@ impl Renderer {
                     let escaped = escape_text.should_escape(parent_escaped);
                     match &template.dynamic_nodes[*index] {
                         DynamicNode::Component(node) => {
-                            if let Some(render_components) = self.render_components.clone() {
-                                let scope_id =
-                                    node.mounted_scope_id(*index, template, dom).unwrap();
-
+                            let scope_id = node.mounted_scope_id(*index, template, dom).unwrap();
+                            // Portals are created in their target on the client, so we only render their placeholder
+                            if self.pre_render && dom.portal_placeholder(scope_id).is_some() {
+                                write!(buf, "<!--placeholder{}-->", self.dynamic_node_id)?;
+                                self.dynamic_node_id += 1;
+                            } else if let Some(render_components) = self.render_components.clone() {
                                 render_components(self, &mut buf, dom, scope_id)?;
                             } else {
                                 let scope = node.mounted_scope(*index, template, dom).unwrap();
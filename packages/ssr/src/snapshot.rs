@@ -0,0 +1,542 @@
+//! Snapshot testing for rendered html.
+//!
+//! Snapshots are stored as normalized, pretty-printed html files. When the rendered output doesn't match the stored
+//! snapshot, the assertion panics with a diff that shows which elements changed. Set the `DIOXUS_UPDATE_SNAPSHOTS`
+//! environment variable to write the new output to the snapshot files instead.
+//!
+//! ```rust, no_run
+//! use dioxus::prelude::*;
+//! use dioxus_ssr::Renderer;
+//!
+//! fn app() -> Element {
+//!     rsx! { ul { li { "one" } li { "two" } } }
+//! }
+//!
+//! let mut dom = VirtualDom::new(app);
+//! dom.rebuild_in_place();
+//! // Compares against `tests/snapshots/list.html`
+//! Renderer::new().assert_snapshot("list", &dom);
+//! ```
+
+use crate::Renderer;
+use dioxus_core::VirtualDom;
+use std::future::Future;
+use std::panic::Location;
+use std::path::{Path, PathBuf};
+
+/// The environment variable that makes snapshot assertions write the rendered output to the snapshot files.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "DIOXUS_UPDATE_SNAPSHOTS";
+
+/// The number of unchanged lines to show around each change in a diff
+const DIFF_CONTEXT: usize = 3;
+
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+impl Renderer {
+    /// Render a virtual dom to normalized, pretty-printed html for a snapshot.
+    pub fn render_snapshot(&mut self, dom: &VirtualDom) -> String {
+        pretty_html(&self.render(dom))
+    }
+
+    /// Render a virtual dom and compare it to the snapshot with this name in `tests/snapshots`.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a diff if the output doesn't match the snapshot, or if the snapshot doesn't exist.
+    #[track_caller]
+    pub fn assert_snapshot(&mut self, name: &str, dom: &VirtualDom) {
+        Snapshots::new().assert(name, &self.render_snapshot(dom));
+    }
+
+    /// Wait for all suspense boundaries in the virtual dom to resolve, then compare it to the snapshot with this name.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a diff if the output doesn't match the snapshot, or if the snapshot doesn't exist. The panic is
+    /// raised while the future is polled, so the message includes the location of the assertion.
+    #[track_caller]
+    pub fn assert_snapshot_after_suspense<'a>(
+        &'a mut self,
+        name: &'a str,
+        dom: &'a mut VirtualDom,
+    ) -> impl Future<Output = ()> + 'a {
+        // Async functions don't propagate the caller, so it is captured before the future is created
+        let caller = Location::caller();
+        async move {
+            dom.wait_for_suspense().await;
+            if let Err(message) = Snapshots::new().compare(name, &self.render_snapshot(dom)) {
+                panic!("{message}\n\nsnapshot asserted at {caller}");
+            }
+        }
+    }
+}
+
+/// A directory of html snapshots.
+#[derive(Clone, Debug)]
+pub struct Snapshots {
+    directory: PathBuf,
+    update: bool,
+}
+
+impl Default for Snapshots {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Snapshots {
+    /// Use the `tests/snapshots` directory of the crate that is being tested. Snapshots are updated if the
+    /// [`UPDATE_SNAPSHOTS_ENV`] environment variable is set.
+    pub fn new() -> Self {
+        let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_default();
+        Self {
+            directory: manifest_dir.join("tests").join("snapshots"),
+            update: std::env::var(UPDATE_SNAPSHOTS_ENV)
+                .is_ok_and(|value| !value.is_empty() && value != "0"),
+        }
+    }
+
+    /// Store snapshots in a different directory.
+    pub fn with_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = directory.into();
+        self
+    }
+
+    /// Set whether snapshots should be overwritten with the new output instead of compared.
+    pub fn with_update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    /// Get the path of the snapshot with this name.
+    pub fn path(&self, name: &str) -> PathBuf {
+        self.directory.join(format!("{name}.html"))
+    }
+
+    /// Compare html to the snapshot with this name. The html is normalized before it is compared.
+    ///
+    /// If the snapshot doesn't match and updating is disabled, the new output is written next to the snapshot
+    /// with a `.new` extension so it can be reviewed.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a diff if the html doesn't match the snapshot, or if the snapshot doesn't exist.
+    #[track_caller]
+    pub fn assert(&self, name: &str, html: &str) {
+        if let Err(message) = self.compare(name, html) {
+            panic!("{message}");
+        }
+    }
+
+    /// Compare html to the snapshot like [`Snapshots::assert`], but return the failure message instead of panicking.
+    fn compare(&self, name: &str, html: &str) -> Result<(), String> {
+        let actual = pretty_html(html);
+        let path = self.path(name);
+        let pending = path.with_extension("html.new");
+
+        if self.update {
+            write_file(&path, &actual);
+            _ = std::fs::remove_file(&pending);
+            return Ok(());
+        }
+
+        let Ok(expected) = std::fs::read_to_string(&path) else {
+            write_file(&pending, &actual);
+            return Err(format!(
+                "snapshot `{name}` doesn't exist at {}\n\nrun the test with {UPDATE_SNAPSHOTS_ENV}=1 to create it:\n\n{actual}",
+                path.display()
+            ));
+        };
+
+        if pretty_html(&expected) == actual {
+            _ = std::fs::remove_file(&pending);
+            return Ok(());
+        }
+
+        write_file(&pending, &actual);
+        Err(format!(
+            "snapshot `{name}` doesn't match {}\n\n{}\nrun the test with {UPDATE_SNAPSHOTS_ENV}=1 to accept the changes",
+            path.display(),
+            diff(&expected, &actual)
+        ))
+    }
+}
+
+fn write_file(path: &Path, contents: &str) {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .unwrap_or_else(|err| panic!("failed to create {}: {err}", parent.display()));
+    }
+    std::fs::write(path, contents)
+        .unwrap_or_else(|err| panic!("failed to write {}: {err}", path.display()));
+}
+
+/// Normalize and pretty print html.
+///
+/// Every element, text node and comment is written on its own line and indented by depth. Attributes are sorted,
+/// whitespace in text is collapsed, and the markers the renderer adds for hydration are removed so the output only
+/// changes when the rendered content does.
+pub fn pretty_html(html: &str) -> String {
+    let mut out = String::new();
+    for line in pretty_lines(html) {
+        for _ in 0..line.depth {
+            out.push_str("  ");
+        }
+        out.push_str(&line.text);
+        out.push('\n');
+    }
+    out
+}
+
+/// A line of pretty printed html and the elements it is nested in
+struct Line {
+    depth: usize,
+    text: String,
+    path: Vec<String>,
+}
+
+enum Token<'a> {
+    Open {
+        tag: String,
+        attrs: Vec<(String, Option<String>)>,
+        self_closing: bool,
+    },
+    Close(String),
+    Text(&'a str),
+    Comment(&'a str),
+}
+
+fn pretty_lines(html: &str) -> Vec<Line> {
+    let mut lines = Vec::new();
+    let mut stack: Vec<(String, String)> = Vec::new();
+    let path = |stack: &[(String, String)]| stack.iter().map(|(_, label)| label.clone()).collect();
+
+    for token in tokenize(html) {
+        match token {
+            Token::Open {
+                tag,
+                mut attrs,
+                self_closing,
+            } => {
+                attrs.retain(|(name, _)| name != "data-node-hydration");
+                attrs.sort_by(|(a, _), (b, _)| a.cmp(b));
+                let mut text = format!("<{tag}");
+                for (name, value) in &attrs {
+                    match value {
+                        Some(value) => text.push_str(&format!(" {name}=\"{value}\"")),
+                        None => text.push_str(&format!(" {name}")),
+                    }
+                }
+                let void = self_closing || VOID_ELEMENTS.contains(&tag.as_str());
+                text.push_str(if void { "/>" } else { ">" });
+                lines.push(Line {
+                    depth: stack.len(),
+                    text,
+                    path: path(&stack),
+                });
+                if !void {
+                    let label = element_label(&tag, &attrs);
+                    stack.push((tag, label));
+                }
+            }
+            Token::Close(tag) => {
+                // Close any elements that were left open inside this one
+                let Some(index) = stack.iter().rposition(|(open, _)| *open == tag) else {
+                    continue;
+                };
+                while stack.len() > index {
+                    let (tag, _) = stack.pop().unwrap();
+                    lines.push(Line {
+                        depth: stack.len(),
+                        text: format!("</{tag}>"),
+                        path: path(&stack),
+                    });
+                }
+            }
+            Token::Text(text) => {
+                let raw = stack
+                    .last()
+                    .is_some_and(|(tag, _)| matches!(tag.as_str(), "pre" | "textarea"));
+                let text = match raw {
+                    true => text.to_string(),
+                    false => text.split_whitespace().collect::<Vec<_>>().join(" "),
+                };
+                if text.trim().is_empty() {
+                    continue;
+                }
+                lines.push(Line {
+                    depth: stack.len(),
+                    text,
+                    path: path(&stack),
+                });
+            }
+            Token::Comment(comment) => {
+                let comment = comment.trim();
+                // Hydration markers for dynamic text and placeholders
+                if comment == "#"
+                    || comment == "/"
+                    || comment.starts_with("node-id")
+                    || comment.starts_with("placeholder")
+                {
+                    continue;
+                }
+                lines.push(Line {
+                    depth: stack.len(),
+                    text: format!("<!--{comment}-->"),
+                    path: path(&stack),
+                });
+            }
+        }
+    }
+
+    while let Some((tag, _)) = stack.pop() {
+        lines.push(Line {
+            depth: stack.len(),
+            text: format!("</{tag}>"),
+            path: path(&stack),
+        });
+    }
+
+    lines
+}
+
+/// A short description of an element used to show where a change happened, like `div#app.card`
+fn element_label(tag: &str, attrs: &[(String, Option<String>)]) -> String {
+    let mut label = tag.to_string();
+    for (name, value) in attrs {
+        match (name.as_str(), value) {
+            ("id", Some(id)) => label.push_str(&format!("#{id}")),
+            ("class", Some(class)) => {
+                for class in class.split_whitespace() {
+                    label.push_str(&format!(".{class}"));
+                }
+            }
+            _ => {}
+        }
+    }
+    label
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    let mut raw_text_tag: Option<String> = None;
+
+    while !rest.is_empty() {
+        // The contents of script and style tags are not html
+        if let Some(tag) = raw_text_tag.take() {
+            let end = rest.find(&format!("</{tag}")).unwrap_or(rest.len());
+            let text = &rest[..end];
+            if !text.trim().is_empty() {
+                tokens.push(Token::Text(text));
+            }
+            rest = &rest[end..];
+            continue;
+        }
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            let end = comment.find("-->").unwrap_or(comment.len());
+            tokens.push(Token::Comment(&comment[..end]));
+            rest = comment.get(end + 3..).unwrap_or_default();
+        } else if let Some(close) = rest.strip_prefix("</") {
+            let end = close.find('>').unwrap_or(close.len());
+            tokens.push(Token::Close(close[..end].trim().to_string()));
+            rest = close.get(end + 1..).unwrap_or_default();
+        } else if rest.starts_with('<') && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+        {
+            let (token, remaining) = parse_open_tag(&rest[1..]);
+            if let Token::Open {
+                tag,
+                self_closing: false,
+                ..
+            } = &token
+                && matches!(tag.as_str(), "script" | "style")
+            {
+                raw_text_tag = Some(tag.clone());
+            }
+            tokens.push(token);
+            rest = remaining;
+        } else {
+            let end = rest
+                .char_indices()
+                .skip(1)
+                .find(|(_, c)| *c == '<')
+                .map_or(rest.len(), |(end, _)| end);
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+
+    tokens
+}
+
+fn parse_open_tag(input: &str) -> (Token<'_>, &str) {
+    let name_end = input
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .unwrap_or(input.len());
+    let tag = input[..name_end].to_string();
+    let mut rest = &input[name_end..];
+    let mut attrs = Vec::new();
+    let mut self_closing = false;
+
+    loop {
+        rest = rest.trim_start();
+        if let Some(remaining) = rest.strip_prefix("/>") {
+            self_closing = true;
+            rest = remaining;
+            break;
+        }
+        if let Some(remaining) = rest.strip_prefix('>') {
+            rest = remaining;
+            break;
+        }
+        if let Some(remaining) = rest.strip_prefix('/') {
+            rest = remaining;
+            continue;
+        }
+        if rest.is_empty() {
+            break;
+        }
+
+        let name_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '>' || c == '/')
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_string();
+        rest = rest[name_end..].trim_start();
+
+        let value = match rest.strip_prefix('=') {
+            Some(value) => {
+                let value = value.trim_start();
+                let (parsed, remaining) = match value.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let inner = &value[1..];
+                        let end = inner.find(quote).unwrap_or(inner.len());
+                        (&inner[..end], inner.get(end + 1..).unwrap_or_default())
+                    }
+                    _ => {
+                        let end = value
+                            .find(|c: char| c.is_whitespace() || c == '>')
+                            .unwrap_or(value.len());
+                        (&value[..end], &value[end..])
+                    }
+                };
+                rest = remaining;
+                Some(parsed.to_string())
+            }
+            None => None,
+        };
+        attrs.push((name, value));
+    }
+
+    (
+        Token::Open {
+            tag,
+            attrs,
+            self_closing,
+        },
+        rest,
+    )
+}
+
+/// Diff two html documents line by line after pretty printing them. Each change is labeled with the elements
+/// it is nested in.
+fn diff(expected: &str, actual: &str) -> String {
+    let old = pretty_lines(expected);
+    let new = pretty_lines(actual);
+    let ops = diff_lines(&old, &new);
+
+    // Find the operations that should be shown: every change and the context around it
+    let mut visible = vec![false; ops.len()];
+    for (index, op) in ops.iter().enumerate() {
+        if !matches!(op, DiffOp::Equal(..)) {
+            let start = index.saturating_sub(DIFF_CONTEXT);
+            let end = (index + DIFF_CONTEXT + 1).min(ops.len());
+            visible[start..end]
+                .iter_mut()
+                .for_each(|visible| *visible = true);
+        }
+    }
+
+    let mut out = String::new();
+    let mut in_hunk = false;
+    for (index, op) in ops.iter().enumerate() {
+        if !visible[index] {
+            in_hunk = false;
+            continue;
+        }
+        if !in_hunk {
+            // Label the hunk with the location of its first change
+            let first_change = ops[index..]
+                .iter()
+                .find(|op| !matches!(op, DiffOp::Equal(..)))
+                .unwrap_or(op);
+            let path = match first_change {
+                DiffOp::Equal(line) | DiffOp::Delete(line) => &old[*line].path,
+                DiffOp::Insert(line) => &new[*line].path,
+            };
+            let path = match path.is_empty() {
+                true => "(root)".to_string(),
+                false => path.join(" > "),
+            };
+            out.push_str(&format!("@@ {path} @@\n"));
+            in_hunk = true;
+        }
+        let (sign, line) = match op {
+            DiffOp::Equal(line) => (' ', &old[*line]),
+            DiffOp::Delete(line) => ('-', &old[*line]),
+            DiffOp::Insert(line) => ('+', &new[*line]),
+        };
+        out.push(sign);
+        for _ in 0..line.depth {
+            out.push_str("  ");
+        }
+        out.push(' ');
+        out.push_str(&line.text);
+        out.push('\n');
+    }
+    out
+}
+
+enum DiffOp {
+    Equal(usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Find the longest common subsequence of lines and turn it into a list of edits
+fn diff_lines(old: &[Line], new: &[Line]) -> Vec<DiffOp> {
+    let same = |a: &Line, b: &Line| a.depth == b.depth && a.text == b.text;
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = match same(&old[i], &new[j]) {
+                true => lengths[i + 1][j + 1] + 1,
+                false => lengths[i + 1][j].max(lengths[i][j + 1]),
+            };
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if same(&old[i], &new[j]) {
+            ops.push(DiffOp::Equal(i));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            ops.push(DiffOp::Delete(i));
+            i += 1;
+        } else {
+            ops.push(DiffOp::Insert(j));
+            j += 1;
+        }
+    }
+    ops.extend((i..old.len()).map(DiffOp::Delete));
+    ops.extend((j..new.len()).map(DiffOp::Insert));
+    ops
+}
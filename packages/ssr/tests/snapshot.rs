@@ -0,0 +1,161 @@
+use dioxus::prelude::*;
+use dioxus_ssr::{
+    Renderer,
+    snapshot::{Snapshots, pretty_html},
+};
+use std::time::Duration;
+
+fn temp_snapshots(name: &str) -> Snapshots {
+    let directory = std::env::temp_dir().join(format!(
+        "dioxus-ssr-snapshots-{name}-{}",
+        std::process::id()
+    ));
+    _ = std::fs::remove_dir_all(&directory);
+    Snapshots::new()
+        .with_directory(directory)
+        .with_update(false)
+}
+
+#[test]
+fn pretty_html_normalizes_output() {
+    assert_eq!(
+        pretty_html(
+            r#"<div id="app" class="card" data-node-hydration="0"><p>  hello
+            world </p><input value="a" disabled/><!--node-id1-->text<!--#--></div>"#
+        ),
+        r#"<div class="card" id="app">
+  <p>
+    hello world
+  </p>
+  <input disabled value="a"/>
+  text
+</div>
+"#
+    );
+}
+
+#[test]
+fn renderer_matches_stored_snapshot() {
+    fn app() -> Element {
+        let items = ["one", "two"];
+        rsx! {
+            ul { class: "list",
+                for item in items {
+                    li { "{item}" }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    Renderer::new().assert_snapshot("list", &dom);
+}
+
+#[tokio::test]
+async fn snapshots_can_wait_for_suspense() {
+    fn app() -> Element {
+        rsx! {
+            SuspenseBoundary { fallback: |_| rsx! { "Loading..." }, user_name {} }
+        }
+    }
+
+    #[component]
+    fn user_name() -> Element {
+        let name = use_resource(|| async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            "Ferris"
+        })
+        .suspend()?;
+        rsx! { h1 { "{name}" } }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    Renderer::new()
+        .assert_snapshot_after_suspense("suspense", &mut dom)
+        .await;
+}
+
+#[test]
+fn mismatches_show_a_structural_diff() {
+    let snapshots = temp_snapshots("mismatch");
+    snapshots.clone().with_update(true).assert(
+        "card",
+        "<div class=\"card\"><h1>Title</h1><p>Body</p></div>",
+    );
+
+    let result = std::panic::catch_unwind(|| {
+        snapshots.assert(
+            "card",
+            "<div class=\"card\"><h1>Title</h1><p>Changed</p></div>",
+        )
+    });
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.contains("@@ div.card > p @@"), "{message}");
+    assert!(message.contains("-     Body"), "{message}");
+    assert!(message.contains("+     Changed"), "{message}");
+
+    // The new output is saved next to the snapshot for review
+    let pending = snapshots.path("card").with_extension("html.new");
+    assert!(
+        std::fs::read_to_string(&pending)
+            .unwrap()
+            .contains("Changed")
+    );
+
+    // Formatting differences don't count as changes
+    snapshots.assert(
+        "card",
+        "<div  class='card'>\n  <h1>Title</h1>\n  <p>Body</p>\n</div>",
+    );
+    assert!(!pending.exists());
+}
+
+#[test]
+fn missing_snapshots_fail_unless_updating() {
+    let snapshots = temp_snapshots("missing");
+    let result = std::panic::catch_unwind(|| snapshots.assert("new", "<p>hi</p>"));
+    assert!(result.is_err());
+
+    snapshots
+        .clone()
+        .with_update(true)
+        .assert("new", "<p>hi</p>");
+    assert_eq!(
+        std::fs::read_to_string(snapshots.path("new")).unwrap(),
+        "<p>\n  hi\n</p>\n"
+    );
+    snapshots.assert("new", "<p>hi</p>");
+}
+
+#[tokio::test]
+async fn suspense_failures_point_at_the_assertion() {
+    fn app() -> Element {
+        rsx! { h1 { "Not Ferris" } }
+    }
+
+    let result = tokio::task::LocalSet::new()
+        .run_until(async {
+            tokio::task::spawn_local(async {
+                let mut dom = VirtualDom::new(app);
+                dom.rebuild_in_place();
+                Renderer::new()
+                    .assert_snapshot_after_suspense("suspense", &mut dom)
+                    .await;
+            })
+            .await
+        })
+        .await;
+    _ = std::fs::remove_file(Snapshots::new().path("suspense").with_extension("html.new"));
+
+    let message = *result
+        .unwrap_err()
+        .into_panic()
+        .downcast::<String>()
+        .unwrap();
+    assert!(
+        message.contains(&format!("snapshot asserted at {}:", file!())),
+        "{message}"
+    );
+}
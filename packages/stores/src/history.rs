@@ -0,0 +1,310 @@
+//! Undo and redo for stores.
+
+use crate::{
+    patch::{PatchRecorder, StorePatch},
+    store::Store,
+};
+use dioxus_core::use_hook;
+use dioxus_signals::{CopyValue, ReadableExt, Signal, WritableExt};
+use serde::{Serialize, de::DeserializeOwned};
+use std::collections::VecDeque;
+
+/// A [`Store`] that records every write made through it so the writes can be undone and redone.
+///
+/// Writes through any lens of [`StoreHistory::store`] are recorded as patches of the paths they changed.
+/// Writes are grouped into transactions, either explicitly with [`StoreHistory::transaction`] or by
+/// calling [`StoreHistory::checkpoint`] between groups of writes. Each transaction keeps the patch that
+/// redoes it and the patch that undoes it, so the history grows with the size of the changes instead of the
+/// size of the store. Undoing or redoing a transaction only reruns the subscribers of the paths that
+/// transaction changed.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+/// use dioxus_stores::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Store, Serialize, Deserialize, Default)]
+/// struct Document {
+///     title: String,
+///     lines: Vec<String>,
+/// }
+///
+/// fn app() -> Element {
+///     let history = use_store_history(Document::default);
+///     let document = history.store();
+///
+///     rsx! {
+///         button {
+///             // Both writes are undone together
+///             onclick: move |_| history.transaction(|document| {
+///                 document.title().set("Untitled".to_string());
+///                 document.lines().push(String::new());
+///             }),
+///             "New document"
+///         }
+///         button {
+///             disabled: !history.can_undo(),
+///             onclick: move |_| _ = history.undo(),
+///             "Undo"
+///         }
+///         button {
+///             disabled: !history.can_redo(),
+///             onclick: move |_| _ = history.redo(),
+///             "Redo"
+///         }
+///         h1 { "{document.title()}" }
+///     }
+/// }
+/// ```
+pub struct StoreHistory<T: 'static> {
+    store: Store<T>,
+    state: CopyValue<HistoryState<T>>,
+    status: Signal<HistoryStatus>,
+}
+
+impl<T: 'static> Clone for StoreHistory<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for StoreHistory<T> {}
+
+impl<T: 'static> PartialEq for StoreHistory<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+struct HistoryState<T: 'static> {
+    /// Records the writes made since the last committed transaction
+    recorder: PatchRecorder<T>,
+    undo: VecDeque<HistoryEntry>,
+    redo: Vec<HistoryEntry>,
+    limit: Option<usize>,
+    transaction_depth: usize,
+}
+
+/// A committed transaction
+struct HistoryEntry {
+    /// The patch that applies the transaction
+    forward: StorePatch,
+    /// The patch that reverts the transaction
+    inverse: StorePatch,
+}
+
+/// The reactive part of the history. This is only written when the length of either stack changes
+/// so reading [`StoreHistory::can_undo`] doesn't rerun on every write.
+#[derive(Clone, Copy, Default, PartialEq)]
+struct HistoryStatus {
+    undo: usize,
+    redo: usize,
+}
+
+impl<T: Serialize + DeserializeOwned + 'static> StoreHistory<T> {
+    /// Creates a new store with history. This allocates memory in the current scope, so this should only be called
+    /// inside of an initialization closure like the closure passed to [`use_hook`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value can't be serialized.
+    #[track_caller]
+    pub fn new(value: T) -> Self {
+        let store = Store::new(value);
+        let recorder =
+            PatchRecorder::new(store).expect("the value of a store with history must serialize");
+
+        Self {
+            store,
+            state: CopyValue::new(HistoryState {
+                recorder,
+                undo: VecDeque::new(),
+                redo: Vec::new(),
+                limit: None,
+                transaction_depth: 0,
+            }),
+            status: Signal::new(HistoryStatus::default()),
+        }
+    }
+
+    /// Get the store that writes are recorded from.
+    pub fn store(&self) -> Store<T> {
+        self.store
+    }
+
+    /// Limit the number of transactions that can be undone. The oldest transactions are dropped
+    /// once the limit is reached. `None` keeps every transaction.
+    pub fn set_limit(&self, limit: Option<usize>) {
+        let mut state = self.state.write_unchecked();
+        state.limit = limit;
+        if let Some(limit) = limit {
+            let excess = state.undo.len().saturating_sub(limit);
+            state.undo.drain(..excess);
+        }
+        drop(state);
+        self.update_status();
+    }
+
+    /// Run `f` and record every write it makes to the store as a single transaction. Any writes made
+    /// before the transaction that have not been committed yet are committed as their own transaction first.
+    ///
+    /// Nested transactions are merged into the outermost transaction.
+    pub fn transaction<R>(&self, f: impl FnOnce(Store<T>) -> R) -> R {
+        if self.state.peek().transaction_depth == 0 {
+            self.checkpoint();
+        }
+
+        self.state.write_unchecked().transaction_depth += 1;
+        let guard = TransactionGuard(self.state);
+        let result = f(self.store);
+        drop(guard);
+
+        if self.state.peek().transaction_depth == 0 {
+            self.checkpoint();
+        }
+
+        result
+    }
+
+    /// Commit every write made since the last checkpoint as a single transaction. Returns `true` if
+    /// there were any changes to commit.
+    ///
+    /// Calling this inside of a [`StoreHistory::transaction`] does nothing.
+    pub fn checkpoint(&self) -> bool {
+        let mut state = self.state.write_unchecked();
+        if state.transaction_depth > 0 {
+            return false;
+        }
+
+        // Writes that don't change the serialized value of the store are not worth undoing
+        let Ok((forward, inverse)) = state.recorder.take_patch_with_inverse() else {
+            return false;
+        };
+        if forward.is_empty() {
+            return false;
+        }
+
+        state.undo.push_back(HistoryEntry { forward, inverse });
+        if state.limit.is_some_and(|limit| state.undo.len() > limit) {
+            state.undo.pop_front();
+        }
+        state.redo.clear();
+        drop(state);
+
+        self.update_status();
+        true
+    }
+
+    /// Undo the last transaction. Any writes that have not been committed yet are committed first.
+    /// Returns `false` if there was nothing to undo, or if the transaction could not be reverted. A
+    /// transaction that fails to revert stays in the history.
+    pub fn undo(&self) -> bool {
+        self.checkpoint();
+
+        let mut state = self.state.write_unchecked();
+        let applied = match state.undo.pop_back() {
+            // The inverse was created from the value the forward patch produced, but the value it restores may
+            // still fail to deserialize. Keep the transaction so the history stays in sync with the store
+            Some(entry) => match state.recorder.apply_patch(&entry.inverse) {
+                Ok(()) => {
+                    state.redo.push(entry);
+                    true
+                }
+                Err(_) => {
+                    state.undo.push_back(entry);
+                    false
+                }
+            },
+            None => false,
+        };
+        drop(state);
+
+        self.update_status();
+        applied
+    }
+
+    /// Redo the last undone transaction. Returns `false` if there was nothing to redo, if the store
+    /// has been written to since the last undo, or if the transaction could not be applied.
+    pub fn redo(&self) -> bool {
+        // A new write invalidates the redo stack
+        if self.checkpoint() {
+            return false;
+        }
+
+        let mut state = self.state.write_unchecked();
+        let applied = match state.redo.pop() {
+            Some(entry) => match state.recorder.apply_patch(&entry.forward) {
+                Ok(()) => {
+                    state.undo.push_back(entry);
+                    true
+                }
+                Err(_) => {
+                    state.redo.push(entry);
+                    false
+                }
+            },
+            None => false,
+        };
+        drop(state);
+
+        self.update_status();
+        applied
+    }
+
+    /// Check if there is a transaction to undo. Reading this will rerun the current scope when the
+    /// length of the undo stack changes.
+    pub fn can_undo(&self) -> bool {
+        self.status.read().undo > 0
+    }
+
+    /// Check if there is a transaction to redo. Reading this will rerun the current scope when the
+    /// length of the redo stack changes.
+    pub fn can_redo(&self) -> bool {
+        self.status.read().redo > 0
+    }
+
+    /// Forget every recorded transaction and treat the current value as the start of the history.
+    pub fn clear(&self) {
+        let mut state = self.state.write_unchecked();
+        _ = state.recorder.take_patch();
+        state.undo.clear();
+        state.redo.clear();
+        drop(state);
+
+        self.update_status();
+    }
+
+    fn update_status(&self) {
+        let status = {
+            let state = self.state.peek();
+            HistoryStatus {
+                undo: state.undo.len(),
+                redo: state.redo.len(),
+            }
+        };
+        let mut current = self.status;
+        if *current.peek() != status {
+            current.set(status);
+        }
+    }
+}
+
+/// Leaves a [`StoreHistory::transaction`] when dropped, so a transaction that panics doesn't stop the
+/// history from recording
+struct TransactionGuard<T: 'static>(CopyValue<HistoryState<T>>);
+
+impl<T: 'static> Drop for TransactionGuard<T> {
+    fn drop(&mut self) {
+        self.0.write_unchecked().transaction_depth -= 1;
+    }
+}
+
+/// Create a new [`StoreHistory`]. The store returned from [`StoreHistory::store`] acts like a
+/// store from [`use_store`](crate::use_store), but its writes can be undone and redone.
+pub fn use_store_history<T: Serialize + DeserializeOwned + 'static>(
+    init: impl FnOnce() -> T,
+) -> StoreHistory<T> {
+    use_hook(move || StoreHistory::new(init()))
+}
@@ -0,0 +1,210 @@
+//! Additional utilities for `BTreeSet` stores.
+
+use std::{borrow::Borrow, collections::BTreeSet, hash::Hash};
+
+use crate::store::Store;
+use dioxus_signals::{Readable, ReadableExt, Writable};
+
+impl<Lens: Readable<Target = BTreeSet<T>> + 'static, T: 'static> Store<BTreeSet<T>, Lens> {
+    /// Get the length of the BTreeSet. This method will track the store shallowly and only cause
+    /// re-runs when items are added or removed from the set.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use std::collections::BTreeSet;
+    /// let mut store = use_store(|| BTreeSet::new());
+    /// assert_eq!(store.len(), 0);
+    /// store.insert(0);
+    /// assert_eq!(store.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.selector().track_shallow();
+        self.selector().peek().len()
+    }
+
+    /// Check if the BTreeSet is empty. This method will track the store shallowly and only cause
+    /// re-runs when items are added or removed from the set.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use std::collections::BTreeSet;
+    /// let mut store = use_store(|| BTreeSet::new());
+    /// assert!(store.is_empty());
+    /// store.insert(0);
+    /// assert!(!store.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.selector().track_shallow();
+        self.selector().peek().is_empty()
+    }
+
+    /// Iterate over a snapshot of the current values in the BTreeSet in order. This method will track the store
+    /// shallowly and only cause re-runs when items are added or removed from the set.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use std::collections::BTreeSet;
+    /// let mut store = use_store(|| BTreeSet::new());
+    /// store.insert("value1".to_string());
+    /// store.insert("value2".to_string());
+    /// for value in store.iter() {
+    ///     println!("{value}");
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = T> + DoubleEndedIterator
+    where
+        T: Clone,
+    {
+        self.selector().track_shallow();
+        let values: Vec<_> = self.selector().peek().iter().cloned().collect();
+        values.into_iter()
+    }
+
+    /// Check if the BTreeSet contains a value. This method only tracks the membership of that value,
+    /// so it will only cause re-runs when that specific value is added or removed from the set.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use std::collections::BTreeSet;
+    /// let mut store = use_store(|| BTreeSet::new());
+    /// assert!(!store.contains(&0));
+    /// store.insert(0);
+    /// assert!(store.contains(&0));
+    /// ```
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        Q: ?Sized + Hash + Ord + 'static,
+        T: Borrow<Q> + Ord,
+    {
+        self.selector()
+            .as_ref()
+            .hash_child_unmapped(value)
+            .track_shallow();
+        self.selector().peek().contains(value)
+    }
+
+    /// Insert a value into the BTreeSet, returning `true` if the value was not already present. If
+    /// the value is new, this will mark the shape of the set and the membership of that value as dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use std::collections::BTreeSet;
+    /// let mut store = use_store(|| BTreeSet::new());
+    /// assert!(store.insert(0));
+    /// assert!(!store.insert(0));
+    /// ```
+    pub fn insert(&mut self, value: T) -> bool
+    where
+        T: Hash + Ord,
+        Lens: Writable,
+    {
+        if self.selector().peek().contains(&value) {
+            return false;
+        }
+        self.selector().mark_dirty_unordered();
+        self.selector()
+            .as_ref()
+            .hash_child_unmapped(&value)
+            .mark_dirty();
+        self.selector().write_untracked().insert(value)
+    }
+
+    /// Remove a value from the BTreeSet, returning `true` if the value was present. If the value was
+    /// removed, this will mark the shape of the set and the membership of that value as dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use std::collections::BTreeSet;
+    /// let mut store = use_store(|| BTreeSet::new());
+    /// store.insert(0);
+    /// assert!(store.remove(&0));
+    /// assert!(!store.contains(&0));
+    /// ```
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        Q: ?Sized + Hash + Ord + 'static,
+        T: Borrow<Q> + Ord,
+        Lens: Writable,
+    {
+        if !self.selector().peek().contains(value) {
+            return false;
+        }
+        self.selector().mark_dirty_unordered();
+        self.selector()
+            .as_ref()
+            .hash_child_unmapped(value)
+            .mark_dirty();
+        self.selector().write_untracked().remove(value)
+    }
+
+    /// Clear the BTreeSet, removing all values. This method will mark the shape of the set and the
+    /// membership of every value as dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use std::collections::BTreeSet;
+    /// let mut store = use_store(|| BTreeSet::new());
+    /// store.insert(1);
+    /// store.insert(2);
+    /// store.clear();
+    /// assert!(store.is_empty());
+    /// ```
+    pub fn clear(&mut self)
+    where
+        Lens: Writable,
+    {
+        self.selector().mark_dirty();
+        self.selector().write_untracked().clear();
+    }
+
+    /// Retain only the values that satisfy the given predicate. This method will mark the shape of
+    /// the set and the membership of each removed value as dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use std::collections::BTreeSet;
+    /// let mut store = use_store(|| BTreeSet::new());
+    /// store.insert(1);
+    /// store.insert(2);
+    /// store.retain(|value| *value == 1);
+    /// assert_eq!(store.len(), 1);
+    /// assert!(store.contains(&1));
+    /// ```
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool)
+    where
+        T: Hash + Ord,
+        Lens: Writable,
+    {
+        let subscriptions = self.selector().subscriptions();
+        let mut removed = Vec::new();
+        self.selector().write_untracked().retain(|value| {
+            let keep = f(value);
+            if !keep {
+                removed.push(subscriptions.hash(value));
+            }
+            keep
+        });
+        if !removed.is_empty() {
+            self.selector().mark_dirty_unordered();
+            for key in removed {
+                self.selector().as_ref().child_unmapped(key).mark_dirty();
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 //! Additional utilities for indexing into stores.
 
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, HashMap, VecDeque},
     hash::Hash,
     ops::{self, Index, IndexMut},
 };
@@ -31,6 +31,12 @@ impl<T> IndexSelector<usize> for [T] {
     }
 }
 
+impl<T> IndexSelector<usize> for VecDeque<T> {
+    fn scope_selector<Lens>(selector: SelectorScope<Lens>, index: &usize) -> SelectorScope<Lens> {
+        selector.child_unmapped(*index as _)
+    }
+}
+
 impl<K, V, I> IndexSelector<I> for HashMap<K, V>
 where
     I: Hash,
@@ -49,6 +55,16 @@ where
     }
 }
 
+#[cfg(feature = "indexmap")]
+impl<K, V, I> IndexSelector<I> for indexmap::IndexMap<K, V>
+where
+    I: Hash,
+{
+    fn scope_selector<Lens>(selector: SelectorScope<Lens>, index: &I) -> SelectorScope<Lens> {
+        selector.hash_child_unmapped(&index)
+    }
+}
+
 impl<Lens, T> Store<T, Lens> {
     /// Index into the store, returning a store that allows access to the item at the given index. The
     /// new store will only update when the item at the index changes.
@@ -0,0 +1,472 @@
+//! Additional utilities for `IndexMap` stores.
+
+use std::{
+    borrow::Borrow,
+    hash::{BuildHasher, Hash},
+    iter::FusedIterator,
+    panic::Location,
+};
+
+use crate::{ReadStore, store::Store};
+use dioxus_signals::{
+    AnyStorage, BorrowError, BorrowMutError, ReadSignal, Readable, ReadableExt, UnsyncStorage,
+    Writable, WriteLock, WriteSignal,
+};
+use generational_box::ValueDroppedError;
+use indexmap::IndexMap;
+
+impl<Lens: Readable<Target = IndexMap<K, V, St>> + 'static, K: 'static, V: 'static, St: 'static>
+    Store<IndexMap<K, V, St>, Lens>
+{
+    /// Get the length of the IndexMap. This method will track the store shallowly and only cause
+    /// re-runs when items are added or removed from the map, not when existing values are modified.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use indexmap::IndexMap;
+    /// let mut store = use_store(|| IndexMap::new());
+    /// assert_eq!(store.len(), 0);
+    /// store.insert(0, "value".to_string());
+    /// assert_eq!(store.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.selector().track_shallow();
+        self.selector().peek().len()
+    }
+
+    /// Check if the IndexMap is empty. This method will track the store shallowly and only cause
+    /// re-runs when items are added or removed from the map, not when existing values are modified.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use indexmap::IndexMap;
+    /// let mut store = use_store(|| IndexMap::new());
+    /// assert!(store.is_empty());
+    /// store.insert(0, "value".to_string());
+    /// assert!(!store.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.selector().track_shallow();
+        self.selector().peek().is_empty()
+    }
+
+    /// Iterate over the current entries in the IndexMap in order, returning a tuple of the key and a store for the value. This method
+    /// will track the store shallowly and only cause re-runs when items are added or removed from the map, not when existing
+    /// values are modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use indexmap::IndexMap;
+    /// let mut store = use_store(|| IndexMap::new());
+    /// store.insert(0, "value1".to_string());
+    /// store.insert(1, "value2".to_string());
+    /// for (key, value_store) in store.iter() {
+    ///     println!("{}: {}", key, value_store.read());
+    /// }
+    /// ```
+    pub fn iter(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (K, Store<V, IndexMapGetWrite<K, Lens>>)>
+    + DoubleEndedIterator
+    + FusedIterator
+    + '_
+    where
+        K: Eq + Hash + Clone,
+        St: BuildHasher,
+        Lens: Clone,
+    {
+        self.selector().track_shallow();
+        let keys: Vec<_> = self.selector().peek_unchecked().keys().cloned().collect();
+        keys.into_iter()
+            .map(move |key| (key.clone(), self.clone().get_unchecked(key)))
+    }
+
+    /// Get an iterator over the values in the IndexMap in order. This method will track the store shallowly and only cause
+    /// re-runs when items are added or removed from the map, not when existing values are modified.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use indexmap::IndexMap;
+    /// let mut store = use_store(|| IndexMap::new());
+    /// store.insert(0, "value1".to_string());
+    /// store.insert(1, "value2".to_string());
+    /// for value_store in store.values() {
+    ///     println!("{}", value_store.read());
+    /// }
+    /// ```
+    pub fn values(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Store<V, IndexMapGetWrite<K, Lens>>>
+    + DoubleEndedIterator
+    + FusedIterator
+    + '_
+    where
+        K: Eq + Hash + Clone,
+        St: BuildHasher,
+        Lens: Clone,
+    {
+        self.selector().track_shallow();
+        let keys = self.selector().peek().keys().cloned().collect::<Vec<_>>();
+        keys.into_iter()
+            .map(move |key| self.clone().get_unchecked(key))
+    }
+
+    /// Insert a key-value pair into the IndexMap. New keys are appended to the end of the map. This method
+    /// marks the value for the key as dirty, and only marks the shape of the map as dirty if the key is new.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use indexmap::IndexMap;
+    /// let mut store = use_store(|| IndexMap::new());
+    /// assert!(store.get(0).is_none());
+    /// store.insert(0, "value".to_string());
+    /// assert_eq!(store.get(0).unwrap().cloned(), "value".to_string());
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        K: Eq + Hash,
+        St: BuildHasher,
+        Lens: Writable,
+    {
+        // Only the shape of the map changes if the key is new
+        if !self.selector().peek().contains_key(&key) {
+            self.selector().mark_dirty_shallow();
+        }
+        self.selector()
+            .as_ref()
+            .hash_child_unmapped(&key)
+            .mark_dirty();
+        self.selector().write_untracked().insert(key, value)
+    }
+
+    /// Remove a key-value pair from the IndexMap, shifting every entry after it to preserve the order of the map.
+    /// This method will mark the shape of the map and the value of the removed key as dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use indexmap::IndexMap;
+    /// let mut store = use_store(|| IndexMap::new());
+    /// store.insert(0, "value".to_string());
+    /// let removed_value = store.shift_remove(&0);
+    /// assert_eq!(removed_value, Some("value".to_string()));
+    /// assert!(store.get(0).is_none());
+    /// ```
+    pub fn shift_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: ?Sized + Hash + Eq + 'static,
+        K: Borrow<Q> + Eq + Hash,
+        St: BuildHasher,
+        Lens: Writable,
+    {
+        if !self.mark_removed(key) {
+            return None;
+        }
+        self.selector().write_untracked().shift_remove(key)
+    }
+
+    /// Remove a key-value pair from the IndexMap by swapping it with the last entry. This is faster than
+    /// [`Self::shift_remove`], but changes the order of the map. This method will mark the shape of the map
+    /// and the value of the removed key as dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use indexmap::IndexMap;
+    /// let mut store = use_store(|| IndexMap::new());
+    /// store.insert(0, "value".to_string());
+    /// let removed_value = store.swap_remove(&0);
+    /// assert_eq!(removed_value, Some("value".to_string()));
+    /// assert!(store.get(0).is_none());
+    /// ```
+    pub fn swap_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: ?Sized + Hash + Eq + 'static,
+        K: Borrow<Q> + Eq + Hash,
+        St: BuildHasher,
+        Lens: Writable,
+    {
+        if !self.mark_removed(key) {
+            return None;
+        }
+        self.selector().write_untracked().swap_remove(key)
+    }
+
+    /// Mark the shape of the map and the key as dirty if the key exists. Returns whether the key exists.
+    fn mark_removed<Q>(&self, key: &Q) -> bool
+    where
+        Q: ?Sized + Hash + Eq + 'static,
+        K: Borrow<Q> + Eq + Hash,
+        St: BuildHasher,
+    {
+        if !self.selector().peek().contains_key(key) {
+            return false;
+        }
+        self.selector().mark_dirty_shallow();
+        self.selector()
+            .as_ref()
+            .hash_child_unmapped(key)
+            .mark_dirty();
+        true
+    }
+
+    /// Clear the IndexMap, removing all key-value pairs. This method will mark the shape of the map and
+    /// every value as dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use indexmap::IndexMap;
+    /// let mut store = use_store(|| IndexMap::new());
+    /// store.insert(1, "value1".to_string());
+    /// store.insert(2, "value2".to_string());
+    /// assert_eq!(store.len(), 2);
+    /// store.clear();
+    /// assert!(store.is_empty());
+    /// ```
+    pub fn clear(&mut self)
+    where
+        Lens: Writable,
+    {
+        self.selector().mark_dirty();
+        self.selector().write_untracked().clear();
+    }
+
+    /// Retain only the key-value pairs that satisfy the given predicate, preserving the order of the map.
+    /// This method will mark the shape of the map and the value of each removed key as dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use indexmap::IndexMap;
+    /// let mut store = use_store(|| IndexMap::new());
+    /// store.insert(1, "value1".to_string());
+    /// store.insert(2, "value2".to_string());
+    /// store.retain(|key, value| *key == 1);
+    /// assert_eq!(store.len(), 1);
+    /// assert!(store.get(1).is_some());
+    /// assert!(store.get(2).is_none());
+    /// ```
+    pub fn retain(&mut self, mut f: impl FnMut(&K, &V) -> bool)
+    where
+        K: Hash,
+        Lens: Writable,
+    {
+        let subscriptions = self.selector().subscriptions();
+        let mut removed = Vec::new();
+        self.selector().write_untracked().retain(|k, v| {
+            let keep = f(k, v);
+            if !keep {
+                removed.push(subscriptions.hash(k));
+            }
+            keep
+        });
+        if !removed.is_empty() {
+            self.selector().mark_dirty_shallow();
+            for key in removed {
+                self.selector().as_ref().child_unmapped(key).mark_dirty();
+            }
+        }
+    }
+
+    /// Check if the IndexMap contains a key. This method only tracks the membership of that key, so it
+    /// will only cause re-runs when that specific key is added or removed from the map.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use indexmap::IndexMap;
+    /// let mut store = use_store(|| IndexMap::new());
+    /// assert!(!store.contains_key(&0));
+    /// store.insert(0, "value".to_string());
+    /// assert!(store.contains_key(&0));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: ?Sized + Hash + Eq + 'static,
+        K: Borrow<Q> + Eq + Hash,
+        St: BuildHasher,
+    {
+        self.selector()
+            .as_ref()
+            .hash_child_unmapped(key)
+            .track_shallow();
+        self.selector().peek().contains_key(key)
+    }
+
+    /// Get a store for the value associated with the given key. This method creates a new store scope
+    /// that tracks just changes to the value associated with the key.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use indexmap::IndexMap;
+    /// let mut store = use_store(|| IndexMap::new());
+    /// assert!(store.get(0).is_none());
+    /// store.insert(0, "value".to_string());
+    /// assert_eq!(store.get(0).unwrap().cloned(), "value".to_string());
+    /// ```
+    pub fn get<Q>(self, key: Q) -> Option<Store<V, IndexMapGetWrite<Q, Lens>>>
+    where
+        Q: Hash + Eq + 'static,
+        K: Borrow<Q> + Eq + Hash,
+        St: BuildHasher,
+    {
+        self.contains_key(&key).then(|| self.get_unchecked(key))
+    }
+
+    /// Get a store for the value associated with the given key without checking if the key exists.
+    /// This method creates a new store scope that tracks just changes to the value associated with the key.
+    ///
+    /// This is not unsafe, but it will panic when you try to read the value if it does not exist.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use dioxus::prelude::*;
+    /// use indexmap::IndexMap;
+    /// let mut store = use_store(|| IndexMap::new());
+    /// store.insert(0, "value".to_string());
+    /// assert_eq!(store.get_unchecked(0).cloned(), "value".to_string());
+    /// ```
+    #[track_caller]
+    pub fn get_unchecked<Q>(self, key: Q) -> Store<V, IndexMapGetWrite<Q, Lens>>
+    where
+        Q: Hash + Eq + 'static,
+        K: Borrow<Q> + Eq + Hash,
+        St: BuildHasher,
+    {
+        let location = Location::caller();
+        self.into_selector()
+            .hash_child_unmapped(key.borrow())
+            .map_writer(move |writer| IndexMapGetWrite {
+                index: key,
+                write: writer,
+                created: location,
+            })
+            .into()
+    }
+}
+
+/// A specific key in a `Readable` / `Writable` IndexMap
+#[derive(Clone, Copy)]
+pub struct IndexMapGetWrite<Index, Write> {
+    index: Index,
+    write: Write,
+    created: &'static Location<'static>,
+}
+
+impl<Index, Write, K, V, St> Readable for IndexMapGetWrite<Index, Write>
+where
+    Write: Readable<Target = IndexMap<K, V, St>>,
+    Index: Hash + Eq + 'static,
+    K: Borrow<Index> + Eq + Hash + 'static,
+    St: BuildHasher + 'static,
+{
+    type Target = V;
+
+    type Storage = Write::Storage;
+
+    fn try_read_unchecked(&self) -> Result<dioxus_signals::ReadableRef<'static, Self>, BorrowError>
+    where
+        Self::Target: 'static,
+    {
+        self.write.try_read_unchecked().and_then(|value| {
+            Self::Storage::try_map(value, |value: &Write::Target| value.get(&self.index))
+                .ok_or_else(|| BorrowError::Dropped(ValueDroppedError::new(self.created)))
+        })
+    }
+
+    fn try_peek_unchecked(&self) -> Result<dioxus_signals::ReadableRef<'static, Self>, BorrowError>
+    where
+        Self::Target: 'static,
+    {
+        self.write.try_peek_unchecked().and_then(|value| {
+            Self::Storage::try_map(value, |value: &Write::Target| value.get(&self.index))
+                .ok_or_else(|| BorrowError::Dropped(ValueDroppedError::new(self.created)))
+        })
+    }
+
+    fn subscribers(&self) -> dioxus_core::Subscribers
+    where
+        Self::Target: 'static,
+    {
+        self.write.subscribers()
+    }
+}
+
+impl<Index, Write, K, V, St> Writable for IndexMapGetWrite<Index, Write>
+where
+    Write: Writable<Target = IndexMap<K, V, St>>,
+    Index: Hash + Eq + 'static,
+    K: Borrow<Index> + Eq + Hash + 'static,
+    St: BuildHasher + 'static,
+{
+    type WriteMetadata = Write::WriteMetadata;
+
+    fn try_write_unchecked(
+        &self,
+    ) -> Result<dioxus_signals::WritableRef<'static, Self>, BorrowMutError>
+    where
+        Self::Target: 'static,
+    {
+        self.write.try_write_unchecked().and_then(|value| {
+            WriteLock::filter_map(value, |value: &mut Write::Target| {
+                value.get_mut(&self.index)
+            })
+            .ok_or_else(|| BorrowMutError::Dropped(ValueDroppedError::new(self.created)))
+        })
+    }
+}
+
+impl<Index, Write, K, V, St> ::std::convert::From<Store<V, IndexMapGetWrite<Index, Write>>>
+    for Store<V, WriteSignal<V>>
+where
+    Write::WriteMetadata: 'static,
+    Write: Writable<Target = IndexMap<K, V, St>, Storage = UnsyncStorage> + 'static,
+    Index: Hash + Eq + 'static,
+    K: Borrow<Index> + Eq + Hash + 'static,
+    St: BuildHasher + 'static,
+    V: 'static,
+{
+    fn from(value: Store<V, IndexMapGetWrite<Index, Write>>) -> Self {
+        value
+            .into_selector()
+            .map_writer(|writer| WriteSignal::new(writer))
+            .into()
+    }
+}
+
+impl<Index, Write, K, V, St> ::std::convert::From<Store<V, IndexMapGetWrite<Index, Write>>>
+    for ReadStore<V>
+where
+    Write: Readable<Target = IndexMap<K, V, St>, Storage = UnsyncStorage> + 'static,
+    Index: Hash + Eq + 'static,
+    K: Borrow<Index> + Eq + Hash + 'static,
+    St: BuildHasher + 'static,
+    V: 'static,
+{
+    fn from(value: Store<V, IndexMapGetWrite<Index, Write>>) -> Self {
+        value
+            .into_selector()
+            .map_writer(|writer| ReadSignal::new(writer))
+            .into()
+    }
+}
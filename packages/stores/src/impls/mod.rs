@@ -1,8 +1,13 @@
 pub mod btreemap;
+mod btreeset;
 mod deref;
 pub mod hashmap;
+mod hashset;
 pub mod index;
+#[cfg(feature = "indexmap")]
+pub mod index_map;
 mod option;
 mod result;
 pub mod slice;
 mod vec;
+pub mod vecdeque;
@@ -131,7 +131,7 @@ where
                 Ok(_) => panic!("Tried to access `err` on an Ok value"),
                 Err(e) => e,
             };
-            self.into_selector().child(1, map, map_mut).into()
+            self.into_selector().child(0, map, map_mut).into()
         })
     }
 
@@ -172,7 +172,7 @@ where
                 Ok(_) => panic!("Tried to access `err` on an Ok value"),
                 Err(e) => e,
             };
-            Err(self.into_selector().child(1, map, map_mut).into())
+            Err(self.into_selector().child(0, map, map_mut).into())
         }
     }
 
@@ -330,7 +330,7 @@ where
                 Ok(_) => panic!("Tried to access `err` on an Ok value"),
                 Err(e) => e,
             };
-            Err(self.into_selector().child(1, map, map_mut).into())
+            Err(self.into_selector().child(0, map, map_mut).into())
         }
     }
 }
@@ -0,0 +1,359 @@
+//! Additional utilities for `VecDeque` stores.
+
+use std::{collections::VecDeque, iter::FusedIterator, panic::Location};
+
+use crate::{ReadStore, impls::index::IndexSelector, store::Store};
+use dioxus_signals::{
+    AnyStorage, BorrowError, BorrowMutError, ReadSignal, Readable, ReadableExt, UnsyncStorage,
+    Writable, WriteLock, WriteSignal,
+};
+use generational_box::ValueDroppedError;
+
+impl<Lens, T> Store<VecDeque<T>, Lens>
+where
+    Lens: Readable<Target = VecDeque<T>> + 'static,
+    T: 'static,
+{
+    /// Returns the length of the deque. This will only track the shallow state of the deque.
+    /// It will only cause a re-run if the length of the deque could change.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use std::collections::VecDeque;
+    /// let store = use_store(|| VecDeque::from([1, 2, 3]));
+    /// assert_eq!(store.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.selector().track_shallow();
+        self.selector().peek().len()
+    }
+
+    /// Checks if the deque is empty. This will only track the shallow state of the deque.
+    /// It will only cause a re-run if the length of the deque could change.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use std::collections::VecDeque;
+    /// let store = use_store(|| VecDeque::from([1, 2, 3]));
+    /// assert!(!store.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.selector().track_shallow();
+        self.selector().peek().is_empty()
+    }
+
+    /// Returns an iterator over the items in the deque from front to back. This will only track the
+    /// shallow state of the deque. It will only cause a re-run if the length of the deque could change.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use std::collections::VecDeque;
+    /// let store = use_store(|| VecDeque::from([1, 2, 3]));
+    /// for item in store.iter() {
+    ///     println!("{}", item);
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn iter(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Store<T, VecDequeGetWrite<Lens>>>
+    + DoubleEndedIterator
+    + FusedIterator
+    + '_
+    where
+        Lens: Clone,
+    {
+        let location = Location::caller();
+        (0..self.len()).map(move |i| self.clone().get_unchecked_at(i, location))
+    }
+
+    /// Try to get an item from the deque. This will only track the shallow state of the deque.
+    /// It will only cause a re-run if the length of the deque could change. The new store
+    /// will only update when the item at the index changes.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use std::collections::VecDeque;
+    /// let store = use_store(|| VecDeque::from([1, 2, 3]));
+    /// let indexed_store = store.get(1).unwrap();
+    /// assert_eq!(indexed_store(), 2);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<Store<T, VecDequeGetWrite<Lens>>>
+    where
+        Lens: Clone,
+    {
+        if index >= self.len() {
+            None
+        } else {
+            Some(self.clone().get_unchecked(index))
+        }
+    }
+
+    /// Get a store for the item at the given index without checking if it is in bounds.
+    ///
+    /// This is not unsafe, but reads will return a [BorrowError::Dropped] error if the index is out of bounds.
+    #[track_caller]
+    pub fn get_unchecked(self, index: usize) -> Store<T, VecDequeGetWrite<Lens>> {
+        self.get_unchecked_at(index, Location::caller())
+    }
+
+    fn get_unchecked_at(
+        self,
+        index: usize,
+        location: &'static Location<'static>,
+    ) -> Store<T, VecDequeGetWrite<Lens>> {
+        <VecDeque<T>>::scope_selector(self.into_selector(), &index)
+            .map_writer(move |write| VecDequeGetWrite {
+                index,
+                write,
+                created: location,
+            })
+            .into()
+    }
+}
+
+impl<Lens: Writable<Target = VecDeque<T>> + 'static, T: 'static> Store<VecDeque<T>, Lens> {
+    /// Appends an item to the back of the deque. This will only mark the length of the deque as dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use std::collections::VecDeque;
+    /// let mut store = use_store(|| VecDeque::from([1, 2, 3]));
+    /// store.push_back(4);
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        self.selector().mark_dirty_shallow();
+        self.selector().write_untracked().push_back(value);
+    }
+
+    /// Prepends an item to the front of the deque. Every existing item moves to a new index, so this
+    /// will mark every item and the length of the deque as dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use std::collections::VecDeque;
+    /// let mut store = use_store(|| VecDeque::from([1, 2, 3]));
+    /// store.push_front(0);
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        self.selector().mark_dirty_shallow();
+        self.selector().mark_dirty_at_and_after_index(0);
+        self.selector().write_untracked().push_front(value);
+    }
+
+    /// Removes the last item from the deque and returns it. This will mark the last item and the
+    /// length of the deque as dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use std::collections::VecDeque;
+    /// let mut store = use_store(|| VecDeque::from([1, 2, 3]));
+    /// assert_eq!(store.pop_back(), Some(3));
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        let len = self.selector().peek().len();
+        if len == 0 {
+            return None;
+        }
+        self.selector().mark_dirty_shallow();
+        self.selector().mark_dirty_at_and_after_index(len - 1);
+        self.selector().write_untracked().pop_back()
+    }
+
+    /// Removes the first item from the deque and returns it. Every remaining item moves to a new
+    /// index, so this will mark every item and the length of the deque as dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use std::collections::VecDeque;
+    /// let mut store = use_store(|| VecDeque::from([1, 2, 3]));
+    /// assert_eq!(store.pop_front(), Some(1));
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.selector().peek().is_empty() {
+            return None;
+        }
+        self.selector().mark_dirty_shallow();
+        self.selector().mark_dirty_at_and_after_index(0);
+        self.selector().write_untracked().pop_front()
+    }
+
+    /// Inserts an item at the specified index in the deque. This will mark items at and after the index
+    /// and the length of the deque as dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use std::collections::VecDeque;
+    /// let mut store = use_store(|| VecDeque::from([1, 2, 3]));
+    /// store.insert(1, 4);
+    /// ```
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.selector().mark_dirty_shallow();
+        self.selector().mark_dirty_at_and_after_index(index);
+        self.selector().write_untracked().insert(index, value);
+    }
+
+    /// Removes an item from the deque at the specified index and returns it. This will mark items after
+    /// the index and the length of the deque as dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use std::collections::VecDeque;
+    /// let mut store = use_store(|| VecDeque::from([1, 2, 3]));
+    /// assert_eq!(store.remove(1), Some(2));
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.selector().peek().len() {
+            return None;
+        }
+        self.selector().mark_dirty_shallow();
+        self.selector().mark_dirty_at_and_after_index(index);
+        self.selector().write_untracked().remove(index)
+    }
+
+    /// Clears the deque, marking it as dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use std::collections::VecDeque;
+    /// let mut store = use_store(|| VecDeque::from([1, 2, 3]));
+    /// store.clear();
+    /// ```
+    pub fn clear(&mut self) {
+        self.selector().mark_dirty();
+        self.selector().write_untracked().clear();
+    }
+
+    /// Retains only the elements specified by the predicate. This will only mark the length of the deque
+    /// and items after the first removed item as dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use std::collections::VecDeque;
+    /// let mut store = use_store(|| VecDeque::from([1, 2, 3, 4, 5]));
+    /// store.retain(|&x| x % 2 == 0);
+    /// assert_eq!(store.len(), 2);
+    /// ```
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        let mut index = 0;
+        let mut first_removed_index = None;
+        self.selector().write_untracked().retain(|item| {
+            let keep = f(item);
+            if !keep {
+                first_removed_index = first_removed_index.or(Some(index));
+            }
+            index += 1;
+            keep
+        });
+        if let Some(index) = first_removed_index {
+            self.selector().mark_dirty_shallow();
+            self.selector().mark_dirty_at_and_after_index(index);
+        }
+    }
+}
+
+/// A specific index in a `Readable` / `Writable` VecDeque that uses safe `.get()` / `.get_mut()` access.
+#[derive(Clone, Copy)]
+pub struct VecDequeGetWrite<Write> {
+    index: usize,
+    write: Write,
+    created: &'static Location<'static>,
+}
+
+impl<Write, T> Readable for VecDequeGetWrite<Write>
+where
+    Write: Readable<Target = VecDeque<T>>,
+    T: 'static,
+{
+    type Target = T;
+    type Storage = Write::Storage;
+
+    fn try_read_unchecked(&self) -> Result<dioxus_signals::ReadableRef<'static, Self>, BorrowError>
+    where
+        Self::Target: 'static,
+    {
+        self.write.try_read_unchecked().and_then(|value| {
+            let index = self.index;
+            Self::Storage::try_map(value, move |value: &VecDeque<T>| value.get(index))
+                .ok_or_else(|| BorrowError::Dropped(ValueDroppedError::new(self.created)))
+        })
+    }
+
+    fn try_peek_unchecked(&self) -> Result<dioxus_signals::ReadableRef<'static, Self>, BorrowError>
+    where
+        Self::Target: 'static,
+    {
+        self.write.try_peek_unchecked().and_then(|value| {
+            let index = self.index;
+            Self::Storage::try_map(value, move |value: &VecDeque<T>| value.get(index))
+                .ok_or_else(|| BorrowError::Dropped(ValueDroppedError::new(self.created)))
+        })
+    }
+
+    fn subscribers(&self) -> dioxus_core::Subscribers
+    where
+        Self::Target: 'static,
+    {
+        self.write.subscribers()
+    }
+}
+
+impl<Write, T> Writable for VecDequeGetWrite<Write>
+where
+    Write: Writable<Target = VecDeque<T>>,
+    T: 'static,
+{
+    type WriteMetadata = Write::WriteMetadata;
+
+    fn try_write_unchecked(
+        &self,
+    ) -> Result<dioxus_signals::WritableRef<'static, Self>, BorrowMutError>
+    where
+        Self::Target: 'static,
+    {
+        self.write.try_write_unchecked().and_then(|value| {
+            let index = self.index;
+            WriteLock::filter_map(value, move |value: &mut VecDeque<T>| value.get_mut(index))
+                .ok_or_else(|| BorrowMutError::Dropped(ValueDroppedError::new(self.created)))
+        })
+    }
+}
+
+impl<T, Write> ::std::convert::From<Store<T, VecDequeGetWrite<Write>>> for Store<T, WriteSignal<T>>
+where
+    Write: Writable<Target = VecDeque<T>, Storage = UnsyncStorage> + 'static,
+    Write::WriteMetadata: 'static,
+    T: 'static,
+{
+    fn from(value: Store<T, VecDequeGetWrite<Write>>) -> Self {
+        value
+            .into_selector()
+            .map_writer(|writer| WriteSignal::new(writer))
+            .into()
+    }
+}
+
+impl<T, Write> ::std::convert::From<Store<T, VecDequeGetWrite<Write>>> for ReadStore<T>
+where
+    Write: Readable<Target = VecDeque<T>, Storage = UnsyncStorage> + 'static,
+    T: 'static,
+{
+    fn from(value: Store<T, VecDequeGetWrite<Write>>) -> Self {
+        value
+            .into_selector()
+            .map_writer(|writer| ReadSignal::new(writer))
+            .into()
+    }
+}
@@ -4,10 +4,18 @@
 #![warn(missing_docs)]
 #![allow(clippy::type_complexity)]
 
+#[cfg(feature = "serialize")]
+mod history;
 mod impls;
+#[cfg(feature = "serialize")]
+mod patch;
 mod store;
 mod subscriptions;
+#[cfg(feature = "serialize")]
+pub use history::*;
 pub use impls::*;
+#[cfg(feature = "serialize")]
+pub use patch::*;
 pub use store::*;
 pub mod scope;
 
@@ -0,0 +1,488 @@
+//! Serializable snapshots of stores and JSON patches generated from store writes.
+
+use crate::{
+    store::Store,
+    subscriptions::{DirtyKind, PathKey},
+};
+use dioxus_signals::{Readable, ReadableExt, Writable};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use std::fmt::Display;
+
+mod record;
+mod resolve;
+
+pub use record::PatchRecorder;
+
+/// A single operation in a [`StorePatch`]. Operations follow the format of [RFC 6902](https://datatracker.ietf.org/doc/html/rfc6902)
+/// JSON patches, and paths are [RFC 6901](https://datatracker.ietf.org/doc/html/rfc6901) JSON pointers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatchOperation {
+    /// Insert a value into an array or add a key to an object.
+    Add {
+        /// The pointer to the new value. For arrays this may be `-` to append to the end of the array.
+        path: String,
+        /// The value to add.
+        value: Value,
+    },
+    /// Remove a value from an array or a key from an object.
+    Remove {
+        /// The pointer to the value to remove.
+        path: String,
+    },
+    /// Replace an existing value.
+    Replace {
+        /// The pointer to the value to replace.
+        path: String,
+        /// The new value.
+        value: Value,
+    },
+}
+
+impl PatchOperation {
+    /// Get the JSON pointer this operation targets.
+    pub fn path(&self) -> &str {
+        match self {
+            PatchOperation::Add { path, .. }
+            | PatchOperation::Remove { path }
+            | PatchOperation::Replace { path, .. } => path,
+        }
+    }
+}
+
+/// A list of changes to the serialized form of a store.
+///
+/// Patches are created from the writes made to a store with a [`PatchRecorder`], or by diffing two snapshots
+/// of a store with [`StorePatch::diff`], and applied with [`Store::apply_patch`]. Applying a patch only marks the parts of the store the patch touches as dirty,
+/// so patches can be streamed between a server and its clients to keep a store mirrored without rerunning
+/// every component that reads the store.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+/// use dioxus_stores::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Store, Serialize, Deserialize, Default)]
+/// struct Todos {
+///     items: Vec<String>,
+/// }
+///
+/// let mut local = use_store(Todos::default);
+/// let mut remote = use_store(Todos::default);
+///
+/// let before = local.snapshot().unwrap();
+/// local.items().push("Write docs".to_string());
+/// let patch = StorePatch::diff(&before, &local.snapshot().unwrap());
+///
+/// // Only the length of the list and the new item are marked dirty in the remote store
+/// remote.apply_patch(&patch).unwrap();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct StorePatch {
+    operations: Vec<PatchOperation>,
+}
+
+impl StorePatch {
+    /// Create an empty patch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create the smallest patch that turns `old` into `new`.
+    ///
+    /// Objects are diffed key by key and arrays are diffed index by index, so a write to a single field
+    /// or item produces a single operation.
+    pub fn diff(old: &Value, new: &Value) -> Self {
+        let mut patch = Self::new();
+        diff_values(&mut String::new(), old, new, &mut patch.operations);
+        patch
+    }
+
+    /// Add an operation to the end of the patch.
+    pub fn push(&mut self, operation: PatchOperation) {
+        self.operations.push(operation);
+    }
+
+    /// Get the operations in this patch.
+    pub fn operations(&self) -> &[PatchOperation] {
+        &self.operations
+    }
+
+    /// Check if the patch doesn't contain any operations.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Apply the patch to a serialized value. If any operation fails, the value is left unchanged.
+    pub fn apply_to(&self, value: &mut Value) -> Result<(), PatchError> {
+        let mut patched = value.clone();
+        for operation in &self.operations {
+            apply_operation(&mut patched, operation)?;
+        }
+        *value = patched;
+        Ok(())
+    }
+}
+
+impl From<Vec<PatchOperation>> for StorePatch {
+    fn from(operations: Vec<PatchOperation>) -> Self {
+        Self { operations }
+    }
+}
+
+impl IntoIterator for StorePatch {
+    type Item = PatchOperation;
+    type IntoIter = std::vec::IntoIter<PatchOperation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.operations.into_iter()
+    }
+}
+
+/// An error that can occur while creating or applying a [`StorePatch`].
+#[derive(Debug)]
+pub enum PatchError {
+    /// A path in the patch was not a valid JSON pointer.
+    InvalidPointer(String),
+    /// A path in the patch pointed to a value that does not exist.
+    PathNotFound(String),
+    /// The store could not be serialized, or the patched value could not be deserialized
+    /// into the type of the store.
+    Serde(serde_json::Error),
+}
+
+impl Display for PatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PatchError::InvalidPointer(path) => write!(f, "invalid JSON pointer {path:?}"),
+            PatchError::PathNotFound(path) => write!(f, "no value exists at {path:?}"),
+            PatchError::Serde(error) => write!(f, "failed to convert the store value: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for PatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PatchError::Serde(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for PatchError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Serde(error)
+    }
+}
+
+impl<Lens: Readable<Target = T> + 'static, T: Serialize + 'static> Store<T, Lens> {
+    /// Serialize the current value of the store. This method will track the store deeply, so it will
+    /// cause re-runs whenever any part of the store changes.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// let store = use_store(|| vec![1, 2, 3]);
+    /// assert_eq!(store.snapshot().unwrap(), serde_json::json!([1, 2, 3]));
+    /// ```
+    pub fn snapshot(&self) -> Result<Value, PatchError> {
+        Ok(serde_json::to_value(&*self.selector().read())?)
+    }
+}
+
+impl<Lens: Writable<Target = T> + 'static, T: Serialize + DeserializeOwned + 'static>
+    Store<T, Lens>
+{
+    /// Apply a [`StorePatch`] to the store. Only the paths the patch touches are marked dirty: replacing
+    /// a field reruns readers of that field, and adding or removing an item reruns readers of the length
+    /// of the collection and of the items that moved.
+    ///
+    /// If any operation in the patch fails, the store is left unchanged.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// use serde_json::json;
+    /// let mut store = use_store(|| vec![1, 2, 3]);
+    /// let patch = StorePatch::diff(&json!([1, 2, 3]), &json!([1, 5, 3, 4]));
+    /// store.apply_patch(&patch).unwrap();
+    /// assert_eq!(store.snapshot().unwrap(), json!([1, 5, 3, 4]));
+    /// ```
+    pub fn apply_patch(&mut self, patch: &StorePatch) -> Result<(), PatchError> {
+        if patch.is_empty() {
+            return Ok(());
+        }
+        let subscriptions = self.selector().subscriptions();
+        let (new, marks) = {
+            let old = self.selector().peek();
+            let mut value = serde_json::to_value(&*old)?;
+            let mut targets = Vec::new();
+            for operation in patch.operations() {
+                let segments = apply_operation(&mut value, operation)?;
+                targets.push((operation, segments));
+            }
+            let new: T = serde_json::from_value(value)?;
+
+            // Removed values can only be found in the old value, and added values can only be found in
+            // the new value. Replaced values exist in both and may have a different shape in each.
+            let mut marks = Vec::new();
+            for (operation, segments) in targets {
+                if !matches!(operation, PatchOperation::Add { .. }) {
+                    let resolved = resolve::resolve(&*old, &segments, subscriptions);
+                    push_dirty_marks(operation, &resolved, &mut marks);
+                }
+                if !matches!(operation, PatchOperation::Remove { .. }) {
+                    let resolved = resolve::resolve(&new, &segments, subscriptions);
+                    push_dirty_marks(operation, &resolved, &mut marks);
+                }
+            }
+            (new, marks)
+        };
+
+        for (path, kind) in marks {
+            let mut scope = self.selector().as_ref();
+            for key in path {
+                scope = scope.child_unmapped(key);
+            }
+            match kind {
+                DirtyKind::Deep => scope.mark_dirty(),
+                DirtyKind::Shallow => scope.mark_dirty_shallow(),
+                DirtyKind::Unordered => scope.mark_dirty_unordered(),
+                DirtyKind::AtAndAfterIndex(index) => scope.mark_dirty_at_and_after_index(index),
+            }
+        }
+        *self.selector().write_untracked() = new;
+        Ok(())
+    }
+
+    /// Replace the value of the store with a serialized snapshot. The snapshot is diffed against the
+    /// current value of the store, so only the parts of the store that changed are marked dirty.
+    ///
+    /// # Example
+    /// ```rust, no_run
+    /// use dioxus_stores::*;
+    /// let mut store = use_store(|| vec![1, 2, 3]);
+    /// store.apply_snapshot(serde_json::json!([1, 2])).unwrap();
+    /// assert_eq!(store.len(), 2);
+    /// ```
+    pub fn apply_snapshot(&mut self, snapshot: Value) -> Result<(), PatchError> {
+        let current = serde_json::to_value(&*self.selector().peek())?;
+        self.apply_patch(&StorePatch::diff(&current, &snapshot))
+    }
+}
+
+/// Collect the dirty marks for an operation that resolved to a path in the store.
+fn push_dirty_marks(
+    operation: &PatchOperation,
+    resolved: &resolve::ResolvedPath,
+    marks: &mut Vec<(Vec<PathKey>, DirtyKind)>,
+) {
+    // If the pointer goes through a value that doesn't exist on one side of the patch, the shape of
+    // the deepest value we could find changed
+    let Some(last) = resolved.last.as_ref().filter(|_| resolved.complete) else {
+        marks.push((resolved.keys.clone(), DirtyKind::Deep));
+        return;
+    };
+    let parent = resolved.parent().to_vec();
+    let with_parent = |key| {
+        let mut path = parent.clone();
+        path.push(key);
+        path
+    };
+
+    match operation {
+        PatchOperation::Replace { .. } => {
+            marks.push((resolved.keys.clone(), DirtyKind::Deep));
+            // Replacing a value in a set changes the membership of the old and new values
+            if let Some(hash) = last.value_hash {
+                marks.push((parent.clone(), DirtyKind::Unordered));
+                marks.push((with_parent(hash), DirtyKind::Deep));
+            }
+        }
+        PatchOperation::Add { .. } | PatchOperation::Remove { .. } => {
+            // Sequences of primitives may be sets, which don't keep the position of their items
+            let shape = match last.value_hash {
+                Some(_) => DirtyKind::Unordered,
+                None => DirtyKind::Shallow,
+            };
+            marks.push((parent.clone(), shape));
+            match last.index {
+                // Every item after the index moved
+                Some(index) => marks.push((parent.clone(), DirtyKind::AtAndAfterIndex(index))),
+                None => marks.push((resolved.keys.clone(), DirtyKind::Deep)),
+            }
+            if let Some(hash) = last.value_hash {
+                marks.push((with_parent(hash), DirtyKind::Deep));
+            }
+        }
+    }
+}
+
+fn diff_values(path: &mut String, old: &Value, new: &Value, operations: &mut Vec<PatchOperation>) {
+    match (old, new) {
+        (old, new) if old == new => {}
+        (Value::Object(old), Value::Object(new)) => {
+            for key in old.keys().filter(|key| !new.contains_key(*key)) {
+                operations.push(PatchOperation::Remove {
+                    path: child_pointer(path, key),
+                });
+            }
+            for (key, new_value) in new {
+                match old.get(key) {
+                    Some(old_value) => {
+                        let len = path.len();
+                        push_segment(path, key);
+                        diff_values(path, old_value, new_value, operations);
+                        path.truncate(len);
+                    }
+                    None => operations.push(PatchOperation::Add {
+                        path: child_pointer(path, key),
+                        value: new_value.clone(),
+                    }),
+                }
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            for (index, (old_value, new_value)) in old.iter().zip(new).enumerate() {
+                let len = path.len();
+                push_segment(path, &index.to_string());
+                diff_values(path, old_value, new_value, operations);
+                path.truncate(len);
+            }
+            for (index, value) in new.iter().enumerate().skip(old.len()) {
+                operations.push(PatchOperation::Add {
+                    path: child_pointer(path, &index.to_string()),
+                    value: value.clone(),
+                });
+            }
+            // Remove from the back so the indexes of the remaining items don't shift
+            for index in (new.len()..old.len()).rev() {
+                operations.push(PatchOperation::Remove {
+                    path: child_pointer(path, &index.to_string()),
+                });
+            }
+        }
+        _ => operations.push(PatchOperation::Replace {
+            path: path.clone(),
+            value: new.clone(),
+        }),
+    }
+}
+
+fn push_segment(path: &mut String, segment: &str) {
+    path.push('/');
+    path.push_str(&segment.replace('~', "~0").replace('/', "~1"));
+}
+
+fn child_pointer(path: &str, segment: &str) -> String {
+    let mut path = path.to_string();
+    push_segment(&mut path, segment);
+    path
+}
+
+/// Split a JSON pointer into unescaped segments.
+fn parse_pointer(path: &str) -> Result<Vec<String>, PatchError> {
+    if path.is_empty() {
+        return Ok(Vec::new());
+    }
+    let Some(rest) = path.strip_prefix('/') else {
+        return Err(PatchError::InvalidPointer(path.to_string()));
+    };
+    Ok(rest
+        .split('/')
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .collect())
+}
+
+fn parse_index(segment: &str, len: usize, path: &str) -> Result<usize, PatchError> {
+    match segment.parse::<usize>() {
+        Ok(index) if index <= len => Ok(index),
+        _ => Err(PatchError::PathNotFound(path.to_string())),
+    }
+}
+
+/// Apply a single operation to a value and return the segments of the path it touched. If the path
+/// appended to an array with `-`, the returned segments contain the index of the new item instead.
+fn apply_operation(
+    value: &mut Value,
+    operation: &PatchOperation,
+) -> Result<Vec<String>, PatchError> {
+    let path = operation.path();
+    let mut segments = parse_pointer(path)?;
+    let Some(last) = segments.pop() else {
+        // The operation targets the root value
+        match operation {
+            PatchOperation::Add { value: new, .. } | PatchOperation::Replace { value: new, .. } => {
+                *value = new.clone();
+                return Ok(segments);
+            }
+            PatchOperation::Remove { .. } => {
+                return Err(PatchError::PathNotFound(path.to_string()));
+            }
+        }
+    };
+
+    let mut parent = &mut *value;
+    for segment in &segments {
+        parent = match parent {
+            Value::Object(map) => map.get_mut(segment),
+            Value::Array(items) => segment
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| items.get_mut(index)),
+            _ => None,
+        }
+        .ok_or_else(|| PatchError::PathNotFound(path.to_string()))?;
+    }
+
+    let last = match (parent, operation) {
+        (Value::Object(map), PatchOperation::Add { value, .. }) => {
+            map.insert(last.clone(), value.clone());
+            last
+        }
+        (Value::Object(map), PatchOperation::Replace { value, .. }) => {
+            let slot = map
+                .get_mut(&last)
+                .ok_or_else(|| PatchError::PathNotFound(path.to_string()))?;
+            *slot = value.clone();
+            last
+        }
+        (Value::Object(map), PatchOperation::Remove { .. }) => {
+            map.remove(&last)
+                .ok_or_else(|| PatchError::PathNotFound(path.to_string()))?;
+            last
+        }
+        (Value::Array(items), PatchOperation::Add { value, .. }) => {
+            let index = match last.as_str() {
+                "-" => items.len(),
+                segment => parse_index(segment, items.len(), path)?,
+            };
+            items.insert(index, value.clone());
+            index.to_string()
+        }
+        (Value::Array(items), PatchOperation::Replace { value, .. }) => {
+            let index = parse_index(&last, items.len(), path)?;
+            let slot = items
+                .get_mut(index)
+                .ok_or_else(|| PatchError::PathNotFound(path.to_string()))?;
+            *slot = value.clone();
+            last
+        }
+        (Value::Array(items), PatchOperation::Remove { .. }) => {
+            let index = parse_index(&last, items.len(), path)?;
+            if index == items.len() {
+                return Err(PatchError::PathNotFound(path.to_string()));
+            }
+            items.remove(index);
+            last
+        }
+        _ => return Err(PatchError::PathNotFound(path.to_string())),
+    };
+    segments.push(last);
+    Ok(segments)
+}
@@ -0,0 +1,1017 @@
+//! Turn the writes recorded in a store into JSON patches.
+//!
+//! Every write to a store marks a path in the subscription tree dirty. The recorder keeps the serialized
+//! value of the store as of the last patch it created, walks the current value along each recorded path
+//! and only serializes the parts of the value those paths point to. A write to a single field costs the
+//! size of that field instead of the size of the whole store.
+
+use std::collections::HashSet;
+
+use serde::{
+    Serialize, Serializer,
+    de::DeserializeOwned,
+    ser::{
+        Error as _, Impossible, SerializeMap, SerializeSeq, SerializeStruct,
+        SerializeStructVariant, SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+    },
+};
+use serde_json::Value;
+
+use super::{
+    PatchError, PatchOperation, StorePatch, apply_operation, diff_values, push_segment,
+    resolve::{CapturePrimitive, Unresolvable},
+};
+use crate::{
+    store::Store,
+    subscriptions::{DirtyKind, DirtyPatch, PathKey, StoreSubscriptions},
+};
+use dioxus_signals::{Readable, ReadableExt, Writable, WriteSignal};
+
+/// Records every write made to a [`Store`] and turns the writes into [`StorePatch`]es.
+///
+/// The recorder keeps the serialized value of the store as of the last patch it created. When a patch is
+/// taken, only the parts of the store that were written to since then are serialized and diffed, so writing
+/// to a single field of a large store produces a patch without serializing the rest of the store.
+///
+/// Writes through any lens of the store are recorded. If the recorder was created from a lens, writes to
+/// the parents of the lens that replace its value are recorded as well.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+/// use dioxus_stores::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Store, Serialize, Deserialize, Default)]
+/// struct Todos {
+///     items: Vec<String>,
+/// }
+///
+/// let local = use_store(Todos::default);
+/// let mut remote = use_store(Todos::default);
+/// let mut recorder = PatchRecorder::new(local).unwrap();
+///
+/// local.items().push("Write docs".to_string());
+///
+/// // Only the new item is serialized
+/// let patch = recorder.take_patch().unwrap();
+/// remote.apply_patch(&patch).unwrap();
+/// ```
+pub struct PatchRecorder<T, Lens = WriteSignal<T>> {
+    store: Store<T, Lens>,
+    recording: usize,
+    /// The serialized value of the store as of the last patch the recorder created or applied
+    mirror: Value,
+}
+
+impl<T, Lens> Drop for PatchRecorder<T, Lens> {
+    fn drop(&mut self) {
+        self.subscriptions().stop_recording(self.recording);
+    }
+}
+
+impl<T, Lens> PatchRecorder<T, Lens> {
+    /// Get the store the recorder records writes from.
+    pub fn store(&self) -> &Store<T, Lens> {
+        &self.store
+    }
+
+    /// Get the serialized value of the store as of the last patch the recorder created or applied.
+    pub fn snapshot(&self) -> &Value {
+        &self.mirror
+    }
+
+    fn subscriptions(&self) -> StoreSubscriptions {
+        self.store.selector().subscriptions()
+    }
+}
+
+impl<T: Serialize + 'static, Lens: Readable<Target = T> + 'static> PatchRecorder<T, Lens> {
+    /// Start recording the writes made to a store.
+    pub fn new(store: Store<T, Lens>) -> Result<Self, PatchError> {
+        let mirror = serde_json::to_value(&*store.selector().peek())?;
+        let recording = store.selector().subscriptions().start_recording();
+        Ok(Self {
+            store,
+            recording,
+            mirror,
+        })
+    }
+
+    /// Create a patch from every write made to the store since the last patch was taken. The patch is
+    /// empty if the writes didn't change the serialized value of the store.
+    pub fn take_patch(&mut self) -> Result<StorePatch, PatchError> {
+        Ok(self.take_operations(None)?.into())
+    }
+
+    /// Create a patch from every write made to the store since the last patch was taken along with the
+    /// patch that reverts it.
+    pub(crate) fn take_patch_with_inverse(
+        &mut self,
+    ) -> Result<(StorePatch, StorePatch), PatchError> {
+        let mut inverse = Vec::new();
+        let forward = self.take_operations(Some(&mut inverse))?;
+        inverse.reverse();
+        Ok((forward.into(), inverse.into()))
+    }
+
+    fn take_operations(
+        &mut self,
+        mut inverse: Option<&mut Vec<PatchOperation>>,
+    ) -> Result<Vec<PatchOperation>, PatchError> {
+        let marks = self.subscriptions().take_recorded(self.recording);
+        let targets = capture_targets(self.store.selector().path(), marks);
+        if targets.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let value = self.store.selector().peek();
+        let subscriptions = self.subscriptions();
+        let mut operations = Vec::new();
+        for (keys, kind) in targets {
+            let mut state = Locate {
+                keys: &keys,
+                kind,
+                subscriptions,
+                mirror: &self.mirror,
+                pointer: String::new(),
+                captured: None,
+            };
+            // Paths that no longer exist in the value were removed by a write to one of their parents,
+            // which is recorded as well
+            _ = locate(&*value, &mut state);
+            let Some(captured) = state.captured else {
+                continue;
+            };
+            let pointer = state.pointer;
+            for operation in operations_for(pointer, captured?, &self.mirror) {
+                if let Some(inverse) = inverse.as_deref_mut() {
+                    inverse.push(invert(&self.mirror, &operation));
+                }
+                apply_operation(&mut self.mirror, &operation)?;
+                operations.push(operation);
+            }
+        }
+        Ok(operations)
+    }
+}
+
+impl<T: Serialize + DeserializeOwned + 'static, Lens: Writable<Target = T> + 'static>
+    PatchRecorder<T, Lens>
+{
+    /// Apply a patch to the store without recording it. Writes made to the store before the patch that
+    /// have not been taken yet are still part of the next patch.
+    ///
+    /// If any operation in the patch fails, the store is left unchanged.
+    pub fn apply_patch(&mut self, patch: &StorePatch) -> Result<(), PatchError> {
+        let subscriptions = self.subscriptions();
+        let store = &mut self.store;
+        subscriptions.without_recording(self.recording, || store.apply_patch(patch))?;
+
+        for operation in patch.operations() {
+            if apply_operation(&mut self.mirror, operation).is_err() {
+                // The patch applied to the store, so the serialized value only differs because of
+                // writes that were not taken yet. Those writes now become part of the snapshot.
+                self.mirror = serde_json::to_value(&*self.store.selector().peek())?;
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace the value of the store with a serialized snapshot without recording it. Writes made to the
+    /// store before the snapshot that have not been taken yet are discarded.
+    pub fn apply_snapshot(&mut self, snapshot: Value) -> Result<(), PatchError> {
+        let subscriptions = self.subscriptions();
+        let store = &mut self.store;
+        subscriptions
+            .without_recording(self.recording, || store.apply_snapshot(snapshot.clone()))?;
+        subscriptions.take_recorded(self.recording);
+        self.mirror = snapshot;
+        Ok(())
+    }
+}
+
+/// Merge recorded marks into the list of paths to capture, relative to the path of the recorded store.
+/// Parents are captured before their children so every capture is diffed against an up to date mirror.
+fn capture_targets(base: &[PathKey], marks: Vec<DirtyPatch>) -> Vec<(Vec<PathKey>, CaptureKind)> {
+    struct Target {
+        keys: Vec<PathKey>,
+        deep: bool,
+        from: usize,
+    }
+
+    let mut targets: Vec<Target> = Vec::new();
+    for DirtyPatch { path, kind } in marks {
+        let (keys, kind) = match path.strip_prefix(base) {
+            Some(keys) => (keys.to_vec(), kind),
+            // A write to a parent of the recorded store replaces its value if it was deep, or if it moved
+            // the item the store points into
+            None if base.starts_with(&path) => {
+                let replaced = match kind {
+                    DirtyKind::Deep => true,
+                    DirtyKind::Shallow | DirtyKind::Unordered => false,
+                    DirtyKind::AtAndAfterIndex(index) => base[path.len()] as usize >= index,
+                };
+                if !replaced {
+                    continue;
+                }
+                (Vec::new(), DirtyKind::Deep)
+            }
+            None => continue,
+        };
+        let target = match targets.iter_mut().find(|target| target.keys == keys) {
+            Some(target) => target,
+            None => {
+                targets.push(Target {
+                    keys,
+                    deep: false,
+                    from: usize::MAX,
+                });
+                targets.last_mut().unwrap()
+            }
+        };
+        match kind {
+            DirtyKind::Deep => target.deep = true,
+            DirtyKind::Shallow => {}
+            // Every item of a set may have moved
+            DirtyKind::Unordered => target.from = 0,
+            DirtyKind::AtAndAfterIndex(index) => target.from = target.from.min(index),
+        }
+    }
+
+    let is_below = |keys: &[PathKey], parent: &[PathKey]| {
+        keys.len() > parent.len() && keys.starts_with(parent)
+    };
+    let mut captures: Vec<_> = targets
+        .iter()
+        .filter(|target| {
+            !targets
+                .iter()
+                .any(|parent| parent.deep && is_below(&target.keys, &parent.keys))
+        })
+        .map(|target| {
+            let kind = match target.deep {
+                true => CaptureKind::Deep,
+                false => CaptureKind::Shallow { from: target.from },
+            };
+            (target.keys.clone(), kind)
+        })
+        .collect();
+    captures.sort_by_key(|(keys, _)| keys.len());
+    captures
+}
+
+/// Create the operations that turn the mirror into the captured value.
+fn operations_for(mut pointer: String, captured: Captured, mirror: &Value) -> Vec<PatchOperation> {
+    let mut operations = Vec::new();
+    let old = mirror.pointer(&pointer);
+    match captured {
+        Captured::Value(new) => match old {
+            Some(old) => diff_values(&mut pointer, old, &new, &mut operations),
+            None => {
+                let parent = &pointer[..pointer.rfind('/').unwrap_or(0)];
+                if mirror.pointer(parent).is_some() {
+                    operations.push(PatchOperation::Add {
+                        path: pointer,
+                        value: new,
+                    });
+                }
+            }
+        },
+        Captured::Tail { len, start, items } => {
+            let old = old.and_then(Value::as_array).map_or(&[][..], Vec::as_slice);
+            for (index, new) in (start..).zip(items) {
+                let len = pointer.len();
+                push_segment(&mut pointer, &index.to_string());
+                match old.get(index) {
+                    Some(old) => diff_values(&mut pointer, old, &new, &mut operations),
+                    None => operations.push(PatchOperation::Add {
+                        path: pointer.clone(),
+                        value: new,
+                    }),
+                }
+                pointer.truncate(len);
+            }
+            // Remove from the back so the indexes of the remaining items don't shift
+            for index in (len..old.len()).rev() {
+                let mut path = pointer.clone();
+                push_segment(&mut path, &index.to_string());
+                operations.push(PatchOperation::Remove { path });
+            }
+        }
+        Captured::Keys { keys, added } => {
+            if let Some(old) = old.and_then(Value::as_object) {
+                for key in old.keys().filter(|key| !keys.contains(*key)) {
+                    let mut path = pointer.clone();
+                    push_segment(&mut path, key);
+                    operations.push(PatchOperation::Remove { path });
+                }
+            }
+            for (key, value) in added {
+                let mut path = pointer.clone();
+                push_segment(&mut path, &key);
+                operations.push(PatchOperation::Add { path, value });
+            }
+        }
+    }
+    operations
+}
+
+/// Create the operation that reverts an operation on a value before it is applied.
+fn invert(value: &Value, operation: &PatchOperation) -> PatchOperation {
+    let path = operation.path().to_string();
+    let old = value.pointer(&path).cloned();
+    let parent = &path[..path.rfind('/').unwrap_or(0)];
+    let inserts = value.pointer(parent).is_some_and(Value::is_array);
+    match (operation, old) {
+        (PatchOperation::Add { .. }, Some(old)) if !inserts => {
+            PatchOperation::Replace { path, value: old }
+        }
+        (PatchOperation::Add { .. }, _) => PatchOperation::Remove { path },
+        (PatchOperation::Remove { .. }, Some(old)) => PatchOperation::Add { path, value: old },
+        (PatchOperation::Replace { .. }, Some(old)) => PatchOperation::Replace { path, value: old },
+        // The operation will fail to apply, so there is nothing to revert
+        (_, None) => PatchOperation::Remove { path },
+    }
+}
+
+/// How much of the value at a recorded path needs to be serialized.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CaptureKind {
+    /// The whole value
+    Deep,
+    /// The shape of a collection. For sequences, the items at and after `from` are also captured.
+    Shallow { from: usize },
+}
+
+/// The serialized part of the value at a recorded path.
+enum Captured {
+    /// The whole value
+    Value(Value),
+    /// The length of a sequence and the items at and after `start`
+    Tail {
+        len: usize,
+        start: usize,
+        items: Vec<Value>,
+    },
+    /// The keys of a map and the values of the keys the mirror doesn't have yet
+    Keys {
+        keys: HashSet<String>,
+        added: Vec<(String, Value)>,
+    },
+}
+
+struct Locate<'a> {
+    /// The keys left to follow
+    keys: &'a [PathKey],
+    kind: CaptureKind,
+    subscriptions: StoreSubscriptions,
+    mirror: &'a Value,
+    /// The JSON pointer of the keys followed so far
+    pointer: String,
+    captured: Option<Result<Captured, serde_json::Error>>,
+}
+
+/// Follow the remaining keys into the value and capture the value they point to.
+fn locate<V: Serialize + ?Sized>(value: &V, state: &mut Locate) -> Result<(), Unresolvable> {
+    if !state.keys.is_empty() {
+        return value.serialize(Locator { state });
+    }
+    let mirror = state.mirror.pointer(&state.pointer);
+    state.captured = Some(capture(value, state.kind, mirror));
+    Ok(())
+}
+
+fn capture<V: Serialize + ?Sized>(
+    value: &V,
+    kind: CaptureKind,
+    mirror: Option<&Value>,
+) -> Result<Captured, serde_json::Error> {
+    if let (CaptureKind::Shallow { from }, Some(mirror)) = (kind, mirror)
+        && let Ok(captured) = value.serialize(CaptureCollection { from, mirror })
+    {
+        return Ok(captured);
+    }
+    serde_json::to_value(value).map(Captured::Value)
+}
+
+struct Locator<'a, 'b> {
+    state: &'a mut Locate<'b>,
+}
+
+impl Locator<'_, '_> {
+    /// Follow a child that is keyed as child 0 of the current value.
+    fn first_child<V: Serialize + ?Sized>(
+        self,
+        segment: Option<&str>,
+        value: &V,
+    ) -> Result<(), Unresolvable> {
+        if self.state.keys[0] != 0 {
+            return Ok(());
+        }
+        if let Some(segment) = segment {
+            push_segment(&mut self.state.pointer, segment);
+        }
+        self.state.keys = &self.state.keys[1..];
+        locate(value, self.state)
+    }
+}
+
+macro_rules! leaf {
+    ($($method:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $method(self, _: $ty) -> Result<(), Unresolvable> {
+                Ok(())
+            }
+        )*
+    };
+}
+
+impl<'a, 'b> Serializer for Locator<'a, 'b> {
+    type Ok = ();
+    type Error = Unresolvable;
+    type SerializeSeq = LocateCompound<'a, 'b>;
+    type SerializeTuple = LocateCompound<'a, 'b>;
+    type SerializeTupleStruct = LocateCompound<'a, 'b>;
+    type SerializeTupleVariant = LocateCompound<'a, 'b>;
+    type SerializeMap = LocateCompound<'a, 'b>;
+    type SerializeStruct = LocateCompound<'a, 'b>;
+    type SerializeStructVariant = LocateCompound<'a, 'b>;
+
+    leaf! {
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_i128: i128,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_u128: u128,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_char: char,
+        serialize_str: &str,
+        serialize_bytes: &[u8],
+        serialize_unit_struct: &'static str,
+    }
+
+    fn serialize_none(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+
+    fn serialize_unit(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Unresolvable> {
+        // Options are transparent in JSON, but the store keys the inner value as child 0
+        self.first_child(None, value)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), Unresolvable> {
+        self.first_child(None, value)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), Unresolvable> {
+        self.first_child(Some(variant), value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<LocateCompound<'a, 'b>, Unresolvable> {
+        Ok(LocateCompound::new(
+            self.state,
+            LocateKind::Seq { len },
+            None,
+        ))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<LocateCompound<'a, 'b>, Unresolvable> {
+        Ok(LocateCompound::new(
+            self.state,
+            LocateKind::Seq { len: Some(len) },
+            None,
+        ))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<LocateCompound<'a, 'b>, Unresolvable> {
+        Ok(LocateCompound::new(self.state, LocateKind::Fields, None))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<LocateCompound<'a, 'b>, Unresolvable> {
+        Ok(LocateCompound::new(
+            self.state,
+            LocateKind::Fields,
+            Some(variant),
+        ))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<LocateCompound<'a, 'b>, Unresolvable> {
+        Ok(LocateCompound::new(self.state, LocateKind::Map, None))
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<LocateCompound<'a, 'b>, Unresolvable> {
+        Ok(LocateCompound::new(self.state, LocateKind::Fields, None))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<LocateCompound<'a, 'b>, Unresolvable> {
+        Ok(LocateCompound::new(
+            self.state,
+            LocateKind::Fields,
+            Some(variant),
+        ))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum LocateKind {
+    /// A sequence keyed by index, or a set keyed by the hash of each value
+    Seq { len: Option<usize> },
+    /// A struct, tuple struct or the fields of an enum variant keyed by field ordinal
+    Fields,
+    /// A map keyed by the hash of the key
+    Map,
+}
+
+struct LocateCompound<'a, 'b> {
+    state: &'a mut Locate<'b>,
+    kind: LocateKind,
+    /// The variant the fields belong to. Enum variants are wrapped in an object with the variant name
+    /// in JSON, but the derived store keys the fields directly under the enum.
+    variant: Option<&'static str>,
+    index: usize,
+    found: bool,
+    /// The JSON key of the map entry whose value we should walk into next
+    matched_key: Option<String>,
+}
+
+impl<'a, 'b> LocateCompound<'a, 'b> {
+    fn new(state: &'a mut Locate<'b>, kind: LocateKind, variant: Option<&'static str>) -> Self {
+        Self {
+            state,
+            kind,
+            variant,
+            index: 0,
+            found: false,
+            matched_key: None,
+        }
+    }
+
+    fn hash<T: Serialize + ?Sized>(&self, value: &T) -> Option<PathKey> {
+        value
+            .serialize(CapturePrimitive)
+            .ok()
+            .map(|key| key.hash(self.state.subscriptions))
+    }
+
+    fn item<T: Serialize + ?Sized>(
+        &mut self,
+        name: Option<&str>,
+        value: &T,
+    ) -> Result<(), Unresolvable> {
+        let index = self.index;
+        self.index += 1;
+        if self.found {
+            return Ok(());
+        }
+        let key = self.state.keys[0];
+        let matches = match self.kind {
+            LocateKind::Seq { len } => {
+                // Sequences are keyed by index, and sets are keyed by the hash of the value. Sets
+                // can't be told apart from sequences, so keys past the end must be hashes.
+                match len {
+                    Some(len) if (key as usize) < len => key as usize == index,
+                    _ => key as usize == index || self.hash(value) == Some(key),
+                }
+            }
+            _ => key as usize == index,
+        };
+        if !matches {
+            return Ok(());
+        }
+        self.found = true;
+        if let Some(variant) = self.variant {
+            push_segment(&mut self.state.pointer, variant);
+        }
+        match name {
+            Some(name) => push_segment(&mut self.state.pointer, name),
+            None => push_segment(&mut self.state.pointer, &index.to_string()),
+        }
+        self.state.keys = &self.state.keys[1..];
+        locate(value, self.state)
+    }
+}
+
+impl SerializeSeq for LocateCompound<'_, '_> {
+    type Ok = ();
+    type Error = Unresolvable;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Unresolvable> {
+        self.item(None, value)
+    }
+
+    fn end(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+}
+
+impl SerializeTuple for LocateCompound<'_, '_> {
+    type Ok = ();
+    type Error = Unresolvable;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Unresolvable> {
+        self.item(None, value)
+    }
+
+    fn end(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+}
+
+impl SerializeTupleStruct for LocateCompound<'_, '_> {
+    type Ok = ();
+    type Error = Unresolvable;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Unresolvable> {
+        self.item(None, value)
+    }
+
+    fn end(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+}
+
+impl SerializeTupleVariant for LocateCompound<'_, '_> {
+    type Ok = ();
+    type Error = Unresolvable;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Unresolvable> {
+        self.item(None, value)
+    }
+
+    fn end(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+}
+
+impl SerializeStruct for LocateCompound<'_, '_> {
+    type Ok = ();
+    type Error = Unresolvable;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Unresolvable> {
+        self.item(Some(key), value)
+    }
+
+    fn skip_field(&mut self, _: &'static str) -> Result<(), Unresolvable> {
+        // Skipped fields still take up an ordinal in the derived store
+        self.index += 1;
+        Ok(())
+    }
+
+    fn end(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+}
+
+impl SerializeStructVariant for LocateCompound<'_, '_> {
+    type Ok = ();
+    type Error = Unresolvable;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Unresolvable> {
+        self.item(Some(key), value)
+    }
+
+    fn skip_field(&mut self, _: &'static str) -> Result<(), Unresolvable> {
+        self.index += 1;
+        Ok(())
+    }
+
+    fn end(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+}
+
+impl SerializeMap for LocateCompound<'_, '_> {
+    type Ok = ();
+    type Error = Unresolvable;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Unresolvable> {
+        if self.found {
+            return Ok(());
+        }
+        if let Ok(primitive) = key.serialize(CapturePrimitive)
+            && primitive.hash(self.state.subscriptions) == self.state.keys[0]
+        {
+            self.matched_key = Some(primitive.to_string());
+        }
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Unresolvable> {
+        let Some(key) = self.matched_key.take() else {
+            return Ok(());
+        };
+        self.found = true;
+        push_segment(&mut self.state.pointer, &key);
+        self.state.keys = &self.state.keys[1..];
+        locate(value, self.state)
+    }
+
+    fn end(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+}
+
+fn not_a_collection() -> serde_json::Error {
+    serde_json::Error::custom("the value is not a collection")
+}
+
+macro_rules! not_a_collection {
+    ($($method:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $method(self, _: $ty) -> Result<Captured, serde_json::Error> {
+                Err(not_a_collection())
+            }
+        )*
+    };
+}
+
+/// A serializer that captures the shape of a collection. Any value that is not a sequence or map of the
+/// same kind as the mirror fails to serialize before any of its children are serialized.
+struct CaptureCollection<'a> {
+    from: usize,
+    mirror: &'a Value,
+}
+
+impl<'a> Serializer for CaptureCollection<'a> {
+    type Ok = Captured;
+    type Error = serde_json::Error;
+    type SerializeSeq = CaptureTail;
+    type SerializeTuple = CaptureTail;
+    type SerializeTupleStruct = Impossible<Captured, serde_json::Error>;
+    type SerializeTupleVariant = Impossible<Captured, serde_json::Error>;
+    type SerializeMap = CaptureKeys<'a>;
+    type SerializeStruct = Impossible<Captured, serde_json::Error>;
+    type SerializeStructVariant = Impossible<Captured, serde_json::Error>;
+
+    not_a_collection! {
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_i128: i128,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_u128: u128,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_char: char,
+        serialize_str: &str,
+        serialize_bytes: &[u8],
+        serialize_unit_struct: &'static str,
+    }
+
+    fn serialize_none(self) -> Result<Captured, serde_json::Error> {
+        Err(not_a_collection())
+    }
+
+    fn serialize_unit(self) -> Result<Captured, serde_json::Error> {
+        Err(not_a_collection())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<Captured, serde_json::Error> {
+        Err(not_a_collection())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(
+        self,
+        value: &T,
+    ) -> Result<Captured, serde_json::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Captured, serde_json::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Captured, serde_json::Error> {
+        Err(not_a_collection())
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<CaptureTail, serde_json::Error> {
+        let old = self.mirror.as_array().ok_or_else(not_a_collection)?;
+        Ok(CaptureTail {
+            start: self.from.min(old.len()),
+            len: 0,
+            items: Vec::new(),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<CaptureTail, serde_json::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Impossible<Captured, serde_json::Error>, serde_json::Error> {
+        Err(not_a_collection())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Impossible<Captured, serde_json::Error>, serde_json::Error> {
+        Err(not_a_collection())
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<CaptureKeys<'a>, serde_json::Error> {
+        let old = self.mirror.as_object().ok_or_else(not_a_collection)?;
+        Ok(CaptureKeys {
+            old,
+            keys: HashSet::new(),
+            added: Vec::new(),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Impossible<Captured, serde_json::Error>, serde_json::Error> {
+        Err(not_a_collection())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Impossible<Captured, serde_json::Error>, serde_json::Error> {
+        Err(not_a_collection())
+    }
+}
+
+struct CaptureTail {
+    start: usize,
+    len: usize,
+    items: Vec<Value>,
+}
+
+impl SerializeSeq for CaptureTail {
+    type Ok = Captured;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        if self.len >= self.start {
+            self.items.push(serde_json::to_value(value)?);
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    fn end(self) -> Result<Captured, serde_json::Error> {
+        Ok(Captured::Tail {
+            len: self.len,
+            start: self.start,
+            items: self.items,
+        })
+    }
+}
+
+impl SerializeTuple for CaptureTail {
+    type Ok = Captured;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Captured, serde_json::Error> {
+        SerializeSeq::end(self)
+    }
+}
+
+struct CaptureKeys<'a> {
+    old: &'a serde_json::Map<String, Value>,
+    keys: HashSet<String>,
+    added: Vec<(String, Value)>,
+    next_key: Option<String>,
+}
+
+impl SerializeMap for CaptureKeys<'_> {
+    type Ok = Captured;
+    type Error = serde_json::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), serde_json::Error> {
+        let key = key
+            .serialize(CapturePrimitive)
+            .map_err(|_| serde_json::Error::custom("map keys must be primitives"))?
+            .to_string();
+        self.next_key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        let key = self
+            .next_key
+            .take()
+            .ok_or_else(|| serde_json::Error::custom("map value without a key"))?;
+        // Values of existing keys are only captured if they were written to, which is recorded separately
+        if !self.old.contains_key(&key) {
+            self.added.push((key.clone(), serde_json::to_value(value)?));
+        }
+        self.keys.insert(key);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Captured, serde_json::Error> {
+        Ok(Captured::Keys {
+            keys: self.keys,
+            added: self.added,
+        })
+    }
+}
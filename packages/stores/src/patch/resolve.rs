@@ -0,0 +1,694 @@
+//! Resolve JSON pointers into paths in the store subscription tree.
+//!
+//! Stores key their children by field ordinal, sequence index or the hash of a map key. The JSON
+//! representation of a value only knows field names and string keys, so we walk the serialized form
+//! of the value with a custom serializer to recover the store path the pointer addresses.
+
+use std::fmt::Display;
+
+use serde::{
+    Serialize, Serializer,
+    ser::{
+        Impossible, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+        SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+    },
+};
+
+use crate::subscriptions::{PathKey, StoreSubscriptions};
+
+/// The store path a JSON pointer resolved to.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct ResolvedPath {
+    /// The keys of the deepest node the pointer reached.
+    pub(crate) keys: Vec<PathKey>,
+    /// If every segment of the pointer was found in the value.
+    pub(crate) complete: bool,
+    /// Information about the container holding the last segment of the pointer.
+    pub(crate) last: Option<LastSegment>,
+}
+
+impl ResolvedPath {
+    /// The keys of the container that holds the target of the pointer.
+    pub(crate) fn parent(&self) -> &[PathKey] {
+        match &self.last {
+            Some(last) => &self.keys[..last.parent_len],
+            None => &self.keys,
+        }
+    }
+}
+
+/// Information about the container holding the last segment of a pointer.
+#[derive(Debug, PartialEq)]
+pub(crate) struct LastSegment {
+    /// The number of keys in the path to the container.
+    pub(crate) parent_len: usize,
+    /// The index of the item if the container is a sequence.
+    pub(crate) index: Option<usize>,
+    /// The hash of the item if the container is a sequence of primitives. Sets are serialized as
+    /// sequences, but their stores are keyed by the hash of each value.
+    pub(crate) value_hash: Option<PathKey>,
+}
+
+/// Resolve a list of unescaped JSON pointer segments against a value.
+pub(crate) fn resolve<T: Serialize + ?Sized>(
+    value: &T,
+    segments: &[String],
+    subscriptions: StoreSubscriptions,
+) -> ResolvedPath {
+    let mut state = ResolveState {
+        segments,
+        consumed: 0,
+        subscriptions,
+        resolved: ResolvedPath::default(),
+    };
+    // Errors only mean we could not walk any deeper into the value. Whatever we resolved before
+    // that point is still a valid (if coarser) path.
+    _ = value.serialize(Resolver { state: &mut state });
+    state.resolved.complete = state.consumed == segments.len();
+    state.resolved
+}
+
+struct ResolveState<'a> {
+    segments: &'a [String],
+    consumed: usize,
+    subscriptions: StoreSubscriptions,
+    resolved: ResolvedPath,
+}
+
+impl ResolveState<'_> {
+    fn next_segment(&self) -> Option<&str> {
+        self.segments.get(self.consumed).map(String::as_str)
+    }
+
+    fn next_index(&self) -> Option<usize> {
+        self.next_segment()?.parse().ok()
+    }
+
+    /// Consume the next segment and push the child key it maps to.
+    fn enter(&mut self, key: PathKey, index: Option<usize>, value_hash: Option<PathKey>) {
+        self.consumed += 1;
+        if self.consumed == self.segments.len() {
+            self.resolved.last = Some(LastSegment {
+                parent_len: self.resolved.keys.len(),
+                index,
+                value_hash,
+            });
+        }
+        self.resolved.keys.push(key);
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct Unresolvable;
+
+impl Display for Unresolvable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("the value could not be resolved")
+    }
+}
+
+impl std::error::Error for Unresolvable {}
+
+impl serde::ser::Error for Unresolvable {
+    fn custom<T: Display>(_: T) -> Self {
+        Self
+    }
+}
+
+struct Resolver<'a, 'b> {
+    state: &'a mut ResolveState<'b>,
+}
+
+macro_rules! leaf {
+    ($($method:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $method(self, _: $ty) -> Result<(), Unresolvable> {
+                Ok(())
+            }
+        )*
+    };
+}
+
+impl<'a, 'b> Serializer for Resolver<'a, 'b> {
+    type Ok = ();
+    type Error = Unresolvable;
+    type SerializeSeq = Compound<'a, 'b>;
+    type SerializeTuple = Compound<'a, 'b>;
+    type SerializeTupleStruct = Compound<'a, 'b>;
+    type SerializeTupleVariant = Compound<'a, 'b>;
+    type SerializeMap = Compound<'a, 'b>;
+    type SerializeStruct = Compound<'a, 'b>;
+    type SerializeStructVariant = Compound<'a, 'b>;
+
+    leaf! {
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_i128: i128,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_u128: u128,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_char: char,
+        serialize_str: &str,
+        serialize_bytes: &[u8],
+        serialize_unit_struct: &'static str,
+    }
+
+    fn serialize_none(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+
+    fn serialize_unit(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Unresolvable> {
+        // Options are transparent in JSON, but the store keys the inner value as child 0
+        if self.state.next_segment().is_some() {
+            self.state.resolved.keys.push(0);
+            value.serialize(self)?;
+        }
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), Unresolvable> {
+        // Newtype structs are transparent in JSON, but the derived store keys the field as child 0
+        if self.state.next_segment().is_some() {
+            self.state.resolved.keys.push(0);
+            value.serialize(self)?;
+        }
+        Ok(())
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), Unresolvable> {
+        if self.state.next_segment() != Some(variant) {
+            return Ok(());
+        }
+        // Enum stores, including the stores of `Result`, key the field of the variant by its ordinal
+        self.state.enter(0, None, None);
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Compound<'a, 'b>, Unresolvable> {
+        Ok(Compound::new(self.state, CompoundKind::Seq))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Compound<'a, 'b>, Unresolvable> {
+        Ok(Compound::new(self.state, CompoundKind::Seq))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Compound<'a, 'b>, Unresolvable> {
+        Ok(Compound::new(self.state, CompoundKind::Fields))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Compound<'a, 'b>, Unresolvable> {
+        Ok(Compound::new_variant(
+            self.state,
+            variant,
+            CompoundKind::Fields,
+        ))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Compound<'a, 'b>, Unresolvable> {
+        Ok(Compound::new(self.state, CompoundKind::Map))
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Compound<'a, 'b>, Unresolvable> {
+        Ok(Compound::new(self.state, CompoundKind::Fields))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Compound<'a, 'b>, Unresolvable> {
+        Ok(Compound::new_variant(
+            self.state,
+            variant,
+            CompoundKind::Fields,
+        ))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum CompoundKind {
+    /// A sequence or tuple keyed by index
+    Seq,
+    /// A struct or tuple struct keyed by field ordinal
+    Fields,
+    /// A map keyed by the hash of the key
+    Map,
+}
+
+struct Compound<'a, 'b> {
+    state: &'a mut ResolveState<'b>,
+    kind: CompoundKind,
+    /// The index of the next item in the compound
+    index: usize,
+    /// If the compound is part of the path. This is false once we find the child we are looking
+    /// for or if the compound is an enum variant that does not match the pointer.
+    active: bool,
+    /// The key of the map entry whose value we should walk into next
+    matched_key: Option<PathKey>,
+}
+
+impl<'a, 'b> Compound<'a, 'b> {
+    fn new(state: &'a mut ResolveState<'b>, kind: CompoundKind) -> Self {
+        let active = state.next_segment().is_some();
+        Self {
+            state,
+            kind,
+            index: 0,
+            active,
+            matched_key: None,
+        }
+    }
+
+    fn new_variant(
+        state: &'a mut ResolveState<'b>,
+        variant: &'static str,
+        kind: CompoundKind,
+    ) -> Self {
+        // Enum variants are wrapped in an object with the variant name in JSON. The derived store
+        // keys the fields of the variant directly under the enum.
+        let active = state.next_segment() == Some(variant);
+        if active {
+            state.consumed += 1;
+        }
+        let mut compound = Self::new(state, kind);
+        compound.active &= active;
+        compound
+    }
+
+    fn item<T: Serialize + ?Sized>(
+        &mut self,
+        name: Option<&str>,
+        value: &T,
+    ) -> Result<(), Unresolvable> {
+        let index = self.index;
+        self.index += 1;
+        if !self.active {
+            return Ok(());
+        }
+        let matches = match name {
+            Some(name) => self.state.next_segment() == Some(name),
+            None => self.state.next_index() == Some(index),
+        };
+        if !matches {
+            return Ok(());
+        }
+        self.active = false;
+        let (seq_index, value_hash) = match self.kind {
+            CompoundKind::Seq => (
+                Some(index),
+                value
+                    .serialize(CapturePrimitive)
+                    .ok()
+                    .map(|key| key.hash(self.state.subscriptions)),
+            ),
+            _ => (None, None),
+        };
+        self.state.enter(index as PathKey, seq_index, value_hash);
+        value.serialize(Resolver {
+            state: &mut *self.state,
+        })
+    }
+}
+
+impl SerializeSeq for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Unresolvable;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Unresolvable> {
+        self.item(None, value)
+    }
+
+    fn end(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+}
+
+impl SerializeTuple for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Unresolvable;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Unresolvable> {
+        self.item(None, value)
+    }
+
+    fn end(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+}
+
+impl SerializeTupleStruct for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Unresolvable;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Unresolvable> {
+        self.item(None, value)
+    }
+
+    fn end(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+}
+
+impl SerializeTupleVariant for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Unresolvable;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Unresolvable> {
+        self.item(None, value)
+    }
+
+    fn end(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+}
+
+impl SerializeStruct for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Unresolvable;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Unresolvable> {
+        self.item(Some(key), value)
+    }
+
+    fn skip_field(&mut self, _: &'static str) -> Result<(), Unresolvable> {
+        // Skipped fields still take up an ordinal in the derived store
+        self.index += 1;
+        Ok(())
+    }
+
+    fn end(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+}
+
+impl SerializeStructVariant for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Unresolvable;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Unresolvable> {
+        self.item(Some(key), value)
+    }
+
+    fn skip_field(&mut self, _: &'static str) -> Result<(), Unresolvable> {
+        self.index += 1;
+        Ok(())
+    }
+
+    fn end(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+}
+
+impl SerializeMap for Compound<'_, '_> {
+    type Ok = ();
+    type Error = Unresolvable;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Unresolvable> {
+        if !self.active {
+            return Ok(());
+        }
+        // Only keys that serialize to a primitive can be addressed by a JSON pointer
+        if let Ok(key) = key.serialize(CapturePrimitive)
+            && self.state.next_segment() == Some(key.to_string().as_str())
+        {
+            self.matched_key = Some(key.hash(self.state.subscriptions));
+        }
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Unresolvable> {
+        let Some(key) = self.matched_key.take() else {
+            return Ok(());
+        };
+        self.active = false;
+        self.state.enter(key, None, None);
+        value.serialize(Resolver {
+            state: &mut *self.state,
+        })
+    }
+
+    fn end(self) -> Result<(), Unresolvable> {
+        Ok(())
+    }
+}
+
+/// A primitive value captured with its original type. Store keys are created by hashing the
+/// original rust value, so we need to keep the type to reproduce the same hash.
+pub(super) enum PrimitiveKey {
+    Bool(bool),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Char(char),
+    Str(String),
+}
+
+impl PrimitiveKey {
+    pub(super) fn hash(&self, subscriptions: StoreSubscriptions) -> PathKey {
+        match self {
+            PrimitiveKey::Bool(value) => subscriptions.hash(value),
+            PrimitiveKey::I8(value) => subscriptions.hash(value),
+            PrimitiveKey::I16(value) => subscriptions.hash(value),
+            PrimitiveKey::I32(value) => subscriptions.hash(value),
+            PrimitiveKey::I64(value) => subscriptions.hash(value),
+            PrimitiveKey::I128(value) => subscriptions.hash(value),
+            PrimitiveKey::U8(value) => subscriptions.hash(value),
+            PrimitiveKey::U16(value) => subscriptions.hash(value),
+            PrimitiveKey::U32(value) => subscriptions.hash(value),
+            PrimitiveKey::U64(value) => subscriptions.hash(value),
+            PrimitiveKey::U128(value) => subscriptions.hash(value),
+            PrimitiveKey::Char(value) => subscriptions.hash(value),
+            PrimitiveKey::Str(value) => subscriptions.hash(value.as_str()),
+        }
+    }
+}
+
+impl Display for PrimitiveKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrimitiveKey::Bool(value) => value.fmt(f),
+            PrimitiveKey::I8(value) => value.fmt(f),
+            PrimitiveKey::I16(value) => value.fmt(f),
+            PrimitiveKey::I32(value) => value.fmt(f),
+            PrimitiveKey::I64(value) => value.fmt(f),
+            PrimitiveKey::I128(value) => value.fmt(f),
+            PrimitiveKey::U8(value) => value.fmt(f),
+            PrimitiveKey::U16(value) => value.fmt(f),
+            PrimitiveKey::U32(value) => value.fmt(f),
+            PrimitiveKey::U64(value) => value.fmt(f),
+            PrimitiveKey::U128(value) => value.fmt(f),
+            PrimitiveKey::Char(value) => value.fmt(f),
+            PrimitiveKey::Str(value) => value.fmt(f),
+        }
+    }
+}
+
+macro_rules! primitive {
+    ($($method:ident: $ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            fn $method(self, value: $ty) -> Result<PrimitiveKey, Unresolvable> {
+                Ok(PrimitiveKey::$variant(value.into()))
+            }
+        )*
+    };
+}
+
+/// A serializer that only accepts primitive values.
+pub(super) struct CapturePrimitive;
+
+impl Serializer for CapturePrimitive {
+    type Ok = PrimitiveKey;
+    type Error = Unresolvable;
+    type SerializeSeq = Impossible<PrimitiveKey, Unresolvable>;
+    type SerializeTuple = Impossible<PrimitiveKey, Unresolvable>;
+    type SerializeTupleStruct = Impossible<PrimitiveKey, Unresolvable>;
+    type SerializeTupleVariant = Impossible<PrimitiveKey, Unresolvable>;
+    type SerializeMap = Impossible<PrimitiveKey, Unresolvable>;
+    type SerializeStruct = Impossible<PrimitiveKey, Unresolvable>;
+    type SerializeStructVariant = Impossible<PrimitiveKey, Unresolvable>;
+
+    primitive! {
+        serialize_bool: bool => Bool,
+        serialize_i8: i8 => I8,
+        serialize_i16: i16 => I16,
+        serialize_i32: i32 => I32,
+        serialize_i64: i64 => I64,
+        serialize_i128: i128 => I128,
+        serialize_u8: u8 => U8,
+        serialize_u16: u16 => U16,
+        serialize_u32: u32 => U32,
+        serialize_u64: u64 => U64,
+        serialize_u128: u128 => U128,
+        serialize_char: char => Char,
+        serialize_str: &str => Str,
+    }
+
+    fn serialize_f32(self, _: f32) -> Result<PrimitiveKey, Unresolvable> {
+        Err(Unresolvable)
+    }
+
+    fn serialize_f64(self, _: f64) -> Result<PrimitiveKey, Unresolvable> {
+        Err(Unresolvable)
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<PrimitiveKey, Unresolvable> {
+        Err(Unresolvable)
+    }
+
+    fn serialize_none(self) -> Result<PrimitiveKey, Unresolvable> {
+        Err(Unresolvable)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, _: &T) -> Result<PrimitiveKey, Unresolvable> {
+        Err(Unresolvable)
+    }
+
+    fn serialize_unit(self) -> Result<PrimitiveKey, Unresolvable> {
+        Err(Unresolvable)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<PrimitiveKey, Unresolvable> {
+        Err(Unresolvable)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<PrimitiveKey, Unresolvable> {
+        Err(Unresolvable)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: &T,
+    ) -> Result<PrimitiveKey, Unresolvable> {
+        Err(Unresolvable)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<PrimitiveKey, Unresolvable> {
+        Err(Unresolvable)
+    }
+
+    fn serialize_seq(
+        self,
+        _: Option<usize>,
+    ) -> Result<Impossible<PrimitiveKey, Unresolvable>, Unresolvable> {
+        Err(Unresolvable)
+    }
+
+    fn serialize_tuple(
+        self,
+        _: usize,
+    ) -> Result<Impossible<PrimitiveKey, Unresolvable>, Unresolvable> {
+        Err(Unresolvable)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Impossible<PrimitiveKey, Unresolvable>, Unresolvable> {
+        Err(Unresolvable)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Impossible<PrimitiveKey, Unresolvable>, Unresolvable> {
+        Err(Unresolvable)
+    }
+
+    fn serialize_map(
+        self,
+        _: Option<usize>,
+    ) -> Result<Impossible<PrimitiveKey, Unresolvable>, Unresolvable> {
+        Err(Unresolvable)
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Impossible<PrimitiveKey, Unresolvable>, Unresolvable> {
+        Err(Unresolvable)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Impossible<PrimitiveKey, Unresolvable>, Unresolvable> {
+        Err(Unresolvable)
+    }
+}
@@ -3,6 +3,8 @@
 
 use std::{fmt::Debug, hash::Hash};
 
+#[cfg(feature = "serialize")]
+use crate::subscriptions::DirtyKind;
 use crate::subscriptions::{PathKey, StoreSubscriptions, TinyVec};
 use dioxus_core::Subscribers;
 use dioxus_signals::{
@@ -90,7 +92,7 @@ impl<Lens> SelectorScope<Lens> {
     /// this should not cause issues.
     pub fn hash_child<U: ?Sized, T, F, FMut>(
         self,
-        index: &impl Hash,
+        index: &(impl Hash + ?Sized),
         map: F,
         map_mut: FMut,
     ) -> SelectorScope<MappedMutSignal<U, Lens, F, FMut>>
@@ -119,7 +121,7 @@ impl<Lens> SelectorScope<Lens> {
 
     /// Create a hashed child selector scope for a specific index without mapping the writer. The scope will only
     /// be marked as dirty when a write occurs to that index or its parents.
-    pub fn hash_child_unmapped(self, index: &impl Hash) -> SelectorScope<Lens> {
+    pub fn hash_child_unmapped(self, index: &(impl Hash + ?Sized)) -> SelectorScope<Lens> {
         let hash = self.store.hash(index);
         self.child_unmapped(hash)
     }
@@ -156,16 +158,31 @@ impl<Lens> SelectorScope<Lens> {
 
     /// Mark this scope as dirty recursively.
     pub fn mark_dirty(&self) {
+        #[cfg(feature = "serialize")]
+        self.store.record(&self.path, DirtyKind::Deep);
         self.store.mark_dirty(&self.path);
     }
 
     /// Mark this scope as dirty shallowly.
     pub fn mark_dirty_shallow(&self) {
+        #[cfg(feature = "serialize")]
+        self.store.record(&self.path, DirtyKind::Shallow);
+        self.store.mark_node_dirty(&self.path);
+    }
+
+    /// Mark this scope as dirty shallowly after changing the members of a set. Sets don't keep the
+    /// position of their items, so the write is recorded as changing the position of every item.
+    pub(crate) fn mark_dirty_unordered(&self) {
+        #[cfg(feature = "serialize")]
+        self.store.record(&self.path, DirtyKind::Unordered);
         self.store.mark_node_dirty(&self.path);
     }
 
     /// Mark this scope as dirty at and after the given index.
     pub fn mark_dirty_at_and_after_index(&self, index: usize) {
+        #[cfg(feature = "serialize")]
+        self.store
+            .record(&self.path, DirtyKind::AtAndAfterIndex(index));
         self.store.mark_dirty_at_and_after_index(&self.path, index);
     }
 
@@ -186,6 +203,17 @@ impl<Lens> SelectorScope<Lens> {
         self.write.write_unchecked()
     }
 
+    /// Get the path of this scope in the subscription tree
+    #[cfg(feature = "serialize")]
+    pub(crate) fn path(&self) -> &[PathKey] {
+        &self.path
+    }
+
+    /// Get the subscription tree this scope is a view into
+    pub(crate) fn subscriptions(&self) -> StoreSubscriptions {
+        self.store
+    }
+
     /// Borrow the writer
     pub(crate) fn as_ref(&self) -> SelectorScope<&Lens> {
         SelectorScope {
@@ -205,10 +205,49 @@ impl Deref for TinyVec {
     }
 }
 
+/// How a path in the store was marked dirty. Recorders use the kind to decide how much of the value at
+/// the path changed.
+#[cfg(feature = "serialize")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DirtyKind {
+    /// The path and all of its children were marked dirty
+    Deep,
+    /// Only the path itself was marked dirty
+    Shallow,
+    /// Only the path itself was marked dirty, and the path is a collection like a set that doesn't keep
+    /// the position of its items
+    Unordered,
+    /// Children of the path at and after the index were marked dirty
+    AtAndAfterIndex(usize),
+}
+
+/// A single dirty mark recorded while the store is being tracked by a recorder
+#[cfg(feature = "serialize")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct DirtyPatch {
+    pub(crate) path: Box<[PathKey]>,
+    pub(crate) kind: DirtyKind,
+}
+
+/// The dirty marks recorded for a single recorder attached to the store
+#[cfg(feature = "serialize")]
+struct Recording {
+    id: usize,
+    paused: bool,
+    marks: Vec<DirtyPatch>,
+}
+
 #[derive(Default)]
 pub(crate) struct StoreSubscriptionsInner {
     root: SelectorNode,
     hasher: std::collections::hash_map::RandomState,
+    /// Dirty marks recorded for every recorder attached to the store since each of them last took
+    /// their marks. Recorders are independent so a history and a sync connection on the same store
+    /// don't take each other's marks.
+    #[cfg(feature = "serialize")]
+    recordings: Vec<Recording>,
+    #[cfg(feature = "serialize")]
+    next_recording: usize,
 }
 
 #[derive(Default)]
@@ -237,13 +276,17 @@ impl StoreSubscriptions {
             inner: CopyValue::new_maybe_sync(StoreSubscriptionsInner {
                 root: SelectorNode::default(),
                 hasher: std::collections::hash_map::RandomState::new(),
+                #[cfg(feature = "serialize")]
+                recordings: Vec::new(),
+                #[cfg(feature = "serialize")]
+                next_recording: 0,
             }),
         }
     }
 
     /// Hash an index into a PathKey using the hasher. The hash should be consistent
     /// across calls
-    pub(crate) fn hash(&self, index: &impl Hash) -> PathKey {
+    pub(crate) fn hash(&self, index: &(impl Hash + ?Sized)) -> PathKey {
         (self.inner.write_unchecked().hasher.hash_one(index) % PathKey::MAX as u64) as PathKey
     }
 
@@ -321,6 +364,85 @@ impl StoreSubscriptions {
         }
     }
 
+    /// Start recording every dirty mark made through a selector in this store. Returns the id of the
+    /// recording that is used to take the marks.
+    #[cfg(feature = "serialize")]
+    pub(crate) fn start_recording(&self) -> usize {
+        let mut write = self.inner.write_unchecked();
+        let id = write.next_recording;
+        write.next_recording += 1;
+        write.recordings.push(Recording {
+            id,
+            paused: false,
+            marks: Vec::new(),
+        });
+        id
+    }
+
+    /// Stop a recording and drop any marks it has not taken yet.
+    #[cfg(feature = "serialize")]
+    pub(crate) fn stop_recording(&self, id: usize) {
+        // The store may already be dropped if the recorder outlives it
+        if let Ok(mut write) = self.inner.try_write_unchecked() {
+            write.recordings.retain(|recording| recording.id != id);
+        }
+    }
+
+    /// Record a dirty mark for every recording attached to the store. Repeated marks are only recorded once.
+    #[cfg(feature = "serialize")]
+    pub(crate) fn record(&self, key: &[PathKey], kind: DirtyKind) {
+        let mut write = self.inner.write_unchecked();
+        for recording in write
+            .recordings
+            .iter_mut()
+            .filter(|recording| !recording.paused)
+        {
+            if !recording
+                .marks
+                .iter()
+                .any(|patch| patch.kind == kind && *patch.path == *key)
+            {
+                recording.marks.push(DirtyPatch {
+                    path: key.into(),
+                    kind,
+                });
+            }
+        }
+    }
+
+    /// Take all dirty marks recorded for a recording since the last call.
+    #[cfg(feature = "serialize")]
+    pub(crate) fn take_recorded(&self, id: usize) -> Vec<DirtyPatch> {
+        self.inner
+            .write_unchecked()
+            .recordings
+            .iter_mut()
+            .find(|recording| recording.id == id)
+            .map(|recording| std::mem::take(&mut recording.marks))
+            .unwrap_or_default()
+    }
+
+    /// Run `f` without recording any of its marks in a recording. Other recordings still see the marks.
+    #[cfg(feature = "serialize")]
+    pub(crate) fn without_recording<R>(&self, id: usize, f: impl FnOnce() -> R) -> R {
+        self.set_paused(id, true);
+        let result = f();
+        self.set_paused(id, false);
+        result
+    }
+
+    #[cfg(feature = "serialize")]
+    fn set_paused(&self, id: usize, paused: bool) {
+        let mut write = self.inner.write_unchecked();
+        if let Some(recording) = write
+            .recordings
+            .iter_mut()
+            .find(|recording| recording.id == id)
+        {
+            recording.paused = paused;
+        }
+    }
+
     /// Get the shallow subscribers for a specific path in the store.
     pub(crate) fn shallow_subscribers(&self, key: &[PathKey]) -> Subscribers {
         Arc::new(StoreSubscribers {
@@ -0,0 +1,175 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use std::{
+    cell::RefCell,
+    collections::{BTreeSet, HashSet, VecDeque},
+    rc::Rc,
+};
+
+#[derive(Store, Default)]
+struct Collections {
+    hash_set: HashSet<String>,
+    btree_set: BTreeSet<i32>,
+    deque: VecDeque<i32>,
+}
+
+#[derive(Default, PartialEq, Debug)]
+struct RunCounter {
+    member_a: usize,
+    member_b: usize,
+    len: usize,
+}
+
+fn use_collections() -> Store<Collections> {
+    let store = use_store(Collections::default);
+    use_context_provider(|| store)
+}
+
+fn collections(dom: &VirtualDom) -> Store<Collections> {
+    dom.in_scope(ScopeId::APP, consume_context::<Store<Collections>>)
+}
+
+#[test]
+fn hash_set_membership_only_notifies_that_key() {
+    #[component]
+    fn member_reader(
+        value: Store<HashSet<String>>,
+        name: String,
+        counter: Rc<RefCell<RunCounter>>,
+    ) -> Element {
+        match name.as_str() {
+            "a" => counter.borrow_mut().member_a += 1,
+            _ => counter.borrow_mut().member_b += 1,
+        }
+        rsx! { "{value.contains(name.as_str())}" }
+    }
+
+    #[component]
+    fn len_reader(value: Store<HashSet<String>>, counter: Rc<RefCell<RunCounter>>) -> Element {
+        counter.borrow_mut().len += 1;
+        rsx! { "{value.len()}" }
+    }
+
+    let counter = Rc::new(RefCell::new(RunCounter::default()));
+    let mut dom = VirtualDom::new_with_props(
+        |counter: Rc<RefCell<RunCounter>>| {
+            let set = use_collections().hash_set();
+            rsx! {
+                member_reader { value: set, name: "a", counter: counter.clone() }
+                member_reader { value: set, name: "b", counter: counter.clone() }
+                len_reader { value: set, counter: counter.clone() }
+            }
+        },
+        counter.clone(),
+    );
+    dom.rebuild_in_place();
+
+    dom.in_scope(ScopeId::APP, || {
+        let mut set = collections(&dom).hash_set();
+        assert!(set.insert("a".to_string()));
+    });
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(
+        *counter.borrow(),
+        RunCounter {
+            member_a: 2,
+            member_b: 1,
+            len: 2
+        }
+    );
+
+    // Inserting an existing value doesn't change the set
+    dom.in_scope(ScopeId::APP, || {
+        let mut set = collections(&dom).hash_set();
+        assert!(!set.insert("a".to_string()));
+    });
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(counter.borrow().member_a, 2);
+
+    dom.in_scope(ScopeId::APP, || {
+        let mut set = collections(&dom).hash_set();
+        set.retain(|value| value != "a");
+        assert!(!set.contains("a"));
+    });
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(
+        *counter.borrow(),
+        RunCounter {
+            member_a: 3,
+            member_b: 1,
+            len: 3
+        }
+    );
+}
+
+#[test]
+fn btree_set_iterates_in_order() {
+    let mut dom = VirtualDom::new(|| {
+        use_collections();
+        VNode::empty()
+    });
+    dom.rebuild_in_place();
+
+    dom.in_scope(ScopeId::APP, || {
+        let mut set = collections(&dom).btree_set();
+        for value in [3, 1, 2] {
+            set.insert(value);
+        }
+        assert!(set.remove(&2));
+        assert!(!set.remove(&2));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![1, 3]);
+    });
+}
+
+#[test]
+fn deque_push_front_shifts_items() {
+    let mut dom = VirtualDom::new(|| {
+        use_collections();
+        VNode::empty()
+    });
+    dom.rebuild_in_place();
+
+    dom.in_scope(ScopeId::APP, || {
+        let mut deque = collections(&dom).deque();
+        deque.push_back(2);
+        deque.push_front(1);
+        deque.push_back(3);
+        assert_eq!(deque.len(), 3);
+        assert_eq!(deque.get(0).unwrap().cloned(), 1);
+        assert_eq!(deque.index(2).cloned(), 3);
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.remove(5), None);
+        assert_eq!(
+            deque.iter().map(|item| item.cloned()).collect::<Vec<_>>(),
+            vec![2]
+        );
+    });
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn index_map_preserves_order() {
+    use indexmap::IndexMap;
+
+    let mut dom = VirtualDom::new(|| {
+        let store = use_store(IndexMap::<String, i32>::new);
+        use_context_provider(|| store);
+        VNode::empty()
+    });
+    dom.rebuild_in_place();
+
+    dom.in_scope(ScopeId::APP, || {
+        let mut map = consume_context::<Store<IndexMap<String, i32>>>();
+        map.insert("b".to_string(), 2);
+        map.insert("a".to_string(), 1);
+        map.insert("c".to_string(), 3);
+        assert_eq!(map.shift_remove("a"), Some(1));
+        assert!(map.contains_key("b"));
+        assert_eq!(
+            map.iter().map(|(key, _)| key).collect::<Vec<_>>(),
+            vec!["b".to_string(), "c".to_string()]
+        );
+        assert_eq!(map.get("c".to_string()).unwrap().cloned(), 3);
+    });
+}
@@ -0,0 +1,261 @@
+#![cfg(feature = "serialize")]
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_stores::{StoreHistory, use_store_history};
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, rc::Rc};
+
+#[derive(Store, Serialize, Deserialize, std::fmt::Debug, Clone, Default, PartialEq)]
+struct Doc {
+    title: String,
+    count: i32,
+    items: Vec<i32>,
+}
+
+#[derive(Default, PartialEq, Debug)]
+struct RunCounter {
+    title: usize,
+    count: usize,
+}
+
+fn history_app(counter: Rc<RefCell<RunCounter>>) -> Element {
+    let history = use_store_history(Doc::default);
+    use_context_provider(|| history);
+    let doc = history.store();
+
+    rsx! {
+        title_reader { value: doc.title(), counter: counter.clone() }
+        count_reader { value: doc.count(), counter: counter.clone() }
+    }
+}
+
+#[component]
+fn title_reader(value: Store<String>, counter: Rc<RefCell<RunCounter>>) -> Element {
+    counter.borrow_mut().title += 1;
+    rsx! { "{value}" }
+}
+
+#[component]
+fn count_reader(value: Store<i32>, counter: Rc<RefCell<RunCounter>>) -> Element {
+    counter.borrow_mut().count += 1;
+    rsx! { "{value}" }
+}
+
+fn history(dom: &VirtualDom) -> StoreHistory<Doc> {
+    dom.in_scope(ScopeId::APP, consume_context::<StoreHistory<Doc>>)
+}
+
+#[test]
+fn undo_redo_restores_values() {
+    let counter = Rc::new(RefCell::new(RunCounter::default()));
+    let mut dom = VirtualDom::new_with_props(history_app, counter);
+    dom.rebuild_in_place();
+    let history = history(&dom);
+
+    dom.in_scope(ScopeId::APP, || {
+        history.transaction(|doc| {
+            doc.title().set("first".to_string());
+            doc.items().push(1);
+        });
+        history.store().count().set(2);
+        history.checkpoint();
+
+        assert!(history.undo());
+        assert_eq!(history.store().count().cloned(), 0);
+        assert_eq!(history.store().title().cloned(), "first");
+
+        assert!(history.undo());
+        assert_eq!(history.store().peek().clone(), Doc::default());
+        assert!(!history.undo());
+
+        assert!(history.redo());
+        assert!(history.redo());
+        assert_eq!(
+            history.store().peek().clone(),
+            Doc {
+                title: "first".to_string(),
+                count: 2,
+                items: vec![1],
+            }
+        );
+        assert!(!history.redo());
+    });
+}
+
+#[test]
+fn new_writes_clear_redo() {
+    let counter = Rc::new(RefCell::new(RunCounter::default()));
+    let mut dom = VirtualDom::new_with_props(history_app, counter);
+    dom.rebuild_in_place();
+    let history = history(&dom);
+
+    dom.in_scope(ScopeId::APP, || {
+        history.store().count().set(1);
+        assert!(history.undo());
+        assert!(history.can_redo());
+
+        history.store().count().set(5);
+        assert!(!history.redo());
+        assert_eq!(history.store().count().cloned(), 5);
+    });
+}
+
+#[test]
+fn history_limit_drops_oldest() {
+    let counter = Rc::new(RefCell::new(RunCounter::default()));
+    let mut dom = VirtualDom::new_with_props(history_app, counter);
+    dom.rebuild_in_place();
+    let history = history(&dom);
+
+    dom.in_scope(ScopeId::APP, || {
+        history.set_limit(Some(2));
+        for i in 1..=3 {
+            history.store().count().set(i);
+            history.checkpoint();
+        }
+
+        assert!(history.undo());
+        assert!(history.undo());
+        assert!(!history.undo());
+        assert_eq!(history.store().count().cloned(), 1);
+    });
+}
+
+#[test]
+fn undo_only_reruns_changed_paths() {
+    let counter = Rc::new(RefCell::new(RunCounter::default()));
+    let mut dom = VirtualDom::new_with_props(history_app, counter.clone());
+    dom.rebuild_in_place();
+    let history = history(&dom);
+
+    dom.in_scope(ScopeId::APP, || {
+        history.store().count().set(1);
+        history.checkpoint();
+    });
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(*counter.borrow(), RunCounter { title: 1, count: 2 });
+
+    dom.in_scope(ScopeId::APP, || assert!(history.undo()));
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(*counter.borrow(), RunCounter { title: 1, count: 3 });
+}
+
+thread_local! {
+    static SERIALIZED: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// A value that counts how many times it was serialized
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+struct Counted(u32);
+
+impl Serialize for Counted {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SERIALIZED.with(|count| count.set(count.get() + 1));
+        self.0.serialize(serializer)
+    }
+}
+
+#[test]
+fn transactions_only_record_the_written_values() {
+    let mut dom = VirtualDom::new(|| rsx! {});
+    dom.rebuild_in_place();
+
+    dom.in_scope(ScopeId::APP, || {
+        let history = StoreHistory::new((0..100).map(Counted).collect::<Vec<_>>());
+
+        SERIALIZED.with(|count| count.set(0));
+        history.transaction(|items| {
+            items.index(5).set(Counted(500));
+            items.index(6).set(Counted(600));
+        });
+        history.store().push(Counted(100));
+        history.checkpoint();
+        assert_eq!(SERIALIZED.with(|count| count.get()), 3);
+
+        assert!(history.undo());
+        assert_eq!(history.store().len(), 100);
+        assert!(history.undo());
+        assert_eq!(history.store().index(5).cloned(), Counted(5));
+        assert!(history.redo());
+        assert_eq!(history.store().index(6).cloned(), Counted(600));
+
+        history.store().remove(0);
+        history.store().retain(|item| item.0 % 2 == 0);
+        history.checkpoint();
+        assert!(history.undo());
+        assert_eq!(
+            history.store().cloned(),
+            (0..100)
+                .map(|i| Counted(if i == 5 {
+                    500
+                } else if i == 6 {
+                    600
+                } else {
+                    i
+                }))
+                .collect::<Vec<_>>()
+        );
+    });
+}
+
+#[test]
+fn panicking_transactions_keep_recording() {
+    let counter = Rc::new(RefCell::new(RunCounter::default()));
+    let mut dom = VirtualDom::new_with_props(history_app, counter);
+    dom.rebuild_in_place();
+    let history = history(&dom);
+
+    dom.in_scope(ScopeId::APP, || {
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            history.transaction(|doc| {
+                doc.count().set(1);
+                panic!("the transaction failed");
+            })
+        }));
+        assert!(panicked.is_err());
+
+        // The writes made before the panic are committed with the next checkpoint
+        history.store().title().set("after".to_string());
+        assert!(history.checkpoint());
+        assert!(history.undo());
+        assert_eq!(history.store().peek().clone(), Doc::default());
+    });
+}
+
+/// A number that refuses to deserialize from values over 100
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+struct Limited(u32);
+
+impl<'de> Deserialize<'de> for Limited {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = u32::deserialize(deserializer)?;
+        if value > 100 {
+            return Err(serde::de::Error::custom("the value is over 100"));
+        }
+        Ok(Self(value))
+    }
+}
+
+#[test]
+fn failed_patches_stay_in_the_history() {
+    let mut dom = VirtualDom::new(|| rsx! {});
+    dom.rebuild_in_place();
+
+    dom.in_scope(ScopeId::APP, || {
+        let history = StoreHistory::new(Limited(0));
+
+        // Values can be written directly even if they can't be read back from a patch
+        history.store().set(Limited(500));
+        history.checkpoint();
+        assert!(history.undo());
+        assert_eq!(history.store().cloned(), Limited(0));
+
+        // Redoing the write fails, but the transaction stays on the redo stack
+        assert!(!history.redo());
+        assert_eq!(history.store().cloned(), Limited(0));
+        assert!(history.can_redo());
+        assert!(!history.can_undo());
+        assert!(!history.redo());
+    });
+}
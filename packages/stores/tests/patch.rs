@@ -0,0 +1,535 @@
+#![cfg(feature = "serialize")]
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_stores::{PatchError, PatchOperation, PatchRecorder, StorePatch};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::{
+    cell::RefCell,
+    collections::{BTreeSet, HashMap, HashSet},
+    rc::Rc,
+};
+
+#[derive(Store, Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+struct Board {
+    title: String,
+    count: i32,
+    items: Vec<i32>,
+    scores: HashMap<String, i32>,
+    tags: HashSet<String>,
+}
+
+#[derive(Default, PartialEq, Debug)]
+struct RunCounter {
+    title: usize,
+    count: usize,
+    len: usize,
+    score: usize,
+    tag: usize,
+}
+
+fn board_app(counter: Rc<RefCell<RunCounter>>) -> Element {
+    let board = use_store(Board::default);
+    use_context_provider(|| board);
+
+    rsx! {
+        title_reader { value: board.title(), counter: counter.clone() }
+        count_reader { value: board.count(), counter: counter.clone() }
+        len_reader { value: board.items(), counter: counter.clone() }
+        score_reader { value: board.scores(), counter: counter.clone() }
+        tag_reader { value: board.tags(), counter: counter.clone() }
+    }
+}
+
+#[component]
+fn title_reader(value: Store<String>, counter: Rc<RefCell<RunCounter>>) -> Element {
+    counter.borrow_mut().title += 1;
+    rsx! { "{value}" }
+}
+
+#[component]
+fn count_reader(value: Store<i32>, counter: Rc<RefCell<RunCounter>>) -> Element {
+    counter.borrow_mut().count += 1;
+    rsx! { "{value}" }
+}
+
+#[component]
+fn len_reader(value: Store<Vec<i32>>, counter: Rc<RefCell<RunCounter>>) -> Element {
+    counter.borrow_mut().len += 1;
+    rsx! { "{value.len()}" }
+}
+
+#[component]
+fn score_reader(value: Store<HashMap<String, i32>>, counter: Rc<RefCell<RunCounter>>) -> Element {
+    counter.borrow_mut().score += 1;
+    let score = value.get("alice".to_string()).map(|score| score.cloned());
+    rsx! { "{score:?}" }
+}
+
+#[component]
+fn tag_reader(value: Store<HashSet<String>>, counter: Rc<RefCell<RunCounter>>) -> Element {
+    counter.borrow_mut().tag += 1;
+    rsx! { "{value.contains(\"urgent\")}" }
+}
+
+fn board(dom: &VirtualDom) -> Store<Board> {
+    dom.in_scope(ScopeId::APP, consume_context::<Store<Board>>)
+}
+
+fn apply(dom: &mut VirtualDom, patch: StorePatch) {
+    dom.in_scope(ScopeId::APP, || board(dom).apply_patch(&patch).unwrap());
+    dom.render_immediate(&mut NoOpMutations);
+}
+
+#[test]
+fn diff_produces_minimal_operations() {
+    let old = json!({ "title": "a", "items": [1, 2, 3], "scores": { "a/b": 1 } });
+    let new = json!({ "title": "b", "items": [1, 2], "scores": { "a/b": 1, "c": 2 } });
+    let patch = StorePatch::diff(&old, &new);
+    assert_eq!(
+        patch.operations(),
+        [
+            PatchOperation::Remove {
+                path: "/items/2".to_string(),
+            },
+            PatchOperation::Add {
+                path: "/scores/c".to_string(),
+                value: json!(2),
+            },
+            PatchOperation::Replace {
+                path: "/title".to_string(),
+                value: json!("b"),
+            },
+        ]
+    );
+
+    let mut patched = old.clone();
+    patch.apply_to(&mut patched).unwrap();
+    assert_eq!(patched, new);
+    assert!(StorePatch::diff(&new, &new).is_empty());
+}
+
+#[test]
+fn patches_round_trip_through_json() {
+    let patch = StorePatch::from(vec![
+        PatchOperation::Add {
+            path: "/items/-".to_string(),
+            value: json!(4),
+        },
+        PatchOperation::Remove {
+            path: "/title".to_string(),
+        },
+    ]);
+    let serialized = serde_json::to_value(&patch).unwrap();
+    assert_eq!(
+        serialized,
+        json!([
+            { "op": "add", "path": "/items/-", "value": 4 },
+            { "op": "remove", "path": "/title" },
+        ])
+    );
+    assert_eq!(
+        serde_json::from_value::<StorePatch>(serialized).unwrap(),
+        patch
+    );
+}
+
+#[test]
+fn applying_patches_only_reruns_affected_readers() {
+    let counter = Rc::new(RefCell::new(RunCounter::default()));
+    let mut dom = VirtualDom::new_with_props(board_app, counter.clone());
+    dom.rebuild_in_place();
+
+    let expected = RunCounter {
+        title: 1,
+        count: 2,
+        len: 1,
+        score: 1,
+        tag: 1,
+    };
+    apply(
+        &mut dom,
+        StorePatch::from(vec![PatchOperation::Replace {
+            path: "/count".to_string(),
+            value: json!(5),
+        }]),
+    );
+    assert_eq!(*counter.borrow(), expected);
+
+    apply(
+        &mut dom,
+        StorePatch::from(vec![PatchOperation::Add {
+            path: "/items/-".to_string(),
+            value: json!(1),
+        }]),
+    );
+    assert_eq!(counter.borrow().len, 2);
+
+    apply(
+        &mut dom,
+        StorePatch::from(vec![PatchOperation::Add {
+            path: "/scores/bob".to_string(),
+            value: json!(1),
+        }]),
+    );
+    // Adding a key marks the map shallowly dirty, so readers of other keys rerun only
+    // through the shape of the map
+    let score_runs = counter.borrow().score;
+
+    apply(
+        &mut dom,
+        StorePatch::from(vec![PatchOperation::Replace {
+            path: "/scores/bob".to_string(),
+            value: json!(2),
+        }]),
+    );
+    assert_eq!(counter.borrow().score, score_runs);
+
+    apply(
+        &mut dom,
+        StorePatch::from(vec![PatchOperation::Add {
+            path: "/scores/alice".to_string(),
+            value: json!(3),
+        }]),
+    );
+    assert_eq!(counter.borrow().score, score_runs + 1);
+
+    apply(
+        &mut dom,
+        StorePatch::from(vec![PatchOperation::Add {
+            path: "/tags/-".to_string(),
+            value: json!("urgent"),
+        }]),
+    );
+    assert_eq!(counter.borrow().tag, 2);
+    assert_eq!(counter.borrow().title, 1);
+
+    dom.in_scope(ScopeId::APP, || {
+        let board = board(&dom);
+        assert_eq!(board.count().cloned(), 5);
+        assert_eq!(board.items().cloned(), vec![1]);
+        assert!(board.tags().contains("urgent"));
+    });
+}
+
+#[test]
+fn failed_patches_leave_the_store_unchanged() {
+    let counter = Rc::new(RefCell::new(RunCounter::default()));
+    let mut dom = VirtualDom::new_with_props(board_app, counter);
+    dom.rebuild_in_place();
+
+    dom.in_scope(ScopeId::APP, || {
+        let mut board = board(&dom);
+        let patch = StorePatch::from(vec![
+            PatchOperation::Replace {
+                path: "/count".to_string(),
+                value: json!(1),
+            },
+            PatchOperation::Remove {
+                path: "/items/3".to_string(),
+            },
+        ]);
+        assert!(matches!(
+            board.apply_patch(&patch),
+            Err(PatchError::PathNotFound(_))
+        ));
+
+        let patch = StorePatch::from(vec![PatchOperation::Replace {
+            path: "/count".to_string(),
+            value: json!("not a number"),
+        }]);
+        assert!(matches!(
+            board.apply_patch(&patch),
+            Err(PatchError::Serde(_))
+        ));
+        assert_eq!(board.count().cloned(), 0);
+    });
+}
+
+#[test]
+fn snapshots_sync_two_stores() {
+    let counter = Rc::new(RefCell::new(RunCounter::default()));
+    let mut dom = VirtualDom::new_with_props(board_app, counter.clone());
+    dom.rebuild_in_place();
+
+    dom.in_scope(ScopeId::APP, || {
+        let source = Store::new(Board::default());
+        let before = source.snapshot().unwrap();
+        source.title().set("Sprint".to_string());
+        source.items().push(3);
+        let patch = StorePatch::diff(&before, &source.snapshot().unwrap());
+        board(&dom).apply_patch(&patch).unwrap();
+        assert_eq!(board(&dom).cloned(), source.cloned());
+
+        source.items().clear();
+        board(&dom)
+            .apply_snapshot(source.snapshot().unwrap())
+            .unwrap();
+        assert!(board(&dom).items().is_empty());
+    });
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(counter.borrow().count, 1);
+    assert_eq!(counter.borrow().title, 2);
+}
+
+mod named_result {
+    use super::*;
+
+    /// A user enum that shares its name with `std::result::Result`
+    #[derive(Store, Serialize, Deserialize, Clone, Debug, PartialEq)]
+    pub enum Result {
+        Passed(i32),
+        Failed(String),
+    }
+
+    fn app(runs: Rc<RefCell<usize>>) -> Element {
+        let result = use_store(|| Result::Failed("timeout".to_string()));
+        use_context_provider(|| result);
+        rsx! {
+            reason_reader { value: result.failed().unwrap(), runs }
+        }
+    }
+
+    #[component]
+    fn reason_reader(value: Store<String>, runs: Rc<RefCell<usize>>) -> Element {
+        *runs.borrow_mut() += 1;
+        rsx! { "{value}" }
+    }
+
+    #[test]
+    fn patches_into_enums_named_result_rerun_the_variant_readers() {
+        let runs = Rc::new(RefCell::new(0));
+        let mut dom = VirtualDom::new_with_props(app, runs.clone());
+        dom.rebuild_in_place();
+
+        let patch = StorePatch::from(vec![PatchOperation::Replace {
+            path: "/Failed".to_string(),
+            value: json!("crashed"),
+        }]);
+        dom.in_scope(ScopeId::APP, || {
+            consume_context::<Store<Result>>()
+                .apply_patch(&patch)
+                .unwrap()
+        });
+        dom.render_immediate(&mut NoOpMutations);
+        assert_eq!(*runs.borrow(), 2);
+    }
+}
+
+#[derive(Store, Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+struct Inventory {
+    owner: Option<String>,
+    boxes: Vec<Crate>,
+    counts: HashMap<String, u32>,
+    labels: BTreeSet<String>,
+}
+
+#[derive(Store, Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+struct Crate {
+    name: String,
+    weight: u32,
+}
+
+fn in_runtime(f: impl FnOnce()) {
+    let mut dom = VirtualDom::new(|| rsx! {});
+    dom.rebuild_in_place();
+    dom.in_scope(ScopeId::APP, f);
+}
+
+#[test]
+fn recorded_writes_only_patch_the_written_paths() {
+    in_runtime(|| {
+        let inventory = Store::new(Inventory {
+            boxes: vec![
+                Crate {
+                    name: "a".to_string(),
+                    weight: 1,
+                },
+                Crate {
+                    name: "b".to_string(),
+                    weight: 2,
+                },
+            ],
+            ..Default::default()
+        });
+        let mut recorder = PatchRecorder::new(inventory).unwrap();
+
+        inventory.boxes().index(1).weight().set(5);
+        assert_eq!(
+            recorder.take_patch().unwrap().operations(),
+            [PatchOperation::Replace {
+                path: "/boxes/1/weight".to_string(),
+                value: json!(5),
+            }]
+        );
+
+        inventory.boxes().push(Crate::default());
+        assert_eq!(
+            recorder.take_patch().unwrap().operations(),
+            [PatchOperation::Add {
+                path: "/boxes/2".to_string(),
+                value: json!({ "name": "", "weight": 0 }),
+            }]
+        );
+
+        inventory.counts().insert("nails".to_string(), 3);
+        inventory.owner().set(Some("Ada".to_string()));
+        assert_eq!(
+            recorder.take_patch().unwrap().operations(),
+            [
+                PatchOperation::Add {
+                    path: "/counts/nails".to_string(),
+                    value: json!(3),
+                },
+                PatchOperation::Replace {
+                    path: "/owner".to_string(),
+                    value: json!("Ada"),
+                },
+            ]
+        );
+
+        // Writes that don't change the serialized value don't produce operations
+        inventory.boxes().index(0).name().set("a".to_string());
+        assert!(recorder.take_patch().unwrap().is_empty());
+    });
+}
+
+#[test]
+fn recorded_patches_keep_a_copy_of_the_store_in_sync() {
+    in_runtime(|| {
+        let source = Store::new(Inventory::default());
+        let mut copy = Store::new(Inventory::default());
+        let mut recorder = PatchRecorder::new(source).unwrap();
+        let mut sync = || {
+            copy.apply_patch(&recorder.take_patch().unwrap()).unwrap();
+            assert_eq!(copy.cloned(), source.cloned());
+            assert_eq!(*recorder.snapshot(), source.snapshot().unwrap());
+        };
+
+        for name in ["a", "b", "c", "d"] {
+            source.boxes().push(Crate {
+                name: name.to_string(),
+                weight: 1,
+            });
+        }
+        sync();
+
+        source.boxes().remove(1);
+        source.boxes().index(2).weight().set(7);
+        sync();
+
+        source.boxes().insert(0, Crate::default());
+        source.boxes().retain(|item| item.weight == 1);
+        sync();
+
+        for label in ["x", "y", "z"] {
+            source.labels().insert(label.to_string());
+        }
+        source.counts().insert("x".to_string(), 1);
+        sync();
+
+        source.labels().remove("y");
+        source.counts().remove("x");
+        source.owner().set(Some("Grace".to_string()));
+        sync();
+
+        source.boxes().clear();
+        source.labels().clear();
+        sync();
+    });
+}
+
+#[test]
+fn recorders_only_patch_the_store_they_were_created_from() {
+    in_runtime(|| {
+        let mut inventory = Store::new(Inventory {
+            boxes: vec![Crate::default()],
+            ..Default::default()
+        });
+        let mut recorder = PatchRecorder::new(inventory.boxes().index(0)).unwrap();
+
+        inventory.owner().set(Some("Ada".to_string()));
+        inventory.boxes().index(0).name().set("tools".to_string());
+        assert_eq!(
+            recorder.take_patch().unwrap().operations(),
+            [PatchOperation::Replace {
+                path: "/name".to_string(),
+                value: json!("tools"),
+            }]
+        );
+
+        // Replacing the whole inventory replaces the crate
+        inventory.set(Inventory {
+            boxes: vec![Crate {
+                name: "food".to_string(),
+                weight: 2,
+            }],
+            ..Default::default()
+        });
+        assert_eq!(
+            *recorder.snapshot(),
+            json!({ "name": "tools", "weight": 0 })
+        );
+        recorder.take_patch().unwrap();
+        assert_eq!(*recorder.snapshot(), json!({ "name": "food", "weight": 2 }));
+    });
+}
+
+#[test]
+fn patches_applied_through_a_recorder_are_not_recorded() {
+    in_runtime(|| {
+        let inventory = Store::new(Inventory::default());
+        let mut recorder = PatchRecorder::new(inventory).unwrap();
+        let mut other = PatchRecorder::new(inventory).unwrap();
+
+        inventory.owner().set(Some("Ada".to_string()));
+        recorder
+            .apply_patch(&StorePatch::from(vec![PatchOperation::Add {
+                path: "/labels/-".to_string(),
+                value: json!("fragile"),
+            }]))
+            .unwrap();
+
+        // The local write is still sent, but the applied patch isn't echoed back
+        assert_eq!(
+            recorder.take_patch().unwrap().operations(),
+            [PatchOperation::Replace {
+                path: "/owner".to_string(),
+                value: json!("Ada"),
+            }]
+        );
+        // Other recorders see both changes
+        assert_eq!(other.take_patch().unwrap().operations().len(), 2);
+    });
+}
+
+thread_local! {
+    static SERIALIZED: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// A value that counts how many times it was serialized
+#[derive(Deserialize, Clone, Debug, Default, PartialEq)]
+struct Counted(u32);
+
+impl Serialize for Counted {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SERIALIZED.with(|count| count.set(count.get() + 1));
+        self.0.serialize(serializer)
+    }
+}
+
+#[test]
+fn recording_a_write_only_serializes_the_written_values() {
+    in_runtime(|| {
+        let mut items = Store::new((0..100).map(Counted).collect::<Vec<_>>());
+        let mut recorder = PatchRecorder::new(items).unwrap();
+
+        SERIALIZED.with(|count| count.set(0));
+        items.push(Counted(100));
+        items.index(3).set(Counted(0));
+        let patch = recorder.take_patch().unwrap();
+        assert_eq!(patch.operations().len(), 2);
+        assert_eq!(SERIALIZED.with(|count| count.get()), 2);
+    });
+}
@@ -0,0 +1,331 @@
+use crate::{NodeId, NodeRef, TestDom};
+use dioxus_core::{ComponentFunction, Element, ElementId, Event, VirtualDom};
+use dioxus_html::{
+    Code, Key, Location, Modifiers, PlatformEventData, SerializedFocusData, SerializedFormData,
+    SerializedFormObject, SerializedHtmlEventConverter, SerializedKeyboardData,
+    SerializedMouseData, set_event_converter,
+};
+use std::{
+    any::Any,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+/// How long [`TestApp::settle`] waits for new work before it considers the app idle
+const IDLE_TIMEOUT: Duration = Duration::from_millis(10);
+
+/// A headless app for testing components.
+///
+/// The app renders into an in-memory [`TestDom`] that can be searched with Testing Library style queries. Events
+/// are sent through the [`VirtualDom`] with the serialized event types from `dioxus-html`, and the app rerenders
+/// immediately after each event.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_testing::TestApp;
+///
+/// fn app() -> Element {
+///     let mut count = use_signal(|| 0);
+///     rsx! {
+///         button { onclick: move |_| count += 1, "Increment" }
+///         p { "data-testid": "count", "{count}" }
+///     }
+/// }
+///
+/// let mut app = TestApp::new(app);
+/// let button = app.dom().get_by_role("button").id();
+/// app.click(button);
+/// assert_eq!(app.dom().get_by_test_id("count").text_content(), "1");
+/// ```
+pub struct TestApp {
+    vdom: VirtualDom,
+    dom: TestDom,
+    timeout: Duration,
+}
+
+impl TestApp {
+    /// Create a new test app from a root component and render it.
+    pub fn new(app: fn() -> Element) -> Self {
+        Self::from_vdom(VirtualDom::new(app))
+    }
+
+    /// Create a new test app from a root component with props and render it.
+    pub fn new_with_props<P: Clone + 'static, M: 'static>(
+        app: impl ComponentFunction<P, M>,
+        props: P,
+    ) -> Self {
+        Self::from_vdom(VirtualDom::new_with_props(app, props))
+    }
+
+    /// Create a new test app from a virtual dom that hasn't been rendered yet. This can be used to provide
+    /// root contexts to the app.
+    pub fn from_vdom(mut vdom: VirtualDom) -> Self {
+        set_event_converter(Box::new(SerializedHtmlEventConverter));
+        let mut dom = TestDom::new();
+        vdom.rebuild(&mut dom);
+        let mut app = Self {
+            vdom,
+            dom,
+            timeout: Duration::from_secs(5),
+        };
+        app.send_mounted_events();
+        app
+    }
+
+    /// Set how long [`TestApp::settle`] and [`TestApp::wait_for`] wait before they panic. Defaults to 5 seconds.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Get the rendered dom.
+    pub fn dom(&self) -> &TestDom {
+        &self.dom
+    }
+
+    /// Get the virtual dom that drives the app.
+    pub fn vdom(&self) -> &VirtualDom {
+        &self.vdom
+    }
+
+    /// Get the virtual dom that drives the app mutably.
+    pub fn vdom_mut(&mut self) -> &mut VirtualDom {
+        &mut self.vdom
+    }
+
+    /// Run any work that is ready and apply the changes to the dom.
+    pub fn render(&mut self) {
+        self.vdom.render_immediate(&mut self.dom);
+        self.send_mounted_events();
+    }
+
+    /// Wait until the app is idle: all suspended components resolved, and no tasks woke up for a short time.
+    ///
+    /// This requires a tokio runtime with time enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if suspended components are still waiting after the timeout.
+    pub async fn settle(&mut self) {
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            self.render();
+            let suspended = self.vdom.suspended_tasks_remaining();
+            let wait = match suspended {
+                true => deadline.saturating_duration_since(Instant::now()),
+                false => IDLE_TIMEOUT,
+            };
+            if tokio::time::timeout(wait, self.vdom.wait_for_work())
+                .await
+                .is_err()
+            {
+                if suspended {
+                    panic!(
+                        "timed out waiting for suspense to resolve\n\n{}",
+                        self.dom.to_html()
+                    );
+                }
+                return;
+            }
+        }
+    }
+
+    /// Keep rendering the app as work becomes ready until the condition is true.
+    ///
+    /// This requires a tokio runtime with time enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the condition is still false after the timeout.
+    pub async fn wait_for(&mut self, mut condition: impl FnMut(&TestDom) -> bool) {
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            self.render();
+            if condition(&self.dom) {
+                return;
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if tokio::time::timeout(remaining, self.vdom.wait_for_work())
+                .await
+                .is_err()
+            {
+                panic!(
+                    "timed out waiting for the condition\n\n{}",
+                    self.dom.to_html()
+                );
+            }
+        }
+    }
+
+    /// Send an event to a node and rerender the app.
+    ///
+    /// The data must be the serialized event type the listener expects, like [`SerializedMouseData`] for `click`.
+    /// If the node wasn't assigned an element id, the event is sent to the closest ancestor that was, which is
+    /// where the event would bubble to in the browser. Whether the event bubbles is decided by the event name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node isn't connected to the dom.
+    pub fn fire_event<T: 'static>(&mut self, node: NodeId, name: &str, data: T) {
+        let target = self.event_target(node);
+        let data = Rc::new(PlatformEventData::new(Box::new(data))) as Rc<dyn Any>;
+        let event = Event::new(data, dioxus_core_types::event_bubbles(name));
+        self.vdom.runtime().handle_event(name, event, target);
+        self.render();
+    }
+
+    /// Click a node.
+    pub fn click(&mut self, node: NodeId) {
+        self.fire_event(node, "click", SerializedMouseData::default());
+    }
+
+    /// Double click a node.
+    pub fn double_click(&mut self, node: NodeId) {
+        self.fire_event(node, "dblclick", SerializedMouseData::default());
+    }
+
+    /// Set the value of a form control and send an `input` event.
+    pub fn input(&mut self, node: NodeId, value: &str) {
+        self.dom
+            .set_attribute_text(node, "value", Some(value.to_string()));
+        let data = SerializedFormData::new(value.to_string(), Vec::new());
+        self.fire_event(node, "input", data);
+    }
+
+    /// Set the value of a form control and send a `change` event.
+    pub fn change(&mut self, node: NodeId, value: &str) {
+        self.dom
+            .set_attribute_text(node, "value", Some(value.to_string()));
+        let data = SerializedFormData::new(value.to_string(), Vec::new());
+        self.fire_event(node, "change", data);
+    }
+
+    /// Toggle a checkbox or select a radio button and send `input` and `change` events.
+    pub fn check(&mut self, node: NodeId, checked: bool) {
+        self.dom
+            .set_attribute_text(node, "checked", checked.then(|| "true".to_string()));
+        let value = self.dom.node(node).attribute("value").unwrap_or("on");
+        let value = if checked { value } else { "" }.to_string();
+        self.fire_event(
+            node,
+            "input",
+            SerializedFormData::new(value.clone(), Vec::new()),
+        );
+        self.fire_event(node, "change", SerializedFormData::new(value, Vec::new()));
+    }
+
+    /// Submit a form with the values of the named controls inside it.
+    pub fn submit(&mut self, form: NodeId) {
+        let values = form_values(self.dom.node(form));
+        self.fire_event(
+            form,
+            "submit",
+            SerializedFormData::new(String::new(), values),
+        );
+    }
+
+    /// Press a key down on a node.
+    pub fn key_down(&mut self, node: NodeId, key: Key) {
+        self.fire_event(node, "keydown", keyboard_data(key));
+    }
+
+    /// Release a key on a node.
+    pub fn key_up(&mut self, node: NodeId, key: Key) {
+        self.fire_event(node, "keyup", keyboard_data(key));
+    }
+
+    /// Focus a node.
+    pub fn focus(&mut self, node: NodeId) {
+        self.fire_event(node, "focus", SerializedFocusData::default());
+    }
+
+    /// Remove focus from a node.
+    pub fn blur(&mut self, node: NodeId) {
+        self.fire_event(node, "blur", SerializedFocusData::default());
+    }
+
+    fn event_target(&self, node: NodeId) -> ElementId {
+        let node = self.dom.node(node);
+        if !node.is_connected() {
+            panic!("can't send an event to a node that isn't connected to the dom: {node:?}");
+        }
+        std::iter::once(node)
+            .chain(node.ancestors())
+            .find_map(|node| node.element_id())
+            .unwrap_or(ElementId(0))
+    }
+
+    fn send_mounted_events(&mut self) {
+        while !self.dom.mounted.is_empty() {
+            for id in std::mem::take(&mut self.dom.mounted) {
+                let data = Rc::new(PlatformEventData::new(Box::new(()))) as Rc<dyn Any>;
+                self.vdom
+                    .runtime()
+                    .handle_event("mounted", Event::new(data, false), id);
+            }
+            self.vdom.render_immediate(&mut self.dom);
+        }
+    }
+}
+
+fn keyboard_data(key: Key) -> SerializedKeyboardData {
+    SerializedKeyboardData::new(
+        key,
+        Code::Unidentified,
+        Location::Standard,
+        false,
+        Modifiers::empty(),
+        false,
+    )
+}
+
+/// Collect the values a form would submit
+fn form_values(form: NodeRef) -> Vec<SerializedFormObject> {
+    form.descendants()
+        .filter(|node| matches!(node.tag(), Some("input" | "select" | "textarea")))
+        .filter(|node| node.attribute("disabled").is_none())
+        .filter(|node| match node.attribute("type") {
+            Some("checkbox" | "radio") => node.attribute("checked").is_some(),
+            Some("submit" | "button" | "reset" | "file") => false,
+            _ => true,
+        })
+        .filter_map(|node| {
+            let key = node.attribute("name")?.to_string();
+            let value = match node.tag() {
+                Some("input") if matches!(node.attribute("type"), Some("checkbox" | "radio")) => {
+                    node.attribute("value").unwrap_or("on").to_string()
+                }
+                Some("textarea") => node
+                    .attribute("value")
+                    .map(str::to_string)
+                    .unwrap_or_else(|| node.text_content()),
+                Some("select") => {
+                    node.attribute("value")
+                        .map(str::to_string)
+                        .unwrap_or_else(|| {
+                            node.descendants()
+                                .filter(|option| option.tag() == Some("option"))
+                                .find(|option| option.attribute("selected").is_some())
+                                .or_else(|| {
+                                    node.descendants()
+                                        .find(|option| option.tag() == Some("option"))
+                                })
+                                .map(|option| {
+                                    option
+                                        .attribute("value")
+                                        .map(str::to_string)
+                                        .unwrap_or_else(|| option.text_content())
+                                })
+                                .unwrap_or_default()
+                        })
+                }
+                _ => node.attribute("value").unwrap_or_default().to_string(),
+            };
+            Some(SerializedFormObject {
+                key,
+                text: Some(value),
+                file: None,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,586 @@
+use dioxus_core::{
+    AttributeValue, ElementId, Template, TemplateAttribute, TemplateNode, WriteMutations,
+};
+use rustc_hash::FxHashMap;
+use std::fmt::Write;
+
+/// Attributes that are removed from the element when they are set to a falsy value, like they are in the browser
+const BOOL_ATTRS: &[&str] = &[
+    "allowfullscreen",
+    "async",
+    "autofocus",
+    "autoplay",
+    "checked",
+    "controls",
+    "default",
+    "defer",
+    "disabled",
+    "formnovalidate",
+    "hidden",
+    "inert",
+    "ismap",
+    "itemscope",
+    "loop",
+    "multiple",
+    "muted",
+    "nomodule",
+    "novalidate",
+    "open",
+    "playsinline",
+    "readonly",
+    "required",
+    "reversed",
+    "selected",
+];
+
+/// The id of a node in a [`TestDom`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(pub(crate) usize);
+
+/// The kind of a node in a [`TestDom`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum NodeKind {
+    /// An element like `div` or `button`
+    Element {
+        /// The tag name of the element
+        tag: String,
+        /// The namespace of the element, if it has one
+        namespace: Option<String>,
+    },
+    /// A text node
+    Text(String),
+    /// A placeholder for a node that isn't rendered yet
+    Placeholder,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Node {
+    pub(crate) kind: NodeKind,
+    pub(crate) parent: Option<NodeId>,
+    pub(crate) children: Vec<NodeId>,
+    pub(crate) attributes: Vec<(String, String)>,
+    pub(crate) styles: Vec<(String, String)>,
+    pub(crate) inner_html: Option<String>,
+    pub(crate) listeners: Vec<String>,
+    pub(crate) element_id: Option<ElementId>,
+}
+
+impl Node {
+    fn new(kind: NodeKind) -> Self {
+        Self {
+            kind,
+            parent: None,
+            children: Vec::new(),
+            attributes: Vec::new(),
+            styles: Vec::new(),
+            inner_html: None,
+            listeners: Vec::new(),
+            element_id: None,
+        }
+    }
+}
+
+/// An in-memory DOM that applies the mutations the [`VirtualDom`](dioxus_core::VirtualDom) writes.
+///
+/// The tree can be inspected with [`NodeRef`]s and searched with the query methods like [`TestDom::get_by_text`].
+pub struct TestDom {
+    nodes: Vec<Node>,
+    element_ids: FxHashMap<ElementId, NodeId>,
+    stack: Vec<NodeId>,
+    pub(crate) mounted: Vec<ElementId>,
+}
+
+impl Default for TestDom {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestDom {
+    /// Create a new, empty dom with a root node the app is mounted to.
+    pub fn new() -> Self {
+        let mut root = Node::new(NodeKind::Element {
+            tag: "main".to_string(),
+            namespace: None,
+        });
+        root.element_id = Some(ElementId(0));
+        let mut element_ids = FxHashMap::default();
+        element_ids.insert(ElementId(0), NodeId(0));
+        Self {
+            nodes: vec![root],
+            element_ids,
+            stack: Vec::new(),
+            mounted: Vec::new(),
+        }
+    }
+
+    /// Get the root node the app is mounted to.
+    pub fn root(&self) -> NodeRef<'_> {
+        self.node(NodeId(0))
+    }
+
+    /// Get a node by id.
+    pub fn node(&self, id: NodeId) -> NodeRef<'_> {
+        NodeRef { dom: self, id }
+    }
+
+    /// Get the node that is mounted with an element id.
+    pub fn element(&self, id: ElementId) -> Option<NodeRef<'_>> {
+        self.element_ids.get(&id).map(|id| self.node(*id))
+    }
+
+    /// Render the children of the root node to html.
+    pub fn to_html(&self) -> String {
+        self.root().inner_html()
+    }
+
+    pub(crate) fn raw(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+
+    pub(crate) fn raw_mut(&mut self, id: NodeId) -> &mut Node {
+        &mut self.nodes[id.0]
+    }
+
+    /// Walk every node attached to the root in document order.
+    pub(crate) fn descendants(&self, id: NodeId) -> Vec<NodeId> {
+        let mut out = Vec::new();
+        let mut stack: Vec<_> = self.raw(id).children.iter().rev().copied().collect();
+        while let Some(id) = stack.pop() {
+            out.push(id);
+            stack.extend(self.raw(id).children.iter().rev().copied());
+        }
+        out
+    }
+
+    /// Set an attribute on a node without going through the virtual dom. This mirrors the browser updating
+    /// properties like `value` or `checked` before it dispatches an event.
+    pub(crate) fn set_attribute_text(&mut self, id: NodeId, name: &str, value: Option<String>) {
+        let attributes = &mut self.raw_mut(id).attributes;
+        let existing = attributes.iter().position(|(key, _)| key == name);
+        match (existing, value) {
+            (Some(index), Some(value)) => attributes[index].1 = value,
+            (None, Some(value)) => attributes.push((name.to_string(), value)),
+            (Some(index), None) => {
+                attributes.remove(index);
+            }
+            (None, None) => {}
+        }
+    }
+
+    fn insert(&mut self, node: Node) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(node);
+        id
+    }
+
+    fn id_of(&self, id: ElementId) -> NodeId {
+        *self
+            .element_ids
+            .get(&id)
+            .unwrap_or_else(|| panic!("element {id:?} is not mounted in the test dom"))
+    }
+
+    fn assign(&mut self, element_id: ElementId, id: NodeId) {
+        self.raw_mut(id).element_id = Some(element_id);
+        self.element_ids.insert(element_id, id);
+    }
+
+    fn create_template_node(&mut self, node: &TemplateNode) -> NodeId {
+        match node {
+            TemplateNode::Element {
+                tag,
+                namespace,
+                attrs,
+                children,
+            } => {
+                let mut element = Node::new(NodeKind::Element {
+                    tag: tag.to_string(),
+                    namespace: namespace.map(|ns| ns.to_string()),
+                });
+                for attr in attrs.iter() {
+                    if let TemplateAttribute::Static {
+                        name,
+                        value,
+                        namespace,
+                    } = attr
+                    {
+                        match *namespace {
+                            Some("style") => {
+                                element.styles.push((name.to_string(), value.to_string()))
+                            }
+                            _ if *name == "dangerous_inner_html" => {
+                                element.inner_html = Some(value.to_string())
+                            }
+                            _ => element
+                                .attributes
+                                .push((name.to_string(), value.to_string())),
+                        }
+                    }
+                }
+                let id = self.insert(element);
+                for child in children.iter() {
+                    let child = self.create_template_node(child);
+                    self.raw_mut(child).parent = Some(id);
+                    self.raw_mut(id).children.push(child);
+                }
+                id
+            }
+            TemplateNode::Text { text } => self.insert(Node::new(NodeKind::Text(text.to_string()))),
+            TemplateNode::Dynamic { .. } => self.insert(Node::new(NodeKind::Placeholder)),
+        }
+    }
+
+    fn load_path(&self, path: &[u8]) -> NodeId {
+        let mut node = *self.stack.last().expect("the mutation stack is empty");
+        for index in path {
+            node = self.raw(node).children[*index as usize];
+        }
+        node
+    }
+
+    fn pop_nodes(&mut self, m: usize) -> Vec<NodeId> {
+        self.stack.split_off(self.stack.len() - m)
+    }
+
+    fn detach(&mut self, id: NodeId) {
+        if let Some(parent) = self.raw_mut(id).parent.take() {
+            self.raw_mut(parent).children.retain(|child| *child != id);
+        }
+    }
+
+    fn insert_at(&mut self, parent: NodeId, index: usize, nodes: Vec<NodeId>) {
+        for node in &nodes {
+            self.detach(*node);
+            self.raw_mut(*node).parent = Some(parent);
+        }
+        let children = &mut self.raw_mut(parent).children;
+        let index = index.min(children.len());
+        children.splice(index..index, nodes);
+    }
+
+    fn position(&self, id: NodeId) -> (NodeId, usize) {
+        let parent = self
+            .raw(id)
+            .parent
+            .expect("can't insert next to a node without a parent");
+        let index = self
+            .raw(parent)
+            .children
+            .iter()
+            .position(|child| *child == id)
+            .unwrap();
+        (parent, index)
+    }
+
+    fn replace(&mut self, id: NodeId, nodes: Vec<NodeId>) {
+        let (parent, index) = self.position(id);
+        self.detach(id);
+        self.insert_at(parent, index, nodes);
+    }
+
+    fn forget(&mut self, id: NodeId) {
+        if let Some(element_id) = self.raw(id).element_id
+            && self.element_ids.get(&element_id) == Some(&id)
+        {
+            self.element_ids.remove(&element_id);
+        }
+    }
+}
+
+impl WriteMutations for TestDom {
+    fn append_children(&mut self, id: ElementId, m: usize) {
+        let parent = self.id_of(id);
+        let nodes = self.pop_nodes(m);
+        let len = self.raw(parent).children.len();
+        self.insert_at(parent, len, nodes);
+    }
+
+    fn assign_node_id(&mut self, path: &'static [u8], id: ElementId) {
+        let node = self.load_path(path);
+        self.assign(id, node);
+    }
+
+    fn create_placeholder(&mut self, id: ElementId) {
+        let node = self.insert(Node::new(NodeKind::Placeholder));
+        self.assign(id, node);
+        self.stack.push(node);
+    }
+
+    fn create_text_node(&mut self, value: &str, id: ElementId) {
+        let node = self.insert(Node::new(NodeKind::Text(value.to_string())));
+        self.assign(id, node);
+        self.stack.push(node);
+    }
+
+    fn load_template(&mut self, template: Template, index: usize, id: ElementId) {
+        let node = self.create_template_node(&template.roots()[index]);
+        self.assign(id, node);
+        self.stack.push(node);
+    }
+
+    fn replace_node_with(&mut self, id: ElementId, m: usize) {
+        let node = self.id_of(id);
+        let nodes = self.pop_nodes(m);
+        self.replace(node, nodes);
+        self.forget(node);
+    }
+
+    fn replace_placeholder_with_nodes(&mut self, path: &'static [u8], m: usize) {
+        let nodes = self.pop_nodes(m);
+        let placeholder = self.load_path(path);
+        self.replace(placeholder, nodes);
+    }
+
+    fn insert_nodes_after(&mut self, id: ElementId, m: usize) {
+        let nodes = self.pop_nodes(m);
+        let (parent, index) = self.position(self.id_of(id));
+        self.insert_at(parent, index + 1, nodes);
+    }
+
+    fn insert_nodes_before(&mut self, id: ElementId, m: usize) {
+        let nodes = self.pop_nodes(m);
+        let (parent, index) = self.position(self.id_of(id));
+        self.insert_at(parent, index, nodes);
+    }
+
+    fn set_attribute(
+        &mut self,
+        name: &'static str,
+        ns: Option<&'static str>,
+        value: &AttributeValue,
+        id: ElementId,
+    ) {
+        let node = self.id_of(id);
+        let text = match value {
+            AttributeValue::Text(value) => Some(value.clone()),
+            AttributeValue::Float(value) => Some(value.to_string()),
+            AttributeValue::Int(value) => Some(value.to_string()),
+            AttributeValue::Bool(value) => Some(value.to_string()),
+            AttributeValue::Listener(_) | AttributeValue::Any(_) | AttributeValue::None => None,
+        };
+        let text = text.filter(|text| {
+            !BOOL_ATTRS.contains(&name)
+                || !(text.is_empty() || text == "0" || text.eq_ignore_ascii_case("false"))
+        });
+
+        if ns == Some("style") {
+            let styles = &mut self.raw_mut(node).styles;
+            styles.retain(|(key, _)| key != name);
+            if let Some(text) = text {
+                styles.push((name.to_string(), text));
+            }
+        } else if name == "dangerous_inner_html" {
+            self.raw_mut(node).inner_html = text;
+        } else {
+            self.set_attribute_text(node, name, text);
+        }
+    }
+
+    fn set_node_text(&mut self, value: &str, id: ElementId) {
+        let node = self.id_of(id);
+        self.raw_mut(node).kind = NodeKind::Text(value.to_string());
+    }
+
+    fn create_event_listener(&mut self, name: &'static str, id: ElementId) {
+        let node = self.id_of(id);
+        self.raw_mut(node).listeners.push(name.to_string());
+        // Mounted events are sent as soon as the listener is created, like they are in the browser
+        if name == "mounted" {
+            self.mounted.push(id);
+        }
+    }
+
+    fn remove_event_listener(&mut self, name: &'static str, id: ElementId) {
+        let node = self.id_of(id);
+        let listeners = &mut self.raw_mut(node).listeners;
+        if let Some(index) = listeners.iter().position(|listener| listener == name) {
+            listeners.remove(index);
+        }
+    }
+
+    fn remove_node(&mut self, id: ElementId) {
+        let node = self.id_of(id);
+        self.detach(node);
+        self.forget(node);
+    }
+
+    fn push_root(&mut self, id: ElementId) {
+        let node = self.id_of(id);
+        self.stack.push(node);
+    }
+}
+
+/// A reference to a node in a [`TestDom`].
+#[derive(Clone, Copy)]
+pub struct NodeRef<'a> {
+    dom: &'a TestDom,
+    id: NodeId,
+}
+
+impl<'a> NodeRef<'a> {
+    /// Get the id of this node. The id stays valid after the dom is updated as long as the node is not removed.
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    /// Get the kind of this node.
+    pub fn kind(&self) -> &'a NodeKind {
+        &self.node().kind
+    }
+
+    /// Get the tag name of this node if it is an element.
+    pub fn tag(&self) -> Option<&'a str> {
+        match &self.node().kind {
+            NodeKind::Element { tag, .. } => Some(tag),
+            _ => None,
+        }
+    }
+
+    /// Get the value of an attribute on this node.
+    pub fn attribute(&self, name: &str) -> Option<&'a str> {
+        self.node()
+            .attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Get all attributes on this node in the order they were set.
+    pub fn attributes(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        self.node()
+            .attributes
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Get the value of an inline style on this node.
+    pub fn style(&self, name: &str) -> Option<&'a str> {
+        self.node()
+            .styles
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Check if the node has a listener for an event like `click`.
+    pub fn has_listener(&self, event: &str) -> bool {
+        self.node()
+            .listeners
+            .iter()
+            .any(|listener| listener == event)
+    }
+
+    /// Get the element id the virtual dom assigned to this node, if it has one.
+    pub fn element_id(&self) -> Option<ElementId> {
+        self.node().element_id
+    }
+
+    /// Get the parent of this node.
+    pub fn parent(&self) -> Option<NodeRef<'a>> {
+        self.node().parent.map(|id| self.dom.node(id))
+    }
+
+    /// Get the children of this node.
+    pub fn children(&self) -> impl Iterator<Item = NodeRef<'a>> {
+        let dom = self.dom;
+        self.node().children.iter().map(move |id| dom.node(*id))
+    }
+
+    /// Get the ancestors of this node, starting with the parent.
+    pub fn ancestors(&self) -> impl Iterator<Item = NodeRef<'a>> {
+        std::iter::successors(self.parent(), |node| node.parent())
+    }
+
+    /// Get all nodes inside this node in document order.
+    pub fn descendants(&self) -> impl Iterator<Item = NodeRef<'a>> {
+        let dom = self.dom;
+        dom.descendants(self.id)
+            .into_iter()
+            .map(move |id| dom.node(id))
+    }
+
+    /// Check if this node is still attached to the root of the dom.
+    pub fn is_connected(&self) -> bool {
+        self.id == NodeId(0) || self.ancestors().any(|node| node.id == NodeId(0))
+    }
+
+    /// Get the text inside this node. Text in `script` and `style` elements is skipped.
+    pub fn text_content(&self) -> String {
+        let mut out = String::new();
+        self.write_text(&mut out);
+        out
+    }
+
+    /// Render this node to html.
+    pub fn outer_html(&self) -> String {
+        let mut out = String::new();
+        self.write_html(&mut out);
+        out
+    }
+
+    /// Render the children of this node to html.
+    pub fn inner_html(&self) -> String {
+        let mut out = String::new();
+        match &self.node().inner_html {
+            Some(html) => out.push_str(html),
+            None => self.children().for_each(|child| child.write_html(&mut out)),
+        }
+        out
+    }
+
+    fn node(&self) -> &'a Node {
+        self.dom.raw(self.id)
+    }
+
+    fn write_text(&self, out: &mut String) {
+        match &self.node().kind {
+            NodeKind::Text(text) => out.push_str(text),
+            NodeKind::Element { tag, .. } if tag == "script" || tag == "style" => {}
+            NodeKind::Element { .. } => self.children().for_each(|child| child.write_text(out)),
+            NodeKind::Placeholder => {}
+        }
+    }
+
+    fn write_html(&self, out: &mut String) {
+        match &self.node().kind {
+            NodeKind::Text(text) => out.push_str(&escape(text)),
+            NodeKind::Placeholder => out.push_str("<!--placeholder-->"),
+            NodeKind::Element { tag, .. } => {
+                let _ = write!(out, "<{tag}");
+                for (name, value) in self.attributes() {
+                    let _ = write!(out, " {name}=\"{}\"", escape(value));
+                }
+                if !self.node().styles.is_empty() {
+                    out.push_str(" style=\"");
+                    for (name, value) in &self.node().styles {
+                        let _ = write!(out, "{name}:{};", escape(value));
+                    }
+                    out.push('"');
+                }
+                out.push('>');
+                out.push_str(&self.inner_html());
+                let _ = write!(out, "</{tag}>");
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for NodeRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.outer_html())
+    }
+}
+
+impl From<NodeRef<'_>> for NodeId {
+    fn from(node: NodeRef<'_>) -> Self {
+        node.id
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
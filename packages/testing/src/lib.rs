@@ -0,0 +1,12 @@
+#![doc = include_str!("../README.md")]
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![warn(missing_docs)]
+
+mod app;
+mod dom;
+mod query;
+
+pub use app::*;
+pub use dom::*;
+pub use query::*;
@@ -0,0 +1,312 @@
+use crate::{NodeKind, NodeRef, TestDom};
+
+/// A way to find nodes in a [`TestDom`].
+///
+/// Queries match the way a user finds elements on the page rather than the structure of the tree.
+///
+/// ```rust, no_run
+/// # use dioxus_testing::By;
+/// let button = By::role("button").name("Increment");
+/// let input = By::label("Username");
+/// let count = By::test_id("count");
+/// let greeting = By::text("Hello world");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct By {
+    kind: ByKind,
+    name: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum ByKind {
+    Text(String),
+    Role(String),
+    Label(String),
+    TestId(String),
+}
+
+impl By {
+    /// Find elements by their text content. Whitespace is collapsed before comparing and only the innermost
+    /// elements that match are returned.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::new(ByKind::Text(text.into()))
+    }
+
+    /// Find elements by their explicit `role` attribute or the implicit role of their tag.
+    pub fn role(role: impl Into<String>) -> Self {
+        Self::new(ByKind::Role(role.into()))
+    }
+
+    /// Find form controls by the text of their `label` or their `aria-label` attribute.
+    pub fn label(label: impl Into<String>) -> Self {
+        Self::new(ByKind::Label(label.into()))
+    }
+
+    /// Find elements by their `data-testid` attribute.
+    pub fn test_id(id: impl Into<String>) -> Self {
+        Self::new(ByKind::TestId(id.into()))
+    }
+
+    /// Only match elements with this accessible name. The accessible name is the `aria-label` attribute,
+    /// the text of the element's label or the text content of the element.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    fn new(kind: ByKind) -> Self {
+        Self { kind, name: None }
+    }
+
+    fn matches(&self, node: NodeRef) -> bool {
+        if node.tag().is_none() {
+            return false;
+        }
+        let matches = match &self.kind {
+            ByKind::Text(text) => normalize(&node.text_content()) == normalize(text),
+            ByKind::Role(role) => self::role(node).is_some_and(|found| &found == role),
+            ByKind::Label(label) => labels(node)
+                .iter()
+                .any(|found| normalize(found) == normalize(label)),
+            ByKind::TestId(id) => node.attribute("data-testid") == Some(id.as_str()),
+        };
+        matches
+            && self
+                .name
+                .as_ref()
+                .is_none_or(|name| normalize(&accessible_name(node)) == normalize(name))
+    }
+}
+
+impl std::fmt::Display for By {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            ByKind::Text(text) => write!(f, "text {text:?}")?,
+            ByKind::Role(role) => write!(f, "role {role:?}")?,
+            ByKind::Label(label) => write!(f, "label {label:?}")?,
+            ByKind::TestId(id) => write!(f, "test id {id:?}")?,
+        }
+        if let Some(name) = &self.name {
+            write!(f, " with name {name:?}")?;
+        }
+        Ok(())
+    }
+}
+
+impl TestDom {
+    /// Find all nodes that match a query in document order.
+    pub fn query_all(&self, by: &By) -> Vec<NodeRef<'_>> {
+        let matches: Vec<_> = self
+            .root()
+            .descendants()
+            .filter(|node| by.matches(*node))
+            .collect();
+
+        // A text match also matches every ancestor that only wraps the text. Keep the innermost match
+        if let ByKind::Text(_) = by.kind {
+            return matches
+                .iter()
+                .filter(|node| {
+                    !matches
+                        .iter()
+                        .any(|other| other.ancestors().any(|ancestor| ancestor.id() == node.id()))
+                })
+                .copied()
+                .collect();
+        }
+
+        matches
+    }
+
+    /// Find the only node that matches a query if it exists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than one node matches the query.
+    pub fn query(&self, by: &By) -> Option<NodeRef<'_>> {
+        let mut matches = self.query_all(by);
+        if matches.len() > 1 {
+            panic!(
+                "found {} elements with {by}, expected at most one\n\n{}",
+                matches.len(),
+                self.to_html()
+            );
+        }
+        matches.pop()
+    }
+
+    /// Find all nodes that match a query.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no nodes match the query.
+    pub fn get_all(&self, by: &By) -> Vec<NodeRef<'_>> {
+        let matches = self.query_all(by);
+        if matches.is_empty() {
+            panic!("unable to find an element with {by}\n\n{}", self.to_html());
+        }
+        matches
+    }
+
+    /// Find the only node that matches a query.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no nodes or more than one node match the query.
+    pub fn get(&self, by: &By) -> NodeRef<'_> {
+        match self.query(by) {
+            Some(node) => node,
+            None => panic!("unable to find an element with {by}\n\n{}", self.to_html()),
+        }
+    }
+
+    /// Find the only element with some text. See [`By::text`].
+    pub fn get_by_text(&self, text: &str) -> NodeRef<'_> {
+        self.get(&By::text(text))
+    }
+
+    /// Find the only element with a role. See [`By::role`].
+    pub fn get_by_role(&self, role: &str) -> NodeRef<'_> {
+        self.get(&By::role(role))
+    }
+
+    /// Find the only form control with a label. See [`By::label`].
+    pub fn get_by_label_text(&self, label: &str) -> NodeRef<'_> {
+        self.get(&By::label(label))
+    }
+
+    /// Find the only element with a test id. See [`By::test_id`].
+    pub fn get_by_test_id(&self, id: &str) -> NodeRef<'_> {
+        self.get(&By::test_id(id))
+    }
+
+    /// Find the element with some text if it exists. See [`By::text`].
+    pub fn query_by_text(&self, text: &str) -> Option<NodeRef<'_>> {
+        self.query(&By::text(text))
+    }
+
+    /// Find the element with a role if it exists. See [`By::role`].
+    pub fn query_by_role(&self, role: &str) -> Option<NodeRef<'_>> {
+        self.query(&By::role(role))
+    }
+
+    /// Find the form control with a label if it exists. See [`By::label`].
+    pub fn query_by_label_text(&self, label: &str) -> Option<NodeRef<'_>> {
+        self.query(&By::label(label))
+    }
+
+    /// Find the element with a test id if it exists. See [`By::test_id`].
+    pub fn query_by_test_id(&self, id: &str) -> Option<NodeRef<'_>> {
+        self.query(&By::test_id(id))
+    }
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Get the explicit or implicit role of an element
+fn role(node: NodeRef) -> Option<String> {
+    if let Some(role) = node.attribute("role") {
+        return role.split_whitespace().next().map(str::to_string);
+    }
+    let role = match node.tag()? {
+        "button" => "button",
+        "a" | "area" if node.attribute("href").is_some() => "link",
+        "input" => match node.attribute("type").unwrap_or("text") {
+            "button" | "submit" | "reset" | "image" => "button",
+            "checkbox" => "checkbox",
+            "radio" => "radio",
+            "range" => "slider",
+            "number" => "spinbutton",
+            "search" => "searchbox",
+            "hidden" | "file" | "color" | "date" | "datetime-local" | "month" | "time" | "week"
+            | "password" => return None,
+            _ => "textbox",
+        },
+        "textarea" => "textbox",
+        "select" if node.attribute("multiple").is_some() => "listbox",
+        "select" => "combobox",
+        "option" => "option",
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "heading",
+        "ul" | "ol" | "menu" => "list",
+        "li" => "listitem",
+        "img" if node.attribute("alt") == Some("") => "presentation",
+        "img" => "img",
+        "nav" => "navigation",
+        "main" => "main",
+        "header" => "banner",
+        "footer" => "contentinfo",
+        "aside" => "complementary",
+        "article" => "article",
+        "form" => "form",
+        "dialog" => "dialog",
+        "table" => "table",
+        "tr" => "row",
+        "td" => "cell",
+        "th" => "columnheader",
+        "progress" => "progressbar",
+        "hr" => "separator",
+        _ => return None,
+    };
+    Some(role.to_string())
+}
+
+fn is_labelable(node: NodeRef) -> bool {
+    matches!(
+        node.kind(),
+        NodeKind::Element { tag, .. }
+            if matches!(tag.as_str(), "input" | "textarea" | "select" | "button" | "meter" | "output" | "progress")
+    )
+}
+
+/// Get the text of every label that points to an element
+fn labels(node: NodeRef) -> Vec<String> {
+    let mut labels = Vec::new();
+    if let Some(label) = node.attribute("aria-label") {
+        labels.push(label.to_string());
+    }
+    if !is_labelable(node) {
+        return labels;
+    }
+
+    let root = std::iter::once(node)
+        .chain(node.ancestors())
+        .last()
+        .unwrap_or(node);
+    let id = node.attribute("id");
+    for label in root
+        .descendants()
+        .filter(|label| label.tag() == Some("label"))
+    {
+        let points_here = match label.attribute("for") {
+            Some(target) => Some(target) == id,
+            None => label
+                .descendants()
+                .find(|child| is_labelable(*child))
+                .is_some_and(|child| child.id() == node.id()),
+        };
+        if points_here {
+            labels.push(label.text_content());
+        }
+    }
+    if let Some(ids) = node.attribute("aria-labelledby") {
+        for id in ids.split_whitespace() {
+            if let Some(label) = root
+                .descendants()
+                .find(|label| label.attribute("id") == Some(id))
+            {
+                labels.push(label.text_content());
+            }
+        }
+    }
+    labels
+}
+
+fn accessible_name(node: NodeRef) -> String {
+    labels(node)
+        .into_iter()
+        .next()
+        .or_else(|| node.attribute("alt").map(str::to_string))
+        .unwrap_or_else(|| node.text_content())
+}
@@ -0,0 +1,216 @@
+use dioxus::prelude::*;
+use dioxus_testing::{By, TestApp};
+use std::time::Duration;
+
+#[test]
+fn queries_find_elements_like_a_user() {
+    fn app() -> Element {
+        rsx! {
+            nav { a { href: "/", "Home" } }
+            h1 { "Sign up" }
+            form {
+                label { r#for: "email", "Email" }
+                input { id: "email", r#type: "email" }
+                label {
+                    "Subscribe"
+                    input { r#type: "checkbox" }
+                }
+                input { r#type: "password", aria_label: "Password" }
+                button { r#type: "submit", "Create account" }
+                button { r#type: "reset", "Clear" }
+            }
+            p { "data-testid": "footer", "Terms   and\n conditions" }
+        }
+    }
+
+    let app = TestApp::new(app);
+    let dom = app.dom();
+
+    assert_eq!(dom.get_by_role("link").attribute("href"), Some("/"));
+    assert_eq!(dom.get_by_role("heading").text_content(), "Sign up");
+    assert_eq!(
+        dom.get_by_label_text("Email").attribute("id"),
+        Some("email")
+    );
+    assert_eq!(
+        dom.get_by_label_text("Subscribe").attribute("type"),
+        Some("checkbox")
+    );
+    assert_eq!(
+        dom.get_by_label_text("Password").attribute("type"),
+        Some("password")
+    );
+    assert_eq!(dom.get_all(&By::role("button")).len(), 2);
+    assert_eq!(
+        dom.get(&By::role("button").name("Clear")).attribute("type"),
+        Some("reset")
+    );
+
+    // Text matches collapse whitespace and only return the innermost element
+    assert_eq!(dom.get_by_text("Terms and conditions").tag(), Some("p"));
+    assert_eq!(
+        dom.get_by_test_id("footer").id(),
+        dom.get_by_text("Terms and conditions").id()
+    );
+    assert!(dom.query_by_text("Missing").is_none());
+    assert!(dom.query_by_role("dialog").is_none());
+}
+
+#[test]
+#[should_panic(expected = "found 2 elements with role \"button\"")]
+fn get_panics_on_multiple_matches() {
+    let app = TestApp::new(|| rsx! { button {} button {} });
+    app.dom().get_by_role("button");
+}
+
+#[test]
+fn events_update_the_dom() {
+    fn app() -> Element {
+        let mut count = use_signal(|| 0);
+        let mut name = use_signal(String::new);
+        let mut last_key = use_signal(String::new);
+        rsx! {
+            div { onclick: move |_| count += 10,
+                button { onclick: move |_| count += 1, span { "Increment" } }
+            }
+            p { "data-testid": "count", "{count}" }
+            input {
+                aria_label: "Name",
+                oninput: move |evt| name.set(evt.value()),
+                onkeydown: move |evt| last_key.set(evt.key().to_string()),
+            }
+            p { "data-testid": "greeting", "Hello {name}" }
+            p { "data-testid": "key", "{last_key}" }
+            if count() > 20 {
+                p { "Big number" }
+            }
+        }
+    }
+
+    let mut app = TestApp::new(app);
+
+    // Clicking the span sends the event to the closest element with a listener, and it bubbles to the div
+    let span = app.dom().get_by_text("Increment").id();
+    app.click(span);
+    assert_eq!(app.dom().get_by_test_id("count").text_content(), "11");
+    assert!(app.dom().query_by_text("Big number").is_none());
+    app.click(span);
+    assert!(app.dom().query_by_text("Big number").is_some());
+
+    let input = app.dom().get_by_label_text("Name").id();
+    app.input(input, "Ferris");
+    assert_eq!(
+        app.dom().get_by_test_id("greeting").text_content(),
+        "Hello Ferris"
+    );
+    assert_eq!(app.dom().node(input).attribute("value"), Some("Ferris"));
+
+    app.key_down(input, Key::Enter);
+    assert_eq!(app.dom().get_by_test_id("key").text_content(), "Enter");
+}
+
+#[test]
+fn submit_sends_named_form_values() {
+    fn app() -> Element {
+        let mut submitted = use_signal(Vec::new);
+        rsx! {
+            form {
+                onsubmit: move |evt| {
+                    let mut values: Vec<_> = evt
+                        .values()
+                        .into_iter()
+                        .filter_map(|(key, value)| match value {
+                            FormValue::Text(value) => Some(format!("{key}={value}")),
+                            FormValue::File(_) => None,
+                        })
+                        .collect();
+                    values.sort();
+                    submitted.set(values);
+                },
+                input { name: "user", value: "ferris" }
+                input { name: "remember", r#type: "checkbox" }
+                input { name: "plan", r#type: "radio", value: "free", checked: true }
+                input { name: "plan", r#type: "radio", value: "pro" }
+                button { r#type: "submit", "Save" }
+            }
+            p { "data-testid": "submitted", "{submitted:?}" }
+        }
+    }
+
+    let mut app = TestApp::new(app);
+    let remember = app.dom().get_by_role("checkbox").id();
+    app.check(remember, true);
+    let form = app.dom().get_by_role("form").id();
+    app.submit(form);
+    assert_eq!(
+        app.dom().get_by_test_id("submitted").text_content(),
+        r#"["plan=free", "remember=on", "user=ferris"]"#
+    );
+}
+
+#[test]
+fn mounted_events_are_sent() {
+    fn app() -> Element {
+        let mut mounted = use_signal(|| false);
+        rsx! {
+            div { onmounted: move |_| mounted.set(true), "mounted: {mounted}" }
+        }
+    }
+
+    let app = TestApp::new(app);
+    assert!(app.dom().query_by_text("mounted: true").is_some());
+}
+
+#[tokio::test]
+async fn settle_waits_for_suspense() {
+    fn app() -> Element {
+        rsx! {
+            SuspenseBoundary {
+                fallback: |_| rsx! { p { "Loading..." } },
+                user_name {}
+            }
+        }
+    }
+
+    #[component]
+    fn user_name() -> Element {
+        let name = use_resource(|| async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            "Ferris"
+        })
+        .suspend()?;
+        rsx! { h1 { "{name}" } }
+    }
+
+    let mut app = TestApp::new(app);
+    assert!(app.dom().query_by_text("Loading...").is_some());
+    app.settle().await;
+    assert_eq!(app.dom().get_by_role("heading").text_content(), "Ferris");
+    assert!(app.dom().query_by_text("Loading...").is_none());
+}
+
+#[tokio::test]
+async fn wait_for_runs_tasks_until_the_condition_holds() {
+    fn app() -> Element {
+        let mut ticks = use_signal(|| 0);
+        use_future(move || async move {
+            for _ in 0..3 {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                ticks += 1;
+            }
+        });
+        rsx! { p { "ticks: {ticks}" } }
+    }
+
+    let mut app = TestApp::new(app);
+    app.wait_for(|dom| dom.query_by_text("ticks: 3").is_some())
+        .await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "timed out waiting for the condition")]
+async fn wait_for_times_out() {
+    let mut app = TestApp::new(|| rsx! { "never" }).with_timeout(Duration::from_millis(20));
+    app.wait_for(|dom| dom.query_by_text("done").is_some())
+        .await;
+}
@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::{rc::Rc, time::Duration};
 
 use dioxus_core::LaunchConfig;
 use wasm_bindgen::JsCast as _;
@@ -16,7 +16,12 @@ pub struct Config {
     pub(crate) hydrate: bool,
     #[allow(dead_code)]
     pub(crate) panic_hook: bool,
+    /// Whether the app connects to the devserver. Custom elements turn this off so the page doesn't open a
+    /// connection for every element.
+    #[allow(dead_code)]
+    pub(crate) devtools: bool,
     pub(crate) root: ConfigRoot,
+    pub(crate) time_slice: Option<Duration>,
     #[cfg(feature = "document")]
     pub(crate) history: Option<Rc<dyn dioxus_history::History>>,
 }
@@ -43,6 +48,8 @@ impl Config {
     /// work and suspended nodes.
     ///
     /// Dioxus will load up all the elements with the `dio_el` data attribute into memory when the page is loaded.
+    ///
+    /// Hydration is enabled by default when the `hydrate` feature is enabled.
     pub fn hydrate(mut self, f: bool) -> Self {
         self.hydrate = f;
         self
@@ -74,6 +81,22 @@ impl Config {
         self
     }
 
+    /// Split large updates into slices of at most `budget` and let the browser handle input and paint between them.
+    ///
+    /// By default, Dioxus renders all pending work before it applies the mutations, which can block the main thread
+    /// on big updates. With time slicing enabled, the mutations rendered in each slice are applied before the next slice
+    /// starts, and urgent updates like input events run before any remaining
+    /// [transitions](dioxus_core::start_transition). Slices start when the browser is idle, and use all of the idle
+    /// time the browser reports if it is longer than the budget.
+    ///
+    /// ```rust, ignore
+    /// dioxus_web::launch::launch_cfg(App, Config::new().time_slicing(Duration::from_millis(5)))
+    /// ```
+    pub fn time_slicing(mut self, budget: Duration) -> Self {
+        self.time_slice = Some(budget);
+        self
+    }
+
     /// Set the history provider for the application.
     ///
     /// `dioxus-web` provides two history providers:
@@ -94,11 +117,13 @@ impl Config {
 impl Default for Config {
     fn default() -> Self {
         Self {
-            hydrate: false,
+            hydrate: cfg!(feature = "hydrate"),
             root: ConfigRoot::RootName("main".to_string()),
+            time_slice: None,
             #[cfg(feature = "document")]
             history: None,
             panic_hook: true,
+            devtools: true,
         }
     }
 }
@@ -0,0 +1,520 @@
+//! Register Dioxus components as [custom elements](https://developer.mozilla.org/en-US/docs/Web/API/Web_components/Using_custom_elements)
+//! so they can be embedded in pages that are not built with Dioxus.
+//!
+//! Every element on the page hosts its own [`VirtualDom`] which renders into the element's shadow root. The dom is
+//! created when the element is connected to the document and dropped once it is removed from the document. Moving the
+//! element to another place in the document keeps the dom and all of its state.
+
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+use dioxus_core::{
+    ComponentFunction, Element, Template, TemplateNode, VNode, VirtualDom, provide_context,
+    schedule_update, try_consume_context, use_hook,
+};
+use dioxus_history::MemoryHistory;
+use futures_util::future::{AbortHandle, Abortable};
+use serde::{Serialize, de::DeserializeOwned};
+use wasm_bindgen::prelude::*;
+use web_sys::{CustomEvent, CustomEventInit, HtmlElement, ShadowRootInit, ShadowRootMode};
+
+use crate::Config;
+
+/// A component that can be registered as a custom element with [`CustomElement::define`].
+///
+/// The props of the component are read from the element's observed attributes. Attribute names are converted to
+/// field names by replacing `-` with `_`, and values are parsed with the same rules as query strings, so numbers,
+/// booleans and strings can be used directly. Attributes that are not set on the element are left out, so any prop
+/// that is optional should be an `Option` or use `#[serde(default)]`.
+///
+/// Children of the element are projected into the shadow root with `slot` elements. Components that take children
+/// can use [`slot_children`] as the default for the field:
+///
+/// ```rust, ignore
+/// #[derive(Props, Clone, PartialEq, Deserialize)]
+/// struct CounterProps {
+///     #[serde(default)]
+///     start: i32,
+///     #[serde(skip, default = "dioxus_web::slot_children")]
+///     children: Element,
+/// }
+///
+/// fn Counter(props: CounterProps) -> Element {
+///     let mut count = use_signal(|| props.start);
+///     let host = custom_element_host().unwrap();
+///     rsx! {
+///         button {
+///             onclick: move |_| {
+///                 count += 1;
+///                 host.dispatch_event("count", count());
+///             },
+///             {props.children}
+///             "{count}"
+///         }
+///     }
+/// }
+///
+/// CustomElement::new("dx-counter", Counter)
+///     .observed_attributes(["start"])
+///     .style("button { color: red; }")
+///     .define();
+/// ```
+///
+/// ```html
+/// <dx-counter start="5">Clicks: </dx-counter>
+/// ```
+pub struct CustomElement<P> {
+    name: String,
+    render: Rc<dyn Fn(P) -> Element>,
+    observed_attributes: Vec<String>,
+    style: Option<String>,
+}
+
+impl<P: DeserializeOwned + Clone + 'static> CustomElement<P> {
+    /// Create a new custom element with a tag name and the component it renders.
+    ///
+    /// Custom element names must contain a `-`, for example `dx-counter`.
+    pub fn new<M: 'static>(
+        name: impl Into<String>,
+        component: impl ComponentFunction<P, M>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            render: Rc::new(move |props| component.rebuild(props)),
+            observed_attributes: Vec::new(),
+            style: None,
+        }
+    }
+
+    /// Set the attributes that are read into the props of the component.
+    ///
+    /// When any of these attributes change, the props are read again and the component re-renders.
+    pub fn observed_attributes(
+        mut self,
+        attributes: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.observed_attributes = attributes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the css that is added to the shadow root of every element.
+    ///
+    /// Styles in the shadow root only apply to the contents of the element, and styles from the page do not leak
+    /// into it.
+    pub fn style(mut self, css: impl Into<String>) -> Self {
+        self.style = Some(css.into());
+        self
+    }
+
+    /// Register the element with the browser's custom element registry.
+    ///
+    /// Elements that are already in the document are upgraded immediately. Each element name can only be defined
+    /// once per page.
+    pub fn define(self) {
+        let definition = Rc::new(Definition {
+            render: self.render,
+            observed_attributes: self.observed_attributes,
+            style: self.style,
+            instances: RefCell::new(Vec::new()),
+        });
+
+        let connected = Closure::<dyn Fn(HtmlElement)>::new({
+            let definition = definition.clone();
+            move |element| definition.connect(element)
+        });
+        let disconnected = Closure::<dyn Fn(HtmlElement)>::new({
+            let definition = definition.clone();
+            move |element| Definition::disconnect(&definition, element)
+        });
+        let attribute_changed = Closure::<dyn Fn(HtmlElement)>::new({
+            let definition = definition.clone();
+            move |element| definition.attribute_changed(&element)
+        });
+
+        define_custom_element(
+            &self.name,
+            definition.observed_attributes.clone(),
+            &connected.into_js_value(),
+            &disconnected.into_js_value(),
+            &attribute_changed.into_js_value(),
+        );
+    }
+}
+
+/// The function that re-renders the root of an element, filled in once the root renders for the first time
+type UpdateSlot = Rc<RefCell<Option<Arc<dyn Fn() + Send + Sync>>>>;
+
+struct Definition<P> {
+    render: Rc<dyn Fn(P) -> Element>,
+    observed_attributes: Vec<String>,
+    style: Option<String>,
+    instances: RefCell<Vec<Instance<P>>>,
+}
+
+/// A connected element and the virtual dom running inside of it
+struct Instance<P> {
+    element: HtmlElement,
+    props: Rc<RefCell<P>>,
+    update: UpdateSlot,
+    abort: AbortHandle,
+}
+
+impl<P: DeserializeOwned + Clone + 'static> Definition<P> {
+    fn connect(&self, element: HtmlElement) {
+        if self.instance_index(&element).is_some() {
+            return;
+        }
+
+        let props = match self.read_props(&element) {
+            Ok(props) => props,
+            Err(err) => {
+                tracing::error!(
+                    "Failed to read the props of <{}> from its attributes: {err}",
+                    element.tag_name().to_lowercase()
+                );
+                return;
+            }
+        };
+
+        let root = match self.prepare_shadow_root(&element) {
+            Ok(root) => root,
+            Err(err) => {
+                tracing::error!("Failed to attach a shadow root to a custom element: {err:?}");
+                return;
+            }
+        };
+
+        let props = Rc::new(RefCell::new(props));
+        let update = Rc::new(RefCell::new(None));
+        let virtual_dom = VirtualDom::new_with_props(
+            host_root::<P>,
+            HostProps {
+                render: self.render.clone(),
+                props: props.clone(),
+                update: update.clone(),
+                host: CustomElementHost {
+                    element: element.clone(),
+                },
+            },
+        );
+
+        // The element is embedded in a page it doesn't own, so it keeps its routes in memory instead of taking over the
+        // browser history, and always renders on the client instead of hydrating the page's server data
+        let mut config = Config::new()
+            .rootelement(root)
+            .history(Rc::new(MemoryHistory::default()));
+        config.hydrate = false;
+        config.panic_hook = false;
+        config.devtools = false;
+
+        let (abort, registration) = AbortHandle::new_pair();
+        wasm_bindgen_futures::spawn_local(async move {
+            _ = Abortable::new(crate::run(virtual_dom, config), registration).await;
+        });
+
+        self.instances.borrow_mut().push(Instance {
+            element,
+            props,
+            update,
+            abort,
+        });
+    }
+
+    /// Tear down the virtual dom of an element that was disconnected.
+    ///
+    /// Moving an element disconnects it and connects it again right away. Teardown waits until the script that
+    /// disconnected the element finishes, and keeps the dom running if the element is back in the document by then.
+    fn disconnect(definition: &Rc<Self>, element: HtmlElement) {
+        let definition = definition.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            if element.is_connected() {
+                return;
+            }
+            let Some(index) = definition.instance_index(&element) else {
+                return;
+            };
+            let instance = definition.instances.borrow_mut().remove(index);
+            instance.abort.abort();
+        });
+    }
+
+    fn attribute_changed(&self, element: &HtmlElement) {
+        // Attributes that are set before the element is connected are read when it connects
+        let Some(index) = self.instance_index(element) else {
+            return;
+        };
+
+        let props = match self.read_props(element) {
+            Ok(props) => props,
+            Err(err) => {
+                tracing::error!(
+                    "Failed to read the props of <{}> from its attributes: {err}",
+                    element.tag_name().to_lowercase()
+                );
+                return;
+            }
+        };
+
+        let instances = self.instances.borrow();
+        let instance = &instances[index];
+        *instance.props.borrow_mut() = props;
+        if let Some(update) = instance.update.borrow().as_ref() {
+            update();
+        }
+    }
+
+    fn instance_index(&self, element: &HtmlElement) -> Option<usize> {
+        self.instances
+            .borrow()
+            .iter()
+            .position(|instance| &instance.element == element)
+    }
+
+    fn read_props(&self, element: &HtmlElement) -> Result<P, serde_urlencoded::de::Error> {
+        props_from_attributes(&self.observed_attributes, |attribute| {
+            element.get_attribute(attribute)
+        })
+    }
+
+    /// Attach a shadow root to the element, or clear the one from a previous connection, and create the element
+    /// the virtual dom renders into.
+    fn prepare_shadow_root(&self, element: &HtmlElement) -> Result<web_sys::Element, JsValue> {
+        let shadow_root = match element.shadow_root() {
+            Some(shadow_root) => {
+                shadow_root.set_inner_html("");
+                shadow_root
+            }
+            None => element.attach_shadow(&ShadowRootInit::new(ShadowRootMode::Open))?,
+        };
+        let document = shadow_root
+            .owner_document()
+            .expect("elements belong to a document");
+
+        if let Some(css) = &self.style {
+            let style = document.create_element("style")?;
+            style.set_text_content(Some(css));
+            shadow_root.append_child(&style)?;
+        }
+
+        // The interpreter listens for events on the root and marks it with an id, which a shadow root can't hold.
+        // Instead we render into an element that doesn't affect the layout of the contents.
+        let root = document.create_element("div")?;
+        root.set_attribute("style", "display: contents")?;
+        shadow_root.append_child(&root)?;
+
+        Ok(root)
+    }
+}
+
+/// Parse the props of a component from the observed attributes that are set on its element
+fn props_from_attributes<P: DeserializeOwned>(
+    observed_attributes: &[String],
+    get_attribute: impl Fn(&str) -> Option<String>,
+) -> Result<P, serde_urlencoded::de::Error> {
+    let fields: Vec<(String, String)> = observed_attributes
+        .iter()
+        .filter_map(|attribute| {
+            let value = get_attribute(attribute)?;
+            Some((attribute.replace('-', "_"), value))
+        })
+        .collect();
+
+    // Attribute values are plain strings just like query parameters, so we reuse the query string deserializer
+    // to parse them into the props
+    let query =
+        serde_urlencoded::to_string(&fields).expect("a list of string pairs can always be encoded");
+    serde_urlencoded::from_str(&query)
+}
+
+#[derive(Clone)]
+struct HostProps<P: 'static> {
+    render: Rc<dyn Fn(P) -> Element>,
+    props: Rc<RefCell<P>>,
+    update: UpdateSlot,
+    host: CustomElementHost,
+}
+
+fn host_root<P: Clone + 'static>(props: HostProps<P>) -> Element {
+    use_hook(|| {
+        *props.update.borrow_mut() = Some(schedule_update());
+        provide_context(props.host.clone());
+    });
+
+    let current = props.props.borrow().clone();
+    (props.render)(current)
+}
+
+/// The element a component registered with [`CustomElement`] is rendered inside of.
+#[derive(Clone)]
+pub struct CustomElementHost {
+    element: HtmlElement,
+}
+
+impl CustomElementHost {
+    /// Get the custom element the component is rendered inside of.
+    pub fn element(&self) -> &HtmlElement {
+        &self.element
+    }
+
+    /// Dispatch a [`CustomEvent`](https://developer.mozilla.org/en-US/docs/Web/API/CustomEvent) from the element with
+    /// the serialized `detail`.
+    ///
+    /// The event bubbles and crosses the shadow root boundary, so the page can listen for it with
+    /// `element.addEventListener(name, ...)` on the element or any of its ancestors. Returns `false` if a listener
+    /// called `preventDefault` on the event.
+    pub fn dispatch_event(&self, name: &str, detail: impl Serialize) -> bool {
+        let detail = match detail.serialize(&serde_wasm_bindgen::Serializer::json_compatible()) {
+            Ok(detail) => detail,
+            Err(err) => {
+                tracing::error!("Failed to serialize the detail of the {name} event: {err}");
+                return true;
+            }
+        };
+
+        let init = CustomEventInit::new();
+        init.set_detail(&detail);
+        init.set_bubbles(true);
+        init.set_composed(true);
+        init.set_cancelable(true);
+        let event = CustomEvent::new_with_event_init_dict(name, &init)
+            .expect("custom event names are always valid");
+
+        self.element.dispatch_event(&event).unwrap_or(true)
+    }
+}
+
+/// Get the custom element the current component is rendered inside of, if the component is part of a
+/// [`CustomElement`].
+pub fn custom_element_host() -> Option<CustomElementHost> {
+    try_consume_context()
+}
+
+/// A `slot` element that renders the children of the custom element.
+///
+/// Use this as the default for the `children` of a component registered with [`CustomElement`]. Named slots can be
+/// rendered with `slot { name: "footer" }`.
+pub fn slot_children() -> Element {
+    static TEMPLATE: Template = Template::new(
+        &[TemplateNode::Element {
+            tag: "slot",
+            namespace: None,
+            attrs: &[],
+            children: &[],
+        }],
+        &[],
+        &[],
+    );
+    Ok(VNode::new(None, TEMPLATE, Box::new([]), Default::default()))
+}
+
+#[wasm_bindgen(inline_js = r#"
+export function define_custom_element(name, observed, connected, disconnected, attribute_changed) {
+    customElements.define(name, class extends HTMLElement {
+        static get observedAttributes() {
+            return observed;
+        }
+
+        connectedCallback() {
+            connected(this);
+        }
+
+        disconnectedCallback() {
+            disconnected(this);
+        }
+
+        attributeChangedCallback(attribute, oldValue, newValue) {
+            if (oldValue !== newValue) {
+                attribute_changed(this);
+            }
+        }
+    });
+}
+"#)]
+extern "C" {
+    fn define_custom_element(
+        name: &str,
+        observed: Vec<String>,
+        connected: &JsValue,
+        disconnected: &JsValue,
+        attribute_changed: &JsValue,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct CounterProps {
+        #[serde(default)]
+        start: i32,
+        step_size: Option<u32>,
+        label: Option<String>,
+        #[serde(default)]
+        disabled: bool,
+    }
+
+    fn parse(attributes: &[(&str, &str)]) -> Result<CounterProps, serde_urlencoded::de::Error> {
+        let observed: Vec<String> = ["start", "step-size", "label", "disabled"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        props_from_attributes(&observed, |name| {
+            attributes
+                .iter()
+                .find(|(attribute, _)| *attribute == name)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn attributes_are_parsed_into_props() {
+        let props = parse(&[
+            ("start", "-5"),
+            ("step-size", "2"),
+            ("label", "Clicks & taps = fun"),
+            ("disabled", "true"),
+        ])
+        .unwrap();
+        assert_eq!(
+            props,
+            CounterProps {
+                start: -5,
+                step_size: Some(2),
+                label: Some("Clicks & taps = fun".to_string()),
+                disabled: true,
+            }
+        );
+    }
+
+    #[test]
+    fn missing_attributes_use_the_defaults() {
+        assert_eq!(
+            parse(&[]).unwrap(),
+            CounterProps {
+                start: 0,
+                step_size: None,
+                label: None,
+                disabled: false,
+            }
+        );
+    }
+
+    #[test]
+    fn unobserved_attributes_are_ignored() {
+        let observed = vec!["start".to_string()];
+        let props: CounterProps = props_from_attributes(&observed, |name| match name {
+            "start" => Some("3".to_string()),
+            _ => Some("not a number".to_string()),
+        })
+        .unwrap();
+        assert_eq!(props.start, 3);
+        assert_eq!(props.step_size, None);
+    }
+
+    #[test]
+    fn invalid_values_are_errors() {
+        assert!(parse(&[("start", "five")]).is_err());
+        assert!(parse(&[("step-size", "-1")]).is_err());
+    }
+}
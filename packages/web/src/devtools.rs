@@ -3,10 +3,12 @@
 //! This sets up a websocket connection to the devserver and handles messages from it.
 //! We also set up a little recursive timer that will attempt to reconnect if the connection is lost.
 
+use dioxus_core::Runtime;
 use dioxus_devtools::{DevserverMsg, HotReloadMsg};
 use futures_channel::mpsc::{UnboundedReceiver, UnboundedSender, unbounded};
 use js_sys::JsString;
 use std::fmt::Display;
+use std::rc::Rc;
 use std::time::Duration;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::prelude::*;
@@ -21,12 +23,15 @@ const POLL_INTERVAL_SCALE_FACTOR: i32 = 2;
 const TOAST_TIMEOUT: Duration = Duration::from_secs(5);
 const TOAST_TIMEOUT_LONG: Duration = Duration::from_secs(3600); // Duration::MAX is too long for JS.
 
-pub(crate) fn init(config: &crate::Config) -> UnboundedReceiver<HotReloadMsg> {
+pub(crate) fn init(
+    config: &crate::Config,
+    runtime: Rc<Runtime>,
+) -> UnboundedReceiver<HotReloadMsg> {
     // Create the tx/rx pair that we'll use for the top-level future in the dioxus loop
     let (tx, rx) = unbounded();
 
     // Wire up the websocket to the devserver
-    make_ws(tx.clone(), POLL_INTERVAL_MIN, false);
+    make_ws(tx.clone(), runtime, POLL_INTERVAL_MIN, false);
 
     // Set up the playground
     playground(tx);
@@ -41,7 +46,12 @@ pub(crate) fn init(config: &crate::Config) -> UnboundedReceiver<HotReloadMsg> {
     rx
 }
 
-fn make_ws(tx: UnboundedSender<HotReloadMsg>, poll_interval: i32, reload: bool) {
+fn make_ws(
+    tx: UnboundedSender<HotReloadMsg>,
+    runtime: Rc<Runtime>,
+    poll_interval: i32,
+    reload: bool,
+) {
     // Get the location of the devserver, using the current location plus the /_dioxus path
     // The idea here being that the devserver is always located on the /_dioxus behind a proxy
     let location = web_sys::window().unwrap().location();
@@ -59,6 +69,8 @@ fn make_ws(tx: UnboundedSender<HotReloadMsg>, poll_interval: i32, reload: bool)
 
     // Set the onmessage handler to bounce messages off to the main dioxus loop
     let tx_ = tx.clone();
+    let ws_ = ws.clone();
+    let runtime_ = runtime.clone();
     ws.set_onmessage(Some(
         Closure::<dyn FnMut(MessageEvent)>::new(move |e: MessageEvent| {
             let Ok(text) = e.data().dyn_into::<JsString>() else {
@@ -119,6 +131,17 @@ fn make_ws(tx: UnboundedSender<HotReloadMsg>, poll_interval: i32, reload: bool)
                     window().unwrap().location().reload().unwrap()
                 }
 
+                // The devserver is profiling the app or inspecting its reactive graph
+                Ok(
+                    msg @ (DevserverMsg::SetProfiling(_)
+                    | DevserverMsg::SetReactiveGraphRecording(_)
+                    | DevserverMsg::QueryReactiveGraph),
+                ) => {
+                    if let Some(reply) = dioxus_devtools::handle_runtime_msg(&runtime_, &msg) {
+                        _ = ws_.send_with_str(&serde_json::to_string(&reply).unwrap());
+                    }
+                }
+
                 Err(e) => web_sys::console::error_1(
                     &format!("Error parsing devserver message: {}", e).into(),
                 ),
@@ -147,12 +170,14 @@ fn make_ws(tx: UnboundedSender<HotReloadMsg>, poll_interval: i32, reload: bool)
 
             // set timeout to reload the page in timeout_ms
             let tx = tx.clone();
+            let runtime = runtime.clone();
             web_sys::window()
                 .unwrap()
                 .set_timeout_with_callback_and_timeout_and_arguments_0(
                     Closure::<dyn FnMut()>::new(move || {
                         make_ws(
                             tx.clone(),
+                            runtime.clone(),
                             POLL_INTERVAL_MAX.min(poll_interval * POLL_INTERVAL_SCALE_FACTOR),
                             true,
                         );
@@ -81,20 +81,16 @@ fn init_document_with(document: impl FnOnce(), history: impl FnOnce()) {
 
 /// Provides the Document through [`dioxus_core::provide_context`].
 pub fn init_document() {
-    // If hydrate is enabled, we add the FullstackWebDocument with the initial hydration data
-    #[cfg(not(feature = "hydrate"))]
-    {
-        use dioxus_history::provide_history_context;
-
-        init_document_with(
-            || {
-                provide_context(Rc::new(WebDocument) as Rc<dyn Document>);
-            },
-            || {
-                provide_history_context(Rc::new(WebHistory::default()));
-            },
-        );
-    }
+    use dioxus_history::provide_history_context;
+
+    init_document_with(
+        || {
+            provide_context(Rc::new(WebDocument) as Rc<dyn Document>);
+        },
+        || {
+            provide_history_context(Rc::new(WebHistory::default()));
+        },
+    );
 }
 
 #[cfg(feature = "hydrate")]
@@ -134,6 +130,13 @@ impl Document for WebDocument {
         });
     }
 
+    /// Remove the meta tags that match the props from the head
+    fn remove_meta(&self, props: MetaProps) {
+        queue_effect(move || {
+            _ = remove_elements_from_head("meta", &props.attributes());
+        });
+    }
+
     /// Create a new script tag in the head
     fn create_script(&self, props: ScriptProps) {
         queue_effect(move || {
@@ -164,6 +167,32 @@ impl Document for WebDocument {
     }
 }
 
+fn remove_elements_from_head(
+    local_name: &str,
+    attributes: &[(&'static str, String)],
+) -> Result<(), JsValue> {
+    let window = web_sys::window().expect("no global `window` exists");
+    let document = window.document().expect("should have a document on window");
+    let head = document.head().expect("document should have a head");
+
+    let elements = head.query_selector_all(local_name)?;
+    for i in 0..elements.length() {
+        let Some(element) = elements
+            .item(i)
+            .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+        else {
+            continue;
+        };
+        if attributes
+            .iter()
+            .all(|(name, value)| element.get_attribute(name).as_deref() == Some(value.as_str()))
+        {
+            element.remove();
+        }
+    }
+    Ok(())
+}
+
 fn append_element_to_head(
     local_name: &str,
     attributes: &Vec<(&'static str, String)>,
@@ -50,6 +50,11 @@ pub struct WebsysDom {
 
     #[cfg(feature = "hydrate")]
     pub(crate) suspense_hydration_ids: crate::hydration::SuspenseHydrationIds,
+
+    // The server only renders a placeholder for portals. These are the portals that were hydrated and still need
+    // to create their children inside of their target
+    #[cfg(feature = "hydrate")]
+    pub(crate) hydrated_portals: Vec<dioxus_core::ScopeId>,
 }
 
 impl WebsysDom {
@@ -139,6 +144,8 @@ impl WebsysDom {
             skip_mutations: false,
             #[cfg(feature = "hydrate")]
             suspense_hydration_ids: Default::default(),
+            #[cfg(feature = "hydrate")]
+            hydrated_portals: Default::default(),
         }
     }
 }
@@ -1,3 +1,5 @@
+use std::{cell::Cell, rc::Rc};
+
 use wasm_bindgen::{JsCast, JsValue, prelude::Closure};
 use web_sys::{Event, History, ScrollRestoration, Window, window};
 
@@ -14,7 +16,7 @@ use web_sys::{Event, History, ScrollRestoration, Window, window};
 /// Application developers are responsible for not rendering the router if the prefix is not present
 /// in the URL. Otherwise, if a router navigation is triggered, the prefix will be added.
 pub struct WebHistory {
-    do_scroll_restoration: bool,
+    do_scroll_restoration: Rc<Cell<bool>>,
     history: History,
     prefix: Option<String>,
     window: Window,
@@ -31,6 +33,8 @@ impl WebHistory {
     ///
     /// If `do_scroll_restoration` is [`true`], [`WebHistory`] will take control of the history
     /// state. It'll also set the browsers scroll restoration to `manual`.
+    ///
+    /// The router turns this off when it restores the scroll position itself, which it does by default.
     pub fn new(prefix: Option<String>, do_scroll_restoration: bool) -> Self {
         let myself = Self::new_inner(prefix, do_scroll_restoration);
 
@@ -38,8 +42,15 @@ impl WebHistory {
         let current_route_str = current_route.to_string();
         let prefix_str = myself.prefix.as_deref().unwrap_or("");
         let current_url = format!("{prefix_str}{current_route_str}");
-        let state = myself.create_state();
-        let _ = replace_state_with_url(&myself.history, &state, Some(&current_url));
+        // Keep the position and state of the entry if the page was reloaded
+        let state = myself.create_state(get_current_index(&myself.history));
+        let entry_state = get_current_state(&myself.history);
+        let _ = replace_state_with_url(
+            &myself.history,
+            &state,
+            entry_state.as_deref(),
+            Some(&current_url),
+        );
 
         myself
     }
@@ -66,7 +77,7 @@ impl WebHistory {
             .map(|prefix| format!("/{prefix}"));
 
         Self {
-            do_scroll_restoration,
+            do_scroll_restoration: Rc::new(Cell::new(do_scroll_restoration)),
             history,
             prefix,
             window,
@@ -74,20 +85,20 @@ impl WebHistory {
     }
 
     fn scroll_pos(&self) -> ScrollPosition {
-        if self.do_scroll_restoration {
+        if self.do_scroll_restoration.get() {
             ScrollPosition::of_window(&self.window)
         } else {
             Default::default()
         }
     }
 
-    fn create_state(&self) -> [f64; 2] {
+    fn create_state(&self, index: usize) -> [f64; 3] {
         let scroll = self.scroll_pos();
-        [scroll.x, scroll.y]
+        [scroll.x, scroll.y, index as f64]
     }
 
     fn handle_nav(&self) {
-        if self.do_scroll_restoration {
+        if self.do_scroll_restoration.get() {
             self.window.scroll_to_with_x_and_y(0.0, 0.0)
         }
     }
@@ -114,6 +125,50 @@ impl WebHistory {
             Some(prefix) => format!("{prefix}{state}"),
         }
     }
+
+    fn push_entry(&self, route: String, state: Option<String>) {
+        if route == dioxus_history::History::current_route(self)
+            && state == get_current_state(&self.history)
+        {
+            // don't push the same state twice
+            return;
+        }
+
+        let w = window().expect("access to `window`");
+        let h = w.history().expect("`window` has access to `history`");
+
+        // update the scroll position before pushing the new state
+        update_scroll(&w, &h);
+
+        let index = get_current_index(&self.history) + 1;
+        if push_state_and_url(
+            &self.history,
+            &self.create_state(index),
+            state.as_deref(),
+            self.full_path(&route),
+        )
+        .is_ok()
+        {
+            self.handle_nav();
+        }
+    }
+
+    fn replace_entry(&self, route: String, state: Option<String>) {
+        // Replacing only the state of the entry, like the selected tab, keeps the scroll position
+        let route_changed = route != dioxus_history::History::current_route(self);
+        let index = get_current_index(&self.history);
+        if replace_state_with_url(
+            &self.history,
+            &self.create_state(index),
+            state.as_deref(),
+            Some(&self.full_path(&route)),
+        )
+        .is_ok()
+            && route_changed
+        {
+            self.handle_nav();
+        }
+    }
 }
 
 impl dioxus_history::History for WebHistory {
@@ -125,6 +180,10 @@ impl dioxus_history::History for WebHistory {
         self.prefix.clone()
     }
 
+    fn current_index(&self) -> Option<usize> {
+        Some(get_current_index(&self.history))
+    }
+
     fn go_back(&self) {
         let _ = self.history.back();
     }
@@ -133,47 +192,44 @@ impl dioxus_history::History for WebHistory {
         let _ = self.history.forward();
     }
 
-    fn push(&self, state: String) {
-        if state == self.current_route() {
-            // don't push the same state twice
-            return;
-        }
-
-        let w = window().expect("access to `window`");
-        let h = w.history().expect("`window` has access to `history`");
+    fn current_state(&self) -> Option<String> {
+        get_current_state(&self.history)
+    }
 
-        // update the scroll position before pushing the new state
-        update_scroll(&w, &h);
+    fn push(&self, state: String) {
+        self.push_entry(state, None);
+    }
 
-        if push_state_and_url(&self.history, &self.create_state(), self.full_path(&state)).is_ok() {
-            self.handle_nav();
-        }
+    fn push_with_state(&self, route: String, state: String) {
+        self.push_entry(route, Some(state));
     }
 
     fn replace(&self, state: String) {
-        if replace_state_with_url(
-            &self.history,
-            &self.create_state(),
-            Some(&self.full_path(&state)),
-        )
-        .is_ok()
-        {
-            self.handle_nav();
-        }
+        self.replace_entry(state, None);
+    }
+
+    fn replace_with_state(&self, path: String, state: String) {
+        self.replace_entry(path, Some(state));
     }
 
     fn external(&self, url: String) -> bool {
         self.window.location().set_href(&url).is_ok()
     }
 
+    fn disable_scroll_restoration(&self) {
+        self.do_scroll_restoration.set(false);
+    }
+
     fn updater(&self, callback: std::sync::Arc<dyn Fn() + Send + Sync>) {
         let w = self.window.clone();
         let h = self.history.clone();
-        let d = self.do_scroll_restoration;
+        let d = self.do_scroll_restoration.clone();
 
         let function = Closure::wrap(Box::new(move |_| {
             (*callback)();
-            if d && let Some([x, y]) = get_current(&h) {
+            if d.get()
+                && let Some([x, y]) = get_current(&h)
+            {
                 ScrollPosition { x, y }.scroll_to(w.clone())
             }
         }) as Box<dyn FnMut(Event)>);
@@ -189,7 +245,7 @@ impl dioxus_history::History for WebHistory {
 /// A [`dioxus_history::History`] provider that integrates with a browser via the [History API](https://developer.mozilla.org/en-US/docs/Web/API/History_API)
 /// but uses the url fragment for the route. This allows serving as a single html file or on a single url path.
 pub struct HashHistory {
-    do_scroll_restoration: bool,
+    do_scroll_restoration: Rc<Cell<bool>>,
     history: History,
     pathname: String,
     window: Window,
@@ -206,6 +262,8 @@ impl HashHistory {
     ///
     /// If `do_scroll_restoration` is [`true`], [`HashHistory`] will take control of the history
     /// state. It'll also set the browsers scroll restoration to `manual`.
+    ///
+    /// The router turns this off when it restores the scroll position itself, which it does by default.
     pub fn new(do_scroll_restoration: bool) -> Self {
         let myself = Self::new_inner(do_scroll_restoration);
 
@@ -213,8 +271,15 @@ impl HashHistory {
         let current_route_str = current_route.to_string();
         let pathname_str = &myself.pathname;
         let current_url = format!("{pathname_str}#{current_route_str}");
-        let state = myself.create_state();
-        let _ = replace_state_with_url(&myself.history, &state, Some(&current_url));
+        // Keep the position and state of the entry if the page was reloaded
+        let state = myself.create_state(get_current_index(&myself.history));
+        let entry_state = get_current_state(&myself.history);
+        let _ = replace_state_with_url(
+            &myself.history,
+            &state,
+            entry_state.as_deref(),
+            Some(&current_url),
+        );
 
         myself
     }
@@ -231,7 +296,7 @@ impl HashHistory {
         }
 
         Self {
-            do_scroll_restoration,
+            do_scroll_restoration: Rc::new(Cell::new(do_scroll_restoration)),
             history,
             pathname,
             window,
@@ -239,16 +304,16 @@ impl HashHistory {
     }
 
     fn scroll_pos(&self) -> ScrollPosition {
-        if self.do_scroll_restoration {
+        if self.do_scroll_restoration.get() {
             ScrollPosition::of_window(&self.window)
         } else {
             Default::default()
         }
     }
 
-    fn create_state(&self) -> [f64; 2] {
+    fn create_state(&self, index: usize) -> [f64; 3] {
         let scroll = self.scroll_pos();
-        [scroll.x, scroll.y]
+        [scroll.x, scroll.y, index as f64]
     }
 
     fn full_path(&self, state: &String) -> String {
@@ -256,10 +321,54 @@ impl HashHistory {
     }
 
     fn handle_nav(&self) {
-        if self.do_scroll_restoration {
+        if self.do_scroll_restoration.get() {
             self.window.scroll_to_with_x_and_y(0.0, 0.0)
         }
     }
+
+    fn push_entry(&self, route: String, state: Option<String>) {
+        if route == dioxus_history::History::current_route(self)
+            && state == get_current_state(&self.history)
+        {
+            // don't push the same state twice
+            return;
+        }
+
+        let w = window().expect("access to `window`");
+        let h = w.history().expect("`window` has access to `history`");
+
+        // update the scroll position before pushing the new state
+        update_scroll(&w, &h);
+
+        let index = get_current_index(&self.history) + 1;
+        if push_state_and_url(
+            &self.history,
+            &self.create_state(index),
+            state.as_deref(),
+            self.full_path(&route),
+        )
+        .is_ok()
+        {
+            self.handle_nav();
+        }
+    }
+
+    fn replace_entry(&self, route: String, state: Option<String>) {
+        // Replacing only the state of the entry, like the selected tab, keeps the scroll position
+        let route_changed = route != dioxus_history::History::current_route(self);
+        let index = get_current_index(&self.history);
+        if replace_state_with_url(
+            &self.history,
+            &self.create_state(index),
+            state.as_deref(),
+            Some(&self.full_path(&route)),
+        )
+        .is_ok()
+            && route_changed
+        {
+            self.handle_nav();
+        }
+    }
 }
 
 impl dioxus_history::History for HashHistory {
@@ -279,6 +388,10 @@ impl dioxus_history::History for HashHistory {
         Some(format!("{}#", self.pathname))
     }
 
+    fn current_index(&self) -> Option<usize> {
+        Some(get_current_index(&self.history))
+    }
+
     fn go_back(&self) {
         let _ = self.history.back();
     }
@@ -287,47 +400,44 @@ impl dioxus_history::History for HashHistory {
         let _ = self.history.forward();
     }
 
-    fn push(&self, state: String) {
-        if state == self.current_route() {
-            // don't push the same state twice
-            return;
-        }
-
-        let w = window().expect("access to `window`");
-        let h = w.history().expect("`window` has access to `history`");
+    fn current_state(&self) -> Option<String> {
+        get_current_state(&self.history)
+    }
 
-        // update the scroll position before pushing the new state
-        update_scroll(&w, &h);
+    fn push(&self, state: String) {
+        self.push_entry(state, None);
+    }
 
-        if push_state_and_url(&self.history, &self.create_state(), self.full_path(&state)).is_ok() {
-            self.handle_nav();
-        }
+    fn push_with_state(&self, route: String, state: String) {
+        self.push_entry(route, Some(state));
     }
 
     fn replace(&self, state: String) {
-        if replace_state_with_url(
-            &self.history,
-            &self.create_state(),
-            Some(&self.full_path(&state)),
-        )
-        .is_ok()
-        {
-            self.handle_nav();
-        }
+        self.replace_entry(state, None);
+    }
+
+    fn replace_with_state(&self, path: String, state: String) {
+        self.replace_entry(path, Some(state));
     }
 
     fn external(&self, url: String) -> bool {
         self.window.location().set_href(&url).is_ok()
     }
 
+    fn disable_scroll_restoration(&self) {
+        self.do_scroll_restoration.set(false);
+    }
+
     fn updater(&self, callback: std::sync::Arc<dyn Fn() + Send + Sync>) {
         let w = self.window.clone();
         let h = self.history.clone();
-        let d = self.do_scroll_restoration;
+        let d = self.do_scroll_restoration.clone();
 
         let function = Closure::wrap(Box::new(move |_| {
             (*callback)();
-            if d && let Some([x, y]) = get_current(&h) {
+            if d.get()
+                && let Some([x, y]) = get_current(&h)
+            {
                 ScrollPosition { x, y }.scroll_to(w.clone())
             }
         }) as Box<dyn FnMut(Event)>);
@@ -366,26 +476,35 @@ impl ScrollPosition {
     }
 }
 
+/// The state of every history entry is the scroll position of the entry, its position in the history stack and the
+/// serialized state the app attached to it, if any.
+fn state_array(value: &[f64; 3], state: Option<&str>) -> js_sys::Array {
+    let array = js_sys::Array::new();
+    for value in value {
+        array.push(&JsValue::from(*value));
+    }
+    if let Some(state) = state {
+        array.push(&JsValue::from_str(state));
+    }
+    array
+}
+
 pub(crate) fn replace_state_with_url(
     history: &History,
-    value: &[f64; 2],
+    value: &[f64; 3],
+    state: Option<&str>,
     url: Option<&str>,
 ) -> Result<(), JsValue> {
-    let position = js_sys::Array::new();
-    position.push(&JsValue::from(value[0]));
-    position.push(&JsValue::from(value[1]));
-    history.replace_state_with_url(&position, "", url)
+    history.replace_state_with_url(&state_array(value, state), "", url)
 }
 
 pub(crate) fn push_state_and_url(
     history: &History,
-    value: &[f64; 2],
+    value: &[f64; 3],
+    state: Option<&str>,
     url: String,
 ) -> Result<(), JsValue> {
-    let position = js_sys::Array::new();
-    position.push(&JsValue::from(value[0]));
-    position.push(&JsValue::from(value[1]));
-    history.push_state_with_url(&position, "", Some(&url))
+    history.push_state_with_url(&state_array(value, state), "", Some(&url))
 }
 
 pub(crate) fn get_current(history: &History) -> Option<[f64; 2]> {
@@ -398,7 +517,34 @@ pub(crate) fn get_current(history: &History) -> Option<[f64; 2]> {
     })
 }
 
+/// The position of the current entry in the history stack. Entries that were not created by dioxus are at 0.
+pub(crate) fn get_current_index(history: &History) -> usize {
+    history
+        .state()
+        .ok()
+        .and_then(|state| state.dyn_into::<js_sys::Array>().ok())
+        .and_then(|state| state.get(2).as_f64())
+        .map(|index| index as usize)
+        .unwrap_or_default()
+}
+
+/// The serialized state the app attached to the current entry.
+pub(crate) fn get_current_state(history: &History) -> Option<String> {
+    history
+        .state()
+        .ok()
+        .and_then(|state| state.dyn_into::<js_sys::Array>().ok())
+        .and_then(|state| state.get(3).as_string())
+}
+
 fn update_scroll(window: &Window, history: &History) {
     let scroll = ScrollPosition::of_window(window);
-    let _ = replace_state_with_url(history, &[scroll.x, scroll.y], None);
+    let index = get_current_index(history) as f64;
+    let state = get_current_state(history);
+    let _ = replace_state_with_url(
+        history,
+        &[scroll.x, scroll.y, index],
+        state.as_deref(),
+        None,
+    );
 }
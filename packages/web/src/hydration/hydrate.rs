@@ -182,10 +182,22 @@ impl WebsysDom {
             .current_path
             .clone_from(&suspense_path);
         self.start_hydration_at_scope(root_scope, dom, children)?;
+        self.create_hydrated_portals(dom);
 
         Ok(())
     }
 
+    /// Create the children of the portals that were hydrated inside of their targets
+    pub(crate) fn create_hydrated_portals(&mut self, dom: &mut VirtualDom) {
+        if self.hydrated_portals.is_empty() {
+            return;
+        }
+        for portal in std::mem::take(&mut self.hydrated_portals) {
+            dom.create_hydrated_portal(self, portal);
+        }
+        self.flush_edits();
+    }
+
     fn start_hydration_at_scope(
         &mut self,
         scope: &ScopeState,
@@ -362,7 +374,14 @@ impl WebsysDom {
                 let scope = comp
                     .mounted_scope(dynamic_node_index, vnode, dom)
                     .ok_or(VNodeNotInitialized)?;
-                self.rehydrate_scope(scope, dom, ids, to_mount)?;
+                // The server rendered a placeholder instead of the children of portals. Their children are created
+                // inside of their target once the rest of the tree is hydrated
+                if let Some(placeholder) = dom.portal_placeholder(scope.id()) {
+                    ids.push(placeholder.0 as u32);
+                    self.hydrated_portals.push(scope.id());
+                } else {
+                    self.rehydrate_scope(scope, dom, ids, to_mount)?;
+                }
             }
             dioxus_core::DynamicNode::Fragment(fragment) => {
                 for vnode in fragment {
@@ -36,6 +36,13 @@ pub use data_transfer::*;
 #[cfg(all(feature = "devtools", debug_assertions))]
 mod devtools;
 
+mod work_loop;
+
+#[cfg(feature = "custom-element")]
+mod custom_element;
+#[cfg(feature = "custom-element")]
+pub use custom_element::{CustomElement, CustomElementHost, custom_element_host, slot_children};
+
 mod hydration;
 #[allow(unused)]
 pub use hydration::*;
@@ -52,20 +59,28 @@ pub use hydration::*;
 /// ```
 pub async fn run(mut virtual_dom: VirtualDom, web_config: Config) -> ! {
     #[cfg(all(feature = "devtools", debug_assertions))]
-    let mut hotreload_rx = devtools::init(&web_config);
+    let mut hotreload_rx = match web_config.devtools {
+        true => devtools::init(&web_config, virtual_dom.runtime()),
+        false => futures_channel::mpsc::unbounded().1,
+    };
 
     #[cfg(feature = "document")]
     if let Some(history) = web_config.history.clone() {
         virtual_dom.in_scope(ScopeId::ROOT, || dioxus_core::provide_context(history));
     }
 
+    // Hydration is on by default if the hydrate feature is enabled
+    let should_hydrate = web_config.hydrate;
+
+    // When hydrating, the fullstack document is provided along with the hydration data instead
     #[cfg(feature = "document")]
-    virtual_dom.in_runtime(document::init_document);
+    if !should_hydrate {
+        virtual_dom.in_runtime(document::init_document);
+    }
 
     let runtime = virtual_dom.runtime();
 
-    // If the hydrate feature is enabled, launch the client with hydration enabled
-    let should_hydrate = web_config.hydrate || cfg!(feature = "hydrate");
+    let work_loop = web_config.time_slice.map(work_loop::WorkLoop::new);
 
     let mut websys_dom = WebsysDom::new(web_config, runtime);
 
@@ -148,6 +163,7 @@ pub async fn run(mut virtual_dom: VirtualDom, web_config: Config) -> ! {
 
             let rx = websys_dom.rehydrate(&virtual_dom).unwrap();
             hydration_receiver = Some(rx);
+            websys_dom.create_hydrated_portals(&mut virtual_dom);
 
             #[cfg(feature = "mounted")]
             {
@@ -243,22 +259,17 @@ pub async fn run(mut virtual_dom: VirtualDom, web_config: Config) -> ! {
             websys_dom.rehydrate_streaming(hydration_data, &mut virtual_dom);
         }
 
-        // Todo: This is currently disabled because it has a negative impact on response times for events but it could be re-enabled for tasks
-        // Jank free rendering
-        //
-        // 1. wait for the browser to give us "idle" time
-        // 2. During idle time, diff the dom
-        // 3. Stop diffing if the deadline is exceeded
-        // 4. Wait for the animation frame to patch the dom
-
-        // wait for the mainthread to schedule us in
-        // let deadline = work_loop.wait_for_idle_time().await;
-
-        // run the virtualdom work phase until the frame deadline is reached
-        virtual_dom.render_immediate(&mut websys_dom);
-
-        // wait for the animation frame to fire so we can apply our changes
-        // work_loop.wait_for_raf().await;
+        match &work_loop {
+            // Render in slices, applying the mutations and yielding to the browser between each one
+            Some(work_loop) => {
+                let mut deadline = work_loop.start_slice();
+                while !virtual_dom.render_with_deadline(&mut websys_dom, || deadline.reached()) {
+                    websys_dom.flush_edits();
+                    deadline = work_loop.yield_to_browser().await;
+                }
+            }
+            None => virtual_dom.render_immediate(&mut websys_dom),
+        }
 
         websys_dom.flush_edits();
     }
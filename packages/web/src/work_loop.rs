@@ -0,0 +1,97 @@
+//! Time sliced rendering for the web renderer.
+//!
+//! When time slicing is enabled, the renderer diffs dirty scopes until the slice's deadline, applies the mutations it
+//! has so far and then gives the main thread back to the browser so it can handle input and paint before the next
+//! slice. The browser tells us when it is idle with `requestIdleCallback`. Browsers without it fall back to a
+//! `MessageChannel` message, which runs as soon as the browser has handled any pending events.
+
+use std::time::Duration;
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+use web_sys::{IdleDeadline, IdleRequestOptions, MessageChannel};
+
+/// The longest time we wait for the browser to become idle before we continue rendering anyway
+const IDLE_TIMEOUT_MS: u32 = 50;
+
+pub(crate) struct WorkLoop {
+    budget_ms: f64,
+    /// The channel used to yield in browsers without `requestIdleCallback`
+    channel: Option<MessageChannel>,
+}
+
+impl WorkLoop {
+    pub(crate) fn new(budget: Duration) -> Self {
+        let window = web_sys::window().expect("should have access to the window");
+        let has_idle_callback =
+            js_sys::Reflect::has(&window, &JsValue::from_str("requestIdleCallback"))
+                .unwrap_or(false);
+        let channel = match has_idle_callback {
+            true => None,
+            false => {
+                Some(MessageChannel::new().expect("should be able to create a message channel"))
+            }
+        };
+
+        Self {
+            budget_ms: budget.as_secs_f64() * 1000.0,
+            channel,
+        }
+    }
+
+    /// Start a slice of work right away
+    pub(crate) fn start_slice(&self) -> Deadline {
+        Deadline {
+            end: now() + self.budget_ms,
+        }
+    }
+
+    /// Give the main thread back to the browser, and start a new slice once it is idle
+    pub(crate) async fn yield_to_browser(&self) -> Deadline {
+        let (tx, rx) = futures_channel::oneshot::channel::<f64>();
+
+        match &self.channel {
+            Some(channel) => {
+                let callback = Closure::once_into_js(move || {
+                    _ = tx.send(0.0);
+                });
+                channel
+                    .port1()
+                    .set_onmessage(Some(callback.unchecked_ref()));
+                _ = channel.port2().post_message(&JsValue::NULL);
+            }
+            None => {
+                let callback = Closure::once_into_js(move |deadline: IdleDeadline| {
+                    _ = tx.send(deadline.time_remaining());
+                });
+                let options = IdleRequestOptions::new();
+                options.set_timeout(IDLE_TIMEOUT_MS);
+                let window = web_sys::window().expect("should have access to the window");
+                _ = window.request_idle_callback_with_options(callback.unchecked_ref(), &options);
+            }
+        }
+
+        // If the browser is idle for longer than our budget, use all of the idle time
+        let idle_ms = rx.await.unwrap_or_default();
+        Deadline {
+            end: now() + idle_ms.max(self.budget_ms),
+        }
+    }
+}
+
+/// The end of a slice of work
+pub(crate) struct Deadline {
+    end: f64,
+}
+
+impl Deadline {
+    pub(crate) fn reached(&self) -> bool {
+        now() >= self.end
+    }
+}
+
+/// The current time in milliseconds
+fn now() -> f64 {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map(|performance| performance.now())
+        .unwrap_or_else(js_sys::Date::now)
+}
@@ -0,0 +1,167 @@
+//! Run with `wasm-pack test --headless --chrome packages/web --features custom-element`
+#![cfg(all(target_arch = "wasm32", feature = "custom-element"))]
+
+use dioxus::prelude::*;
+use dioxus_web::{CustomElement, custom_element_host};
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen(inline_js = r#"
+export function shadow_text(element) {
+    return element.shadowRoot.textContent;
+}
+
+export function click_button(element) {
+    element.shadowRoot.querySelector("button").click();
+}
+
+export function record_events(target, name) {
+    const details = [];
+    target.addEventListener(name, (event) => details.push(JSON.stringify(event.detail)));
+    return details;
+}
+"#)]
+extern "C" {
+    fn shadow_text(element: &HtmlElement) -> String;
+    fn click_button(element: &HtmlElement);
+    fn record_events(target: &web_sys::EventTarget, name: &str) -> js_sys::Array;
+}
+
+#[derive(Props, Clone, PartialEq, Deserialize)]
+struct GreetingProps {
+    #[serde(default)]
+    name: String,
+}
+
+#[allow(non_snake_case)]
+fn Greeting(props: GreetingProps) -> Element {
+    let mut clicks = use_signal(|| 0);
+    let host = custom_element_host().unwrap();
+    let name = props.name.clone();
+    rsx! {
+        button {
+            onclick: move |_| {
+                clicks += 1;
+                host.dispatch_event("greet", (name.clone(), clicks()));
+            },
+            "Hello {props.name} {clicks}"
+        }
+    }
+}
+
+/// Define the element once for every test on the page
+fn greeting(name: &str) -> HtmlElement {
+    static DEFINE: std::sync::Once = std::sync::Once::new();
+    DEFINE.call_once(|| {
+        CustomElement::new("dx-greeting", Greeting)
+            .observed_attributes(["name"])
+            .define()
+    });
+
+    let element: HtmlElement = document()
+        .create_element("dx-greeting")
+        .unwrap()
+        .unchecked_into();
+    element.set_attribute("name", name).unwrap();
+    element
+}
+
+fn document() -> web_sys::Document {
+    web_sys::window().unwrap().document().unwrap()
+}
+
+/// Wait for the virtual doms on the page to handle any pending work
+async fn settle() {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        web_sys::window()
+            .unwrap()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, 50)
+            .unwrap();
+    });
+    wasm_bindgen_futures::JsFuture::from(promise).await.unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn attribute_changes_rerender_the_element() {
+    let element = greeting("Ada");
+    document().body().unwrap().append_child(&element).unwrap();
+    settle().await;
+    assert_eq!(shadow_text(&element), "Hello Ada 0");
+
+    element.set_attribute("name", "Grace").unwrap();
+    settle().await;
+    assert_eq!(shadow_text(&element), "Hello Grace 0");
+
+    element.remove();
+}
+
+#[wasm_bindgen_test]
+async fn events_are_dispatched_from_the_element() {
+    let body = document().body().unwrap();
+    let details = record_events(&body, "greet");
+    let element = greeting("Ada");
+    body.append_child(&element).unwrap();
+    settle().await;
+
+    click_button(&element);
+    settle().await;
+    assert_eq!(shadow_text(&element), "Hello Ada 1");
+    assert_eq!(details.length(), 1);
+    assert_eq!(details.get(0).as_string().unwrap(), r#"["Ada",1]"#);
+
+    element.remove();
+}
+
+#[wasm_bindgen_test]
+async fn moving_the_element_keeps_its_state() {
+    let body = document().body().unwrap();
+    let element = greeting("Ada");
+    body.append_child(&element).unwrap();
+    settle().await;
+    click_button(&element);
+    settle().await;
+    assert_eq!(shadow_text(&element), "Hello Ada 1");
+
+    // Moving the element disconnects and reconnects it, but the virtual dom keeps running
+    let container = document().create_element("div").unwrap();
+    body.append_child(&container).unwrap();
+    container.append_child(&element).unwrap();
+    settle().await;
+    assert_eq!(shadow_text(&element), "Hello Ada 1");
+    click_button(&element);
+    settle().await;
+    assert_eq!(shadow_text(&element), "Hello Ada 2");
+
+    // Removing the element drops the virtual dom, so it starts over when it is added again
+    element.remove();
+    settle().await;
+    body.append_child(&element).unwrap();
+    settle().await;
+    assert_eq!(shadow_text(&element), "Hello Ada 0");
+
+    container.remove();
+    element.remove();
+}
+
+#[wasm_bindgen_test]
+async fn elements_leave_the_page_history_alone() {
+    let history = web_sys::window().unwrap().history().unwrap();
+    history
+        .replace_state(&JsValue::from_str("host state"), "")
+        .unwrap();
+    let scroll_restoration = history.scroll_restoration().unwrap();
+
+    let element = greeting("Ada");
+    document().body().unwrap().append_child(&element).unwrap();
+    settle().await;
+    assert_eq!(shadow_text(&element), "Hello Ada 0");
+
+    assert_eq!(history.state().unwrap(), JsValue::from_str("host state"));
+    assert_eq!(history.scroll_restoration().unwrap(), scroll_restoration);
+
+    element.remove();
+}